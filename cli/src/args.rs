@@ -1,6 +1,8 @@
 use anchor_client::solana_sdk::pubkey::Pubkey;
 use anchor_client::Cluster;
 use clap::*;
+use lb_clmm::instructions::set_position_vesting::VestingParameter;
+use lb_clmm::state::admin_config::AdminRole;
 
 #[derive(Parser, Debug)]
 pub struct ConfigOverride {
@@ -62,6 +64,38 @@ fn parse_bin_liquidity_distribution(src: &str) -> Result<(i32, f64, f64), Error>
     Ok((delta_id, dist_x, dist_y))
 }
 
+fn parse_bin_weight(src: &str) -> Result<(i32, u16), Error> {
+    let mut parsed_str: Vec<&str> = src.split(',').collect();
+
+    let weight = parsed_str
+        .pop()
+        .and_then(|s| s.parse::<u16>().ok())
+        .ok_or_else(|| clap::error::Error::new(error::ErrorKind::InvalidValue))?;
+
+    let bin_id = parsed_str
+        .pop()
+        .and_then(|s| s.parse::<i32>().ok())
+        .ok_or_else(|| clap::error::Error::new(error::ErrorKind::InvalidValue))?;
+
+    Ok((bin_id, weight))
+}
+
+fn parse_bin_amount(src: &str) -> Result<(i32, u64), Error> {
+    let mut parsed_str: Vec<&str> = src.split(',').collect();
+
+    let amount = parsed_str
+        .pop()
+        .and_then(|s| s.parse::<u64>().ok())
+        .ok_or_else(|| clap::error::Error::new(error::ErrorKind::InvalidValue))?;
+
+    let bin_id = parsed_str
+        .pop()
+        .and_then(|s| s.parse::<i32>().ok())
+        .ok_or_else(|| clap::error::Error::new(error::ErrorKind::InvalidValue))?;
+
+    Ok((bin_id, amount))
+}
+
 #[derive(Debug, Clone, ValueEnum)]
 pub enum SelectiveRounding {
     Up,
@@ -69,6 +103,79 @@ pub enum SelectiveRounding {
     None,
 }
 
+/// Shape of the liquidity distributed over the bins.
+#[derive(Debug, Clone, ValueEnum)]
+pub enum LiquidityStrategy {
+    Spot,
+    Curve,
+    BidAsk,
+}
+
+/// Linear vesting schedule of the liquidity seeded by operator.
+#[derive(Args, Debug, Clone, Copy)]
+pub struct VestingArgs {
+    /// Point which the linear vesting starts. No vesting when not set.
+    #[clap(long, requires = "vesting_end_point")]
+    pub vesting_start_point: Option<u64>,
+    /// Point before which none of the liquidity can be withdrawn. Default to the vesting start point.
+    #[clap(long, requires = "vesting_start_point")]
+    pub vesting_cliff_point: Option<u64>,
+    /// Point which the liquidity is fully vested.
+    #[clap(long, requires = "vesting_start_point")]
+    pub vesting_end_point: Option<u64>,
+}
+
+impl VestingArgs {
+    pub fn to_vesting_parameter(self) -> Option<VestingParameter> {
+        let start_point = self.vesting_start_point?;
+        Some(VestingParameter {
+            start_point,
+            cliff_point: self.vesting_cliff_point.unwrap_or(start_point),
+            end_point: self.vesting_end_point?,
+        })
+    }
+}
+
+/// Range of the bins to deposit to, by UI price or by bin id.
+#[derive(Args, Debug, Clone, Copy)]
+#[group(required = true, multiple = true)]
+pub struct DepositRangeArgs {
+    /// Lower bound of the UI price.
+    #[clap(long, requires = "max_price", conflicts_with_all = ["min_bin_id", "max_bin_id"])]
+    pub min_price: Option<f64>,
+    /// Upper bound of the UI price.
+    #[clap(long, requires = "min_price")]
+    pub max_price: Option<f64>,
+    /// Lower bound of the bin range.
+    #[clap(long, allow_negative_numbers = true, requires = "max_bin_id")]
+    pub min_bin_id: Option<i32>,
+    /// Upper bound of the bin range.
+    #[clap(long, allow_negative_numbers = true, requires = "min_bin_id")]
+    pub max_bin_id: Option<i32>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum DepositRange {
+    Price { min_price: f64, max_price: f64 },
+    BinId { min_bin_id: i32, max_bin_id: i32 },
+}
+
+impl DepositRangeArgs {
+    pub fn to_deposit_range(self) -> Option<DepositRange> {
+        if let (Some(min_bin_id), Some(max_bin_id)) = (self.min_bin_id, self.max_bin_id) {
+            return Some(DepositRange::BinId {
+                min_bin_id,
+                max_bin_id,
+            });
+        }
+
+        Some(DepositRange::Price {
+            min_price: self.min_price?,
+            max_price: self.max_price?,
+        })
+    }
+}
+
 #[derive(Parser, Debug)]
 pub enum Command {
     /// Create a new liquidity pair.
@@ -133,6 +240,19 @@ pub enum Command {
         /// NFT mint for alpha access
         nft_mint: Option<Pubkey>,
     },
+    /// Initialize tokenized position for the given liquidity pair based on bin range. The ownership of the position is represented by an NFT minted to the wallet.
+    InitializeTokenizedPosition {
+        /// Address of the liquidity pair.
+        lb_pair: Pubkey,
+        /// Lower bound of the bin range.
+        #[clap(long, allow_negative_numbers = true)]
+        lower_bin_id: i32,
+        /// Width of the position. Start with 1 until 70.
+        width: i32,
+        /// URI of the position NFT metadata.
+        #[clap(long, default_value = "")]
+        uri: String,
+    },
     /// Deposit liquidity to the position of the given liquidity pair.
     AddLiquidity {
         /// Address of the liquidity pair.
@@ -151,6 +271,91 @@ pub enum Command {
         #[clap(long, value_parser = parse_bin_liquidity_distribution, value_delimiter = ' ', allow_hyphen_values = true)]
         bin_liquidity_distribution: Vec<(i32, f64, f64)>,
     },
+    /// Deposit liquidity to the position of the given liquidity pair, distributed to the bins by weight. The amounts are split at the active
+    /// bin, token Y to the bins below and token X to the bins above, following the composition of the active bin.
+    AddLiquidityByWeight {
+        /// Address of the liquidity pair.
+        lb_pair: Pubkey,
+        /// Position for the deposit.
+        position: Pubkey,
+        /// Amount of token X to be deposited.
+        amount_x: u64,
+        /// Amount of token Y to be deposited.
+        amount_y: u64,
+        /// Weight of the bins. "<BIN_ID,WEIGHT BIN_ID,WEIGHT ...>", in ascending bin id order.
+        /// For example: --bin-liquidity-distribution "-1,100 0,200 1,100"
+        #[clap(long, value_parser = parse_bin_weight, value_delimiter = ' ', allow_hyphen_values = true)]
+        bin_liquidity_distribution: Vec<(i32, u16)>,
+        /// Max active bin slippage allowed.
+        #[clap(long, default_value_t = 3)]
+        max_active_bin_slippage: i32,
+        /// Print the preview of the deposit without sending the transaction.
+        #[clap(long)]
+        dry_run: bool,
+    },
+    /// Deposit only token X, or only token Y with `--deposit-for-y`, to the position of the given liquidity pair, distributed to the bins by weight.
+    AddLiquidityOneSide {
+        /// Address of the liquidity pair.
+        lb_pair: Pubkey,
+        /// Position for the deposit.
+        position: Pubkey,
+        /// Amount of the token to be deposited.
+        amount: u64,
+        /// Deposit token Y, to the bins up to the active bin, instead of token X.
+        #[clap(long)]
+        deposit_for_y: bool,
+        /// Weight of the bins. "<BIN_ID,WEIGHT BIN_ID,WEIGHT ...>", in ascending bin id order.
+        /// For example: --bin-liquidity-distribution "1,100 2,200 3,100"
+        #[clap(long, value_parser = parse_bin_weight, value_delimiter = ' ', allow_hyphen_values = true)]
+        bin_liquidity_distribution: Vec<(i32, u16)>,
+        /// Max active bin slippage allowed.
+        #[clap(long, default_value_t = 3)]
+        max_active_bin_slippage: i32,
+    },
+    /// Deposit the exact amount of each bin, only token X, or only token Y with `--deposit-for-y`, to the position of the given liquidity pair.
+    /// Amounts beyond u32 are rounded down to a multiple of the decompress multiplier.
+    AddLiquidityOneSidePrecise {
+        /// Address of the liquidity pair.
+        lb_pair: Pubkey,
+        /// Position for the deposit.
+        position: Pubkey,
+        /// Deposit token Y instead of token X.
+        #[clap(long)]
+        deposit_for_y: bool,
+        /// Amount of the bins. "<BIN_ID,AMOUNT BIN_ID,AMOUNT ...>"
+        /// For example: --bin-amounts "1,1000000 2,2000000"
+        #[clap(long, value_parser = parse_bin_amount, value_delimiter = ' ', allow_hyphen_values = true)]
+        bin_amounts: Vec<(i32, u64)>,
+    },
+    /// Add liquidity to the pair by strategy, over a price range or bin range. Only token X, or only token Y, is deposited when the other amount is 0.
+    /// Missing bin arrays, and the position when not given, are created after printing the preview of the deposit.
+    AddLiquidityByStrategy {
+        /// Address of the liquidity pair.
+        lb_pair: Pubkey,
+        /// UI amount of token X to be deposited.
+        #[clap(long, default_value_t = 0.0)]
+        amount_x: f64,
+        /// UI amount of token Y to be deposited.
+        #[clap(long, default_value_t = 0.0)]
+        amount_y: f64,
+        #[clap(flatten)]
+        range: DepositRangeArgs,
+        /// Shape of the liquidity distributed over the bins.
+        #[clap(long, value_enum, default_value_t = LiquidityStrategy::Spot)]
+        strategy: LiquidityStrategy,
+        /// Deposit only token Y to the active bin, instead of following the composition of the active bin. Rejected for deposit of a single token.
+        #[clap(long)]
+        imbalanced: bool,
+        /// Position for the deposit, which must cover the range. A new position covering the range is created when not set.
+        #[clap(long)]
+        position: Option<Pubkey>,
+        /// Max active bin slippage allowed.
+        #[clap(long, default_value_t = 3)]
+        max_active_bin_slippage: i32,
+        /// Print the preview of the deposit without sending any transaction.
+        #[clap(long)]
+        dry_run: bool,
+    },
     /// Remove liquidity from the position of the given liquidity pair.
     RemoveLiquidity {
         /// Address of the liquidity pair.
@@ -222,11 +427,39 @@ pub enum Command {
         /// Address of the position.
         position: Pubkey,
     },
+    /// Transfer ownership of the position to another wallet, without withdrawing the liquidity.
+    TransferPositionOwner {
+        /// Address of the position.
+        position: Pubkey,
+        /// Address of the new owner.
+        new_owner: Pubkey,
+    },
     /// Claim fee
     ClaimFee {
         /// Address of the position.
         position: Pubkey,
     },
+    /// Claim fee and rewards of every position of the wallet, batching positions of the same pair into minimal transactions.
+    ClaimAll,
+    /// Compound the pending fee of the position into liquidity of its bins.
+    CompoundFees {
+        /// Address of the position.
+        position: Pubkey,
+        /// Strategy to distribute the fee into the bins of the position.
+        #[clap(long, value_enum, default_value_t = LiquidityStrategy::Spot)]
+        strategy: LiquidityStrategy,
+        /// Max active bin slippage allowed.
+        #[clap(long, default_value_t = 3)]
+        max_active_bin_slippage: i32,
+        /// Compound every interval seconds. Compound once when not set.
+        #[clap(long)]
+        interval: Option<u64>,
+    },
+    /// Close every bin array of the pair without liquidity, refunding the rent to the funder of each bin array.
+    CloseEmptyBinArrays {
+        /// Address of the pair
+        lb_pair: Pubkey,
+    },
     /// Increase an oracle observation sample length
     IncreaseLength {
         /// Address of the pair
@@ -234,6 +467,11 @@ pub enum Command {
         /// Length to add
         length_to_add: u64,
     },
+    /// Show the history covered by the oracle of the pair, and the growth required to cover the target history duration
+    ShowOracle {
+        /// Address of the pair
+        lb_pair: Pubkey,
+    },
 
     ShowPresetParameter {
         /// Preset parameter pubkey. Get from ListAllBinStep
@@ -342,6 +580,8 @@ pub enum Command {
         /// lock release point
         #[clap(long)]
         lock_release_point: u64,
+        #[clap(flatten)]
+        vesting: VestingArgs,
         /// Max retries
         #[clap(long)]
         max_retries: u16,
@@ -392,6 +632,8 @@ pub enum Command {
         /// lock release point
         #[clap(long)]
         lock_release_point: u64,
+        #[clap(flatten)]
+        vesting: VestingArgs,
         /// fee owner
         #[clap(long)]
         fee_owner: Pubkey,
@@ -413,6 +655,26 @@ pub struct Cli {
     pub command: Command,
 }
 
+/// Admin role of the admin config.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum Role {
+    FeeAdmin,
+    PauseGuardian,
+    RewardAdmin,
+    LaunchPoolAdmin,
+}
+
+impl From<Role> for AdminRole {
+    fn from(role: Role) -> Self {
+        match role {
+            Role::FeeAdmin => AdminRole::FeeAdmin,
+            Role::PauseGuardian => AdminRole::PauseGuardian,
+            Role::RewardAdmin => AdminRole::RewardAdmin,
+            Role::LaunchPoolAdmin => AdminRole::LaunchPoolAdmin,
+        }
+    }
+}
+
 #[derive(Debug, Parser)]
 pub enum AdminCommand {
     /// Create a new permission liquidity pair. It allow liquidity fragmentation with exact bin step.
@@ -531,4 +793,68 @@ pub enum AdminCommand {
         /// Preactivation swap address
         pre_activation_swap_address: Pubkey,
     },
+
+    /// Configure the circuit breaker of the pair. A halted pair resume swap immediately.
+    UpdateCircuitBreaker {
+        /// Address of the pair
+        lb_pair: Pubkey,
+        /// Maximum number of bins the active bin can move away from the time weighted active bin. 0 = disabled.
+        max_bin_movement: u16,
+        /// Time window of the time weighted active bin, in seconds
+        window: u16,
+        /// Duration swap stay halted after the circuit breaker tripped, in seconds
+        cooldown: u32,
+    },
+
+    /// Show the circuit breaker state of the pair
+    ShowCircuitBreaker {
+        /// Address of the pair
+        lb_pair: Pubkey,
+    },
+
+    /// Configure the oracle sampling of the pair
+    UpdateOracleParameters {
+        /// Address of the pair
+        lb_pair: Pubkey,
+        /// Duration before a new observation sample is created, in seconds
+        sample_lifetime: u16,
+        /// Duration of the history the oracle should cover, in seconds
+        target_history_duration: u32,
+        /// Let swaps grow the oracle until the target history duration is covered
+        #[clap(long)]
+        auto_growth: bool,
+    },
+
+    /// Initialize the admin config. Once initialized, the authority of each role replaces the predefined admins.
+    InitializeAdminConfig {
+        /// Authority of the fee admin role
+        #[clap(long)]
+        fee_admin: Pubkey,
+        /// Authority of the pause guardian role
+        #[clap(long)]
+        pause_guardian: Pubkey,
+        /// Authority of the reward admin role
+        #[clap(long)]
+        reward_admin: Pubkey,
+        /// Authority of the launch pool admin role
+        #[clap(long)]
+        launch_pool_admin: Pubkey,
+    },
+
+    /// Propose a new authority for the role. Propose 11111111111111111111111111111111 to cancel the pending change.
+    ProposeAdminRole {
+        #[clap(value_enum)]
+        role: Role,
+        /// New authority of the role
+        new_authority: Pubkey,
+    },
+
+    /// Accept the role as its pending authority
+    AcceptAdminRole {
+        #[clap(value_enum)]
+        role: Role,
+    },
+
+    /// Show the authorities of the admin config
+    ShowAdminConfig,
 }
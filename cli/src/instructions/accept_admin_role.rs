@@ -0,0 +1,26 @@
+use anchor_client::solana_client::rpc_config::RpcSendTransactionConfig;
+use anchor_client::solana_sdk::signer::Signer;
+use anchor_client::Program;
+use anyhow::*;
+use lb_clmm::state::admin_config::AdminRole;
+use std::ops::Deref;
+
+pub async fn accept_admin_role<C: Deref<Target = impl Signer> + Clone>(
+    role: AdminRole,
+    program: &Program<C>,
+    transaction_config: RpcSendTransactionConfig,
+) -> Result<()> {
+    let ix = commons::instructions::admin_config::accept_admin_role(program.payer(), role);
+
+    let request_builder = program.request();
+    let signature = request_builder
+        .instruction(ix)
+        .send_with_spinner_and_config(transaction_config)
+        .await;
+
+    println!("Accept {:?}. Signature: {:#?}", role, signature);
+
+    signature?;
+
+    Ok(())
+}
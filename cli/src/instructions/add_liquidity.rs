@@ -9,7 +9,11 @@ use lb_clmm::accounts;
 use lb_clmm::instruction;
 use lb_clmm::instructions::deposit::add_liquidity::{BinLiquidityDistribution, LiquidityParameter};
 
-use crate::instructions::utils::{get_bin_arrays_for_position, get_or_create_ata};
+use crate::instructions::utils::{
+    get_bin_arrays_for_position, get_liquidity_transfer_hook_accounts, get_mint_accounts,
+    get_or_create_ata,
+};
+use commons::token_2022::get_token_program;
 use lb_clmm::constants::BASIS_POINT_MAX;
 use lb_clmm::state::lb_pair::LbPair;
 use lb_clmm::utils::pda::{derive_bin_array_bitmap_extension, derive_event_authority_pda};
@@ -77,6 +81,18 @@ pub async fn add_liquidity<C: Deref<Target = impl Signer> + Clone>(
         Some(bin_array_bitmap_extension)
     };
 
+    let mint_accounts = get_mint_accounts(program, &lb_pair_state).await?;
+    let transfer_hook_accounts = get_liquidity_transfer_hook_accounts(
+        program,
+        lb_pair,
+        &lb_pair_state,
+        &mint_accounts,
+        [user_token_x, user_token_y],
+        [amount_x, amount_y],
+        true,
+    )
+    .await?;
+
     let (event_authority, _bump) = derive_event_authority_pda();
 
     let accounts = accounts::ModifyLiquidity {
@@ -92,9 +108,9 @@ pub async fn add_liquidity<C: Deref<Target = impl Signer> + Clone>(
         sender: program.payer(),
         user_token_x,
         user_token_y,
-        // TODO: token 2022
-        token_x_program: anchor_spl::token::ID,
-        token_y_program: anchor_spl::token::ID,
+        token_x_program: get_token_program(&mint_accounts[0]),
+        token_y_program: get_token_program(&mint_accounts[1]),
+        position_nft_account: None,
         event_authority,
         program: lb_clmm::ID,
     };
@@ -113,6 +129,7 @@ pub async fn add_liquidity<C: Deref<Target = impl Signer> + Clone>(
     let signature = request_builder
         .instruction(compute_budget_ix)
         .accounts(accounts)
+        .accounts(transfer_hook_accounts)
         .args(ix)
         .send_with_spinner_and_config(transaction_config)
         .await;
@@ -0,0 +1,422 @@
+use std::collections::{BTreeSet, HashMap};
+use std::ops::Deref;
+
+use anchor_client::solana_client::rpc_config::RpcSendTransactionConfig;
+use anchor_client::solana_sdk::compute_budget::ComputeBudgetInstruction;
+use anchor_client::{solana_sdk::pubkey::Pubkey, solana_sdk::signer::Signer, Program};
+
+use anchor_spl::token::Mint;
+use anyhow::*;
+use commons::deposit::{
+    preview_add_liquidity_by_strategy, preview_add_liquidity_by_strategy_one_side, DepositPreview,
+};
+use commons::token_2022::{get_token_program, get_transfer_hook_account_metas};
+use lb_clmm::accounts;
+use lb_clmm::constants::MAX_BIN_PER_POSITION;
+use lb_clmm::instruction;
+use lb_clmm::instructions::deposit::add_liquidity_by_strategy::{
+    LiquidityParameterByStrategy, StrategyParameters, StrategyType,
+};
+use lb_clmm::instructions::deposit::add_liquidity_by_strategy_one_side::LiquidityParameterByStrategyOneSide;
+use lb_clmm::state::bin::BinArray;
+use lb_clmm::state::lb_pair::LbPair;
+use lb_clmm::state::position::PositionV2;
+use lb_clmm::utils::pda::{
+    derive_bin_array_bitmap_extension, derive_bin_array_pda, derive_event_authority_pda,
+};
+
+use crate::args::{DepositRange, LiquidityStrategy};
+use crate::instructions::initialize_bin_array::{initialize_bin_array, InitBinArrayParameters};
+use crate::instructions::initialize_position::{initialize_position, InitPositionParameters};
+use crate::instructions::seed_liquidity::{
+    convert_min_max_ui_price_to_min_max_bin_id, get_ui_price_from_id,
+};
+use crate::instructions::utils::{
+    get_liquidity_transfer_hook_accounts, get_mint_accounts, get_or_create_ata,
+};
+use crate::math::ui_amount_to_native_amount;
+
+#[derive(Debug)]
+pub struct AddLiquidityByStrategyParams {
+    pub lb_pair: Pubkey,
+    /// UI amount of token X
+    pub amount_x: f64,
+    /// UI amount of token Y
+    pub amount_y: f64,
+    pub range: DepositRange,
+    pub strategy: LiquidityStrategy,
+    pub imbalanced: bool,
+    pub position: Option<Pubkey>,
+    pub max_active_bin_slippage: i32,
+    pub dry_run: bool,
+}
+
+fn to_strategy_type(strategy: LiquidityStrategy, one_side: bool, imbalanced: bool) -> StrategyType {
+    match (strategy, one_side, imbalanced) {
+        (LiquidityStrategy::Spot, true, _) => StrategyType::SpotOneSide,
+        (LiquidityStrategy::Curve, true, _) => StrategyType::CurveOneSide,
+        (LiquidityStrategy::BidAsk, true, _) => StrategyType::BidAskOneSide,
+        (LiquidityStrategy::Spot, false, false) => StrategyType::SpotBalanced,
+        (LiquidityStrategy::Curve, false, false) => StrategyType::CurveBalanced,
+        (LiquidityStrategy::BidAsk, false, false) => StrategyType::BidAskBalanced,
+        (LiquidityStrategy::Spot, false, true) => StrategyType::SpotImBalanced,
+        (LiquidityStrategy::Curve, false, true) => StrategyType::CurveImBalanced,
+        (LiquidityStrategy::BidAsk, false, true) => StrategyType::BidAskImBalanced,
+    }
+}
+
+pub fn print_deposit_preview(
+    preview: &DepositPreview,
+    lb_pair_state: &LbPair,
+    token_mint_base: &Mint,
+    token_mint_quote: &Mint,
+) {
+    let to_ui_amount =
+        |amount: u64, decimals: u8| amount as f64 / 10u64.pow(decimals.into()) as f64;
+    let to_ui_amount_x = |amount: u64| to_ui_amount(amount, token_mint_base.decimals);
+    let to_ui_amount_y = |amount: u64| to_ui_amount(amount, token_mint_quote.decimals);
+
+    for bin in &preview.bins {
+        let ui_price = get_ui_price_from_id(
+            lb_pair_state.bin_step,
+            bin.bin_id,
+            token_mint_base.decimals.into(),
+            token_mint_quote.decimals.into(),
+        );
+        println!(
+            "Bin {} price {} amount_x {} amount_y {} liquidity_share {}",
+            bin.bin_id,
+            ui_price,
+            to_ui_amount_x(bin.amount_x),
+            to_ui_amount_y(bin.amount_y),
+            bin.liquidity_share
+        );
+    }
+
+    println!(
+        "Total deposit amount_x {} amount_y {}",
+        to_ui_amount_x(preview.total_amount_x),
+        to_ui_amount_y(preview.total_amount_y)
+    );
+    println!(
+        "Composition fee amount_x {} amount_y {}",
+        to_ui_amount_x(preview.composition_fee_x),
+        to_ui_amount_y(preview.composition_fee_y)
+    );
+    println!(
+        "Refund amount_x {} amount_y {}",
+        to_ui_amount_x(preview.refund_x),
+        to_ui_amount_y(preview.refund_y)
+    );
+}
+
+pub async fn add_liquidity_by_strategy<C: Deref<Target = impl Signer> + Clone>(
+    params: AddLiquidityByStrategyParams,
+    program: &Program<C>,
+    transaction_config: RpcSendTransactionConfig,
+) -> Result<()> {
+    let AddLiquidityByStrategyParams {
+        lb_pair,
+        amount_x,
+        amount_y,
+        range,
+        strategy,
+        imbalanced,
+        position,
+        max_active_bin_slippage,
+        dry_run,
+    } = params;
+
+    let lb_pair_state: LbPair = program.account(lb_pair).await?;
+    let token_mint_base: Mint = program.account(lb_pair_state.token_x_mint).await?;
+    let token_mint_quote: Mint = program.account(lb_pair_state.token_y_mint).await?;
+
+    let amount_x = ui_amount_to_native_amount(amount_x, token_mint_base.decimals)
+        .context("Invalid amount x")?;
+    let amount_y = ui_amount_to_native_amount(amount_y, token_mint_quote.decimals)
+        .context("Invalid amount y")?;
+    ensure!(amount_x > 0 || amount_y > 0, "Nothing to deposit");
+
+    let (min_bin_id, max_bin_id) = match range {
+        DepositRange::Price {
+            min_price,
+            max_price,
+        } => convert_min_max_ui_price_to_min_max_bin_id(
+            lb_pair_state.bin_step,
+            min_price,
+            max_price,
+            token_mint_base.decimals,
+            token_mint_quote.decimals,
+        )?,
+        DepositRange::BinId {
+            min_bin_id,
+            max_bin_id,
+        } => (min_bin_id, max_bin_id),
+    };
+    ensure!(min_bin_id <= max_bin_id, "Invalid bin range");
+    let width = max_bin_id - min_bin_id + 1;
+    ensure!(
+        width <= MAX_BIN_PER_POSITION as i32,
+        "Bin range wider than {} bins",
+        MAX_BIN_PER_POSITION
+    );
+
+    // Deposit of a single token follows the one side strategy
+    let deposit_one_side = amount_x == 0 || amount_y == 0;
+    let deposit_for_y = amount_x == 0;
+    ensure!(
+        !(imbalanced && deposit_one_side),
+        "--imbalanced is only for deposit of both tokens"
+    );
+
+    let strategy_parameters = StrategyParameters {
+        min_bin_id,
+        max_bin_id,
+        strategy_type: to_strategy_type(strategy, deposit_one_side, imbalanced),
+        parameteres: [0u8; 64],
+    };
+
+    let mut bin_arrays = HashMap::new();
+    let bin_array_indexes = [min_bin_id, max_bin_id, lb_pair_state.active_id]
+        .into_iter()
+        .map(BinArray::bin_id_to_bin_array_index)
+        .collect::<std::result::Result<BTreeSet<_>, _>>()?;
+    for bin_array_index in bin_array_indexes {
+        let (bin_array, _bump) = derive_bin_array_pda(lb_pair, bin_array_index.into());
+        if let std::result::Result::Ok(bin_array_state) =
+            program.account::<BinArray>(bin_array).await
+        {
+            bin_arrays.insert(bin_array, bin_array_state);
+        }
+    }
+
+    let (preview, liquidity_parameter_one_side, liquidity_parameter) = if deposit_one_side {
+        let liquidity_parameter = LiquidityParameterByStrategyOneSide {
+            amount: amount_x.max(amount_y),
+            active_id: lb_pair_state.active_id,
+            max_active_bin_slippage,
+            strategy_parameters,
+        };
+        let preview = preview_add_liquidity_by_strategy_one_side(
+            lb_pair,
+            &lb_pair_state,
+            &bin_arrays,
+            &liquidity_parameter,
+            deposit_for_y,
+        )?;
+        (preview, Some(liquidity_parameter), None)
+    } else {
+        let liquidity_parameter = LiquidityParameterByStrategy {
+            amount_x,
+            amount_y,
+            active_id: lb_pair_state.active_id,
+            max_active_bin_slippage,
+            strategy_parameters,
+        };
+        let preview = preview_add_liquidity_by_strategy(
+            lb_pair,
+            &lb_pair_state,
+            &bin_arrays,
+            &liquidity_parameter,
+        )?;
+        (preview, None, Some(liquidity_parameter))
+    };
+
+    print_deposit_preview(
+        &preview,
+        &lb_pair_state,
+        &token_mint_base,
+        &token_mint_quote,
+    );
+
+    if dry_run {
+        return Ok(());
+    }
+
+    let (position, lower_bin_id) = match position {
+        Some(position) => {
+            let position_state: PositionV2 = program.account(position).await?;
+            ensure!(
+                position_state.lb_pair == lb_pair,
+                "Position of another pair"
+            );
+            ensure!(
+                position_state.lower_bin_id <= min_bin_id
+                    && max_bin_id <= position_state.upper_bin_id,
+                "Position does not cover the bin range"
+            );
+            (position, position_state.lower_bin_id)
+        }
+        None => {
+            let position = initialize_position(
+                InitPositionParameters {
+                    lb_pair,
+                    lower_bin_id: min_bin_id,
+                    width,
+                    nft_mint: None,
+                },
+                program,
+                transaction_config,
+            )
+            .await?;
+            (position, min_bin_id)
+        }
+    };
+
+    let lower_bin_array_idx = BinArray::bin_id_to_bin_array_index(lower_bin_id)?;
+    let upper_bin_array_idx = lower_bin_array_idx.checked_add(1).context("MathOverflow")?;
+
+    let mut position_bin_arrays = vec![];
+    for bin_array_index in [lower_bin_array_idx, upper_bin_array_idx] {
+        let (bin_array, _bump) = derive_bin_array_pda(lb_pair, bin_array_index.into());
+        if program.rpc().get_account(&bin_array).is_err() {
+            initialize_bin_array(
+                InitBinArrayParameters {
+                    lb_pair,
+                    bin_array_index: bin_array_index.into(),
+                },
+                program,
+                transaction_config,
+            )
+            .await?;
+        }
+        position_bin_arrays.push(bin_array);
+    }
+    let [bin_array_lower, bin_array_upper] = [position_bin_arrays[0], position_bin_arrays[1]];
+
+    let (bin_array_bitmap_extension, _bump) = derive_bin_array_bitmap_extension(lb_pair);
+    let bin_array_bitmap_extension = if program
+        .rpc()
+        .get_account(&bin_array_bitmap_extension)
+        .is_err()
+    {
+        None
+    } else {
+        Some(bin_array_bitmap_extension)
+    };
+
+    let mint_accounts = get_mint_accounts(program, &lb_pair_state).await?;
+    let (event_authority, _bump) = derive_event_authority_pda();
+    let compute_budget_ix = ComputeBudgetInstruction::set_compute_unit_limit(1_400_000);
+
+    let request_builder = program.request().instruction(compute_budget_ix);
+
+    let request_builder = if let Some(liquidity_parameter) = liquidity_parameter_one_side {
+        let (token_mint, mint_account, reserve) = if deposit_for_y {
+            (
+                lb_pair_state.token_y_mint,
+                &mint_accounts[1],
+                lb_pair_state.reserve_y,
+            )
+        } else {
+            (
+                lb_pair_state.token_x_mint,
+                &mint_accounts[0],
+                lb_pair_state.reserve_x,
+            )
+        };
+
+        let user_token =
+            get_or_create_ata(program, transaction_config, token_mint, program.payer()).await?;
+
+        let transfer_hook_accounts = get_transfer_hook_account_metas(
+            &program.async_rpc(),
+            token_mint,
+            mint_account,
+            user_token,
+            reserve,
+            program.payer(),
+            liquidity_parameter.amount,
+        )
+        .await?;
+
+        let accounts = accounts::ModifyLiquidityOneSide {
+            position,
+            lb_pair,
+            bin_array_bitmap_extension,
+            user_token,
+            reserve,
+            token_mint,
+            bin_array_lower,
+            bin_array_upper,
+            sender: program.payer(),
+            token_program: get_token_program(mint_account),
+            position_nft_account: None,
+            event_authority,
+            program: lb_clmm::ID,
+        };
+
+        request_builder
+            .accounts(accounts)
+            .accounts(transfer_hook_accounts)
+            .args(instruction::AddLiquidityByStrategyOneSide {
+                liquidity_parameter,
+            })
+    } else {
+        let liquidity_parameter = liquidity_parameter.context("Missing liquidity parameter")?;
+
+        let user_token_x = get_or_create_ata(
+            program,
+            transaction_config,
+            lb_pair_state.token_x_mint,
+            program.payer(),
+        )
+        .await?;
+
+        let user_token_y = get_or_create_ata(
+            program,
+            transaction_config,
+            lb_pair_state.token_y_mint,
+            program.payer(),
+        )
+        .await?;
+
+        let transfer_hook_accounts = get_liquidity_transfer_hook_accounts(
+            program,
+            lb_pair,
+            &lb_pair_state,
+            &mint_accounts,
+            [user_token_x, user_token_y],
+            [amount_x, amount_y],
+            true,
+        )
+        .await?;
+
+        let accounts = accounts::ModifyLiquidity {
+            bin_array_lower,
+            bin_array_upper,
+            lb_pair,
+            bin_array_bitmap_extension,
+            position,
+            reserve_x: lb_pair_state.reserve_x,
+            reserve_y: lb_pair_state.reserve_y,
+            token_x_mint: lb_pair_state.token_x_mint,
+            token_y_mint: lb_pair_state.token_y_mint,
+            sender: program.payer(),
+            user_token_x,
+            user_token_y,
+            token_x_program: get_token_program(&mint_accounts[0]),
+            token_y_program: get_token_program(&mint_accounts[1]),
+            position_nft_account: None,
+            event_authority,
+            program: lb_clmm::ID,
+        };
+
+        request_builder
+            .accounts(accounts)
+            .accounts(transfer_hook_accounts)
+            .args(instruction::AddLiquidityByStrategy {
+                liquidity_parameter,
+            })
+    };
+
+    let signature = request_builder
+        .send_with_spinner_and_config(transaction_config)
+        .await;
+
+    println!("Add liquidity by strategy. Signature: {:#?}", signature);
+
+    signature?;
+
+    Ok(())
+}
@@ -0,0 +1,192 @@
+use std::collections::{BTreeSet, HashMap};
+use std::ops::Deref;
+
+use anchor_client::solana_client::rpc_config::RpcSendTransactionConfig;
+use anchor_client::solana_sdk::compute_budget::ComputeBudgetInstruction;
+use anchor_client::{solana_sdk::pubkey::Pubkey, solana_sdk::signer::Signer, Program};
+
+use anchor_spl::token::Mint;
+use anyhow::*;
+use commons::deposit::preview_add_liquidity_by_weight;
+use commons::token_2022::get_token_program;
+use lb_clmm::accounts;
+use lb_clmm::instruction;
+use lb_clmm::instructions::deposit::add_liquidity_by_weight::{
+    BinLiquidityDistributionByWeight, LiquidityParameterByWeight,
+};
+use lb_clmm::state::bin::BinArray;
+use lb_clmm::state::lb_pair::LbPair;
+use lb_clmm::state::position::PositionV2;
+use lb_clmm::utils::pda::{
+    derive_bin_array_bitmap_extension, derive_bin_array_pda, derive_event_authority_pda,
+};
+
+use crate::instructions::add_liquidity_by_strategy::print_deposit_preview;
+use crate::instructions::utils::{
+    get_liquidity_transfer_hook_accounts, get_mint_accounts, get_or_create_ata,
+};
+
+#[derive(Debug)]
+pub struct AddLiquidityByWeightParam {
+    pub lb_pair: Pubkey,
+    pub position: Pubkey,
+    pub amount_x: u64,
+    pub amount_y: u64,
+    pub bin_liquidity_distribution: Vec<(i32, u16)>,
+    pub max_active_bin_slippage: i32,
+    pub dry_run: bool,
+}
+
+pub async fn add_liquidity_by_weight<C: Deref<Target = impl Signer> + Clone>(
+    params: AddLiquidityByWeightParam,
+    program: &Program<C>,
+    transaction_config: RpcSendTransactionConfig,
+) -> Result<()> {
+    let AddLiquidityByWeightParam {
+        lb_pair,
+        position,
+        amount_x,
+        amount_y,
+        bin_liquidity_distribution,
+        max_active_bin_slippage,
+        dry_run,
+    } = params;
+
+    let lb_pair_state: LbPair = program.account(lb_pair).await?;
+    let position_state: PositionV2 = program.account(position).await?;
+    ensure!(
+        position_state.lb_pair == lb_pair,
+        "Position of another pair"
+    );
+
+    let liquidity_parameter = LiquidityParameterByWeight {
+        amount_x,
+        amount_y,
+        active_id: lb_pair_state.active_id,
+        max_active_bin_slippage,
+        bin_liquidity_dist: bin_liquidity_distribution
+            .into_iter()
+            .map(|(bin_id, weight)| BinLiquidityDistributionByWeight { bin_id, weight })
+            .collect(),
+    };
+
+    let lower_bin_array_idx = BinArray::bin_id_to_bin_array_index(position_state.lower_bin_id)?;
+    let upper_bin_array_idx = lower_bin_array_idx.checked_add(1).context("MathOverflow")?;
+    let (bin_array_lower, _bump) = derive_bin_array_pda(lb_pair, lower_bin_array_idx.into());
+    let (bin_array_upper, _bump) = derive_bin_array_pda(lb_pair, upper_bin_array_idx.into());
+
+    // Bin arrays of the position, and of the active bin for the composition fee
+    let mut bin_arrays = HashMap::new();
+    let bin_array_indexes = BTreeSet::from([
+        lower_bin_array_idx,
+        upper_bin_array_idx,
+        BinArray::bin_id_to_bin_array_index(lb_pair_state.active_id)?,
+    ]);
+    for bin_array_index in bin_array_indexes {
+        let (bin_array, _bump) = derive_bin_array_pda(lb_pair, bin_array_index.into());
+        if let std::result::Result::Ok(bin_array_state) =
+            program.account::<BinArray>(bin_array).await
+        {
+            bin_arrays.insert(bin_array, bin_array_state);
+        }
+    }
+
+    let preview = preview_add_liquidity_by_weight(
+        lb_pair,
+        &lb_pair_state,
+        &bin_arrays,
+        &liquidity_parameter,
+    )?;
+    let token_mint_base: Mint = program.account(lb_pair_state.token_x_mint).await?;
+    let token_mint_quote: Mint = program.account(lb_pair_state.token_y_mint).await?;
+    print_deposit_preview(
+        &preview,
+        &lb_pair_state,
+        &token_mint_base,
+        &token_mint_quote,
+    );
+
+    if dry_run {
+        return Ok(());
+    }
+
+    let user_token_x = get_or_create_ata(
+        program,
+        transaction_config,
+        lb_pair_state.token_x_mint,
+        program.payer(),
+    )
+    .await?;
+
+    let user_token_y = get_or_create_ata(
+        program,
+        transaction_config,
+        lb_pair_state.token_y_mint,
+        program.payer(),
+    )
+    .await?;
+
+    let (bin_array_bitmap_extension, _bump) = derive_bin_array_bitmap_extension(lb_pair);
+    let bin_array_bitmap_extension = if program
+        .rpc()
+        .get_account(&bin_array_bitmap_extension)
+        .is_err()
+    {
+        None
+    } else {
+        Some(bin_array_bitmap_extension)
+    };
+
+    let mint_accounts = get_mint_accounts(program, &lb_pair_state).await?;
+    let transfer_hook_accounts = get_liquidity_transfer_hook_accounts(
+        program,
+        lb_pair,
+        &lb_pair_state,
+        &mint_accounts,
+        [user_token_x, user_token_y],
+        [amount_x, amount_y],
+        true,
+    )
+    .await?;
+
+    let (event_authority, _bump) = derive_event_authority_pda();
+
+    let accounts = accounts::ModifyLiquidity {
+        bin_array_lower,
+        bin_array_upper,
+        lb_pair,
+        bin_array_bitmap_extension,
+        position,
+        reserve_x: lb_pair_state.reserve_x,
+        reserve_y: lb_pair_state.reserve_y,
+        token_x_mint: lb_pair_state.token_x_mint,
+        token_y_mint: lb_pair_state.token_y_mint,
+        sender: program.payer(),
+        user_token_x,
+        user_token_y,
+        token_x_program: get_token_program(&mint_accounts[0]),
+        token_y_program: get_token_program(&mint_accounts[1]),
+        position_nft_account: None,
+        event_authority,
+        program: lb_clmm::ID,
+    };
+
+    let compute_budget_ix = ComputeBudgetInstruction::set_compute_unit_limit(1_400_000);
+
+    let request_builder = program.request();
+    let signature = request_builder
+        .instruction(compute_budget_ix)
+        .accounts(accounts)
+        .accounts(transfer_hook_accounts)
+        .args(instruction::AddLiquidityByWeight {
+            liquidity_parameter,
+        })
+        .send_with_spinner_and_config(transaction_config)
+        .await;
+
+    println!("Add liquidity by weight. Signature: {:#?}", signature);
+
+    signature?;
+
+    Ok(())
+}
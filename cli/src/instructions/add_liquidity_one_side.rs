@@ -0,0 +1,173 @@
+use std::ops::Deref;
+
+use anchor_client::solana_client::rpc_config::RpcSendTransactionConfig;
+use anchor_client::solana_sdk::compute_budget::ComputeBudgetInstruction;
+use anchor_client::solana_sdk::instruction::AccountMeta;
+use anchor_client::{solana_sdk::pubkey::Pubkey, solana_sdk::signer::Signer, Program};
+use anchor_lang::ToAccountMetas;
+
+use anyhow::*;
+use commons::token_2022::{get_token_program, get_transfer_hook_account_metas};
+use lb_clmm::accounts;
+use lb_clmm::instruction;
+use lb_clmm::instructions::deposit::add_liquidity_by_weight::BinLiquidityDistributionByWeight;
+use lb_clmm::instructions::deposit::add_liquidity_by_weight_one_side::LiquidityOneSideParameter;
+use lb_clmm::state::bin::BinArray;
+use lb_clmm::state::lb_pair::LbPair;
+use lb_clmm::state::position::PositionV2;
+use lb_clmm::utils::pda::{
+    derive_bin_array_bitmap_extension, derive_bin_array_pda, derive_event_authority_pda,
+};
+
+use crate::instructions::utils::{get_mint_accounts, get_or_create_ata};
+
+#[derive(Debug)]
+pub struct AddLiquidityOneSideParam {
+    pub lb_pair: Pubkey,
+    pub position: Pubkey,
+    pub amount: u64,
+    pub deposit_for_y: bool,
+    pub bin_liquidity_distribution: Vec<(i32, u16)>,
+    pub max_active_bin_slippage: i32,
+}
+
+/// Accounts of a deposit of only token Y when `deposit_for_y`, else only token X, to the position, followed by the extra accounts required by the
+/// transfer hook of the token.
+pub async fn get_one_side_deposit_accounts<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    transaction_config: RpcSendTransactionConfig,
+    lb_pair: Pubkey,
+    lb_pair_state: &LbPair,
+    position: Pubkey,
+    deposit_for_y: bool,
+    amount: u64,
+) -> Result<Vec<AccountMeta>> {
+    let position_state: PositionV2 = program.account(position).await?;
+    ensure!(
+        position_state.lb_pair == lb_pair,
+        "Position of another pair"
+    );
+
+    let lower_bin_array_idx = BinArray::bin_id_to_bin_array_index(position_state.lower_bin_id)?;
+    let upper_bin_array_idx = lower_bin_array_idx.checked_add(1).context("MathOverflow")?;
+    let (bin_array_lower, _bump) = derive_bin_array_pda(lb_pair, lower_bin_array_idx.into());
+    let (bin_array_upper, _bump) = derive_bin_array_pda(lb_pair, upper_bin_array_idx.into());
+
+    let (bin_array_bitmap_extension, _bump) = derive_bin_array_bitmap_extension(lb_pair);
+    let bin_array_bitmap_extension = if program
+        .rpc()
+        .get_account(&bin_array_bitmap_extension)
+        .is_err()
+    {
+        None
+    } else {
+        Some(bin_array_bitmap_extension)
+    };
+
+    let mint_accounts = get_mint_accounts(program, lb_pair_state).await?;
+    let (token_mint, mint_account, reserve) = if deposit_for_y {
+        (
+            lb_pair_state.token_y_mint,
+            &mint_accounts[1],
+            lb_pair_state.reserve_y,
+        )
+    } else {
+        (
+            lb_pair_state.token_x_mint,
+            &mint_accounts[0],
+            lb_pair_state.reserve_x,
+        )
+    };
+
+    let user_token =
+        get_or_create_ata(program, transaction_config, token_mint, program.payer()).await?;
+
+    let transfer_hook_accounts = get_transfer_hook_account_metas(
+        &program.async_rpc(),
+        token_mint,
+        mint_account,
+        user_token,
+        reserve,
+        program.payer(),
+        amount,
+    )
+    .await?;
+
+    let (event_authority, _bump) = derive_event_authority_pda();
+
+    let mut accounts = accounts::ModifyLiquidityOneSide {
+        position,
+        lb_pair,
+        bin_array_bitmap_extension,
+        user_token,
+        reserve,
+        token_mint,
+        bin_array_lower,
+        bin_array_upper,
+        sender: program.payer(),
+        token_program: get_token_program(mint_account),
+        position_nft_account: None,
+        event_authority,
+        program: lb_clmm::ID,
+    }
+    .to_account_metas(None);
+    accounts.extend(transfer_hook_accounts);
+
+    Ok(accounts)
+}
+
+pub async fn add_liquidity_one_side<C: Deref<Target = impl Signer> + Clone>(
+    params: AddLiquidityOneSideParam,
+    program: &Program<C>,
+    transaction_config: RpcSendTransactionConfig,
+) -> Result<()> {
+    let AddLiquidityOneSideParam {
+        lb_pair,
+        position,
+        amount,
+        deposit_for_y,
+        bin_liquidity_distribution,
+        max_active_bin_slippage,
+    } = params;
+
+    let lb_pair_state: LbPair = program.account(lb_pair).await?;
+
+    let accounts = get_one_side_deposit_accounts(
+        program,
+        transaction_config,
+        lb_pair,
+        &lb_pair_state,
+        position,
+        deposit_for_y,
+        amount,
+    )
+    .await?;
+
+    let ix = instruction::AddLiquidityOneSide {
+        liquidity_parameter: LiquidityOneSideParameter {
+            amount,
+            active_id: lb_pair_state.active_id,
+            max_active_bin_slippage,
+            bin_liquidity_dist: bin_liquidity_distribution
+                .into_iter()
+                .map(|(bin_id, weight)| BinLiquidityDistributionByWeight { bin_id, weight })
+                .collect(),
+        },
+    };
+
+    let compute_budget_ix = ComputeBudgetInstruction::set_compute_unit_limit(1_400_000);
+
+    let request_builder = program.request();
+    let signature = request_builder
+        .instruction(compute_budget_ix)
+        .accounts(accounts)
+        .args(ix)
+        .send_with_spinner_and_config(transaction_config)
+        .await;
+
+    println!("Add liquidity one side. Signature: {:#?}", signature);
+
+    signature?;
+
+    Ok(())
+}
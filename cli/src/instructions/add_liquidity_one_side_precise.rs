@@ -0,0 +1,76 @@
+use std::ops::Deref;
+
+use anchor_client::solana_client::rpc_config::RpcSendTransactionConfig;
+use anchor_client::solana_sdk::compute_budget::ComputeBudgetInstruction;
+use anchor_client::{solana_sdk::pubkey::Pubkey, solana_sdk::signer::Signer, Program};
+
+use anyhow::*;
+use commons::deposit::compress_bin_deposit_amounts;
+use lb_clmm::instruction;
+use lb_clmm::state::lb_pair::LbPair;
+
+use crate::instructions::add_liquidity_one_side::get_one_side_deposit_accounts;
+
+#[derive(Debug)]
+pub struct AddLiquidityOneSidePreciseParam {
+    pub lb_pair: Pubkey,
+    pub position: Pubkey,
+    pub deposit_for_y: bool,
+    pub bin_amounts: Vec<(i32, u64)>,
+}
+
+pub async fn add_liquidity_one_side_precise<C: Deref<Target = impl Signer> + Clone>(
+    params: AddLiquidityOneSidePreciseParam,
+    program: &Program<C>,
+    transaction_config: RpcSendTransactionConfig,
+) -> Result<()> {
+    let AddLiquidityOneSidePreciseParam {
+        lb_pair,
+        position,
+        deposit_for_y,
+        bin_amounts,
+    } = params;
+
+    let lb_pair_state: LbPair = program.account(lb_pair).await?;
+
+    let parameter = compress_bin_deposit_amounts(&bin_amounts)?;
+    let amount = parameter
+        .bins
+        .iter()
+        .try_fold(0u64, |total, bin| {
+            u64::from(bin.amount)
+                .checked_mul(parameter.decompress_multiplier)
+                .and_then(|amount| total.checked_add(amount))
+        })
+        .context("MathOverflow")?;
+
+    let accounts = get_one_side_deposit_accounts(
+        program,
+        transaction_config,
+        lb_pair,
+        &lb_pair_state,
+        position,
+        deposit_for_y,
+        amount,
+    )
+    .await?;
+
+    let compute_budget_ix = ComputeBudgetInstruction::set_compute_unit_limit(1_400_000);
+
+    let request_builder = program.request();
+    let signature = request_builder
+        .instruction(compute_budget_ix)
+        .accounts(accounts)
+        .args(instruction::AddLiquidityOneSidePrecise { parameter })
+        .send_with_spinner_and_config(transaction_config)
+        .await;
+
+    println!(
+        "Add liquidity one side precise. Signature: {:#?}",
+        signature
+    );
+
+    signature?;
+
+    Ok(())
+}
@@ -3,7 +3,8 @@ use crate::math::price_per_token_to_per_lamport;
 use anchor_client::{solana_sdk::pubkey::Pubkey, solana_sdk::signer::Signer, Program};
 use anchor_spl::token_interface::Mint;
 use anyhow::*;
-use lb_clmm::constants::{MAX_BIN_PER_ARRAY, MAX_BIN_PER_POSITION};
+use commons::withdraw::preview_remove_liquidity_by_range;
+use lb_clmm::constants::{BASIS_POINT_MAX, MAX_BIN_PER_ARRAY, MAX_BIN_PER_POSITION};
 use lb_clmm::math::safe_math::SafeMath;
 use lb_clmm::math::u128x128_math::Rounding;
 use lb_clmm::math::u64x64_math::SCALE_OFFSET;
@@ -13,6 +14,7 @@ use lb_clmm::state::bin::BinArray;
 use lb_clmm::state::lb_pair::LbPair;
 use lb_clmm::state::position::PositionV2;
 use lb_clmm::utils::pda::*;
+use std::collections::HashMap;
 use std::ops::Deref;
 use std::result::Result::Ok;
 #[derive(Debug)]
@@ -77,27 +79,30 @@ pub async fn check_my_balance<C: Deref<Target = impl Signer> + Clone>(
                     lower_bin_array_idx.checked_add(1).context("MathOverflow")?;
 
                 let mut bin_arrays = vec![];
+                let mut bin_arrays_by_key = HashMap::new();
                 for i in lower_bin_array_idx..=upper_bin_array_idx {
                     let (bin_array, _bump) = derive_bin_array_pda(lb_pair, i.into());
 
                     match program.account::<BinArray>(bin_array).await {
-                        Ok(bin_array_state) => bin_arrays.push(bin_array_state),
+                        Ok(bin_array_state) => {
+                            bin_arrays_by_key.insert(bin_array, bin_array_state);
+                            bin_arrays.push(bin_array_state);
+                        }
                         Err(_err) => {}
                     }
                 }
                 let bin_array_manager = BinArrayManager {
                     bin_arrays: &bin_arrays,
                 };
-                for (i, &share) in position_state.liquidity_shares.iter().enumerate() {
-                    if share == 0 {
-                        continue;
-                    }
-                    let bin_id = position_state.from_idx_to_bin_id(i)?;
-                    let bin = bin_array_manager.get_bin(bin_id)?;
-                    let (amount_x, amount_y) = bin.calculate_out_amount(share)?;
-                    total_amount_x = total_amount_x.safe_add(amount_x).unwrap();
-                    total_amount_y = total_amount_y.safe_add(amount_y).unwrap();
-                }
+                let preview = preview_remove_liquidity_by_range(
+                    &position_state,
+                    &bin_arrays_by_key,
+                    position_state.lower_bin_id,
+                    position_state.upper_bin_id,
+                    BASIS_POINT_MAX as u16,
+                )?;
+                total_amount_x = total_amount_x.safe_add(preview.amount_x).unwrap();
+                total_amount_y = total_amount_y.safe_add(preview.amount_y).unwrap();
 
                 let (fee_x_pending, fee_y_pending) =
                     bin_array_manager.get_total_fee_pending(&position_state)?;
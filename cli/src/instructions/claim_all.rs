@@ -0,0 +1,173 @@
+use super::utils::{get_liquidity_transfer_hook_accounts, get_mint_accounts, get_or_create_ata};
+use anchor_client::anchor_lang::Space;
+use anchor_client::solana_client::rpc_config::RpcSendTransactionConfig;
+use anchor_client::solana_client::rpc_filter::{Memcmp, RpcFilterType};
+use anchor_client::solana_sdk::compute_budget::ComputeBudgetInstruction;
+use anchor_client::solana_sdk::instruction::Instruction;
+use anchor_client::solana_sdk::packet::PACKET_DATA_SIZE;
+use anchor_client::solana_sdk::transaction::Transaction;
+use anchor_client::{solana_sdk::pubkey::Pubkey, solana_sdk::signer::Signer, Program};
+use anyhow::*;
+use commons::instructions::claim_all_rewards::claim_all_rewards;
+use commons::instructions::claim_fee_multiple::claim_fee_multiple;
+use commons::token_2022::get_token_program;
+use lb_clmm::constants::NUM_REWARDS;
+use lb_clmm::state::lb_pair::LbPair;
+use lb_clmm::state::position::PositionV2;
+use std::collections::BTreeMap;
+use std::ops::Deref;
+
+/// Claim the fee and rewards of every position of the wallet. Positions of the same pair are packed into as few transactions as possible.
+/// Tokenized positions don't store their owner, and are claimed with claim-fee and claim-reward instead.
+pub async fn claim_all<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    transaction_config: RpcSendTransactionConfig,
+) -> Result<()> {
+    let position_states = program
+        .accounts::<PositionV2>(vec![
+            RpcFilterType::DataSize((8 + PositionV2::INIT_SPACE) as u64),
+            RpcFilterType::Memcmp(Memcmp::new_raw_bytes(
+                8 + 32,
+                program.payer().to_bytes().to_vec(),
+            )),
+        ])
+        .await?;
+
+    let mut positions_by_pair: BTreeMap<Pubkey, Vec<(Pubkey, PositionV2)>> = BTreeMap::new();
+    for (position, position_state) in position_states {
+        positions_by_pair
+            .entry(position_state.lb_pair)
+            .or_default()
+            .push((position, position_state));
+    }
+
+    for (lb_pair, positions) in positions_by_pair {
+        let lb_pair_state: LbPair = program.account(lb_pair).await?;
+        let mint_accounts = get_mint_accounts(program, &lb_pair_state).await?;
+        let token_x_program = get_token_program(&mint_accounts[0]);
+        let token_y_program = get_token_program(&mint_accounts[1]);
+
+        let user_token_x = get_or_create_ata(
+            program,
+            transaction_config,
+            lb_pair_state.token_x_mint,
+            program.payer(),
+        )
+        .await?;
+        let user_token_y = get_or_create_ata(
+            program,
+            transaction_config,
+            lb_pair_state.token_y_mint,
+            program.payer(),
+        )
+        .await?;
+
+        let transfer_hook_accounts = get_liquidity_transfer_hook_accounts(
+            program,
+            lb_pair,
+            &lb_pair_state,
+            &mint_accounts,
+            [user_token_x, user_token_y],
+            [0, 0],
+            false,
+        )
+        .await?;
+
+        let mut reward_token_programs = vec![];
+        for reward_index in 0..NUM_REWARDS {
+            let reward_info = lb_pair_state.reward_infos[reward_index];
+            if !reward_info.initialized() {
+                continue;
+            }
+            get_or_create_ata(
+                program,
+                transaction_config,
+                reward_info.mint,
+                program.payer(),
+            )
+            .await?;
+            let reward_token_program = program
+                .async_rpc()
+                .get_account(&reward_info.mint)
+                .await?
+                .owner;
+            reward_token_programs.push((reward_index as u64, reward_token_program));
+        }
+
+        let build_instructions = |positions: &[(Pubkey, PositionV2)]| -> Result<Vec<Instruction>> {
+            let mut claim_fee_ix = claim_fee_multiple(
+                lb_pair,
+                &lb_pair_state,
+                program.payer(),
+                token_x_program,
+                token_y_program,
+                positions,
+            )?;
+            claim_fee_ix
+                .accounts
+                .extend(transfer_hook_accounts.iter().cloned());
+
+            let mut instructions = vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                claim_fee_ix,
+            ];
+
+            for &(reward_index, reward_token_program) in reward_token_programs.iter() {
+                instructions.push(claim_all_rewards(
+                    lb_pair,
+                    &lb_pair_state,
+                    program.payer(),
+                    reward_token_program,
+                    reward_index,
+                    positions,
+                )?);
+            }
+
+            Ok(instructions)
+        };
+
+        // Greedily grow each batch of positions until the transaction no longer fit in a packet
+        let mut batches = vec![];
+        let mut start = 0;
+        while start < positions.len() {
+            let mut end = start + 1;
+            while end < positions.len()
+                && end - start < u8::MAX as usize
+                && is_within_packet_size(
+                    &build_instructions(&positions[start..end + 1])?,
+                    &program.payer(),
+                )?
+            {
+                end += 1;
+            }
+            batches.push(build_instructions(&positions[start..end])?);
+            start = end;
+        }
+
+        for (batch_index, instructions) in batches.into_iter().enumerate() {
+            let mut request_builder = program.request();
+            for instruction in instructions {
+                request_builder = request_builder.instruction(instruction);
+            }
+
+            let signature = request_builder
+                .send_with_spinner_and_config(transaction_config)
+                .await;
+
+            println!(
+                "Claim all of pair {} batch {}. Signature: {:#?}",
+                lb_pair, batch_index, signature
+            );
+
+            signature?;
+        }
+    }
+
+    Ok(())
+}
+
+fn is_within_packet_size(instructions: &[Instruction], payer: &Pubkey) -> Result<bool> {
+    let transaction = Transaction::new_with_payer(instructions, Some(payer));
+    let size = bincode::serialized_size(&transaction)?;
+    Ok(size <= PACKET_DATA_SIZE as u64)
+}
@@ -50,6 +50,8 @@ pub async fn claim_fee<C: Deref<Target = impl Signer> + Clone>(
         token_y_mint: lb_pair_state.token_y_mint,
         user_token_x,
         user_token_y,
+        position_nft_account: None,
+        admin_config: None,
         event_authority,
         program: lb_clmm::ID,
     };
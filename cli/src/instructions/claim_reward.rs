@@ -48,6 +48,7 @@ pub async fn claim_reward<C: Deref<Target = impl Signer> + Clone>(
         position,
         user_token_account,
         sender: program.payer(),
+        position_nft_account: None,
         event_authority,
         program: lb_clmm::ID,
     };
@@ -0,0 +1,123 @@
+use std::ops::Deref;
+
+use anchor_client::solana_client::rpc_config::RpcSendTransactionConfig;
+use anchor_client::solana_client::rpc_filter::{Memcmp, RpcFilterType};
+use anchor_client::{solana_sdk::pubkey::Pubkey, solana_sdk::signer::Signer, Program};
+
+use anyhow::*;
+use commons::instructions::close_bin_array::{close_bin_array, close_legacy_bin_array};
+use lb_clmm::state::admin_config::{AdminConfig, AdminRole};
+use lb_clmm::state::bin::BinArray;
+use lb_clmm::state::bin_array_bitmap_extension::BinArrayBitmapExtension;
+use lb_clmm::state::bin_array_funder::BinArrayFunder;
+use lb_clmm::state::lb_pair::LbPair;
+use lb_clmm::utils::pda::{
+    derive_admin_config_pda, derive_bin_array_bitmap_extension, derive_bin_array_funder_pda,
+};
+
+/// Number of close_bin_array instructions packed into a transaction
+const CLOSE_BIN_ARRAY_PER_TX: usize = 8;
+
+/// Whether the payer can close the bin arrays of the pair initialized without funder record, as creator of the pair or fee admin
+async fn can_close_legacy_bin_array<C: Deref<Target = impl Signer> + Clone>(
+    lb_pair_state: &LbPair,
+    program: &Program<C>,
+) -> bool {
+    let payer = program.payer();
+    if lb_pair_state.creator == payer {
+        return true;
+    }
+
+    let (admin_config, _bump) = derive_admin_config_pda();
+    match program.account::<AdminConfig>(admin_config).await {
+        Result::Ok(admin_config) => admin_config.get_authority(AdminRole::FeeAdmin) == payer,
+        Err(_) => lb_clmm::assert_eq_admin(payer),
+    }
+}
+
+/// Close every bin array of the pair without liquidity, refunding the rent to the funder of each bin array.
+/// Bin arrays initialized without funder record are closed to the payer when it is the creator of the pair or the fee admin.
+pub async fn close_empty_bin_arrays<C: Deref<Target = impl Signer> + Clone>(
+    lb_pair: Pubkey,
+    program: &Program<C>,
+    transaction_config: RpcSendTransactionConfig,
+) -> Result<()> {
+    let lb_pair_state: LbPair = program.account(lb_pair).await?;
+    let active_bin_array_index = BinArray::bin_id_to_bin_array_index(lb_pair_state.active_id)?;
+
+    let (bitmap_extension_key, _bump) = derive_bin_array_bitmap_extension(lb_pair);
+    let bitmap_extension = program
+        .account::<BinArrayBitmapExtension>(bitmap_extension_key)
+        .await
+        .ok()
+        .map(|_| bitmap_extension_key);
+
+    let lb_pair_filter = RpcFilterType::Memcmp(Memcmp::new_base58_encoded(16, &lb_pair.to_bytes()));
+    let mut bin_arrays: Vec<(Pubkey, BinArray)> = program.accounts(vec![lb_pair_filter]).await?;
+    bin_arrays.sort_by_key(|(_, bin_array)| bin_array.index);
+
+    let can_close_legacy = can_close_legacy_bin_array(&lb_pair_state, program).await;
+
+    let mut instructions = vec![];
+    for (bin_array, bin_array_state) in bin_arrays {
+        if !bin_array_state.is_zero_liquidity()
+            || bin_array_state.index == active_bin_array_index as i64
+        {
+            continue;
+        }
+
+        let (bin_array_funder, _bump) = derive_bin_array_funder_pda(bin_array);
+        let Result::Ok(bin_array_funder_state) =
+            program.account::<BinArrayFunder>(bin_array_funder).await
+        else {
+            if can_close_legacy {
+                instructions.push(close_legacy_bin_array(
+                    lb_pair,
+                    bin_array,
+                    program.payer(),
+                    program.payer(),
+                    bitmap_extension,
+                ));
+            } else {
+                println!(
+                    "Skip bin array {} without funder record, only closable by the pair creator or the fee admin",
+                    bin_array
+                );
+            }
+            continue;
+        };
+
+        instructions.push(close_bin_array(
+            lb_pair,
+            bin_array,
+            bin_array_funder_state.funder,
+            bitmap_extension,
+        ));
+    }
+
+    if instructions.is_empty() {
+        println!("No empty bin array to close");
+        return Ok(());
+    }
+
+    for chunk in instructions.chunks(CLOSE_BIN_ARRAY_PER_TX) {
+        let mut request_builder = program.request();
+        for instruction in chunk {
+            request_builder = request_builder.instruction(instruction.clone());
+        }
+
+        let signature = request_builder
+            .send_with_spinner_and_config(transaction_config)
+            .await;
+
+        println!(
+            "Close {} empty bin arrays. Signature: {:#?}",
+            chunk.len(),
+            signature
+        );
+
+        signature?;
+    }
+
+    Ok(())
+}
@@ -29,6 +29,7 @@ pub async fn close_position<C: Deref<Target = impl Signer> + Clone>(
         sender: position_state.owner,
         rent_receiver: position_state.owner,
         position,
+        position_nft_account: None,
         event_authority,
         program: lb_clmm::ID,
     };
@@ -7,6 +7,7 @@ use anchor_lang::ToAccountMetas;
 use anyhow::*;
 use lb_clmm::accounts;
 use lb_clmm::instruction;
+use lb_clmm::utils::pda::derive_admin_config_pda;
 
 pub async fn close_preset_parameter<C: Deref<Target = impl Signer> + Clone>(
     preset_parameter: Pubkey,
@@ -14,6 +15,7 @@ pub async fn close_preset_parameter<C: Deref<Target = impl Signer> + Clone>(
     transaction_config: RpcSendTransactionConfig,
 ) -> Result<Pubkey> {
     let accounts = accounts::ClosePresetParameter {
+        admin_config: derive_admin_config_pda().0,
         admin: program.payer(),
         rent_receiver: program.payer(),
         preset_parameter,
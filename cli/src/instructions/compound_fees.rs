@@ -0,0 +1,112 @@
+use crate::LiquidityStrategy;
+use anchor_client::solana_client::rpc_config::RpcSendTransactionConfig;
+use anchor_client::solana_sdk::compute_budget::ComputeBudgetInstruction;
+use anchor_client::{solana_sdk::pubkey::Pubkey, solana_sdk::signer::Signer, Program};
+use anyhow::*;
+use lb_clmm::instructions::compound_fees::CompoundFeeParameter;
+use lb_clmm::instructions::deposit::StrategyType;
+use lb_clmm::state::bin_array_bitmap_extension::BinArrayBitmapExtension;
+use lb_clmm::state::lb_pair::LbPair;
+use lb_clmm::state::position::PositionV2;
+use lb_clmm::utils::pda::derive_bin_array_bitmap_extension;
+use std::ops::Deref;
+use std::time::Duration;
+
+#[derive(Debug)]
+pub struct CompoundFeesParams {
+    pub position: Pubkey,
+    pub strategy: LiquidityStrategy,
+    pub max_active_bin_slippage: i32,
+    pub interval: Option<u64>,
+}
+
+pub async fn compound_fees<C: Deref<Target = impl Signer> + Clone>(
+    params: CompoundFeesParams,
+    program: &Program<C>,
+    transaction_config: RpcSendTransactionConfig,
+) -> Result<()> {
+    let CompoundFeesParams {
+        position,
+        strategy,
+        max_active_bin_slippage,
+        interval,
+    } = params;
+
+    let strategy_type = match strategy {
+        LiquidityStrategy::Spot => StrategyType::SpotBalanced,
+        LiquidityStrategy::Curve => StrategyType::CurveBalanced,
+        LiquidityStrategy::BidAsk => StrategyType::BidAskBalanced,
+    };
+
+    let Some(interval) = interval else {
+        return compound_fees_once(
+            position,
+            strategy_type,
+            max_active_bin_slippage,
+            program,
+            transaction_config,
+        )
+        .await;
+    };
+
+    let mut interval = tokio::time::interval(Duration::from_secs(interval));
+    loop {
+        interval.tick().await;
+        if let Err(err) = compound_fees_once(
+            position,
+            strategy_type.clone(),
+            max_active_bin_slippage,
+            program,
+            transaction_config,
+        )
+        .await
+        {
+            println!("Compound fees failed: {}", err);
+        }
+    }
+}
+
+async fn compound_fees_once<C: Deref<Target = impl Signer> + Clone>(
+    position: Pubkey,
+    strategy_type: StrategyType,
+    max_active_bin_slippage: i32,
+    program: &Program<C>,
+    transaction_config: RpcSendTransactionConfig,
+) -> Result<()> {
+    let position_state: PositionV2 = program.account(position).await?;
+    let lb_pair_state: LbPair = program.account(position_state.lb_pair).await?;
+
+    let (bitmap_extension_key, _bump) = derive_bin_array_bitmap_extension(position_state.lb_pair);
+    let bitmap_extension = program
+        .account::<BinArrayBitmapExtension>(bitmap_extension_key)
+        .await
+        .ok()
+        .map(|_| bitmap_extension_key);
+
+    let ix = commons::instructions::compound_fees::compound_fees(
+        position,
+        &position_state,
+        program.payer(),
+        bitmap_extension,
+        CompoundFeeParameter {
+            active_id: lb_pair_state.active_id,
+            max_active_bin_slippage,
+            strategy_type,
+        },
+    )?;
+
+    let compute_budget_ix = ComputeBudgetInstruction::set_compute_unit_limit(1_400_000);
+
+    let request_builder = program.request();
+    let signature = request_builder
+        .instruction(compute_budget_ix)
+        .instruction(ix)
+        .send_with_spinner_and_config(transaction_config)
+        .await;
+
+    println!("Compound fees. Signature: {:#?}", signature);
+
+    signature?;
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use anchor_client::solana_client::rpc_config::RpcSendTransactionConfig;
+use anchor_client::solana_sdk::signer::Signer;
+use anchor_client::Program;
+use anchor_lang::prelude::Pubkey;
+use anyhow::*;
+use lb_clmm::instructions::admin::initialize_admin_config::InitAdminConfigParameter;
+use std::ops::Deref;
+
+#[derive(Debug)]
+pub struct InitAdminConfigParams {
+    pub fee_admin: Pubkey,
+    pub pause_guardian: Pubkey,
+    pub reward_admin: Pubkey,
+    pub launch_pool_admin: Pubkey,
+}
+
+pub async fn initialize_admin_config<C: Deref<Target = impl Signer> + Clone>(
+    params: InitAdminConfigParams,
+    program: &Program<C>,
+    transaction_config: RpcSendTransactionConfig,
+) -> Result<()> {
+    let InitAdminConfigParams {
+        fee_admin,
+        pause_guardian,
+        reward_admin,
+        launch_pool_admin,
+    } = params;
+
+    let ix = commons::instructions::admin_config::initialize_admin_config(
+        program.payer(),
+        InitAdminConfigParameter {
+            fee_admin,
+            pause_guardian,
+            reward_admin,
+            launch_pool_admin,
+        },
+    );
+
+    let request_builder = program.request();
+    let signature = request_builder
+        .instruction(ix)
+        .send_with_spinner_and_config(transaction_config)
+        .await;
+
+    println!("Initialize admin config. Signature: {:#?}", signature);
+
+    signature?;
+
+    Ok(())
+}
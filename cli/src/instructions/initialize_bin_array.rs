@@ -26,14 +26,15 @@ pub async fn initialize_bin_array<C: Deref<Target = impl Signer> + Clone>(
 
     let (bin_array, _bump) = derive_bin_array_pda(lb_pair, bin_array_index);
 
-    let accounts = accounts::InitializeBinArray {
+    let accounts = accounts::InitializeBinArrayWithFunder {
         bin_array,
+        bin_array_funder: derive_bin_array_funder_pda(bin_array).0,
         funder: program.payer(),
         lb_pair,
         system_program: anchor_client::solana_sdk::system_program::ID,
     };
 
-    let ix = instruction::InitializeBinArray {
+    let ix = instruction::InitializeBinArrayWithFunder {
         index: bin_array_index,
     };
 
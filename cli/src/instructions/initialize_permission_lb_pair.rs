@@ -79,6 +79,7 @@ pub async fn initialize_permission_lb_pair<C: Deref<Target = impl Signer> + Clon
         token_mint_x,
         token_mint_y,
         oracle,
+        admin_config: derive_admin_config_pda().0,
         admin: program.payer(),
         rent: anchor_client::solana_sdk::sysvar::rent::ID,
         system_program: anchor_client::solana_sdk::system_program::ID,
@@ -8,7 +8,7 @@ use anyhow::*;
 use lb_clmm::accounts;
 use lb_clmm::instruction;
 use lb_clmm::instructions::admin::initialize_preset_parameters::InitPresetParametersIx;
-use lb_clmm::utils::pda::derive_preset_parameter_pda2;
+use lb_clmm::utils::pda::{derive_admin_config_pda, derive_preset_parameter_pda2};
 
 #[derive(Debug)]
 pub struct InitPresetParameters {
@@ -56,6 +56,7 @@ pub async fn initialize_preset_parameter<C: Deref<Target = impl Signer> + Clone>
 
     let accounts = accounts::InitializePresetParameter {
         preset_parameter,
+        admin_config: derive_admin_config_pda().0,
         admin: program.payer(),
         rent: anchor_client::solana_sdk::sysvar::rent::ID,
         system_program: anchor_client::solana_sdk::system_program::ID,
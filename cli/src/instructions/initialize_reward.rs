@@ -1,6 +1,6 @@
 use anchor_client::solana_client::rpc_config::RpcSendTransactionConfig;
 use anchor_client::{solana_sdk::pubkey::Pubkey, solana_sdk::signer::Signer, Program};
-use lb_clmm::utils::pda::derive_event_authority_pda;
+use lb_clmm::utils::pda::{derive_admin_config_pda, derive_event_authority_pda};
 use std::ops::Deref;
 
 use anyhow::*;
@@ -40,6 +40,7 @@ pub async fn initialize_reward<C: Deref<Target = impl Signer> + Clone>(
         lb_pair,
         reward_vault,
         reward_mint,
+        admin_config: derive_admin_config_pda().0,
         admin: program.payer(),
         token_program: anchor_spl::token::ID,
         rent: anchor_client::solana_sdk::sysvar::rent::ID,
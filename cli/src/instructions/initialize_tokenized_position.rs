@@ -0,0 +1,58 @@
+use std::ops::Deref;
+
+use anchor_client::solana_client::rpc_config::RpcSendTransactionConfig;
+use anchor_client::solana_sdk::signature::Keypair;
+use anchor_client::{solana_sdk::pubkey::Pubkey, solana_sdk::signer::Signer, Program};
+use anyhow::*;
+use lb_clmm::utils::pda::derive_tokenized_position_pda;
+
+#[derive(Debug)]
+pub struct InitTokenizedPositionParameters {
+    pub lb_pair: Pubkey,
+    pub lower_bin_id: i32,
+    pub width: i32,
+    pub uri: String,
+}
+
+pub async fn initialize_tokenized_position<C: Deref<Target = impl Signer> + Clone>(
+    params: InitTokenizedPositionParameters,
+    program: &Program<C>,
+    transaction_config: RpcSendTransactionConfig,
+) -> Result<Pubkey> {
+    let InitTokenizedPositionParameters {
+        lb_pair,
+        lower_bin_id,
+        width,
+        uri,
+    } = params;
+
+    let position_nft_mint_keypair = Keypair::new();
+    let (position, _bump) = derive_tokenized_position_pda(position_nft_mint_keypair.pubkey());
+
+    let ix = commons::instructions::initialize_tokenized_position::initialize_tokenized_position(
+        lb_pair,
+        program.payer(),
+        position_nft_mint_keypair.pubkey(),
+        anchor_spl::token::ID,
+        lower_bin_id,
+        width,
+        uri,
+    );
+
+    let request_builder = program.request();
+    let signature = request_builder
+        .instruction(ix)
+        .signer(&position_nft_mint_keypair)
+        .send_with_spinner_and_config(transaction_config)
+        .await;
+
+    println!(
+        "Initialize tokenized position {} with position NFT {}. Signature: {signature:#?}",
+        position,
+        position_nft_mint_keypair.pubkey()
+    );
+
+    signature?;
+
+    Ok(position)
+}
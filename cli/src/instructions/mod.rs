@@ -1,11 +1,20 @@
+pub mod accept_admin_role;
 pub mod add_liquidity;
+pub mod add_liquidity_by_strategy;
+pub mod add_liquidity_by_weight;
+pub mod add_liquidity_one_side;
+pub mod add_liquidity_one_side_precise;
 pub mod check_my_balance;
+pub mod claim_all;
 pub mod claim_fee;
 pub mod claim_reward;
+pub mod close_empty_bin_arrays;
 pub mod close_position;
 pub mod close_preset_parameter;
+pub mod compound_fees;
 pub mod fund_reward;
 pub mod increase_length;
+pub mod initialize_admin_config;
 pub mod initialize_bin_array;
 pub mod initialize_bin_array_with_bin_range;
 pub mod initialize_bin_array_with_price_range;
@@ -16,7 +25,9 @@ pub mod initialize_position;
 pub mod initialize_position_with_price_range;
 pub mod initialize_preset_parameter;
 pub mod initialize_reward;
+pub mod initialize_tokenized_position;
 pub mod list_all_binstep;
+pub mod propose_admin_role;
 pub mod remove_liquidity;
 pub mod remove_liquidity_by_price_range;
 pub mod seed_liquidity;
@@ -26,12 +37,18 @@ pub mod seed_liquidity_single_bin_by_operator;
 pub mod set_activation_point;
 pub mod set_pre_activation_duration;
 pub mod set_pre_activation_swap_address;
+pub mod show_admin_config;
+pub mod show_circuit_breaker;
+pub mod show_oracle;
 pub mod show_pair;
 pub mod simulate_swap_demand;
 pub mod swap_exact_in;
 pub mod swap_exact_out;
 pub mod swap_with_price_impact;
 pub mod toggle_pair_status;
+pub mod transfer_position_owner;
+pub mod update_circuit_breaker;
+pub mod update_oracle_parameters;
 pub mod update_reward_duration;
 pub mod update_reward_funder;
 pub mod utils;
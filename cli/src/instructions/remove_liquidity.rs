@@ -13,7 +13,11 @@ use lb_clmm::instructions::withdraw::remove_liquidity::BinLiquidityReduction;
 use lb_clmm::state::lb_pair::LbPair;
 use lb_clmm::utils::pda::{derive_bin_array_bitmap_extension, derive_event_authority_pda};
 
-use crate::instructions::utils::{get_bin_arrays_for_position, get_or_create_ata};
+use crate::instructions::utils::{
+    get_bin_arrays_for_position, get_liquidity_transfer_hook_accounts, get_mint_accounts,
+    get_or_create_ata,
+};
+use commons::token_2022::get_token_program;
 
 pub struct RemoveLiquidityParameters {
     pub lb_pair: Pubkey,
@@ -64,6 +68,18 @@ pub async fn remove_liquidity<C: Deref<Target = impl Signer> + Clone>(
         Some(bin_array_bitmap_extension)
     };
 
+    let mint_accounts = get_mint_accounts(program, &lb_pair_state).await?;
+    let transfer_hook_accounts = get_liquidity_transfer_hook_accounts(
+        program,
+        lb_pair,
+        &lb_pair_state,
+        &mint_accounts,
+        [user_token_x, user_token_y],
+        [0, 0],
+        false,
+    )
+    .await?;
+
     let (event_authority, _bump) = derive_event_authority_pda();
 
     let accounts = accounts::ModifyLiquidity {
@@ -79,8 +95,9 @@ pub async fn remove_liquidity<C: Deref<Target = impl Signer> + Clone>(
         sender: program.payer(),
         user_token_x,
         user_token_y,
-        token_x_program: anchor_spl::token::ID,
-        token_y_program: anchor_spl::token::ID,
+        token_x_program: get_token_program(&mint_accounts[0]),
+        token_y_program: get_token_program(&mint_accounts[1]),
+        position_nft_account: None,
         event_authority,
         program: lb_clmm::ID,
     };
@@ -103,6 +120,7 @@ pub async fn remove_liquidity<C: Deref<Target = impl Signer> + Clone>(
     let signature = request_builder
         .instruction(compute_budget_ix)
         .accounts(accounts)
+        .accounts(transfer_hook_accounts)
         .args(ix)
         .send_with_spinner_and_config(transaction_config)
         .await;
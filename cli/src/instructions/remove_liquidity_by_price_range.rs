@@ -1,5 +1,7 @@
-use crate::instructions::utils::get_or_create_ata;
-use crate::math::{get_id_from_price, price_per_token_to_per_lamport};
+use crate::instructions::utils::{
+    get_liquidity_transfer_hook_accounts, get_mint_accounts, get_or_create_ata,
+};
+use crate::math::{decimal_to_q64x64_price, price_per_token_to_per_lamport};
 use anchor_client::solana_client::rpc_config::RpcSendTransactionConfig;
 use anchor_client::solana_sdk::compute_budget::ComputeBudgetInstruction;
 use anchor_client::solana_sdk::instruction::Instruction;
@@ -8,13 +10,15 @@ use anchor_lang::InstructionData;
 use anchor_lang::ToAccountMetas;
 use anchor_spl::token::Mint;
 use anyhow::*;
+use commons::price::get_bin_id_range_from_price_range;
+use commons::token_2022::get_token_program;
+use commons::withdraw::get_reductions_by_price_range;
 use lb_clmm::accounts;
-use lb_clmm::constants::MAX_BIN_PER_POSITION;
+use lb_clmm::constants::{BASIS_POINT_MAX, MAX_BIN_PER_POSITION};
 use lb_clmm::instruction;
-use lb_clmm::math::u128x128_math::Rounding;
 use lb_clmm::state::bin::BinArray;
 use lb_clmm::state::lb_pair::LbPair;
-use lb_clmm::state::position::Position;
+use lb_clmm::state::position::PositionV2;
 use lb_clmm::utils::pda::*;
 use std::ops::Deref;
 use std::result::Result::Ok;
@@ -27,6 +31,8 @@ pub struct RemoveLiquidityByPriceRangeParameters {
     pub max_price: f64,
 }
 
+/// Remove the liquidity priced within the price range from the positions of the base position key. Positions fully within the
+/// price range are closed, after claiming their fee.
 pub async fn remove_liquidity_by_price_range<C: Deref<Target = impl Signer> + Clone>(
     params: RemoveLiquidityByPriceRangeParameters,
     program: &Program<C>,
@@ -45,139 +51,171 @@ pub async fn remove_liquidity_by_price_range<C: Deref<Target = impl Signer> + Cl
     let token_mint_base: Mint = program.account(lb_pair_state.token_x_mint).await?;
     let token_mint_quote: Mint = program.account(lb_pair_state.token_y_mint).await?;
 
-    let min_price_per_lamport = price_per_token_to_per_lamport(
-        min_price,
-        token_mint_base.decimals,
-        token_mint_quote.decimals,
+    let mut q64x64_prices = [0u128; 2];
+    for (price, q64x64_price) in [min_price, max_price].into_iter().zip(&mut q64x64_prices) {
+        let price_per_lamport = price_per_token_to_per_lamport(
+            price,
+            token_mint_base.decimals,
+            token_mint_quote.decimals,
+        )
+        .context("price_per_token_to_per_lamport overflow")?;
+        *q64x64_price = decimal_to_q64x64_price(&price_per_lamport)
+            .context("decimal_to_q64x64_price overflow")?;
+    }
+    let [min_price, max_price] = q64x64_prices;
+    let (min_bin_id, max_bin_id) =
+        get_bin_id_range_from_price_range(bin_step, min_price, max_price)?;
+
+    let user_token_x = get_or_create_ata(
+        program,
+        transaction_config,
+        lb_pair_state.token_x_mint,
+        program.payer(),
     )
-    .context("price_per_token_to_per_lamport overflow")?;
-    let min_active_id = get_id_from_price(bin_step, &min_price_per_lamport, Rounding::Up)
-        .context("get_id_from_price overflow")?;
+    .await?;
 
-    let max_price_per_lamport = price_per_token_to_per_lamport(
-        max_price,
-        token_mint_base.decimals,
-        token_mint_quote.decimals,
+    let user_token_y = get_or_create_ata(
+        program,
+        transaction_config,
+        lb_pair_state.token_y_mint,
+        program.payer(),
     )
-    .context("price_per_token_to_per_lamport overflow")?;
-    let max_active_id = get_id_from_price(bin_step, &max_price_per_lamport, Rounding::Up)
-        .context("get_id_from_price overflow")?;
+    .await?;
 
-    assert!(min_active_id < max_active_id);
+    let mint_accounts = get_mint_accounts(program, &lb_pair_state).await?;
+    let transfer_hook_accounts = get_liquidity_transfer_hook_accounts(
+        program,
+        lb_pair,
+        &lb_pair_state,
+        &mint_accounts,
+        [user_token_x, user_token_y],
+        [0, 0],
+        false,
+    )
+    .await?;
+    let token_x_program = get_token_program(&mint_accounts[0]);
+    let token_y_program = get_token_program(&mint_accounts[1]);
+    let (event_authority, _bump) = derive_event_authority_pda();
 
-    println!("go here");
+    // Positions overlapping the bins of the price range
     let width = MAX_BIN_PER_POSITION as i32;
-    for i in min_active_id..=max_active_id {
-        let (position, _bump) = derive_position_pda(lb_pair, base_position_key, i, width);
-
-        // if program.rpc().get_account_data(&position).is_ok() {
-        //     let position_state: Position = program.account(position)?;
-        //     println!("{position_state:?}");
-        // }
-        // continue;
-
-        match program.account::<Position>(position).await {
-            Ok(position_state) => {
-                let lower_bin_array_idx =
-                    BinArray::bin_id_to_bin_array_index(position_state.lower_bin_id)?;
-                let upper_bin_array_idx =
-                    lower_bin_array_idx.checked_add(1).context("MathOverflow")?;
-
-                let (bin_array_lower, _bump) =
-                    derive_bin_array_pda(lb_pair, lower_bin_array_idx.into());
-                let (bin_array_upper, _bump) =
-                    derive_bin_array_pda(lb_pair, upper_bin_array_idx.into());
-                let user_token_x = get_or_create_ata(
-                    program,
-                    transaction_config,
-                    lb_pair_state.token_x_mint,
-                    program.payer(),
-                )
-                .await?;
-
-                let user_token_y = get_or_create_ata(
-                    program,
-                    transaction_config,
-                    lb_pair_state.token_y_mint,
-                    program.payer(),
-                )
-                .await?;
-                let (event_authority, _bump) = derive_event_authority_pda();
-
-                let instructions = vec![
-                    ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
-                    Instruction {
-                        program_id: lb_clmm::ID,
-                        accounts: accounts::ModifyLiquidity {
-                            bin_array_lower,
-                            bin_array_upper,
-                            lb_pair,
-                            bin_array_bitmap_extension: None,
-                            position,
-                            reserve_x: lb_pair_state.reserve_x,
-                            reserve_y: lb_pair_state.reserve_y,
-                            token_x_mint: lb_pair_state.token_x_mint,
-                            token_y_mint: lb_pair_state.token_y_mint,
-                            sender: program.payer(),
-                            user_token_x,
-                            user_token_y,
-                            token_x_program: anchor_spl::token::ID,
-                            token_y_program: anchor_spl::token::ID,
-                            event_authority,
-                            program: lb_clmm::ID,
-                        }
-                        .to_account_metas(None),
-                        data: instruction::RemoveAllLiquidity {}.data(),
-                    },
-                    Instruction {
-                        program_id: lb_clmm::ID,
-                        accounts: accounts::ClaimFee {
-                            bin_array_lower,
-                            bin_array_upper,
-                            lb_pair,
-                            sender: program.payer(),
-                            position,
-                            reserve_x: lb_pair_state.reserve_x,
-                            reserve_y: lb_pair_state.reserve_y,
-                            token_program: anchor_spl::token::ID,
-                            token_x_mint: lb_pair_state.token_x_mint,
-                            token_y_mint: lb_pair_state.token_y_mint,
-                            user_token_x,
-                            user_token_y,
-                            event_authority,
-                            program: lb_clmm::ID,
-                        }
-                        .to_account_metas(None),
-                        data: instruction::ClaimFee {}.data(),
-                    },
-                    Instruction {
-                        program_id: lb_clmm::ID,
-                        accounts: accounts::ClosePosition {
-                            lb_pair,
-                            position,
-                            bin_array_lower,
-                            bin_array_upper,
-                            rent_receiver: program.payer(),
-                            sender: program.payer(),
-                            event_authority,
-                            program: lb_clmm::ID,
-                        }
-                        .to_account_metas(None),
-                        data: instruction::ClosePosition {}.data(),
-                    },
-                ];
-
-                let builder = program.request();
-                let builder = instructions
-                    .into_iter()
-                    .fold(builder, |bld, ix| bld.instruction(ix));
-                let signature = builder
-                    .send_with_spinner_and_config(transaction_config)
-                    .await?;
-                println!("close popsition min_bin_id {i} {signature}");
-            }
-            Err(_err) => continue,
+    for lower_bin_id in min_bin_id.saturating_sub(width - 1)..=max_bin_id {
+        let (position, _bump) =
+            derive_position_pda(lb_pair, base_position_key, lower_bin_id, width);
+
+        let Ok(position_state) = program.account::<PositionV2>(position).await else {
+            continue;
+        };
+
+        let bin_liquidity_removal = get_reductions_by_price_range(
+            &position_state,
+            bin_step,
+            min_price,
+            max_price,
+            BASIS_POINT_MAX as u16,
+        )?;
+        if bin_liquidity_removal.is_empty() {
+            continue;
+        }
+
+        let lower_bin_array_idx = BinArray::bin_id_to_bin_array_index(position_state.lower_bin_id)?;
+        let upper_bin_array_idx = lower_bin_array_idx.checked_add(1).context("MathOverflow")?;
+
+        let (bin_array_lower, _bump) = derive_bin_array_pda(lb_pair, lower_bin_array_idx.into());
+        let (bin_array_upper, _bump) = derive_bin_array_pda(lb_pair, upper_bin_array_idx.into());
+
+        let mut remove_liquidity_accounts = accounts::ModifyLiquidity {
+            bin_array_lower,
+            bin_array_upper,
+            lb_pair,
+            bin_array_bitmap_extension: None,
+            position,
+            reserve_x: lb_pair_state.reserve_x,
+            reserve_y: lb_pair_state.reserve_y,
+            token_x_mint: lb_pair_state.token_x_mint,
+            token_y_mint: lb_pair_state.token_y_mint,
+            sender: program.payer(),
+            user_token_x,
+            user_token_y,
+            token_x_program,
+            token_y_program,
+            position_nft_account: None,
+            event_authority,
+            program: lb_clmm::ID,
+        }
+        .to_account_metas(None);
+        remove_liquidity_accounts.extend(transfer_hook_accounts.iter().cloned());
+
+        let mut instructions = vec![
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            Instruction {
+                program_id: lb_clmm::ID,
+                accounts: remove_liquidity_accounts,
+                data: instruction::RemoveLiquidity {
+                    bin_liquidity_removal,
+                }
+                .data(),
+            },
+        ];
+
+        let is_position_within_range =
+            min_bin_id <= position_state.lower_bin_id && position_state.upper_bin_id <= max_bin_id;
+        if is_position_within_range {
+            instructions.push(Instruction {
+                program_id: lb_clmm::ID,
+                accounts: accounts::ClaimFee {
+                    bin_array_lower,
+                    bin_array_upper,
+                    lb_pair,
+                    sender: program.payer(),
+                    position,
+                    reserve_x: lb_pair_state.reserve_x,
+                    reserve_y: lb_pair_state.reserve_y,
+                    token_program: token_x_program,
+                    token_x_mint: lb_pair_state.token_x_mint,
+                    token_y_mint: lb_pair_state.token_y_mint,
+                    user_token_x,
+                    user_token_y,
+                    position_nft_account: None,
+                    admin_config: None,
+                    event_authority,
+                    program: lb_clmm::ID,
+                }
+                .to_account_metas(None),
+                data: instruction::ClaimFee {}.data(),
+            });
+            instructions.push(Instruction {
+                program_id: lb_clmm::ID,
+                accounts: accounts::ClosePosition {
+                    lb_pair,
+                    position,
+                    bin_array_lower,
+                    bin_array_upper,
+                    rent_receiver: program.payer(),
+                    sender: program.payer(),
+                    position_nft_account: None,
+                    event_authority,
+                    program: lb_clmm::ID,
+                }
+                .to_account_metas(None),
+                data: instruction::ClosePosition {}.data(),
+            });
+        }
+
+        let builder = program.request();
+        let builder = instructions
+            .into_iter()
+            .fold(builder, |bld, ix| bld.instruction(ix));
+        let signature = builder
+            .send_with_spinner_and_config(transaction_config)
+            .await?;
+
+        if is_position_within_range {
+            println!("Remove liquidity and close position {position} {signature}");
+        } else {
+            println!("Remove liquidity of position {position} {signature}");
         }
     }
+
     Ok(())
 }
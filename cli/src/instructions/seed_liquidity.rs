@@ -200,6 +200,7 @@ pub async fn deposit<C: Deref<Target = impl Signer> + Clone>(
             bin_array_lower,
             bin_array_upper,
             sender: program.payer(),
+            position_nft_account: None,
             event_authority,
             program: lb_clmm::ID,
             reserve_x: lb_pair_state.reserve_x,
@@ -256,14 +257,15 @@ pub async fn create_position_bin_array_if_not_exists<C: Deref<Target = impl Sign
         let (bin_array, _bump) = derive_bin_array_pda(lb_pair, idx.into());
 
         if program.rpc().get_account_data(&bin_array).is_err() {
-            let accounts = accounts::InitializeBinArray {
+            let accounts = accounts::InitializeBinArrayWithFunder {
                 bin_array,
+                bin_array_funder: derive_bin_array_funder_pda(bin_array).0,
                 funder: program.payer(),
                 lb_pair,
                 system_program: anchor_client::solana_sdk::system_program::ID,
             };
 
-            let ix_data = instruction::InitializeBinArray { index: idx.into() };
+            let ix_data = instruction::InitializeBinArrayWithFunder { index: idx.into() };
             let ix = Instruction {
                 accounts: accounts.to_account_metas(None),
                 data: ix_data.data(),
@@ -17,29 +17,46 @@ use anchor_lang::InstructionData;
 use anchor_lang::ToAccountMetas;
 use anchor_spl::token::{spl_token, Mint};
 use anyhow::*;
+use commons::instructions::set_position_vesting::set_position_vesting;
 use lb_clmm::accounts;
 use lb_clmm::constants::MAX_BIN_PER_POSITION;
 use lb_clmm::instruction;
 use lb_clmm::instructions::deposit::BinLiquidityDistribution;
+use lb_clmm::instructions::set_position_vesting::VestingParameter;
 use lb_clmm::state::lb_pair::LbPair;
 use lb_clmm::state::position::PositionV2;
 use lb_clmm::utils::pda::*;
 use spl_associated_token_account::get_associated_token_address;
 use spl_associated_token_account::instruction::create_associated_token_account;
 
-async fn get_or_create_position<C: Deref<Target = impl Signer> + Clone>(
-    program: &Program<C>,
+struct OperatorPositionParams<'a> {
     lb_pair: Pubkey,
-    base_keypair: &Keypair,
+    base_keypair: &'a Keypair,
     lower_bin_id: i32,
     upper_bin_id: i32,
     width: i32,
     owner: Pubkey,
     fee_owner: Pubkey,
     lock_release_point: u64,
+}
+
+async fn get_or_create_position<C: Deref<Target = impl Signer> + Clone>(
+    params: OperatorPositionParams<'_>,
+    program: &Program<C>,
     transaction_config: RpcSendTransactionConfig,
     compute_unit_price_ix: Option<Instruction>,
 ) -> Result<PositionV2> {
+    let OperatorPositionParams {
+        lb_pair,
+        base_keypair,
+        lower_bin_id,
+        upper_bin_id,
+        width,
+        owner,
+        fee_owner,
+        lock_release_point,
+    } = params;
+
     let (event_authority, _bump) = derive_event_authority_pda();
     let base = base_keypair.pubkey();
 
@@ -135,6 +152,38 @@ async fn get_or_create_position<C: Deref<Target = impl Signer> + Clone>(
     Ok(position_state)
 }
 
+async fn set_vesting_if_not_exists<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    position: Pubkey,
+    position_state: &PositionV2,
+    vesting: VestingParameter,
+    transaction_config: RpcSendTransactionConfig,
+    compute_unit_price_ix: Option<Instruction>,
+) -> Result<()> {
+    let current_vesting = (
+        position_state.vesting_start_point,
+        position_state.vesting_cliff_point,
+        position_state.vesting_end_point,
+    );
+    if current_vesting == (vesting.start_point, vesting.cliff_point, vesting.end_point) {
+        return Ok(());
+    }
+
+    let mut builder = program.request();
+    if let Some(compute_unit_price_ix) = compute_unit_price_ix {
+        builder = builder.instruction(compute_unit_price_ix);
+    }
+
+    let signature = builder
+        .instruction(set_position_vesting(position, program.payer(), vesting))
+        .send_with_spinner_and_config(transaction_config)
+        .await;
+    println!("Set position {position} vesting {vesting:?}. signature {signature:#?}");
+    signature?;
+
+    Ok(())
+}
+
 #[derive(Debug)]
 pub struct SeedLiquidityByOperatorParameters {
     pub lb_pair: Pubkey,
@@ -146,6 +195,7 @@ pub struct SeedLiquidityByOperatorParameters {
     pub position_owner: Pubkey,
     pub fee_owner: Pubkey,
     pub lock_release_point: u64,
+    pub vesting: Option<VestingParameter>,
     pub curvature: f64,
 }
 
@@ -164,6 +214,7 @@ pub async fn seed_liquidity_by_operator<C: Deref<Target = impl Signer> + Clone>(
         position_owner,
         fee_owner,
         lock_release_point,
+        vesting,
         base_pubkey,
         curvature,
     } = params;
@@ -274,15 +325,17 @@ pub async fn seed_liquidity_by_operator<C: Deref<Target = impl Signer> + Clone>(
             derive_position_pda(lb_pair, position_base_kp.pubkey(), lower_bin_id, width);
 
         let position_state = get_or_create_position(
+            OperatorPositionParams {
+                lb_pair,
+                base_keypair: &position_base_kp,
+                lower_bin_id,
+                upper_bin_id,
+                width,
+                owner: position_owner,
+                fee_owner,
+                lock_release_point,
+            },
             program,
-            lb_pair,
-            &position_base_kp,
-            lower_bin_id,
-            upper_bin_id,
-            width,
-            position_owner,
-            fee_owner,
-            lock_release_point,
             transaction_config,
             compute_unit_price.clone(),
         )
@@ -293,6 +346,18 @@ pub async fn seed_liquidity_by_operator<C: Deref<Target = impl Signer> + Clone>(
             continue;
         }
 
+        if let Some(vesting) = vesting {
+            set_vesting_if_not_exists(
+                program,
+                position,
+                &position_state,
+                vesting,
+                transaction_config,
+                compute_unit_price.clone(),
+            )
+            .await?;
+        }
+
         assert_eq!(
             position_state.lower_bin_id, lower_bin_id,
             "Position lower bin id not equals"
@@ -18,7 +18,8 @@ use lb_clmm::{
     math::u128x128_math::Rounding,
     state::bin::BinArray,
     utils::pda::{
-        derive_bin_array_bitmap_extension, derive_bin_array_pda, derive_event_authority_pda,
+        derive_bin_array_bitmap_extension, derive_bin_array_funder_pda, derive_bin_array_pda,
+        derive_event_authority_pda,
     },
 };
 use lb_clmm::{state::lb_pair::LbPair, utils::pda::derive_position_pda};
@@ -181,14 +182,15 @@ pub async fn seed_liquidity_single_bin<C: Deref<Target = impl Signer> + Clone>(
         if program.rpc().get_account(&lower_bin_array).is_err() {
             let initialize_bin_array_ix = Instruction {
                 program_id: lb_clmm::ID,
-                accounts: accounts::InitializeBinArray {
+                accounts: accounts::InitializeBinArrayWithFunder {
                     lb_pair,
                     bin_array,
+                    bin_array_funder: derive_bin_array_funder_pda(bin_array).0,
                     funder: program.payer(),
                     system_program: anchor_lang::system_program::ID,
                 }
                 .to_account_metas(None),
-                data: instruction::InitializeBinArray {
+                data: instruction::InitializeBinArrayWithFunder {
                     index: bin_array_index.into(),
                 }
                 .data(),
@@ -215,6 +217,7 @@ pub async fn seed_liquidity_single_bin<C: Deref<Target = impl Signer> + Clone>(
             sender: program.payer(),
             token_x_program: anchor_spl::token::ID,
             token_y_program: anchor_spl::token::ID,
+            position_nft_account: None,
             event_authority,
             program: lb_clmm::ID,
         }
@@ -12,13 +12,16 @@ use anchor_lang::InstructionData;
 use anchor_lang::ToAccountMetas;
 use anchor_spl::token::{spl_token, Mint};
 use anyhow::{Context, Result};
+use commons::instructions::set_position_vesting::set_position_vesting;
 use lb_clmm::{
     accounts, instruction,
     instructions::deposit::{BinLiquidityDistribution, LiquidityParameter},
+    instructions::set_position_vesting::VestingParameter,
     math::u128x128_math::Rounding,
     state::bin::BinArray,
     utils::pda::{
-        derive_bin_array_bitmap_extension, derive_bin_array_pda, derive_event_authority_pda,
+        derive_bin_array_bitmap_extension, derive_bin_array_funder_pda, derive_bin_array_pda,
+        derive_event_authority_pda,
     },
 };
 use lb_clmm::{state::lb_pair::LbPair, utils::pda::derive_position_pda};
@@ -40,6 +43,7 @@ pub struct SeedLiquiditySingleBinByOperatorParameters {
     pub position_owner: Pubkey,
     pub base_pubkey: Pubkey,
     pub lock_release_point: u64,
+    pub vesting: Option<VestingParameter>,
     pub fee_owner: Pubkey,
     pub selective_rounding: SelectiveRounding,
 }
@@ -58,6 +62,7 @@ pub async fn seed_liquidity_single_bin_by_operator<C: Deref<Target = impl Signer
         position_owner,
         base_pubkey,
         lock_release_point,
+        vesting,
         fee_owner,
         selective_rounding,
     } = params;
@@ -165,14 +170,15 @@ pub async fn seed_liquidity_single_bin_by_operator<C: Deref<Target = impl Signer
         if program.rpc().get_account(&lower_bin_array).is_err() {
             let initialize_bin_array_ix = Instruction {
                 program_id: lb_clmm::ID,
-                accounts: accounts::InitializeBinArray {
+                accounts: accounts::InitializeBinArrayWithFunder {
                     lb_pair,
                     bin_array,
+                    bin_array_funder: derive_bin_array_funder_pda(bin_array).0,
                     funder: program.payer(),
                     system_program: anchor_lang::system_program::ID,
                 }
                 .to_account_metas(None),
-                data: instruction::InitializeBinArray {
+                data: instruction::InitializeBinArrayWithFunder {
                     index: bin_array_index.into(),
                 }
                 .data(),
@@ -248,6 +254,10 @@ pub async fn seed_liquidity_single_bin_by_operator<C: Deref<Target = impl Signer
 
     instructions.push(initialize_position_ix);
 
+    if let Some(vesting) = vesting {
+        instructions.push(set_position_vesting(position, program.payer(), vesting));
+    }
+
     let deposit_ix = Instruction {
         program_id: lb_clmm::ID,
         accounts: accounts::ModifyLiquidity {
@@ -265,6 +275,7 @@ pub async fn seed_liquidity_single_bin_by_operator<C: Deref<Target = impl Signer
             sender: program.payer(),
             token_x_program: anchor_spl::token::ID,
             token_y_program: anchor_spl::token::ID,
+            position_nft_account: None,
             event_authority,
             program: lb_clmm::ID,
         }
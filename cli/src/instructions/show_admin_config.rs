@@ -0,0 +1,35 @@
+use anchor_client::{solana_sdk::signer::Signer, Program};
+use anyhow::*;
+use lb_clmm::state::admin_config::{AdminConfig, AdminRole};
+use lb_clmm::utils::pda::derive_admin_config_pda;
+use std::ops::Deref;
+
+pub async fn show_admin_config<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+) -> Result<()> {
+    let (admin_config, _bump) = derive_admin_config_pda();
+
+    let Result::Ok(admin_config_state) = program.account::<AdminConfig>(admin_config).await else {
+        println!(
+            "Admin config {} is not initialized, predefined admins are in use",
+            admin_config
+        );
+        return Ok(());
+    };
+
+    for role in [
+        AdminRole::FeeAdmin,
+        AdminRole::PauseGuardian,
+        AdminRole::RewardAdmin,
+        AdminRole::LaunchPoolAdmin,
+    ] {
+        println!(
+            "{:?}: {} pending: {}",
+            role,
+            admin_config_state.get_authority(role),
+            admin_config_state.get_pending_authority(role)
+        );
+    }
+
+    Ok(())
+}
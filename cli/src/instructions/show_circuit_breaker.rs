@@ -0,0 +1,50 @@
+use anchor_client::solana_sdk::clock::Clock;
+use anchor_client::solana_sdk::sysvar::SysvarId;
+use anchor_client::{solana_sdk::pubkey::Pubkey, solana_sdk::signer::Signer, Program};
+use anyhow::*;
+use commons::quote::get_circuit_breaker_time_weighted_active_id;
+use lb_clmm::state::lb_pair::LbPair;
+use std::ops::Deref;
+
+pub async fn show_circuit_breaker<C: Deref<Target = impl Signer> + Clone>(
+    lb_pair: Pubkey,
+    program: &Program<C>,
+) -> Result<()> {
+    let lb_pair_state: LbPair = program.account(lb_pair).await?;
+    let circuit_breaker = lb_pair_state.circuit_breaker;
+
+    println!("{:#?}", circuit_breaker);
+
+    if !circuit_breaker.is_enabled() {
+        println!("Circuit breaker is disabled");
+        return Ok(());
+    }
+
+    let rpc_client = program.async_rpc();
+    let clock_account = rpc_client.get_account(&Clock::id()).await?;
+    let clock: Clock = bincode::deserialize(clock_account.data.as_ref())?;
+    let oracle_account = rpc_client.get_account(&lb_pair_state.oracle).await?;
+
+    if circuit_breaker.is_halted(clock.unix_timestamp) {
+        println!(
+            "Swap is halted for {} seconds",
+            circuit_breaker.halted_until - clock.unix_timestamp
+        );
+    }
+
+    match get_circuit_breaker_time_weighted_active_id(
+        &lb_pair_state,
+        &oracle_account,
+        clock.unix_timestamp,
+    )? {
+        Some(time_weighted_active_id) => println!(
+            "Active id {}, time weighted active id {}, {} bins moved",
+            lb_pair_state.active_id,
+            time_weighted_active_id,
+            lb_pair_state.active_id.abs_diff(time_weighted_active_id)
+        ),
+        None => println!("Insufficient oracle samples"),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use anchor_client::solana_sdk::clock::Clock;
+use anchor_client::solana_sdk::sysvar::SysvarId;
+use anchor_client::{solana_sdk::pubkey::Pubkey, solana_sdk::signer::Signer, Program};
+use anyhow::*;
+use commons::oracle::get_oracle_coverage;
+use lb_clmm::state::lb_pair::LbPair;
+use std::ops::Deref;
+
+pub async fn show_oracle<C: Deref<Target = impl Signer> + Clone>(
+    lb_pair: Pubkey,
+    program: &Program<C>,
+) -> Result<()> {
+    let lb_pair_state: LbPair = program.account(lb_pair).await?;
+
+    let rpc_client = program.async_rpc();
+    let clock_account = rpc_client.get_account(&Clock::id()).await?;
+    let clock: Clock = bincode::deserialize(clock_account.data.as_ref())?;
+    let oracle_account = rpc_client.get_account(&lb_pair_state.oracle).await?;
+
+    let coverage = get_oracle_coverage(&lb_pair_state, &oracle_account, clock.unix_timestamp)?;
+
+    println!("{:#?}", coverage);
+
+    if coverage.missing_length > 0 {
+        println!(
+            "{} observations, {} lamports, missing to cover the target history duration",
+            coverage.missing_length, coverage.missing_length_rent
+        );
+    }
+
+    Ok(())
+}
@@ -10,10 +10,12 @@ use anchor_client::solana_sdk::sysvar::SysvarId;
 use anchor_client::{solana_sdk::pubkey::Pubkey, solana_sdk::signer::Signer, Program};
 use anchor_lang::solana_program::instruction::AccountMeta;
 use anchor_lang::AccountDeserialize;
-use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
 
+use super::utils::{get_mint_accounts, get_swap_transfer_hook_accounts};
 use anyhow::*;
-use commons::quote::{get_bin_array_pubkeys_for_swap, quote_exact_in};
+use commons::quote::{get_bin_array_pubkeys_for_swap, quote_exact_in, SwapQuoteAccounts};
+use commons::token_2022::get_token_program;
 use lb_clmm::accounts;
 use lb_clmm::constants::BASIS_POINT_MAX;
 use lb_clmm::instruction;
@@ -43,15 +45,35 @@ pub async fn swap<C: Deref<Target = impl Signer> + Clone>(
 
     let lb_pair_state: LbPair = program.account(lb_pair).await?;
 
+    let mint_accounts = get_mint_accounts(program, &lb_pair_state).await?;
+    let token_x_program = get_token_program(&mint_accounts[0]);
+    let token_y_program = get_token_program(&mint_accounts[1]);
+
     let (user_token_in, user_token_out) = if swap_for_y {
         (
-            get_associated_token_address(&program.payer(), &lb_pair_state.token_x_mint),
-            get_associated_token_address(&program.payer(), &lb_pair_state.token_y_mint),
+            get_associated_token_address_with_program_id(
+                &program.payer(),
+                &lb_pair_state.token_x_mint,
+                &token_x_program,
+            ),
+            get_associated_token_address_with_program_id(
+                &program.payer(),
+                &lb_pair_state.token_y_mint,
+                &token_y_program,
+            ),
         )
     } else {
         (
-            get_associated_token_address(&program.payer(), &lb_pair_state.token_y_mint),
-            get_associated_token_address(&program.payer(), &lb_pair_state.token_x_mint),
+            get_associated_token_address_with_program_id(
+                &program.payer(),
+                &lb_pair_state.token_y_mint,
+                &token_y_program,
+            ),
+            get_associated_token_address_with_program_id(
+                &program.payer(),
+                &lb_pair_state.token_x_mint,
+                &token_x_program,
+            ),
         )
     };
 
@@ -95,17 +117,31 @@ pub async fn swap<C: Deref<Target = impl Signer> + Clone>(
             Ok(clock)
         })??;
 
+    let oracle_account = program
+        .async_rpc()
+        .get_account(&lb_pair_state.oracle)
+        .await?;
+
     let quote = quote_exact_in(
-        lb_pair,
-        &lb_pair_state,
+        SwapQuoteAccounts {
+            lb_pair_pubkey: lb_pair,
+            lb_pair: &lb_pair_state,
+            bin_arrays,
+            bitmap_extension: bitmap_extension.as_ref(),
+            mint_x_account: &mint_accounts[0],
+            mint_y_account: &mint_accounts[1],
+            oracle_account: &oracle_account,
+        },
         amount_in,
         swap_for_y,
-        bin_arrays,
-        bitmap_extension.as_ref(),
-        clock.unix_timestamp as u64,
-        clock.slot,
+        &clock,
+        None,
     )?;
 
+    if quote.would_trip_circuit_breaker {
+        println!("Warning: the swap would trip the circuit breaker of the pair");
+    }
+
     let (event_authority, _bump) =
         Pubkey::find_program_address(&[b"__event_authority"], &lb_clmm::ID);
 
@@ -118,8 +154,8 @@ pub async fn swap<C: Deref<Target = impl Signer> + Clone>(
         reserve_y: lb_pair_state.reserve_y,
         token_x_mint: lb_pair_state.token_x_mint,
         token_y_mint: lb_pair_state.token_y_mint,
-        token_x_program: anchor_spl::token::ID,
-        token_y_program: anchor_spl::token::ID,
+        token_x_program,
+        token_y_program,
         user: program.payer(),
         user_token_in,
         user_token_out,
@@ -137,11 +173,24 @@ pub async fn swap<C: Deref<Target = impl Signer> + Clone>(
         min_amount_out,
     };
 
-    let remaining_accounts = bin_arrays_for_swap
+    let mut remaining_accounts = bin_arrays_for_swap
         .into_iter()
         .map(|key| AccountMeta::new(key, false))
         .collect::<Vec<_>>();
 
+    remaining_accounts.extend(
+        get_swap_transfer_hook_accounts(
+            program,
+            lb_pair,
+            &lb_pair_state,
+            &mint_accounts,
+            swap_for_y,
+            [user_token_in, user_token_out],
+            [amount_in, quote.amount_out],
+        )
+        .await?,
+    );
+
     let compute_budget_ix = ComputeBudgetInstruction::set_compute_unit_limit(1_400_000);
 
     let request_builder = program.request();
@@ -235,19 +284,40 @@ pub async fn swap_exact_in_instructions<C: Deref<Target = impl Signer> + Clone>(
     lb_pair_state._padding_3 = hack_lb_pair_state._padding_3;
     lb_pair_state._padding_4 = hack_lb_pair_state._padding_4;
     lb_pair_state.creator = hack_lb_pair_state.creator;
-    lb_pair_state._reserved = hack_lb_pair_state._reserved;
+    lb_pair_state.circuit_breaker = hack_lb_pair_state.circuit_breaker;
+    lb_pair_state.oracle_parameters = hack_lb_pair_state.oracle_parameters;
     // End copy
     println!("Pass through lb_pair_state workaround copy");
 
+    let mint_accounts = get_mint_accounts(program, &lb_pair_state).await?;
+    let token_x_program = get_token_program(&mint_accounts[0]);
+    let token_y_program = get_token_program(&mint_accounts[1]);
+
     let (user_token_in, user_token_out) = if swap_for_y {
         (
-            get_associated_token_address(&program.payer(), &lb_pair_state.token_x_mint),
-            get_associated_token_address(&program.payer(), &lb_pair_state.token_y_mint),
+            get_associated_token_address_with_program_id(
+                &program.payer(),
+                &lb_pair_state.token_x_mint,
+                &token_x_program,
+            ),
+            get_associated_token_address_with_program_id(
+                &program.payer(),
+                &lb_pair_state.token_y_mint,
+                &token_y_program,
+            ),
         )
     } else {
         (
-            get_associated_token_address(&program.payer(), &lb_pair_state.token_y_mint),
-            get_associated_token_address(&program.payer(), &lb_pair_state.token_x_mint),
+            get_associated_token_address_with_program_id(
+                &program.payer(),
+                &lb_pair_state.token_y_mint,
+                &token_y_program,
+            ),
+            get_associated_token_address_with_program_id(
+                &program.payer(),
+                &lb_pair_state.token_x_mint,
+                &token_x_program,
+            ),
         )
     };
 
@@ -378,17 +448,31 @@ pub async fn swap_exact_in_instructions<C: Deref<Target = impl Signer> + Clone>(
 
     println!("Pass through getting clock");
 
+    let oracle_account = program
+        .async_rpc()
+        .get_account(&lb_pair_state.oracle)
+        .await?;
+
     let quote = quote_exact_in(
-        lb_pair,
-        &lb_pair_state,
+        SwapQuoteAccounts {
+            lb_pair_pubkey: lb_pair,
+            lb_pair: &lb_pair_state,
+            bin_arrays,
+            bitmap_extension: bitmap_extension.as_ref(),
+            mint_x_account: &mint_accounts[0],
+            mint_y_account: &mint_accounts[1],
+            oracle_account: &oracle_account,
+        },
         amount_in,
         swap_for_y,
-        bin_arrays,
-        bitmap_extension.as_ref(),
-        clock.unix_timestamp as u64,
-        clock.slot,
+        &clock,
+        None,
     )?;
 
+    if quote.would_trip_circuit_breaker {
+        println!("Warning: the swap would trip the circuit breaker of the pair");
+    }
+
     println!("Pass through getting quote with quote_exact_in");
 
     let (event_authority, _bump) =
@@ -403,8 +487,8 @@ pub async fn swap_exact_in_instructions<C: Deref<Target = impl Signer> + Clone>(
         reserve_y: lb_pair_state.reserve_y,
         token_x_mint: lb_pair_state.token_x_mint,
         token_y_mint: lb_pair_state.token_y_mint,
-        token_x_program: anchor_spl::token::ID,
-        token_y_program: anchor_spl::token::ID,
+        token_x_program,
+        token_y_program,
         user: program.payer(),
         user_token_in,
         user_token_out,
@@ -424,12 +508,30 @@ pub async fn swap_exact_in_instructions<C: Deref<Target = impl Signer> + Clone>(
         min_amount_out,
     };
 
-    let remaining_accounts = bin_arrays_for_swap
+    let mut remaining_accounts = bin_arrays_for_swap
         .into_iter()
         .map(|key| AccountMeta::new(key, false))
         .collect::<Vec<_>>();
 
+    remaining_accounts.extend(
+        get_swap_transfer_hook_accounts(
+            program,
+            lb_pair,
+            &lb_pair_state,
+            &mint_accounts,
+            swap_for_y,
+            [user_token_in, user_token_out],
+            [amount_in, quote.amount_out],
+        )
+        .await?,
+    );
+
     // let compute_budget_ix = ComputeBudgetInstruction::set_compute_unit_limit(1_400_000);
+    let user_mint_out_program = if swap_for_y {
+        token_y_program
+    } else {
+        token_x_program
+    };
     let mut is_creating_ata = false;
     let mut ata_ix = Vec::new();
     // let user_output_token_account = spl_associated_token_account::get_associated_token_address(
@@ -456,7 +558,7 @@ pub async fn swap_exact_in_instructions<C: Deref<Target = impl Signer> + Clone>(
                 &program.payer(),
                 &program.payer(),
                 &user_mint_out,
-                Some(&anchor_spl::token::ID),
+                Some(&user_mint_out_program),
             );
         }
     } else {
@@ -472,7 +574,7 @@ pub async fn swap_exact_in_instructions<C: Deref<Target = impl Signer> + Clone>(
             &program.payer(),
             &program.payer(),
             &user_mint_out,
-            Some(&anchor_spl::token::ID),
+            Some(&user_mint_out_program),
         );
     }
 
@@ -5,10 +5,12 @@ use anchor_client::solana_client::rpc_config::RpcSendTransactionConfig;
 use anchor_client::solana_sdk::compute_budget::ComputeBudgetInstruction;
 use anchor_client::{solana_sdk::pubkey::Pubkey, solana_sdk::signer::Signer, Program};
 use anchor_lang::solana_program::instruction::AccountMeta;
-use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
 
+use super::utils::{get_mint_accounts, get_swap_transfer_hook_accounts};
 use anyhow::*;
 use commons::quote::get_bin_array_pubkeys_for_swap;
+use commons::token_2022::get_token_program;
 use lb_clmm::accounts;
 use lb_clmm::instruction;
 
@@ -38,15 +40,35 @@ pub async fn swap_with_price_impact<C: Deref<Target = impl Signer> + Clone>(
 
     let lb_pair_state: LbPair = program.account(lb_pair).await?;
 
+    let mint_accounts = get_mint_accounts(program, &lb_pair_state).await?;
+    let token_x_program = get_token_program(&mint_accounts[0]);
+    let token_y_program = get_token_program(&mint_accounts[1]);
+
     let (user_token_in, user_token_out) = if swap_for_y {
         (
-            get_associated_token_address(&program.payer(), &lb_pair_state.token_x_mint),
-            get_associated_token_address(&program.payer(), &lb_pair_state.token_y_mint),
+            get_associated_token_address_with_program_id(
+                &program.payer(),
+                &lb_pair_state.token_x_mint,
+                &token_x_program,
+            ),
+            get_associated_token_address_with_program_id(
+                &program.payer(),
+                &lb_pair_state.token_y_mint,
+                &token_y_program,
+            ),
         )
     } else {
         (
-            get_associated_token_address(&program.payer(), &lb_pair_state.token_y_mint),
-            get_associated_token_address(&program.payer(), &lb_pair_state.token_x_mint),
+            get_associated_token_address_with_program_id(
+                &program.payer(),
+                &lb_pair_state.token_y_mint,
+                &token_y_program,
+            ),
+            get_associated_token_address_with_program_id(
+                &program.payer(),
+                &lb_pair_state.token_x_mint,
+                &token_x_program,
+            ),
         )
     };
 
@@ -77,8 +99,8 @@ pub async fn swap_with_price_impact<C: Deref<Target = impl Signer> + Clone>(
         reserve_y: lb_pair_state.reserve_y,
         token_x_mint: lb_pair_state.token_x_mint,
         token_y_mint: lb_pair_state.token_y_mint,
-        token_x_program: anchor_spl::token::ID,
-        token_y_program: anchor_spl::token::ID,
+        token_x_program,
+        token_y_program,
         user: program.payer(),
         user_token_in,
         user_token_out,
@@ -94,11 +116,25 @@ pub async fn swap_with_price_impact<C: Deref<Target = impl Signer> + Clone>(
         max_price_impact_bps: price_impact_bps,
     };
 
-    let remaining_accounts = bin_arrays_for_swap
+    let mut remaining_accounts = bin_arrays_for_swap
         .into_iter()
         .map(|key| AccountMeta::new(key, false))
         .collect::<Vec<_>>();
 
+    // Amount out is unknown without quote
+    remaining_accounts.extend(
+        get_swap_transfer_hook_accounts(
+            program,
+            lb_pair,
+            &lb_pair_state,
+            &mint_accounts,
+            swap_for_y,
+            [user_token_in, user_token_out],
+            [amount_in, 0],
+        )
+        .await?,
+    );
+
     let compute_budget_ix = ComputeBudgetInstruction::set_compute_unit_limit(1_400_000);
 
     let request_builder = program.request();
@@ -5,6 +5,7 @@ use anchor_lang::prelude::Pubkey;
 use anyhow::*;
 use lb_clmm::accounts;
 use lb_clmm::instruction;
+use lb_clmm::utils::pda::derive_admin_config_pda;
 use std::ops::Deref;
 
 pub async fn toggle_pool_status<C: Deref<Target = impl Signer> + Clone>(
@@ -13,6 +14,7 @@ pub async fn toggle_pool_status<C: Deref<Target = impl Signer> + Clone>(
     transaction_config: RpcSendTransactionConfig,
 ) -> Result<()> {
     let accounts = accounts::TogglePairStatus {
+        admin_config: derive_admin_config_pda().0,
         admin: program.payer(),
         lb_pair,
     };
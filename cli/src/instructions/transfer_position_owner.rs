@@ -0,0 +1,45 @@
+use anchor_client::solana_client::rpc_config::RpcSendTransactionConfig;
+use anchor_client::solana_sdk::signer::Signer;
+use anchor_client::Program;
+use anchor_lang::prelude::Pubkey;
+use anyhow::*;
+use lb_clmm::state::position::PositionV2;
+use std::ops::Deref;
+
+#[derive(Debug)]
+pub struct TransferPositionOwnerParams {
+    pub position: Pubkey,
+    pub new_owner: Pubkey,
+}
+
+pub async fn transfer_position_owner<C: Deref<Target = impl Signer> + Clone>(
+    params: TransferPositionOwnerParams,
+    program: &Program<C>,
+    transaction_config: RpcSendTransactionConfig,
+) -> Result<()> {
+    let TransferPositionOwnerParams {
+        position,
+        new_owner,
+    } = params;
+
+    let position_state: PositionV2 = program.account(position).await?;
+
+    let ix = commons::instructions::transfer_position_owner::transfer_position_owner(
+        position,
+        position_state.lb_pair,
+        program.payer(),
+        new_owner,
+    );
+
+    let request_builder = program.request();
+    let signature = request_builder
+        .instruction(ix)
+        .send_with_spinner_and_config(transaction_config)
+        .await;
+
+    println!("Transfer position owner. Signature: {:#?}", signature);
+
+    signature?;
+
+    Ok(())
+}
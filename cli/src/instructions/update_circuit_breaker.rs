@@ -0,0 +1,47 @@
+use anchor_client::solana_client::rpc_config::RpcSendTransactionConfig;
+use anchor_client::solana_sdk::signer::Signer;
+use anchor_client::Program;
+use anchor_lang::prelude::Pubkey;
+use anyhow::*;
+use std::ops::Deref;
+
+#[derive(Debug)]
+pub struct UpdateCircuitBreakerParams {
+    pub lb_pair: Pubkey,
+    pub max_bin_movement: u16,
+    pub window: u16,
+    pub cooldown: u32,
+}
+
+pub async fn update_circuit_breaker<C: Deref<Target = impl Signer> + Clone>(
+    params: UpdateCircuitBreakerParams,
+    program: &Program<C>,
+    transaction_config: RpcSendTransactionConfig,
+) -> Result<()> {
+    let UpdateCircuitBreakerParams {
+        lb_pair,
+        max_bin_movement,
+        window,
+        cooldown,
+    } = params;
+
+    let ix = commons::instructions::update_circuit_breaker::update_circuit_breaker(
+        lb_pair,
+        program.payer(),
+        max_bin_movement,
+        window,
+        cooldown,
+    );
+
+    let request_builder = program.request();
+    let signature = request_builder
+        .instruction(ix)
+        .send_with_spinner_and_config(transaction_config)
+        .await;
+
+    println!("Update circuit breaker. Signature: {:#?}", signature);
+
+    signature?;
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use anchor_client::solana_client::rpc_config::RpcSendTransactionConfig;
+use anchor_client::solana_sdk::signer::Signer;
+use anchor_client::Program;
+use anchor_lang::prelude::Pubkey;
+use anyhow::*;
+use std::ops::Deref;
+
+#[derive(Debug)]
+pub struct UpdateOracleParametersParams {
+    pub lb_pair: Pubkey,
+    pub sample_lifetime: u16,
+    pub target_history_duration: u32,
+    pub auto_growth: bool,
+}
+
+pub async fn update_oracle_parameters<C: Deref<Target = impl Signer> + Clone>(
+    params: UpdateOracleParametersParams,
+    program: &Program<C>,
+    transaction_config: RpcSendTransactionConfig,
+) -> Result<()> {
+    let UpdateOracleParametersParams {
+        lb_pair,
+        sample_lifetime,
+        target_history_duration,
+        auto_growth,
+    } = params;
+
+    let ix = commons::instructions::update_oracle_parameters::update_oracle_parameters(
+        lb_pair,
+        program.payer(),
+        sample_lifetime,
+        target_history_duration,
+        auto_growth,
+    );
+
+    let request_builder = program.request();
+    let signature = request_builder
+        .instruction(ix)
+        .send_with_spinner_and_config(transaction_config)
+        .await;
+
+    println!("Update oracle parameters. Signature: {:#?}", signature);
+
+    signature?;
+
+    Ok(())
+}
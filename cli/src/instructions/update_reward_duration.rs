@@ -35,6 +35,7 @@ pub async fn update_reward_duration<C: Deref<Target = impl Signer> + Clone>(
 
     let accounts = accounts::UpdateRewardDuration {
         lb_pair,
+        admin_config: derive_admin_config_pda().0,
         admin: program.payer(),
         bin_array,
         event_authority,
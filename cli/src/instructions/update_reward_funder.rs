@@ -3,7 +3,7 @@ use anchor_client::{solana_sdk::pubkey::Pubkey, solana_sdk::signer::Signer, Prog
 use anyhow::*;
 use lb_clmm::accounts;
 use lb_clmm::instruction;
-use lb_clmm::utils::pda::derive_event_authority_pda;
+use lb_clmm::utils::pda::{derive_admin_config_pda, derive_event_authority_pda};
 
 use std::ops::Deref;
 
@@ -29,6 +29,7 @@ pub async fn update_reward_funder<C: Deref<Target = impl Signer> + Clone>(
 
     let accounts = accounts::UpdateRewardFunder {
         lb_pair,
+        admin_config: derive_admin_config_pda().0,
         admin: program.payer(),
         event_authority,
         program: lb_clmm::ID,
@@ -1,6 +1,10 @@
+use anchor_client::solana_sdk::account::Account;
+use anchor_client::solana_sdk::instruction::AccountMeta;
 use anchor_client::solana_sdk::signer::Signer;
 use anchor_client::Program;
+use commons::token_2022::get_transfer_hook_account_metas;
 use lb_clmm::state::bin::BinArray;
+use lb_clmm::state::lb_pair::LbPair;
 use lb_clmm::state::position::Position;
 use lb_clmm::utils::pda::derive_bin_array_pda;
 use spl_associated_token_account::instruction::create_associated_token_account;
@@ -8,8 +12,7 @@ use std::ops::Deref;
 
 use anchor_client::solana_client::rpc_config::RpcSendTransactionConfig;
 use anchor_client::solana_sdk::pubkey::Pubkey;
-use anchor_spl::associated_token::get_associated_token_address;
-use anchor_spl::token::spl_token;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
 use anyhow::*;
 
 pub async fn get_or_create_ata<C: Deref<Target = impl Signer> + Clone>(
@@ -18,9 +21,13 @@ pub async fn get_or_create_ata<C: Deref<Target = impl Signer> + Clone>(
     token_mint: Pubkey,
     wallet_address: Pubkey,
 ) -> Result<Pubkey> {
-    let user_ata = get_associated_token_address(&wallet_address, &token_mint);
-
     let rpc_client = program.rpc();
+
+    // SPL token or token 2022
+    let token_program = rpc_client.get_account(&token_mint)?.owner;
+    let user_ata =
+        get_associated_token_address_with_program_id(&wallet_address, &token_mint, &token_program);
+
     let user_ata_exists = rpc_client.get_account(&user_ata).is_ok();
 
     match user_ata_exists {
@@ -32,7 +39,7 @@ pub async fn get_or_create_ata<C: Deref<Target = impl Signer> + Clone>(
                     &program.payer(),
                     &wallet_address,
                     &token_mint,
-                    &spl_token::ID,
+                    &token_program,
                 ));
 
             builder
@@ -59,3 +66,138 @@ pub async fn get_bin_arrays_for_position<C: Deref<Target = impl Signer> + Clone>
 
     Ok([lower_bin_array, upper_bin_array])
 }
+
+/// Fetch token X and token Y mint accounts of the pair. Owner of the account is the token program of the mint.
+pub async fn get_mint_accounts<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    lb_pair_state: &LbPair,
+) -> Result<[Account; 2]> {
+    let mut accounts = program
+        .async_rpc()
+        .get_multiple_accounts(&[lb_pair_state.token_x_mint, lb_pair_state.token_y_mint])
+        .await?
+        .into_iter();
+
+    let mint_x_account = accounts
+        .next()
+        .flatten()
+        .context("Token X mint not found")?;
+    let mint_y_account = accounts
+        .next()
+        .flatten()
+        .context("Token Y mint not found")?;
+
+    Ok([mint_x_account, mint_y_account])
+}
+
+/// Extra accounts required by the transfer hooks of token in and token out, to be appended after the bin arrays of the swap.
+pub async fn get_swap_transfer_hook_accounts<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    lb_pair: Pubkey,
+    lb_pair_state: &LbPair,
+    [mint_x_account, mint_y_account]: &[Account; 2],
+    swap_for_y: bool,
+    [user_token_in, user_token_out]: [Pubkey; 2],
+    [amount_in, amount_out]: [u64; 2],
+) -> Result<Vec<AccountMeta>> {
+    let (mint_in, mint_in_account, reserve_in, mint_out, mint_out_account, reserve_out) =
+        if swap_for_y {
+            (
+                lb_pair_state.token_x_mint,
+                mint_x_account,
+                lb_pair_state.reserve_x,
+                lb_pair_state.token_y_mint,
+                mint_y_account,
+                lb_pair_state.reserve_y,
+            )
+        } else {
+            (
+                lb_pair_state.token_y_mint,
+                mint_y_account,
+                lb_pair_state.reserve_y,
+                lb_pair_state.token_x_mint,
+                mint_x_account,
+                lb_pair_state.reserve_x,
+            )
+        };
+
+    let rpc_client = program.async_rpc();
+
+    let mut transfer_hook_accounts = get_transfer_hook_account_metas(
+        &rpc_client,
+        mint_in,
+        mint_in_account,
+        user_token_in,
+        reserve_in,
+        program.payer(),
+        amount_in,
+    )
+    .await?;
+
+    transfer_hook_accounts.extend(
+        get_transfer_hook_account_metas(
+            &rpc_client,
+            mint_out,
+            mint_out_account,
+            reserve_out,
+            user_token_out,
+            lb_pair,
+            amount_out,
+        )
+        .await?,
+    );
+
+    Ok(transfer_hook_accounts)
+}
+
+/// Extra accounts required by the transfer hooks of token X and token Y, for deposit to or withdraw from the reserves.
+pub async fn get_liquidity_transfer_hook_accounts<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    lb_pair: Pubkey,
+    lb_pair_state: &LbPair,
+    [mint_x_account, mint_y_account]: &[Account; 2],
+    [user_token_x, user_token_y]: [Pubkey; 2],
+    [amount_x, amount_y]: [u64; 2],
+    is_deposit: bool,
+) -> Result<Vec<AccountMeta>> {
+    let rpc_client = program.async_rpc();
+    let mut transfer_hook_accounts = vec![];
+
+    for (mint, mint_account, user_token, reserve, amount) in [
+        (
+            lb_pair_state.token_x_mint,
+            mint_x_account,
+            user_token_x,
+            lb_pair_state.reserve_x,
+            amount_x,
+        ),
+        (
+            lb_pair_state.token_y_mint,
+            mint_y_account,
+            user_token_y,
+            lb_pair_state.reserve_y,
+            amount_y,
+        ),
+    ] {
+        let (source, destination, authority) = if is_deposit {
+            (user_token, reserve, program.payer())
+        } else {
+            (reserve, user_token, lb_pair)
+        };
+
+        transfer_hook_accounts.extend(
+            get_transfer_hook_account_metas(
+                &rpc_client,
+                mint,
+                mint_account,
+                source,
+                destination,
+                authority,
+                amount,
+            )
+            .await?,
+        );
+    }
+
+    Ok(transfer_hook_accounts)
+}
@@ -42,14 +42,25 @@ use crate::instructions::initialize_preset_parameter::InitPresetParameters;
 use crate::{
     args::Command,
     instructions::{
+        accept_admin_role::accept_admin_role,
         add_liquidity::{add_liquidity, AddLiquidityParam},
+        add_liquidity_by_strategy::{add_liquidity_by_strategy, AddLiquidityByStrategyParams},
+        add_liquidity_by_weight::{add_liquidity_by_weight, AddLiquidityByWeightParam},
+        add_liquidity_one_side::{add_liquidity_one_side, AddLiquidityOneSideParam},
+        add_liquidity_one_side_precise::{
+            add_liquidity_one_side_precise, AddLiquidityOneSidePreciseParam,
+        },
         check_my_balance::{check_my_balance, CheckMyBalanceParameters},
+        claim_all::claim_all,
         claim_fee::claim_fee,
         claim_reward::*,
+        close_empty_bin_arrays::close_empty_bin_arrays,
         close_position::close_position,
         close_preset_parameter::close_preset_parameter,
+        compound_fees::{compound_fees, CompoundFeesParams},
         fund_reward::*,
         increase_length::{increase_length, IncreaseLengthParams},
+        initialize_admin_config::{initialize_admin_config, InitAdminConfigParams},
         initialize_bin_array::{initialize_bin_array, InitBinArrayParameters},
         initialize_bin_array_with_price_range::{
             initialize_bin_array_with_price_range, InitBinArrayWithPriceRangeParameters,
@@ -61,7 +72,11 @@ use crate::{
         initialize_position::{initialize_position, InitPositionParameters},
         initialize_preset_parameter::initialize_preset_parameter,
         initialize_reward::*,
+        initialize_tokenized_position::{
+            initialize_tokenized_position, InitTokenizedPositionParameters,
+        },
         list_all_binstep::list_all_binstep,
+        propose_admin_role::propose_admin_role,
         remove_liquidity::{remove_liquidity, RemoveLiquidityParameters},
         remove_liquidity_by_price_range::{
             remove_liquidity_by_price_range, RemoveLiquidityByPriceRangeParameters,
@@ -72,12 +87,18 @@ use crate::{
         set_pre_activation_swap_address::{
             set_pre_activation_swap_address, SetPreactivationSwapAddressParam,
         },
+        show_admin_config::show_admin_config,
+        show_circuit_breaker::show_circuit_breaker,
+        show_oracle::show_oracle,
         show_pair::show_pair,
         simulate_swap_demand::{simulate_swap_demand, SimulateSwapDemandParameters},
         swap_exact_in::{swap, SwapExactInParameters},
         swap_exact_out::{swap_exact_out, SwapExactOutParameters},
         swap_with_price_impact::{swap_with_price_impact, SwapWithPriceImpactParameters},
         toggle_pair_status::toggle_pool_status,
+        transfer_position_owner::{transfer_position_owner, TransferPositionOwnerParams},
+        update_circuit_breaker::{update_circuit_breaker, UpdateCircuitBreakerParams},
+        update_oracle_parameters::{update_oracle_parameters, UpdateOracleParametersParams},
         update_reward_duration::*,
         update_reward_funder::*,
         withdraw_protocol_fee::{withdraw_protocol_fee, WithdrawProtocolFeeParams},
@@ -199,6 +220,20 @@ async fn main() -> Result<()> {
             };
             initialize_position(params, &amm_program, transaction_config).await?;
         }
+        Command::InitializeTokenizedPosition {
+            lb_pair,
+            lower_bin_id,
+            width,
+            uri,
+        } => {
+            let params = InitTokenizedPositionParameters {
+                lb_pair,
+                lower_bin_id,
+                width,
+                uri,
+            };
+            initialize_tokenized_position(params, &amm_program, transaction_config).await?;
+        }
         Command::AddLiquidity {
             lb_pair,
             position,
@@ -215,6 +250,84 @@ async fn main() -> Result<()> {
             };
             add_liquidity(params, &amm_program, transaction_config).await?;
         }
+        Command::AddLiquidityByWeight {
+            lb_pair,
+            position,
+            amount_x,
+            amount_y,
+            bin_liquidity_distribution,
+            max_active_bin_slippage,
+            dry_run,
+        } => {
+            let params = AddLiquidityByWeightParam {
+                lb_pair,
+                position,
+                amount_x,
+                amount_y,
+                bin_liquidity_distribution,
+                max_active_bin_slippage,
+                dry_run,
+            };
+            add_liquidity_by_weight(params, &amm_program, transaction_config).await?;
+        }
+        Command::AddLiquidityOneSide {
+            lb_pair,
+            position,
+            amount,
+            deposit_for_y,
+            bin_liquidity_distribution,
+            max_active_bin_slippage,
+        } => {
+            let params = AddLiquidityOneSideParam {
+                lb_pair,
+                position,
+                amount,
+                deposit_for_y,
+                bin_liquidity_distribution,
+                max_active_bin_slippage,
+            };
+            add_liquidity_one_side(params, &amm_program, transaction_config).await?;
+        }
+        Command::AddLiquidityOneSidePrecise {
+            lb_pair,
+            position,
+            deposit_for_y,
+            bin_amounts,
+        } => {
+            let params = AddLiquidityOneSidePreciseParam {
+                lb_pair,
+                position,
+                deposit_for_y,
+                bin_amounts,
+            };
+            add_liquidity_one_side_precise(params, &amm_program, transaction_config).await?;
+        }
+        Command::AddLiquidityByStrategy {
+            lb_pair,
+            amount_x,
+            amount_y,
+            range,
+            strategy,
+            imbalanced,
+            position,
+            max_active_bin_slippage,
+            dry_run,
+        } => {
+            let params = AddLiquidityByStrategyParams {
+                lb_pair,
+                amount_x,
+                amount_y,
+                range: range
+                    .to_deposit_range()
+                    .context("Missing price range or bin range")?,
+                strategy,
+                imbalanced,
+                position,
+                max_active_bin_slippage,
+                dry_run,
+            };
+            add_liquidity_by_strategy(params, &amm_program, transaction_config).await?;
+        }
         Command::RemoveLiquidity {
             lb_pair,
             position,
@@ -288,9 +401,39 @@ async fn main() -> Result<()> {
         Command::ClosePosition { position } => {
             close_position(position, &amm_program, transaction_config).await?;
         }
+        Command::TransferPositionOwner {
+            position,
+            new_owner,
+        } => {
+            let params = TransferPositionOwnerParams {
+                position,
+                new_owner,
+            };
+            transfer_position_owner(params, &amm_program, transaction_config).await?;
+        }
         Command::ClaimFee { position } => {
             claim_fee(position, &amm_program, transaction_config).await?;
         }
+        Command::ClaimAll => {
+            claim_all(&amm_program, transaction_config).await?;
+        }
+        Command::CompoundFees {
+            position,
+            strategy,
+            max_active_bin_slippage,
+            interval,
+        } => {
+            let params = CompoundFeesParams {
+                position,
+                strategy,
+                max_active_bin_slippage,
+                interval,
+            };
+            compound_fees(params, &amm_program, transaction_config).await?;
+        }
+        Command::CloseEmptyBinArrays { lb_pair } => {
+            close_empty_bin_arrays(lb_pair, &amm_program, transaction_config).await?;
+        }
         Command::IncreaseLength {
             lb_pair,
             length_to_add,
@@ -301,6 +444,9 @@ async fn main() -> Result<()> {
             };
             increase_length(params, &amm_program, transaction_config).await?;
         }
+        Command::ShowOracle { lb_pair } => {
+            show_oracle(lb_pair, &amm_program).await?;
+        }
 
         Command::ShowPresetParameter { preset_parameter } => {
             let preset_param_state: PresetParameter = amm_program.account(preset_parameter).await?;
@@ -439,6 +585,7 @@ async fn main() -> Result<()> {
             position_owner,
             fee_owner,
             lock_release_point,
+            vesting,
             max_retries,
         } => {
             let mut retry_count = 0;
@@ -456,6 +603,7 @@ async fn main() -> Result<()> {
                     position_owner,
                     fee_owner,
                     lock_release_point,
+                    vesting: vesting.to_vesting_parameter(),
                     curvature,
                 };
                 if let Err(err) = seed_liquidity_by_operator(
@@ -519,6 +667,7 @@ async fn main() -> Result<()> {
             position_owner,
             fee_owner,
             lock_release_point,
+            vesting,
             selective_rounding,
         } => {
             let position_base_kp = read_keypair_file(base_position_path)
@@ -533,6 +682,7 @@ async fn main() -> Result<()> {
                 position_owner,
                 fee_owner,
                 lock_release_point,
+                vesting: vesting.to_vesting_parameter(),
                 selective_rounding,
             };
             seed_liquidity_single_bin_by_operator(
@@ -699,6 +849,64 @@ async fn main() -> Result<()> {
                 };
                 set_pre_activation_duration(params, &amm_program, transaction_config).await?;
             }
+            AdminCommand::UpdateCircuitBreaker {
+                lb_pair,
+                max_bin_movement,
+                window,
+                cooldown,
+            } => {
+                let params = UpdateCircuitBreakerParams {
+                    lb_pair,
+                    max_bin_movement,
+                    window,
+                    cooldown,
+                };
+                update_circuit_breaker(params, &amm_program, transaction_config).await?;
+            }
+            AdminCommand::ShowCircuitBreaker { lb_pair } => {
+                show_circuit_breaker(lb_pair, &amm_program).await?;
+            }
+            AdminCommand::UpdateOracleParameters {
+                lb_pair,
+                sample_lifetime,
+                target_history_duration,
+                auto_growth,
+            } => {
+                let params = UpdateOracleParametersParams {
+                    lb_pair,
+                    sample_lifetime,
+                    target_history_duration,
+                    auto_growth,
+                };
+                update_oracle_parameters(params, &amm_program, transaction_config).await?;
+            }
+            AdminCommand::InitializeAdminConfig {
+                fee_admin,
+                pause_guardian,
+                reward_admin,
+                launch_pool_admin,
+            } => {
+                let params = InitAdminConfigParams {
+                    fee_admin,
+                    pause_guardian,
+                    reward_admin,
+                    launch_pool_admin,
+                };
+                initialize_admin_config(params, &amm_program, transaction_config).await?;
+            }
+            AdminCommand::ProposeAdminRole {
+                role,
+                new_authority,
+            } => {
+                propose_admin_role(role.into(), new_authority, &amm_program, transaction_config)
+                    .await?;
+            }
+            AdminCommand::AcceptAdminRole { role } => {
+                accept_admin_role(role.into(), &amm_program, transaction_config).await?;
+            }
+            AdminCommand::ShowAdminConfig => {
+                show_admin_config(&amm_program).await?;
+            }
         },
     };
 
@@ -111,6 +111,12 @@ pub fn q64x64_price_to_decimal(q64x64_price: u128) -> Option<Decimal> {
     q_price.checked_div(scale_off)
 }
 
+/// Convert human readable decimal price per lamport to Q64xQ64 price, rounded down
+pub fn decimal_to_q64x64_price(price: &Decimal) -> Option<u128> {
+    let scale_off = Decimal::TWO.powu(lb_clmm::math::u64x64_math::SCALE_OFFSET.into());
+    price.checked_mul(scale_off)?.floor().to_u128()
+}
+
 /// price_per_lamport = price_per_token * 10 ** quote_token_decimal / 10 ** base_token_decimal
 pub fn price_per_token_to_per_lamport(
     price_per_token: f64,
@@ -138,6 +144,15 @@ pub fn price_per_lamport_to_price_per_token(
         .checked_div(one_ui_quote_token_amount)
 }
 
+/// native_amount = ui_amount * 10 ** decimal, rounded down
+pub fn ui_amount_to_native_amount(ui_amount: f64, decimal: u8) -> Option<u64> {
+    let ui_amount = Decimal::from_f64(ui_amount)?;
+    ui_amount
+        .checked_mul(Decimal::TEN.powu(decimal.into()))?
+        .floor()
+        .to_u64()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -169,6 +184,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_decimal_to_q64x64_price() {
+        let q64x64_price: u128 = 408988714829317079040;
+        let decimal_price = q64x64_price_to_decimal(q64x64_price).unwrap();
+
+        assert_eq!(decimal_to_q64x64_price(&decimal_price), Some(q64x64_price));
+        assert_eq!(decimal_to_q64x64_price(&Decimal::NEGATIVE_ONE), None);
+    }
+
     #[test]
     fn test_price_per_lamport_to_price_per_token() {
         let price_per_lamport = 0.211713226574294_f64;
@@ -239,4 +263,11 @@ mod tests {
         let computed_price = computed_price_dec.to_u64();
         assert_eq!(computed_price, Some(208929004));
     }
+
+    #[test]
+    fn test_ui_amount_to_native_amount() {
+        assert_eq!(ui_amount_to_native_amount(1.5, 6), Some(1_500_000));
+        assert_eq!(ui_amount_to_native_amount(0.123456789, 6), Some(123_456));
+        assert_eq!(ui_amount_to_native_amount(-1.0, 6), None);
+    }
 }
@@ -0,0 +1,365 @@
+use crate::deposit::{get_bin, preview_add_liquidity_by_strategy};
+use anchor_lang::solana_program::pubkey::Pubkey;
+use anyhow::{ensure, Context, Result};
+use lb_clmm::{
+    constants::NUM_REWARDS,
+    events::Swap,
+    instructions::deposit::add_liquidity_by_strategy::LiquidityParameterByStrategy,
+    math::{price_math::get_price_from_id, u64x64_math::SCALE_OFFSET},
+    state::{bin::BinArray, lb_pair::LbPair},
+};
+use std::collections::HashMap;
+
+pub const SECONDS_PER_YEAR: u64 = 365 * 24 * 3600;
+
+/// Swap volume traded through a bin
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SwapVolume {
+    pub bin_id: i32,
+    pub amount_in: u64,
+    pub swap_for_y: bool,
+    /// Swap fee, in token in, including the protocol fee
+    pub fee: u64,
+    /// Portion of the fee retained by the protocol, including the host fee
+    pub protocol_fee: u64,
+}
+
+impl SwapVolume {
+    /// Volume with the fee estimated from the current fee rate of the pair, `get_base_fee` + `get_variable_fee`
+    pub fn estimate(
+        lb_pair: &LbPair,
+        bin_id: i32,
+        amount_in: u64,
+        swap_for_y: bool,
+    ) -> Result<Self> {
+        let fee = lb_pair.compute_fee_from_amount(amount_in)?;
+        let protocol_fee = lb_pair.compute_protocol_fee(fee)?;
+
+        Ok(Self {
+            bin_id,
+            amount_in,
+            swap_for_y,
+            fee,
+            protocol_fee,
+        })
+    }
+
+    /// Volume of a decoded `Swap` event, spread evenly over the bins crossed by the swap
+    pub fn from_swap_event(event: &Swap) -> Vec<Self> {
+        let (lower_bin_id, upper_bin_id) = if event.start_bin_id <= event.end_bin_id {
+            (event.start_bin_id, event.end_bin_id)
+        } else {
+            (event.end_bin_id, event.start_bin_id)
+        };
+        let bin_count = (upper_bin_id - lower_bin_id + 1) as u64;
+        let protocol_fee = event.protocol_fee + event.host_fee;
+
+        (lower_bin_id..=upper_bin_id)
+            .enumerate()
+            .map(|(i, bin_id)| {
+                // The first bin take the remainder
+                let split = |amount: u64| {
+                    let share = amount / bin_count;
+                    if i == 0 {
+                        share + amount % bin_count
+                    } else {
+                        share
+                    }
+                };
+                Self {
+                    bin_id,
+                    amount_in: split(event.amount_in),
+                    swap_for_y: event.swap_for_y,
+                    fee: split(event.fee),
+                    protocol_fee: split(protocol_fee),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Activity of the pair observed over a period, to be annualized
+#[derive(Debug, Clone)]
+pub struct ObservedActivity<'a> {
+    /// Swap volume observed during the period
+    pub volumes: &'a [SwapVolume],
+    /// Duration of the period, in seconds
+    pub period: u64,
+    /// Price of a reward token lamport in token Y lamports, for each reward of the pair
+    pub reward_prices: [f64; NUM_REWARDS],
+    pub current_timestamp: i64,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct AprEstimate {
+    /// Value of the liquidity, in token Y lamports, at the price of the active bin
+    pub liquidity_value: f64,
+    /// Annualized swap fee earned by the liquidity, excluding the protocol fee, over the liquidity value
+    pub fee_apr: f64,
+    /// Annualized farming rewards earned by the liquidity over the liquidity value
+    pub reward_apr: f64,
+}
+
+fn price_to_f64(bin_id: i32, bin_step: u16) -> Result<f64> {
+    let price = get_price_from_id(bin_id, bin_step)?;
+    Ok(price as f64 / 2f64.powi(SCALE_OFFSET.into()))
+}
+
+/// Fee earned by the liquidity providers of the bin, in token Y lamports
+fn get_lp_fee_value(lb_pair: &LbPair, volume: &SwapVolume) -> Result<f64> {
+    let lp_fee = volume.fee.saturating_sub(volume.protocol_fee) as f64;
+    if volume.swap_for_y {
+        Ok(lp_fee * price_to_f64(volume.bin_id, lb_pair.bin_step)?)
+    } else {
+        Ok(lp_fee)
+    }
+}
+
+/// Farming rewards distributed to the active bin over the next year, in token Y lamports. Rewards are only distributed until the end of their
+/// reward duration, so ended rewards are excluded.
+fn get_yearly_reward_value(lb_pair: &LbPair, activity: &ObservedActivity) -> f64 {
+    let current_timestamp = activity.current_timestamp.max(0) as u64;
+
+    lb_pair
+        .reward_infos
+        .iter()
+        .zip(activity.reward_prices)
+        .filter(|(reward_info, _)| reward_info.initialized())
+        .map(|(reward_info, price)| {
+            let remaining_duration = reward_info
+                .reward_duration_end
+                .saturating_sub(current_timestamp)
+                .min(SECONDS_PER_YEAR);
+            let reward_per_second = reward_info.reward_rate as f64 / 2f64.powi(SCALE_OFFSET.into());
+            reward_per_second * remaining_duration as f64 * price
+        })
+        .sum()
+}
+
+fn annualize(value: f64, period: u64) -> f64 {
+    value * SECONDS_PER_YEAR as f64 / period as f64
+}
+
+/// Estimate the fee and reward APR of the whole liquidity of the pair. `bin_arrays` should contain every bin array with liquidity.
+pub fn estimate_pair_apr(
+    lb_pair_pubkey: Pubkey,
+    lb_pair: &LbPair,
+    bin_arrays: &HashMap<Pubkey, BinArray>,
+    activity: &ObservedActivity,
+) -> Result<AprEstimate> {
+    ensure!(activity.period > 0, "Invalid period");
+
+    let active_price = price_to_f64(lb_pair.active_id, lb_pair.bin_step)?;
+    let liquidity_value: f64 = bin_arrays
+        .values()
+        .filter(|bin_array| bin_array.lb_pair == lb_pair_pubkey)
+        .flat_map(|bin_array| bin_array.bins.iter())
+        .map(|bin| bin.amount_x as f64 * active_price + bin.amount_y as f64)
+        .sum();
+
+    if liquidity_value == 0.0 {
+        return Ok(AprEstimate::default());
+    }
+
+    let mut fee_value = 0.0;
+    for volume in activity.volumes {
+        fee_value += get_lp_fee_value(lb_pair, volume)?;
+    }
+
+    Ok(AprEstimate {
+        liquidity_value,
+        fee_apr: annualize(fee_value, activity.period) / liquidity_value,
+        reward_apr: get_yearly_reward_value(lb_pair, activity) / liquidity_value,
+    })
+}
+
+/// Estimate the fee and reward APR of a hypothetical position depositing `parameter`, assuming the observed activity repeats.
+/// The position earns the fee of each bin in proportion to its share of the bin after deposit, and the rewards of the active bin likewise.
+pub fn estimate_position_apr(
+    lb_pair_pubkey: Pubkey,
+    lb_pair: &LbPair,
+    bin_arrays: &HashMap<Pubkey, BinArray>,
+    parameter: &LiquidityParameterByStrategy,
+    activity: &ObservedActivity,
+) -> Result<AprEstimate> {
+    ensure!(activity.period > 0, "Invalid period");
+
+    let preview =
+        preview_add_liquidity_by_strategy(lb_pair_pubkey, lb_pair, bin_arrays, parameter)?;
+
+    let active_price = price_to_f64(lb_pair.active_id, lb_pair.bin_step)?;
+    let liquidity_value =
+        preview.total_amount_x as f64 * active_price + preview.total_amount_y as f64;
+
+    if liquidity_value == 0.0 {
+        return Ok(AprEstimate::default());
+    }
+
+    let mut position_share_in_bin = HashMap::new();
+    for bin_preview in preview.bins.iter() {
+        let bin = get_bin(lb_pair_pubkey, bin_arrays, bin_preview.bin_id)?;
+        let liquidity_supply = bin
+            .liquidity_supply
+            .checked_add(bin_preview.liquidity_share)
+            .context("overflow")?;
+        if liquidity_supply > 0 {
+            position_share_in_bin.insert(
+                bin_preview.bin_id,
+                bin_preview.liquidity_share as f64 / liquidity_supply as f64,
+            );
+        }
+    }
+
+    let mut fee_value = 0.0;
+    for volume in activity.volumes {
+        if let Some(share) = position_share_in_bin.get(&volume.bin_id) {
+            fee_value += get_lp_fee_value(lb_pair, volume)? * share;
+        }
+    }
+
+    let reward_value = position_share_in_bin
+        .get(&lb_pair.active_id)
+        .map(|share| get_yearly_reward_value(lb_pair, activity) * share)
+        .unwrap_or_default();
+
+    Ok(AprEstimate {
+        liquidity_value,
+        fee_apr: annualize(fee_value, activity.period) / liquidity_value,
+        reward_apr: reward_value / liquidity_value,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lb_clmm::{
+        constants::MAX_BIN_PER_ARRAY,
+        instructions::deposit::add_liquidity_by_strategy::{StrategyParameters, StrategyType},
+        state::{bin::Bin, lb_pair::RewardInfo},
+        utils::pda::derive_bin_array_pda,
+    };
+
+    fn setup() -> (Pubkey, LbPair, HashMap<Pubkey, BinArray>) {
+        let lb_pair_pubkey = Pubkey::new_unique();
+        let mut lb_pair = LbPair {
+            bin_step: 10,
+            ..Default::default()
+        };
+        lb_pair.parameters.base_factor = 10_000;
+        lb_pair.parameters.protocol_share = 2_000;
+
+        let mut bin_array = BinArray {
+            index: 0,
+            version: 1,
+            _padding: [0u8; 7],
+            lb_pair: lb_pair_pubkey,
+            bins: [Bin::default(); MAX_BIN_PER_ARRAY],
+        };
+        // 1_000_000 of token Y in the active bin
+        let active_bin = bin_array.get_bin_mut(0).unwrap();
+        active_bin.amount_y = 1_000_000;
+        active_bin.liquidity_supply = 1_000_000u128 << SCALE_OFFSET;
+
+        let bin_arrays = HashMap::from([(derive_bin_array_pda(lb_pair_pubkey, 0).0, bin_array)]);
+        (lb_pair_pubkey, lb_pair, bin_arrays)
+    }
+
+    #[test]
+    fn test_swap_volume_from_swap_event() {
+        let event = Swap {
+            lb_pair: Pubkey::default(),
+            from: Pubkey::default(),
+            start_bin_id: 2,
+            end_bin_id: 0,
+            amount_in: 1_000,
+            amount_out: 990,
+            swap_for_y: true,
+            fee: 10,
+            protocol_fee: 1,
+            fee_bps: 0,
+            host_fee: 0,
+        };
+
+        let volumes = SwapVolume::from_swap_event(&event);
+
+        assert_eq!(volumes.len(), 3);
+        assert_eq!(volumes.iter().map(|v| v.amount_in).sum::<u64>(), 1_000);
+        assert_eq!(volumes.iter().map(|v| v.fee).sum::<u64>(), 10);
+        assert_eq!(volumes[0].bin_id, 0);
+    }
+
+    #[test]
+    fn test_estimate_pair_and_position_apr() {
+        let (lb_pair_pubkey, lb_pair, bin_arrays) = setup();
+
+        // Swap of token Y into the active bin during a day, earning 1_000 of fee
+        let volumes = [SwapVolume {
+            bin_id: 0,
+            amount_in: 100_000,
+            swap_for_y: false,
+            fee: 1_250,
+            protocol_fee: 250,
+        }];
+        let activity = ObservedActivity {
+            volumes: &volumes,
+            period: 24 * 3600,
+            reward_prices: [0.0; NUM_REWARDS],
+            current_timestamp: 0,
+        };
+
+        let pair_apr = estimate_pair_apr(lb_pair_pubkey, &lb_pair, &bin_arrays, &activity).unwrap();
+        assert_eq!(pair_apr.liquidity_value, 1_000_000.0);
+        assert!((pair_apr.fee_apr - 0.365).abs() < 1e-9);
+
+        // Position doubling the liquidity of the active bin earn half of its fee
+        let parameter = LiquidityParameterByStrategy {
+            amount_x: 0,
+            amount_y: 1_000_000,
+            active_id: 0,
+            max_active_bin_slippage: 0,
+            strategy_parameters: StrategyParameters {
+                min_bin_id: 0,
+                max_bin_id: 0,
+                strategy_type: StrategyType::SpotImBalanced,
+                ..Default::default()
+            },
+        };
+        let position_apr =
+            estimate_position_apr(lb_pair_pubkey, &lb_pair, &bin_arrays, &parameter, &activity)
+                .unwrap();
+        assert_eq!(position_apr.liquidity_value, 1_000_000.0);
+        assert!((position_apr.fee_apr - 0.365 / 2.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_estimate_reward_apr() {
+        let (lb_pair_pubkey, mut lb_pair, bin_arrays) = setup();
+        let current_timestamp = 1_000;
+
+        // 0.5 reward lamport per second, ending in half a year
+        lb_pair.reward_infos[0] = RewardInfo {
+            mint: Pubkey::new_unique(),
+            reward_rate: 1u128 << (SCALE_OFFSET - 1),
+            reward_duration_end: current_timestamp as u64 + SECONDS_PER_YEAR / 2,
+            ..Default::default()
+        };
+        // Ended reward
+        lb_pair.reward_infos[1] = RewardInfo {
+            mint: Pubkey::new_unique(),
+            reward_rate: 1u128 << SCALE_OFFSET,
+            reward_duration_end: current_timestamp as u64,
+            ..Default::default()
+        };
+
+        let activity = ObservedActivity {
+            volumes: &[],
+            period: 24 * 3600,
+            reward_prices: [2.0, 2.0],
+            current_timestamp,
+        };
+
+        let pair_apr = estimate_pair_apr(lb_pair_pubkey, &lb_pair, &bin_arrays, &activity).unwrap();
+        let reward_value = 0.5 * (SECONDS_PER_YEAR / 2) as f64 * 2.0;
+        assert!((pair_apr.reward_apr - reward_value / 1_000_000.0).abs() < 1e-9);
+    }
+}
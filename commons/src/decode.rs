@@ -0,0 +1,148 @@
+use anchor_lang::{AccountDeserialize, Discriminator};
+use anyhow::{ensure, Result};
+use bytemuck::Pod;
+use lb_clmm::state::{
+    bin::BinArray,
+    lb_pair::LbPair,
+    oracle::{Observation, Oracle},
+    position::PositionV2,
+    preset_parameters::PresetParameter,
+};
+
+/// Decode a zero copy account. Unlike `try_deserialize`, the account data need not be aligned.
+pub fn decode_zero_copy<T: Discriminator + Pod>(data: &[u8]) -> Result<T> {
+    let end = 8 + std::mem::size_of::<T>();
+    ensure!(data.len() >= end, "Account data too small");
+    ensure!(
+        data[..8] == T::discriminator(),
+        "Account discriminator mismatch"
+    );
+
+    Ok(bytemuck::pod_read_unaligned(&data[8..end]))
+}
+
+/// Decode a pair account
+pub fn decode_lb_pair(data: &[u8]) -> Result<LbPair> {
+    decode_zero_copy(data)
+}
+
+/// Decode a bin array account
+pub fn decode_bin_array(data: &[u8]) -> Result<BinArray> {
+    decode_zero_copy(data)
+}
+
+/// Decode a position account
+pub fn decode_position_v2(data: &[u8]) -> Result<PositionV2> {
+    decode_zero_copy(data)
+}
+
+/// Decode a preset parameter account
+pub fn decode_preset_parameter(data: &[u8]) -> Result<PresetParameter> {
+    let mut data = data;
+    Ok(PresetParameter::try_deserialize(&mut data)?)
+}
+
+/// Decode an oracle account, including the observation samples
+pub fn decode_oracle(data: &[u8]) -> Result<(Oracle, Vec<Observation>)> {
+    ensure!(
+        data.len() >= Oracle::metadata_len(),
+        "Invalid oracle account"
+    );
+    ensure!(
+        data[..8] == Oracle::discriminator(),
+        "Account discriminator mismatch"
+    );
+
+    let read_u64 = |offset: usize| u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+    let oracle = Oracle {
+        idx: read_u64(8),
+        active_size: read_u64(16),
+        length: read_u64(24),
+    };
+
+    let observations = data[Oracle::metadata_len()..]
+        .chunks_exact(std::mem::size_of::<Observation>())
+        .map(|chunk| Observation {
+            cumulative_active_bin_id: i128::from_le_bytes(chunk[0..16].try_into().unwrap()),
+            created_at: i64::from_le_bytes(chunk[16..24].try_into().unwrap()),
+            last_updated_at: i64::from_le_bytes(chunk[24..32].try_into().unwrap()),
+        })
+        .collect();
+
+    Ok((oracle, observations))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::AccountSerialize;
+
+    #[test]
+    fn test_decode_accounts() {
+        let lb_pair = LbPair {
+            active_id: 42,
+            bin_step: 25,
+            ..Default::default()
+        };
+
+        let mut data = LbPair::discriminator().to_vec();
+        data.extend_from_slice(bytemuck::bytes_of(&lb_pair));
+        // Misaligned copy of the account data
+        let mut misaligned = vec![0u8];
+        misaligned.extend_from_slice(&data);
+
+        let decoded = decode_lb_pair(&misaligned[1..]).unwrap();
+        assert_eq!(decoded.active_id, 42);
+        assert_eq!(decoded.bin_step, 25);
+
+        assert!(decode_bin_array(&data).is_err());
+        assert!(decode_lb_pair(&data[..data.len() - 1]).is_err());
+
+        let preset_parameter = PresetParameter {
+            bin_step: 10,
+            base_factor: 10_000,
+            filter_period: 30,
+            decay_period: 600,
+            reduction_factor: 5_000,
+            variable_fee_control: 40_000,
+            max_volatility_accumulator: 350_000,
+            min_bin_id: -100,
+            max_bin_id: 100,
+            protocol_share: 500,
+        };
+        let mut data = vec![];
+        preset_parameter.try_serialize(&mut data).unwrap();
+
+        let decoded = decode_preset_parameter(&data).unwrap();
+        assert_eq!(decoded.bin_step, 10);
+        assert_eq!(decoded.base_factor, 10_000);
+    }
+
+    #[test]
+    fn test_decode_oracle() {
+        let mut data = Oracle::discriminator().to_vec();
+        data.extend_from_slice(bytemuck::bytes_of(&Oracle {
+            idx: 1,
+            active_size: 2,
+            length: 2,
+        }));
+        for created_at in [100, 200] {
+            data.extend_from_slice(bytemuck::bytes_of(&Observation {
+                cumulative_active_bin_id: -5,
+                created_at,
+                last_updated_at: created_at + 10,
+            }));
+        }
+
+        let (oracle, observations) = decode_oracle(&data).unwrap();
+        assert_eq!((oracle.idx, oracle.active_size, oracle.length), (1, 2, 2));
+        assert_eq!(observations.len(), 2);
+        assert_eq!(observations[1].cumulative_active_bin_id, -5);
+        assert_eq!(observations[1].created_at, 200);
+        assert_eq!(observations[1].last_updated_at, 210);
+
+        // Account of another type
+        data[..8].copy_from_slice(&LbPair::discriminator());
+        assert!(decode_oracle(&data).is_err());
+    }
+}
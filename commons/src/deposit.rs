@@ -0,0 +1,530 @@
+use anchor_lang::solana_program::pubkey::Pubkey;
+use anyhow::{ensure, Context, Result};
+use lb_clmm::{
+    instructions::deposit::{
+        add_liquidity::{get_composite_deposit_info, CompositeDepositInfo},
+        add_liquidity_by_strategy::{
+            validate_add_liquidity_by_strategy_params, LiquidityParameterByStrategy,
+        },
+        add_liquidity_by_strategy_one_side::LiquidityParameterByStrategyOneSide,
+        add_liquidity_by_weight::LiquidityParameterByWeight,
+        add_liquidity_single_side_precise::{
+            AddLiquiditySingleSidePreciseParameter, CompressedBinDepositAmount,
+        },
+    },
+    math::bin_math::get_liquidity,
+    state::{
+        bin::{Bin, BinArray},
+        lb_pair::LbPair,
+    },
+    utils::pda::derive_bin_array_pda,
+};
+use std::collections::HashMap;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct BinDepositPreview {
+    pub bin_id: i32,
+    /// Amount of token X transferred into the bin, including the composition fee
+    pub amount_x: u64,
+    /// Amount of token Y transferred into the bin, including the composition fee
+    pub amount_y: u64,
+    /// Liquidity share minted to the position
+    pub liquidity_share: u128,
+    /// Composition fee of token X charged on the active bin, including the protocol fee
+    pub composition_fee_x: u64,
+    /// Composition fee of token Y charged on the active bin, including the protocol fee
+    pub composition_fee_y: u64,
+    /// Portion of the composition fee of token X retained by the protocol
+    pub protocol_fee_x: u64,
+    /// Portion of the composition fee of token Y retained by the protocol
+    pub protocol_fee_y: u64,
+}
+
+#[derive(Debug, Default)]
+pub struct DepositPreview {
+    /// Deposit of each bin, in ascending bin id order
+    pub bins: Vec<BinDepositPreview>,
+    /// Amount of token X transferred from the user
+    pub total_amount_x: u64,
+    /// Amount of token Y transferred from the user
+    pub total_amount_y: u64,
+    /// Total composition fee of token X
+    pub composition_fee_x: u64,
+    /// Total composition fee of token Y
+    pub composition_fee_y: u64,
+    /// Amount of token X of the parameter left out of the bins by rounding, never transferred from the user
+    pub refund_x: u64,
+    /// Amount of token Y of the parameter left out of the bins by rounding, never transferred from the user
+    pub refund_y: u64,
+}
+
+/// Preview `add_liquidity_by_strategy`. `bin_arrays` should contain the bin arrays covering the strategy range, missing bin arrays are considered empty.
+pub fn preview_add_liquidity_by_strategy(
+    lb_pair_pubkey: Pubkey,
+    lb_pair: &LbPair,
+    bin_arrays: &HashMap<Pubkey, BinArray>,
+    parameter: &LiquidityParameterByStrategy,
+) -> Result<DepositPreview> {
+    validate_add_liquidity_by_strategy_params(
+        parameter.active_id,
+        lb_pair.active_id,
+        parameter.max_active_bin_slippage,
+        &parameter.strategy_parameters,
+    )?;
+
+    let active_bin = get_bin(lb_pair_pubkey, bin_arrays, lb_pair.active_id)?;
+    let amounts_into_bin = parameter.to_amounts_into_bin(
+        lb_pair.active_id,
+        lb_pair.bin_step,
+        active_bin.amount_x,
+        active_bin.amount_y,
+    )?;
+
+    preview_deposit(
+        lb_pair_pubkey,
+        lb_pair,
+        bin_arrays,
+        &amounts_into_bin,
+        parameter.amount_x,
+        parameter.amount_y,
+    )
+}
+
+/// Preview `add_liquidity_by_strategy_one_side`, depositing only token Y when `deposit_for_y`, else only token X.
+/// `bin_arrays` should contain the bin arrays covering the strategy range, missing bin arrays are considered empty.
+pub fn preview_add_liquidity_by_strategy_one_side(
+    lb_pair_pubkey: Pubkey,
+    lb_pair: &LbPair,
+    bin_arrays: &HashMap<Pubkey, BinArray>,
+    parameter: &LiquidityParameterByStrategyOneSide,
+    deposit_for_y: bool,
+) -> Result<DepositPreview> {
+    validate_add_liquidity_by_strategy_params(
+        parameter.active_id,
+        lb_pair.active_id,
+        parameter.max_active_bin_slippage,
+        &parameter.strategy_parameters,
+    )?;
+
+    let amounts_into_bin = parameter
+        .to_amounts_into_bin(lb_pair.active_id, lb_pair.bin_step, deposit_for_y)?
+        .into_iter()
+        .map(|(bin_id, amount)| {
+            if deposit_for_y {
+                (bin_id, 0, amount)
+            } else {
+                (bin_id, amount, 0)
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let (amount_x, amount_y) = if deposit_for_y {
+        (0, parameter.amount)
+    } else {
+        (parameter.amount, 0)
+    };
+
+    preview_deposit(
+        lb_pair_pubkey,
+        lb_pair,
+        bin_arrays,
+        &amounts_into_bin,
+        amount_x,
+        amount_y,
+    )
+}
+
+/// Parameter of `add_liquidity_one_side_precise` depositing `bin_amounts`, the amount of each bin id. The amounts are compressed to u32 with the
+/// smallest decompress multiplier fitting the largest amount, so they are rounded down to a multiple of the multiplier.
+pub fn compress_bin_deposit_amounts(
+    bin_amounts: &[(i32, u64)],
+) -> Result<AddLiquiditySingleSidePreciseParameter> {
+    let max_amount = bin_amounts
+        .iter()
+        .map(|(_, amount)| *amount)
+        .max()
+        .context("No bin to deposit")?;
+    let decompress_multiplier = max_amount.div_ceil(u32::MAX.into()).max(1);
+
+    let bins = bin_amounts
+        .iter()
+        .map(|&(bin_id, amount)| {
+            Ok(CompressedBinDepositAmount {
+                bin_id,
+                amount: (amount / decompress_multiplier).try_into()?,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(AddLiquiditySingleSidePreciseParameter {
+        bins,
+        decompress_multiplier,
+    })
+}
+
+/// Preview `add_liquidity_by_weight`. `bin_arrays` should contain the bin arrays covering the distribution, missing bin arrays are considered empty.
+pub fn preview_add_liquidity_by_weight(
+    lb_pair_pubkey: Pubkey,
+    lb_pair: &LbPair,
+    bin_arrays: &HashMap<Pubkey, BinArray>,
+    parameter: &LiquidityParameterByWeight,
+) -> Result<DepositPreview> {
+    parameter.validate(lb_pair.active_id)?;
+
+    let active_bin = get_bin(lb_pair_pubkey, bin_arrays, lb_pair.active_id)?;
+    let amounts_into_bin = parameter.to_amounts_into_bin(
+        lb_pair.active_id,
+        lb_pair.bin_step,
+        active_bin.amount_x,
+        active_bin.amount_y,
+    )?;
+
+    preview_deposit(
+        lb_pair_pubkey,
+        lb_pair,
+        bin_arrays,
+        &amounts_into_bin,
+        parameter.amount_x,
+        parameter.amount_y,
+    )
+}
+
+/// Bin of the pair. Bins of a bin array not initialized yet are empty.
+pub(crate) fn get_bin(
+    lb_pair_pubkey: Pubkey,
+    bin_arrays: &HashMap<Pubkey, BinArray>,
+    bin_id: i32,
+) -> Result<Bin> {
+    let bin_array_index = BinArray::bin_id_to_bin_array_index(bin_id)?;
+    let (bin_array_pubkey, _bump) = derive_bin_array_pda(lb_pair_pubkey, bin_array_index.into());
+
+    match bin_arrays.get(&bin_array_pubkey) {
+        Some(bin_array) => Ok(*bin_array.get_bin(bin_id)?),
+        None => Ok(Bin::default()),
+    }
+}
+
+fn preview_deposit(
+    lb_pair_pubkey: Pubkey,
+    lb_pair: &LbPair,
+    bin_arrays: &HashMap<Pubkey, BinArray>,
+    amounts_into_bin: &[(i32, u64, u64)],
+    amount_x: u64,
+    amount_y: u64,
+) -> Result<DepositPreview> {
+    let mut preview = DepositPreview::default();
+
+    for &(bin_id, amount_x_into_bin, amount_y_into_bin) in amounts_into_bin {
+        if amount_x_into_bin == 0 && amount_y_into_bin == 0 {
+            continue;
+        }
+
+        let mut bin = get_bin(lb_pair_pubkey, bin_arrays, bin_id)?;
+        let bin_preview = preview_bin_deposit(
+            lb_pair,
+            &mut bin,
+            bin_id,
+            amount_x_into_bin,
+            amount_y_into_bin,
+        )?;
+
+        preview.total_amount_x = preview
+            .total_amount_x
+            .checked_add(amount_x_into_bin)
+            .context("overflow")?;
+        preview.total_amount_y = preview
+            .total_amount_y
+            .checked_add(amount_y_into_bin)
+            .context("overflow")?;
+        preview.composition_fee_x += bin_preview.composition_fee_x;
+        preview.composition_fee_y += bin_preview.composition_fee_y;
+        preview.bins.push(bin_preview);
+    }
+
+    preview.refund_x = amount_x.saturating_sub(preview.total_amount_x);
+    preview.refund_y = amount_y.saturating_sub(preview.total_amount_y);
+
+    Ok(preview)
+}
+
+/// Deposit into the bin, as `deposit_in_bins` of the program. A deposit into the active bin with a composition different from the bin
+/// is charged the composition fee on the implicitly swapped amount, the fee after protocol fee goes to the existing liquidity of the bin.
+fn preview_bin_deposit(
+    lb_pair: &LbPair,
+    bin: &mut Bin,
+    bin_id: i32,
+    amount_x: u64,
+    amount_y: u64,
+) -> Result<BinDepositPreview> {
+    let price = bin.get_or_store_bin_price(bin_id, lb_pair.bin_step)?;
+
+    let CompositeDepositInfo {
+        liquidity_share,
+        token_x_fee_amount,
+        token_y_fee_amount,
+        protocol_token_x_fee_amount,
+        protocol_token_y_fee_amount,
+    } = if bin_id == lb_pair.active_id {
+        get_composite_deposit_info(lb_pair, bin, price, amount_x, amount_y)?
+    } else {
+        CompositeDepositInfo {
+            liquidity_share: get_liquidity(amount_x, amount_y, price)?,
+            ..Default::default()
+        }
+    };
+    ensure!(
+        liquidity_share > 0,
+        "Zero liquidity deposited into bin {}",
+        bin_id
+    );
+
+    bin.deposit_composition_fee(
+        token_x_fee_amount - protocol_token_x_fee_amount,
+        token_y_fee_amount - protocol_token_y_fee_amount,
+    )?;
+    bin.deposit(
+        amount_x - token_x_fee_amount,
+        amount_y - token_y_fee_amount,
+        liquidity_share,
+    )?;
+
+    Ok(BinDepositPreview {
+        bin_id,
+        amount_x,
+        amount_y,
+        liquidity_share,
+        composition_fee_x: token_x_fee_amount,
+        composition_fee_y: token_y_fee_amount,
+        protocol_fee_x: protocol_token_x_fee_amount,
+        protocol_fee_y: protocol_token_y_fee_amount,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lb_clmm::instructions::deposit::add_liquidity_by_strategy::{
+        StrategyParameters, StrategyType,
+    };
+    use lb_clmm::instructions::deposit::add_liquidity_by_weight::BinLiquidityDistributionByWeight;
+
+    fn lb_pair() -> LbPair {
+        let mut lb_pair = LbPair {
+            bin_step: 10,
+            ..Default::default()
+        };
+        lb_pair.parameters.base_factor = 10_000;
+        lb_pair.parameters.protocol_share = 2_000;
+        lb_pair
+    }
+
+    #[test]
+    fn test_preview_by_strategy_into_empty_bins() {
+        let lb_pair = lb_pair();
+        let parameter = LiquidityParameterByStrategy {
+            amount_x: 1_000_000,
+            amount_y: 1_000_000,
+            active_id: 0,
+            max_active_bin_slippage: 0,
+            strategy_parameters: StrategyParameters {
+                min_bin_id: -3,
+                max_bin_id: 3,
+                strategy_type: StrategyType::SpotBalanced,
+                ..Default::default()
+            },
+        };
+
+        let preview = preview_add_liquidity_by_strategy(
+            Pubkey::new_unique(),
+            &lb_pair,
+            &HashMap::new(),
+            &parameter,
+        )
+        .unwrap();
+
+        assert_eq!(preview.bins.len(), 7);
+        assert_eq!(preview.composition_fee_x, 0);
+        assert_eq!(preview.composition_fee_y, 0);
+        assert!(preview.bins.iter().all(|bin| bin.liquidity_share > 0));
+        assert_eq!(
+            preview.total_amount_x + preview.refund_x,
+            parameter.amount_x
+        );
+        assert_eq!(
+            preview.total_amount_y + preview.refund_y,
+            parameter.amount_y
+        );
+    }
+
+    #[test]
+    fn test_preview_by_strategy_one_side_only_deposit_one_token() {
+        let lb_pair = lb_pair();
+        let parameter = LiquidityParameterByStrategyOneSide {
+            amount: 1_000_000,
+            active_id: 0,
+            max_active_bin_slippage: 0,
+            strategy_parameters: StrategyParameters {
+                min_bin_id: -5,
+                max_bin_id: 0,
+                strategy_type: StrategyType::BidAskOneSide,
+                ..Default::default()
+            },
+        };
+
+        let preview = preview_add_liquidity_by_strategy_one_side(
+            Pubkey::new_unique(),
+            &lb_pair,
+            &HashMap::new(),
+            &parameter,
+            true,
+        )
+        .unwrap();
+
+        assert_eq!(preview.bins.len(), 6);
+        assert_eq!(preview.total_amount_x, 0);
+        assert_eq!(preview.total_amount_y + preview.refund_y, parameter.amount);
+        assert!(preview.bins.iter().all(|bin| bin.amount_x == 0));
+        // Bid ask puts more liquidity away from the active bin
+        assert!(preview.bins[0].amount_y > preview.bins[5].amount_y);
+
+        let balanced = LiquidityParameterByStrategyOneSide {
+            strategy_parameters: StrategyParameters {
+                strategy_type: StrategyType::SpotBalanced,
+                ..parameter.strategy_parameters.clone()
+            },
+            ..parameter
+        };
+        assert!(preview_add_liquidity_by_strategy_one_side(
+            Pubkey::new_unique(),
+            &lb_pair,
+            &HashMap::new(),
+            &balanced,
+            true,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_compress_bin_deposit_amounts() {
+        let parameter = compress_bin_deposit_amounts(&[(-1, 1_000), (0, 2_000)]).unwrap();
+        assert_eq!(parameter.decompress_multiplier, 1);
+        assert_eq!(
+            parameter
+                .bins
+                .iter()
+                .map(|bin| (bin.bin_id, bin.amount))
+                .collect::<Vec<_>>(),
+            vec![(-1, 1_000), (0, 2_000)]
+        );
+
+        // Amounts beyond u32 are rounded down to a multiple of the multiplier
+        let max_amount = 10 * u64::from(u32::MAX) + 5;
+        let parameter = compress_bin_deposit_amounts(&[(3, max_amount), (4, 25)]).unwrap();
+        assert_eq!(parameter.decompress_multiplier, 11);
+        assert_eq!(parameter.bins[0].amount, (max_amount / 11) as u32);
+        assert_eq!(parameter.bins[1].amount, 2);
+
+        assert!(compress_bin_deposit_amounts(&[]).is_err());
+    }
+
+    #[test]
+    fn test_preview_charge_composition_fee_on_active_bin() {
+        let lb_pair = lb_pair();
+        let lb_pair_pubkey = Pubkey::new_unique();
+
+        // Active bin holding only token X
+        let mut bin_array = BinArray {
+            index: 0,
+            version: 1,
+            _padding: [0u8; 7],
+            lb_pair: lb_pair_pubkey,
+            bins: [Bin::default(); lb_clmm::constants::MAX_BIN_PER_ARRAY],
+        };
+        let active_bin = bin_array.get_bin_mut(0).unwrap();
+        let price = active_bin
+            .get_or_store_bin_price(0, lb_pair.bin_step)
+            .unwrap();
+        active_bin.amount_x = 1_000_000;
+        active_bin.liquidity_supply = get_liquidity(1_000_000, 0, price).unwrap();
+
+        let bin_arrays = HashMap::from([(derive_bin_array_pda(lb_pair_pubkey, 0).0, bin_array)]);
+
+        // Imbalanced strategy only add token Y into the active bin
+        let parameter = LiquidityParameterByStrategy {
+            amount_x: 1_000_000,
+            amount_y: 1_000_000,
+            active_id: 0,
+            max_active_bin_slippage: 0,
+            strategy_parameters: StrategyParameters {
+                min_bin_id: -2,
+                max_bin_id: 2,
+                strategy_type: StrategyType::SpotImBalanced,
+                ..Default::default()
+            },
+        };
+
+        let preview =
+            preview_add_liquidity_by_strategy(lb_pair_pubkey, &lb_pair, &bin_arrays, &parameter)
+                .unwrap();
+
+        let active_bin_preview = preview.bins.iter().find(|bin| bin.bin_id == 0).unwrap();
+        assert_eq!(active_bin_preview.amount_x, 0);
+        assert!(active_bin_preview.composition_fee_y > 0);
+        assert_eq!(
+            active_bin_preview.protocol_fee_y,
+            lb_pair
+                .compute_protocol_fee(active_bin_preview.composition_fee_y)
+                .unwrap()
+        );
+        assert_eq!(preview.composition_fee_x, 0);
+        assert_eq!(
+            preview.composition_fee_y,
+            active_bin_preview.composition_fee_y
+        );
+    }
+
+    #[test]
+    fn test_preview_bin_deposit_zero_liquidity_rejected() {
+        let lb_pair = lb_pair();
+        let mut bin = Bin::default();
+        assert!(preview_bin_deposit(&lb_pair, &mut bin, 1, 0, 0).is_err());
+        assert!(preview_bin_deposit(&lb_pair, &mut bin, 1, 1, 0).is_ok());
+    }
+
+    #[test]
+    fn test_preview_by_weight_match_distribution() {
+        let lb_pair = lb_pair();
+        let parameter = LiquidityParameterByWeight {
+            amount_x: 1_000_000,
+            amount_y: 0,
+            active_id: 0,
+            max_active_bin_slippage: 0,
+            bin_liquidity_dist: vec![
+                BinLiquidityDistributionByWeight {
+                    bin_id: 1,
+                    weight: 1,
+                },
+                BinLiquidityDistributionByWeight {
+                    bin_id: 2,
+                    weight: 3,
+                },
+            ],
+        };
+
+        let preview = preview_add_liquidity_by_weight(
+            Pubkey::new_unique(),
+            &lb_pair,
+            &HashMap::new(),
+            &parameter,
+        )
+        .unwrap();
+
+        assert_eq!(preview.bins.len(), 2);
+        assert!(preview.bins[1].amount_x > 2 * preview.bins[0].amount_x);
+        assert_eq!(
+            preview.total_amount_x + preview.refund_x,
+            parameter.amount_x
+        );
+        assert_eq!(preview.total_amount_y, 0);
+    }
+}
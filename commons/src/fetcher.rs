@@ -0,0 +1,477 @@
+use crate::decode::decode_zero_copy;
+use crate::quote::{get_bin_array_pubkeys_for_swap, SwapQuoteAccounts};
+use anchor_client::anchor_lang::Discriminator;
+use anchor_client::solana_client::nonblocking::rpc_client::RpcClient;
+use anchor_client::solana_sdk::{
+    account::Account,
+    clock::{Clock, DEFAULT_MS_PER_SLOT},
+    pubkey::Pubkey,
+    sysvar::{is_sysvar_id, SysvarId},
+};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use bytemuck::Pod;
+use lb_clmm::{
+    state::{bin::BinArray, bin_array_bitmap_extension::BinArrayBitmapExtension, lb_pair::LbPair},
+    utils::pda::derive_bin_array_bitmap_extension,
+};
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+use std::time::Instant;
+
+/// Maximum number of accounts of a `getMultipleAccounts` request
+pub const MAX_MULTIPLE_ACCOUNTS: usize = 100;
+
+/// RPC methods used by the fetcher
+#[async_trait]
+pub trait AccountRpc: Send + Sync {
+    /// Accounts of `pubkeys`, in order, and the slot at which they were read
+    async fn get_multiple_accounts(
+        &self,
+        pubkeys: &[Pubkey],
+    ) -> Result<(u64, Vec<Option<Account>>)>;
+
+    async fn get_slot(&self) -> Result<u64>;
+}
+
+#[async_trait]
+impl AccountRpc for RpcClient {
+    async fn get_multiple_accounts(
+        &self,
+        pubkeys: &[Pubkey],
+    ) -> Result<(u64, Vec<Option<Account>>)> {
+        let response = self
+            .get_multiple_accounts_with_commitment(pubkeys, self.commitment())
+            .await?;
+        Ok((response.context.slot, response.value))
+    }
+
+    async fn get_slot(&self) -> Result<u64> {
+        Ok(RpcClient::get_slot(self).await?)
+    }
+}
+
+/// In memory RPC, for tests. Every `getMultipleAccounts` request is recorded.
+#[derive(Default)]
+pub struct MemoryRpc {
+    accounts: Mutex<HashMap<Pubkey, Account>>,
+    slot: Mutex<u64>,
+    requests: Mutex<Vec<Vec<Pubkey>>>,
+}
+
+impl MemoryRpc {
+    pub fn set_account(&self, pubkey: Pubkey, account: Account) {
+        self.accounts.lock().unwrap().insert(pubkey, account);
+    }
+
+    pub fn set_slot(&self, slot: u64) {
+        *self.slot.lock().unwrap() = slot;
+    }
+
+    /// Pubkeys of each `getMultipleAccounts` request received, in order
+    pub fn requests(&self) -> Vec<Vec<Pubkey>> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl AccountRpc for MemoryRpc {
+    async fn get_multiple_accounts(
+        &self,
+        pubkeys: &[Pubkey],
+    ) -> Result<(u64, Vec<Option<Account>>)> {
+        self.requests.lock().unwrap().push(pubkeys.to_vec());
+        let accounts = self.accounts.lock().unwrap();
+        Ok((
+            *self.slot.lock().unwrap(),
+            pubkeys
+                .iter()
+                .map(|key| accounts.get(key).cloned())
+                .collect(),
+        ))
+    }
+
+    async fn get_slot(&self) -> Result<u64> {
+        Ok(*self.slot.lock().unwrap())
+    }
+}
+
+struct CachedAccount {
+    /// Slot at which the account was read
+    slot: u64,
+    account: Option<Account>,
+}
+
+/// Accounts required to quote a swap of a pair
+pub struct QuoteAccounts {
+    pub lb_pair_pubkey: Pubkey,
+    pub lb_pair: LbPair,
+    pub bitmap_extension: Option<BinArrayBitmapExtension>,
+    /// Bin arrays with liquidity in the direction of the swap, from the active bin array
+    pub bin_arrays: HashMap<Pubkey, BinArray>,
+    pub mint_x_account: Account,
+    pub mint_y_account: Account,
+    pub oracle_account: Account,
+    pub clock: Clock,
+}
+
+impl QuoteAccounts {
+    /// Pair state to be passed to `quote_exact_in`, `quote_exact_out`, `quote_route_exact_in` or `quote_timed_swaps`
+    pub fn swap_quote_accounts(&self) -> SwapQuoteAccounts<'_> {
+        SwapQuoteAccounts {
+            lb_pair_pubkey: self.lb_pair_pubkey,
+            lb_pair: &self.lb_pair,
+            bin_arrays: self.bin_arrays.clone(),
+            bitmap_extension: self.bitmap_extension.as_ref(),
+            mint_x_account: &self.mint_x_account,
+            mint_y_account: &self.mint_y_account,
+            oracle_account: &self.oracle_account,
+        }
+    }
+}
+
+/// Fetch accounts with batched `getMultipleAccounts` requests, and cache them. A cached account is reused until the current slot is more
+/// than `max_age_slots` after the slot it was read. The current slot is estimated from the latest slot read from the RPC, with every
+/// request or `refresh_slot`, and the time elapsed since. Sysvar accounts are never served from the cache.
+pub struct AccountFetcher<R: AccountRpc> {
+    rpc: R,
+    max_age_slots: u64,
+    /// Latest slot read from the RPC
+    slot: u64,
+    slot_read_at: Instant,
+    cache: HashMap<Pubkey, CachedAccount>,
+}
+
+impl<R: AccountRpc> AccountFetcher<R> {
+    pub fn new(rpc: R, max_age_slots: u64) -> Self {
+        Self {
+            rpc,
+            max_age_slots,
+            slot: 0,
+            slot_read_at: Instant::now(),
+            cache: HashMap::new(),
+        }
+    }
+
+    pub fn rpc(&self) -> &R {
+        &self.rpc
+    }
+
+    /// Current slot, estimated from the latest slot read from the RPC and the time elapsed since
+    pub fn slot(&self) -> u64 {
+        let elapsed_slots =
+            self.slot_read_at.elapsed().as_millis() / u128::from(DEFAULT_MS_PER_SLOT);
+        self.slot
+            .saturating_add(elapsed_slots.try_into().unwrap_or(u64::MAX))
+    }
+
+    fn observe_slot(&mut self, slot: u64) {
+        if slot >= self.slot {
+            self.slot = slot;
+            self.slot_read_at = Instant::now();
+        }
+    }
+
+    /// Read the current slot from the RPC, expiring the outdated accounts
+    pub async fn refresh_slot(&mut self) -> Result<u64> {
+        let slot = self.rpc.get_slot().await?;
+        self.observe_slot(slot);
+        Ok(self.slot())
+    }
+
+    /// Evict the account, such as after sending a transaction modifying it
+    pub fn invalidate(&mut self, pubkey: &Pubkey) {
+        self.cache.remove(pubkey);
+    }
+
+    pub fn invalidate_all(&mut self) {
+        self.cache.clear();
+    }
+
+    fn is_fresh(&self, pubkey: &Pubkey, current_slot: u64) -> bool {
+        !is_sysvar_id(pubkey)
+            && self
+                .cache
+                .get(pubkey)
+                .map(|cached| cached.slot.saturating_add(self.max_age_slots) >= current_slot)
+                .unwrap_or(false)
+    }
+
+    /// Accounts of `pubkeys`, in order. Only the accounts missing or outdated in the cache, and the sysvars, are requested, by batch of
+    /// `MAX_MULTIPLE_ACCOUNTS`.
+    pub async fn get_multiple_accounts(
+        &mut self,
+        pubkeys: &[Pubkey],
+    ) -> Result<Vec<Option<Account>>> {
+        let current_slot = self.slot();
+        let mut seen = HashSet::new();
+        let outdated_pubkeys: Vec<Pubkey> = pubkeys
+            .iter()
+            .filter(|pubkey| !self.is_fresh(pubkey, current_slot) && seen.insert(**pubkey))
+            .copied()
+            .collect();
+
+        for chunk in outdated_pubkeys.chunks(MAX_MULTIPLE_ACCOUNTS) {
+            let (slot, accounts) = self.rpc.get_multiple_accounts(chunk).await?;
+            self.observe_slot(slot);
+
+            for (pubkey, account) in chunk.iter().zip(accounts) {
+                self.cache.insert(*pubkey, CachedAccount { slot, account });
+            }
+        }
+
+        Ok(pubkeys
+            .iter()
+            .map(|pubkey| {
+                self.cache
+                    .get(pubkey)
+                    .and_then(|cached| cached.account.clone())
+            })
+            .collect())
+    }
+
+    pub async fn get_account(&mut self, pubkey: &Pubkey) -> Result<Option<Account>> {
+        Ok(self
+            .get_multiple_accounts(&[*pubkey])
+            .await?
+            .pop()
+            .flatten())
+    }
+
+    /// Decoded zero copy accounts of `pubkeys`, in order. Fail when any account is missing.
+    pub async fn get_zero_copy_accounts<T: Discriminator + Pod>(
+        &mut self,
+        pubkeys: &[Pubkey],
+    ) -> Result<Vec<T>> {
+        let accounts = self.get_multiple_accounts(pubkeys).await?;
+
+        pubkeys
+            .iter()
+            .zip(accounts)
+            .map(|(pubkey, account)| {
+                let account = account.with_context(|| format!("Account {} not found", pubkey))?;
+                decode_zero_copy(&account.data)
+            })
+            .collect()
+    }
+
+    pub async fn get_lb_pair(&mut self, lb_pair_pubkey: Pubkey) -> Result<LbPair> {
+        self.get_zero_copy_accounts(&[lb_pair_pubkey])
+            .await?
+            .pop()
+            .context("Pair not found")
+    }
+
+    pub async fn get_bin_arrays(
+        &mut self,
+        bin_array_pubkeys: &[Pubkey],
+    ) -> Result<HashMap<Pubkey, BinArray>> {
+        let bin_arrays = self.get_zero_copy_accounts(bin_array_pubkeys).await?;
+        Ok(bin_array_pubkeys.iter().copied().zip(bin_arrays).collect())
+    }
+
+    /// Bitmap extension of the pair, None when the pair has no extension
+    pub async fn get_bitmap_extension(
+        &mut self,
+        lb_pair_pubkey: Pubkey,
+    ) -> Result<Option<BinArrayBitmapExtension>> {
+        let (bitmap_extension_pubkey, _bump) = derive_bin_array_bitmap_extension(lb_pair_pubkey);
+
+        self.get_account(&bitmap_extension_pubkey)
+            .await?
+            .map(|account| decode_zero_copy(&account.data))
+            .transpose()
+    }
+
+    /// Load every account required to quote a swap of the pair in the direction of `swap_for_y`, covering up to `bin_array_count` bin arrays
+    /// with liquidity. The pair, its bitmap extension and the clock are requested together, then the bin arrays, mints and oracle. The clock
+    /// is always read fresh, to evaluate the activation and fees at the current time.
+    pub async fn load_quote_accounts(
+        &mut self,
+        lb_pair_pubkey: Pubkey,
+        swap_for_y: bool,
+        bin_array_count: u8,
+    ) -> Result<QuoteAccounts> {
+        let (bitmap_extension_pubkey, _bump) = derive_bin_array_bitmap_extension(lb_pair_pubkey);
+
+        let [lb_pair_account, bitmap_extension_account, clock_account]: [Option<Account>; 3] = self
+            .get_multiple_accounts(&[lb_pair_pubkey, bitmap_extension_pubkey, Clock::id()])
+            .await?
+            .try_into()
+            .unwrap();
+
+        let lb_pair: LbPair = decode_zero_copy(&lb_pair_account.context("Pair not found")?.data)?;
+        let bitmap_extension: Option<BinArrayBitmapExtension> = bitmap_extension_account
+            .map(|account| decode_zero_copy(&account.data))
+            .transpose()?;
+        let clock: Clock =
+            bincode::deserialize(clock_account.context("Clock not found")?.data.as_ref())?;
+
+        let bin_array_pubkeys = get_bin_array_pubkeys_for_swap(
+            lb_pair_pubkey,
+            &lb_pair,
+            bitmap_extension.as_ref(),
+            swap_for_y,
+            bin_array_count,
+        )?;
+
+        let mut pubkeys = vec![lb_pair.token_x_mint, lb_pair.token_y_mint, lb_pair.oracle];
+        pubkeys.extend_from_slice(&bin_array_pubkeys);
+        let mut accounts = self.get_multiple_accounts(&pubkeys).await?.into_iter();
+
+        let mut next_account = |name: &str| -> Result<Account> {
+            accounts
+                .next()
+                .flatten()
+                .with_context(|| format!("{} not found", name))
+        };
+        let mint_x_account = next_account("Mint x")?;
+        let mint_y_account = next_account("Mint y")?;
+        let oracle_account = next_account("Oracle")?;
+
+        let mut bin_arrays = HashMap::new();
+        for bin_array_pubkey in bin_array_pubkeys {
+            let account = next_account("Bin array")?;
+            bin_arrays.insert(bin_array_pubkey, decode_zero_copy(&account.data)?);
+        }
+
+        Ok(QuoteAccounts {
+            lb_pair_pubkey,
+            lb_pair,
+            bitmap_extension,
+            bin_arrays,
+            mint_x_account,
+            mint_y_account,
+            oracle_account,
+            clock,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lb_clmm::{
+        constants::MAX_BIN_PER_ARRAY, state::bin::Bin, utils::pda::derive_bin_array_pda,
+    };
+
+    fn zero_copy_account<T: Discriminator + bytemuck::Pod>(state: &T) -> Account {
+        let mut data = T::discriminator().to_vec();
+        data.extend_from_slice(bytemuck::bytes_of(state));
+        Account {
+            data,
+            owner: lb_clmm::ID,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_load_quote_accounts() {
+        let rpc = MemoryRpc::default();
+        rpc.set_slot(100);
+
+        let lb_pair_pubkey = Pubkey::new_unique();
+        let mut lb_pair = LbPair {
+            token_x_mint: Pubkey::new_unique(),
+            token_y_mint: Pubkey::new_unique(),
+            oracle: Pubkey::new_unique(),
+            ..Default::default()
+        };
+        lb_pair.flip_bin_array_bit(&None, 0).unwrap();
+        rpc.set_account(lb_pair_pubkey, zero_copy_account(&lb_pair));
+
+        let bin_array_pubkey = derive_bin_array_pda(lb_pair_pubkey, 0).0;
+        let bin_array = BinArray {
+            index: 0,
+            version: 1,
+            _padding: [0u8; 7],
+            lb_pair: lb_pair_pubkey,
+            bins: [Bin::default(); MAX_BIN_PER_ARRAY],
+        };
+        rpc.set_account(bin_array_pubkey, zero_copy_account(&bin_array));
+
+        for pubkey in [lb_pair.token_x_mint, lb_pair.token_y_mint, lb_pair.oracle] {
+            rpc.set_account(pubkey, Account::default());
+        }
+        rpc.set_account(
+            Clock::id(),
+            Account {
+                data: bincode::serialize(&Clock::default()).unwrap(),
+                ..Default::default()
+            },
+        );
+
+        let mut fetcher = AccountFetcher::new(rpc, 10);
+        let accounts = fetcher
+            .load_quote_accounts(lb_pair_pubkey, true, 3)
+            .await
+            .unwrap();
+
+        assert!(accounts.bitmap_extension.is_none());
+        assert_eq!(accounts.bin_arrays.len(), 1);
+        assert!(accounts.bin_arrays.contains_key(&bin_array_pubkey));
+        assert_eq!(fetcher.rpc().requests().len(), 2);
+
+        // Served from the cache until outdated, except the clock
+        fetcher
+            .load_quote_accounts(lb_pair_pubkey, true, 3)
+            .await
+            .unwrap();
+        let requests = fetcher.rpc().requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[2], vec![Clock::id()]);
+
+        fetcher.rpc().set_slot(111);
+        fetcher.refresh_slot().await.unwrap();
+        fetcher
+            .load_quote_accounts(lb_pair_pubkey, true, 3)
+            .await
+            .unwrap();
+        assert_eq!(fetcher.rpc().requests().len(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_cache_expire_with_elapsed_slots() {
+        let rpc = MemoryRpc::default();
+        let pubkey = Pubkey::new_unique();
+        rpc.set_account(pubkey, Account::default());
+
+        let mut fetcher = AccountFetcher::new(rpc, 1);
+        fetcher.get_account(&pubkey).await.unwrap();
+        fetcher.get_account(&pubkey).await.unwrap();
+        assert_eq!(fetcher.rpc().requests().len(), 1);
+
+        // Outdated once more than 1 slot elapsed, without any request advancing the slot
+        tokio::time::sleep(std::time::Duration::from_millis(
+            2 * DEFAULT_MS_PER_SLOT + 100,
+        ))
+        .await;
+        assert!(fetcher.slot() >= 2);
+        fetcher.get_account(&pubkey).await.unwrap();
+        assert_eq!(fetcher.rpc().requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_get_multiple_accounts_batched() {
+        let rpc = MemoryRpc::default();
+        let pubkeys: Vec<Pubkey> = (0..250).map(|_| Pubkey::new_unique()).collect();
+        for pubkey in pubkeys.iter() {
+            rpc.set_account(*pubkey, Account::default());
+        }
+
+        let mut fetcher = AccountFetcher::new(rpc, 0);
+        let accounts = fetcher.get_multiple_accounts(&pubkeys).await.unwrap();
+
+        assert_eq!(accounts.len(), 250);
+        assert!(accounts.iter().all(|account| account.is_some()));
+        assert_eq!(
+            fetcher
+                .rpc()
+                .requests()
+                .iter()
+                .map(|request| request.len())
+                .collect::<Vec<_>>(),
+            vec![100, 100, 50]
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use anchor_lang::solana_program::{instruction::Instruction, pubkey::Pubkey, system_program};
+use anchor_lang::{InstructionData, ToAccountMetas};
+use lb_clmm::instructions::admin::initialize_admin_config::InitAdminConfigParameter;
+use lb_clmm::state::admin_config::AdminRole;
+use lb_clmm::utils::pda::{derive_admin_config_pda, derive_event_authority_pda};
+
+/// Build `initialize_admin_config` instruction. Predefined admin only.
+pub fn initialize_admin_config(admin: Pubkey, parameter: InitAdminConfigParameter) -> Instruction {
+    let (admin_config, _bump) = derive_admin_config_pda();
+    let (event_authority, _bump) = derive_event_authority_pda();
+
+    let accounts = lb_clmm::accounts::InitializeAdminConfig {
+        admin_config,
+        admin,
+        system_program: system_program::ID,
+        event_authority,
+        program: lb_clmm::ID,
+    }
+    .to_account_metas(None);
+
+    let data = lb_clmm::instruction::InitializeAdminConfig { parameter }.data();
+
+    Instruction {
+        program_id: lb_clmm::ID,
+        accounts,
+        data,
+    }
+}
+
+/// Build `propose_admin_role` instruction. Must be signed by the current authority of the role.
+pub fn propose_admin_role(
+    authority: Pubkey,
+    role: AdminRole,
+    new_authority: Pubkey,
+) -> Instruction {
+    let (admin_config, _bump) = derive_admin_config_pda();
+    let (event_authority, _bump) = derive_event_authority_pda();
+
+    let accounts = lb_clmm::accounts::ProposeAdminRole {
+        admin_config,
+        authority,
+        event_authority,
+        program: lb_clmm::ID,
+    }
+    .to_account_metas(None);
+
+    let data = lb_clmm::instruction::ProposeAdminRole {
+        role,
+        new_authority,
+    }
+    .data();
+
+    Instruction {
+        program_id: lb_clmm::ID,
+        accounts,
+        data,
+    }
+}
+
+/// Build `accept_admin_role` instruction. Must be signed by the pending authority of the role, which can be a multisig PDA signing through CPI.
+pub fn accept_admin_role(pending_authority: Pubkey, role: AdminRole) -> Instruction {
+    let (admin_config, _bump) = derive_admin_config_pda();
+    let (event_authority, _bump) = derive_event_authority_pda();
+
+    let accounts = lb_clmm::accounts::AcceptAdminRole {
+        admin_config,
+        pending_authority,
+        event_authority,
+        program: lb_clmm::ID,
+    }
+    .to_account_metas(None);
+
+    let data = lb_clmm::instruction::AcceptAdminRole { role }.data();
+
+    Instruction {
+        program_id: lb_clmm::ID,
+        accounts,
+        data,
+    }
+}
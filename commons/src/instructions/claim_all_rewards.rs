@@ -0,0 +1,51 @@
+use super::claim_fee_multiple::get_position_account_metas;
+use anchor_lang::solana_program::{instruction::Instruction, pubkey::Pubkey};
+use anchor_lang::{InstructionData, ToAccountMetas};
+use lb_clmm::state::lb_pair::LbPair;
+use lb_clmm::state::position::PositionV2;
+use lb_clmm::utils::pda::derive_event_authority_pda;
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+
+/// Build `claim_all_rewards` instruction, claiming the reward of `reward_index` of `positions` of the pair to the associated token account of `sender`.
+/// Tokenized positions are not supported. Append the transfer hook accounts of the reward mint, if any, to the accounts of the instruction.
+pub fn claim_all_rewards(
+    lb_pair: Pubkey,
+    lb_pair_state: &LbPair,
+    sender: Pubkey,
+    reward_token_program: Pubkey,
+    reward_index: u64,
+    positions: &[(Pubkey, PositionV2)],
+) -> anyhow::Result<Instruction> {
+    let (event_authority, _bump) = derive_event_authority_pda();
+    let reward_info = &lb_pair_state.reward_infos[usize::try_from(reward_index)?];
+
+    let mut accounts = lb_clmm::accounts::ClaimAllRewards {
+        lb_pair,
+        sender,
+        reward_vault: reward_info.vault,
+        reward_mint: reward_info.mint,
+        user_token_account: get_associated_token_address_with_program_id(
+            &sender,
+            &reward_info.mint,
+            &reward_token_program,
+        ),
+        token_program: reward_token_program,
+        event_authority,
+        program: lb_clmm::ID,
+    }
+    .to_account_metas(None);
+
+    accounts.extend(get_position_account_metas(lb_pair, positions)?);
+
+    let data = lb_clmm::instruction::ClaimAllRewards {
+        reward_index,
+        position_count: positions.len().try_into()?,
+    }
+    .data();
+
+    Ok(Instruction {
+        program_id: lb_clmm::ID,
+        accounts,
+        data,
+    })
+}
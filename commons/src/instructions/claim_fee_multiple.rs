@@ -0,0 +1,81 @@
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::pubkey::Pubkey;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use lb_clmm::state::bin::BinArray;
+use lb_clmm::state::lb_pair::LbPair;
+use lb_clmm::state::position::PositionV2;
+use lb_clmm::utils::pda::{derive_bin_array_pda, derive_event_authority_pda};
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+
+/// Remaining accounts of the positions for `claim_fee_multiple` and `claim_all_rewards`. [position, bin_array_lower, bin_array_upper] of each position.
+pub fn get_position_account_metas(
+    lb_pair: Pubkey,
+    positions: &[(Pubkey, PositionV2)],
+) -> anyhow::Result<Vec<AccountMeta>> {
+    let mut accounts = Vec::with_capacity(positions.len() * 3);
+
+    for (position, position_state) in positions {
+        let lower_bin_array_idx = BinArray::bin_id_to_bin_array_index(position_state.lower_bin_id)?;
+        let upper_bin_array_idx = BinArray::bin_id_to_bin_array_index(position_state.upper_bin_id)?;
+
+        let (bin_array_lower, _bump) = derive_bin_array_pda(lb_pair, lower_bin_array_idx.into());
+        let (bin_array_upper, _bump) = derive_bin_array_pda(lb_pair, upper_bin_array_idx.into());
+
+        accounts.push(AccountMeta::new(*position, false));
+        accounts.push(AccountMeta::new(bin_array_lower, false));
+        accounts.push(AccountMeta::new(bin_array_upper, false));
+    }
+
+    Ok(accounts)
+}
+
+/// Build `claim_fee_multiple` instruction, claiming the fee of `positions` of the pair to the associated token accounts of `sender`.
+/// Tokenized positions are not supported. Append the transfer hook accounts of token X and Y, if any, to the accounts of the instruction.
+pub fn claim_fee_multiple(
+    lb_pair: Pubkey,
+    lb_pair_state: &LbPair,
+    sender: Pubkey,
+    token_x_program: Pubkey,
+    token_y_program: Pubkey,
+    positions: &[(Pubkey, PositionV2)],
+) -> anyhow::Result<Instruction> {
+    let (event_authority, _bump) = derive_event_authority_pda();
+
+    let mut accounts = lb_clmm::accounts::ClaimFeeMultiple {
+        lb_pair,
+        sender,
+        reserve_x: lb_pair_state.reserve_x,
+        reserve_y: lb_pair_state.reserve_y,
+        user_token_x: get_associated_token_address_with_program_id(
+            &sender,
+            &lb_pair_state.token_x_mint,
+            &token_x_program,
+        ),
+        user_token_y: get_associated_token_address_with_program_id(
+            &sender,
+            &lb_pair_state.token_y_mint,
+            &token_y_program,
+        ),
+        token_x_mint: lb_pair_state.token_x_mint,
+        token_y_mint: lb_pair_state.token_y_mint,
+        token_x_program,
+        token_y_program,
+        admin_config: None,
+        event_authority,
+        program: lb_clmm::ID,
+    }
+    .to_account_metas(None);
+
+    accounts.extend(get_position_account_metas(lb_pair, positions)?);
+
+    let data = lb_clmm::instruction::ClaimFeeMultiple {
+        position_count: positions.len().try_into()?,
+    }
+    .data();
+
+    Ok(Instruction {
+        program_id: lb_clmm::ID,
+        accounts,
+        data,
+    })
+}
@@ -0,0 +1,55 @@
+use anchor_lang::solana_program::{instruction::Instruction, pubkey::Pubkey};
+use anchor_lang::{InstructionData, ToAccountMetas};
+use lb_clmm::state::lb_pair::LbPair;
+use lb_clmm::utils::pda::{
+    derive_event_authority_pda, derive_referral_fee_pda, derive_referrer_pda,
+};
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+
+/// Build `claim_referral_fee` instruction. The fee is sent to the associated token accounts of `owner`, the owner of the referrer.
+/// Append the transfer hook accounts of token X and Y, if any, to the accounts of the instruction.
+pub fn claim_referral_fee(
+    lb_pair: Pubkey,
+    lb_pair_state: &LbPair,
+    owner: Pubkey,
+    token_x_program: Pubkey,
+    token_y_program: Pubkey,
+) -> Instruction {
+    let (event_authority, _bump) = derive_event_authority_pda();
+    let (referrer, _bump) = derive_referrer_pda(owner);
+    let (referral_fee, _bump) = derive_referral_fee_pda(referrer, lb_pair);
+
+    let accounts = lb_clmm::accounts::ClaimReferralFee {
+        lb_pair,
+        referral_fee,
+        referrer,
+        reserve_x: lb_pair_state.reserve_x,
+        reserve_y: lb_pair_state.reserve_y,
+        receiver_token_x: get_associated_token_address_with_program_id(
+            &owner,
+            &lb_pair_state.token_x_mint,
+            &token_x_program,
+        ),
+        receiver_token_y: get_associated_token_address_with_program_id(
+            &owner,
+            &lb_pair_state.token_y_mint,
+            &token_y_program,
+        ),
+        token_x_mint: lb_pair_state.token_x_mint,
+        token_y_mint: lb_pair_state.token_y_mint,
+        owner,
+        token_x_program,
+        token_y_program,
+        event_authority,
+        program: lb_clmm::ID,
+    }
+    .to_account_metas(None);
+
+    let data = lb_clmm::instruction::ClaimReferralFee {}.data();
+
+    Instruction {
+        program_id: lb_clmm::ID,
+        accounts,
+        data,
+    }
+}
@@ -0,0 +1,70 @@
+use anchor_lang::solana_program::{instruction::Instruction, pubkey::Pubkey};
+use anchor_lang::{InstructionData, ToAccountMetas};
+use lb_clmm::utils::pda::{
+    derive_admin_config_pda, derive_bin_array_funder_pda, derive_event_authority_pda,
+};
+
+/// Build `close_bin_array` instruction. The rent of the bin array and its funder record goes back to `funder`.
+pub fn close_bin_array(
+    lb_pair: Pubkey,
+    bin_array: Pubkey,
+    funder: Pubkey,
+    bin_array_bitmap_extension: Option<Pubkey>,
+) -> Instruction {
+    let (event_authority, _bump) = derive_event_authority_pda();
+    let (bin_array_funder, _bump) = derive_bin_array_funder_pda(bin_array);
+
+    let accounts = lb_clmm::accounts::CloseBinArray {
+        lb_pair,
+        bin_array_bitmap_extension: bin_array_bitmap_extension.or(Some(lb_clmm::ID)),
+        bin_array,
+        bin_array_funder,
+        funder,
+        event_authority,
+        program: lb_clmm::ID,
+    }
+    .to_account_metas(None);
+
+    let data = lb_clmm::instruction::CloseBinArray {}.data();
+
+    Instruction {
+        program_id: lb_clmm::ID,
+        accounts,
+        data,
+    }
+}
+
+/// Build `close_legacy_bin_array` instruction, closing a bin array initialized without funder record. `authority` is the creator
+/// of the pair or the fee admin, and the rent of the bin array goes to `rent_receiver`.
+pub fn close_legacy_bin_array(
+    lb_pair: Pubkey,
+    bin_array: Pubkey,
+    authority: Pubkey,
+    rent_receiver: Pubkey,
+    bin_array_bitmap_extension: Option<Pubkey>,
+) -> Instruction {
+    let (event_authority, _bump) = derive_event_authority_pda();
+    let (bin_array_funder, _bump) = derive_bin_array_funder_pda(bin_array);
+    let (admin_config, _bump) = derive_admin_config_pda();
+
+    let accounts = lb_clmm::accounts::CloseLegacyBinArray {
+        lb_pair,
+        bin_array_bitmap_extension: bin_array_bitmap_extension.or(Some(lb_clmm::ID)),
+        bin_array,
+        bin_array_funder,
+        admin_config,
+        authority,
+        rent_receiver,
+        event_authority,
+        program: lb_clmm::ID,
+    }
+    .to_account_metas(None);
+
+    let data = lb_clmm::instruction::CloseLegacyBinArray {}.data();
+
+    Instruction {
+        program_id: lb_clmm::ID,
+        accounts,
+        data,
+    }
+}
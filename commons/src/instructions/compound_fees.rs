@@ -0,0 +1,45 @@
+use anchor_lang::solana_program::{instruction::Instruction, pubkey::Pubkey};
+use anchor_lang::{InstructionData, ToAccountMetas};
+use lb_clmm::instructions::compound_fees::CompoundFeeParameter;
+use lb_clmm::state::bin::BinArray;
+use lb_clmm::state::position::PositionV2;
+use lb_clmm::utils::pda::{derive_bin_array_pda, derive_event_authority_pda};
+
+/// Build `compound_fees` instruction, converting the pending fee of the position into liquidity of its bins.
+/// `bin_array_bitmap_extension` is required when the bin arrays of the position are out of the range of the default bitmap.
+pub fn compound_fees(
+    position: Pubkey,
+    position_state: &PositionV2,
+    sender: Pubkey,
+    bin_array_bitmap_extension: Option<Pubkey>,
+    parameter: CompoundFeeParameter,
+) -> anyhow::Result<Instruction> {
+    let (event_authority, _bump) = derive_event_authority_pda();
+    let lb_pair = position_state.lb_pair;
+
+    let lower_bin_array_idx = BinArray::bin_id_to_bin_array_index(position_state.lower_bin_id)?;
+    let upper_bin_array_idx = BinArray::bin_id_to_bin_array_index(position_state.upper_bin_id)?;
+    let (bin_array_lower, _bump) = derive_bin_array_pda(lb_pair, lower_bin_array_idx.into());
+    let (bin_array_upper, _bump) = derive_bin_array_pda(lb_pair, upper_bin_array_idx.into());
+
+    let accounts = lb_clmm::accounts::CompoundFees {
+        position,
+        lb_pair,
+        bin_array_bitmap_extension: bin_array_bitmap_extension.or(Some(lb_clmm::ID)),
+        bin_array_lower,
+        bin_array_upper,
+        sender,
+        position_nft_account: None,
+        event_authority,
+        program: lb_clmm::ID,
+    }
+    .to_account_metas(None);
+
+    let data = lb_clmm::instruction::CompoundFees { parameter }.data();
+
+    Ok(Instruction {
+        program_id: lb_clmm::ID,
+        accounts,
+        data,
+    })
+}
@@ -0,0 +1,48 @@
+use anchor_lang::solana_program::{instruction::Instruction, pubkey::Pubkey, sysvar};
+use anchor_lang::{InstructionData, ToAccountMetas};
+use lb_clmm::state::lb_pair::LbPair;
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+
+/// Build `flash_borrow` instruction. The borrowed tokens are sent to the associated token accounts of `user`.
+/// The transaction must contain a `flash_repay` of the pair referring to the index of this instruction, see [`super::flash_repay::flash_repay`].
+/// Append the transfer hook accounts of token X and Y, if any, to the accounts of the instruction.
+pub fn flash_borrow(
+    lb_pair: Pubkey,
+    lb_pair_state: &LbPair,
+    user: Pubkey,
+    token_x_program: Pubkey,
+    token_y_program: Pubkey,
+    amount_x: u64,
+    amount_y: u64,
+) -> Instruction {
+    let accounts = lb_clmm::accounts::FlashBorrow {
+        lb_pair,
+        reserve_x: lb_pair_state.reserve_x,
+        reserve_y: lb_pair_state.reserve_y,
+        user_token_x: get_associated_token_address_with_program_id(
+            &user,
+            &lb_pair_state.token_x_mint,
+            &token_x_program,
+        ),
+        user_token_y: get_associated_token_address_with_program_id(
+            &user,
+            &lb_pair_state.token_y_mint,
+            &token_y_program,
+        ),
+        token_x_mint: lb_pair_state.token_x_mint,
+        token_y_mint: lb_pair_state.token_y_mint,
+        user,
+        token_x_program,
+        token_y_program,
+        instructions_sysvar: sysvar::instructions::ID,
+    }
+    .to_account_metas(None);
+
+    let data = lb_clmm::instruction::FlashBorrow { amount_x, amount_y }.data();
+
+    Instruction {
+        program_id: lb_clmm::ID,
+        accounts,
+        data,
+    }
+}
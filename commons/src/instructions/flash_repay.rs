@@ -0,0 +1,59 @@
+use anchor_lang::solana_program::{instruction::Instruction, pubkey::Pubkey, sysvar};
+use anchor_lang::{InstructionData, ToAccountMetas};
+use lb_clmm::state::bin::BinArray;
+use lb_clmm::state::lb_pair::LbPair;
+use lb_clmm::utils::pda::{derive_bin_array_pda, derive_event_authority_pda};
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+
+/// Build `flash_repay` instruction, repaying the borrowed amount plus fee from the associated token accounts of `user`.
+/// `borrow_instruction_index` is the index of the `flash_borrow` instruction in the transaction. The bin array of the active bin
+/// receive the LP fee, therefore `lb_pair_state` must be fresh. Append the transfer hook accounts of token X and Y, if any, to the accounts of the instruction.
+pub fn flash_repay(
+    lb_pair: Pubkey,
+    lb_pair_state: &LbPair,
+    user: Pubkey,
+    token_x_program: Pubkey,
+    token_y_program: Pubkey,
+    borrow_instruction_index: u16,
+) -> anyhow::Result<Instruction> {
+    let (event_authority, _bump) = derive_event_authority_pda();
+    let active_bin_array_idx = BinArray::bin_id_to_bin_array_index(lb_pair_state.active_id)?;
+    let (bin_array, _bump) = derive_bin_array_pda(lb_pair, active_bin_array_idx.into());
+
+    let accounts = lb_clmm::accounts::FlashRepay {
+        lb_pair,
+        bin_array,
+        reserve_x: lb_pair_state.reserve_x,
+        reserve_y: lb_pair_state.reserve_y,
+        user_token_x: get_associated_token_address_with_program_id(
+            &user,
+            &lb_pair_state.token_x_mint,
+            &token_x_program,
+        ),
+        user_token_y: get_associated_token_address_with_program_id(
+            &user,
+            &lb_pair_state.token_y_mint,
+            &token_y_program,
+        ),
+        token_x_mint: lb_pair_state.token_x_mint,
+        token_y_mint: lb_pair_state.token_y_mint,
+        user,
+        token_x_program,
+        token_y_program,
+        instructions_sysvar: sysvar::instructions::ID,
+        event_authority,
+        program: lb_clmm::ID,
+    }
+    .to_account_metas(None);
+
+    let data = lb_clmm::instruction::FlashRepay {
+        borrow_instruction_index,
+    }
+    .data();
+
+    Ok(Instruction {
+        program_id: lb_clmm::ID,
+        accounts,
+        data,
+    })
+}
@@ -0,0 +1,41 @@
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    system_program,
+};
+use anchor_lang::{InstructionData, ToAccountMetas};
+use lb_clmm::utils::pda::derive_referral_fee_pda;
+
+/// Build `initialize_referral_fee` instruction, creating the account accumulating the referral fee of `referrer` in `lb_pair`.
+/// It must exist before swapping with the referrer.
+pub fn initialize_referral_fee(referrer: Pubkey, lb_pair: Pubkey, payer: Pubkey) -> Instruction {
+    let (referral_fee, _bump) = derive_referral_fee_pda(referrer, lb_pair);
+
+    let accounts = lb_clmm::accounts::InitializeReferralFee {
+        referral_fee,
+        referrer,
+        lb_pair,
+        payer,
+        system_program: system_program::ID,
+    }
+    .to_account_metas(None);
+
+    let data = lb_clmm::instruction::InitializeReferralFee {}.data();
+
+    Instruction {
+        program_id: lb_clmm::ID,
+        accounts,
+        data,
+    }
+}
+
+/// Accounts of a swap referred by `referrer`, appended last to the remaining accounts of the swap, after the bin arrays and the transfer hook accounts.
+/// The referrer earns its fee share of the protocol fee in its referral fee account of `lb_pair`.
+pub fn get_referral_account_metas(referrer: Pubkey, lb_pair: Pubkey) -> Vec<AccountMeta> {
+    let (referral_fee, _bump) = derive_referral_fee_pda(referrer, lb_pair);
+
+    vec![
+        AccountMeta::new_readonly(referrer, false),
+        AccountMeta::new(referral_fee, false),
+    ]
+}
@@ -0,0 +1,52 @@
+use anchor_lang::solana_program::{instruction::Instruction, pubkey::Pubkey, system_program};
+use anchor_lang::{InstructionData, ToAccountMetas};
+use lb_clmm::utils::pda::{
+    derive_admin_config_pda, derive_event_authority_pda, derive_referrer_pda,
+};
+
+/// Build `initialize_referrer` instruction, registering `owner` as referrer with `fee_share` bps of the protocol fee. Admin only.
+pub fn initialize_referrer(owner: Pubkey, admin: Pubkey, fee_share: u16) -> Instruction {
+    let (event_authority, _bump) = derive_event_authority_pda();
+    let (referrer, _bump) = derive_referrer_pda(owner);
+
+    let accounts = lb_clmm::accounts::InitializeReferrer {
+        referrer,
+        owner,
+        admin_config: derive_admin_config_pda().0,
+        admin,
+        system_program: system_program::ID,
+        event_authority,
+        program: lb_clmm::ID,
+    }
+    .to_account_metas(None);
+
+    let data = lb_clmm::instruction::InitializeReferrer { fee_share }.data();
+
+    Instruction {
+        program_id: lb_clmm::ID,
+        accounts,
+        data,
+    }
+}
+
+/// Build `update_referrer_fee_share` instruction. Admin only.
+pub fn update_referrer_fee_share(referrer: Pubkey, admin: Pubkey, fee_share: u16) -> Instruction {
+    let (event_authority, _bump) = derive_event_authority_pda();
+
+    let accounts = lb_clmm::accounts::UpdateReferrerFeeShare {
+        referrer,
+        admin_config: derive_admin_config_pda().0,
+        admin,
+        event_authority,
+        program: lb_clmm::ID,
+    }
+    .to_account_metas(None);
+
+    let data = lb_clmm::instruction::UpdateReferrerFeeShare { fee_share }.data();
+
+    Instruction {
+        program_id: lb_clmm::ID,
+        accounts,
+        data,
+    }
+}
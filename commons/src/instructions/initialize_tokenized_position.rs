@@ -0,0 +1,56 @@
+use anchor_lang::solana_program::{
+    instruction::Instruction, pubkey::Pubkey, system_program, sysvar,
+};
+use anchor_lang::{InstructionData, ToAccountMetas};
+use lb_clmm::utils::pda::{derive_event_authority_pda, derive_tokenized_position_pda};
+use mpl_token_metadata::accounts::Metadata;
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+
+/// Build `initialize_tokenized_position` instruction. `owner` pays for the accounts and receives the position NFT in its
+/// associated token account of `token_program` (SPL token or Token-2022). `position_nft_mint` must be a new keypair signing the transaction.
+pub fn initialize_tokenized_position(
+    lb_pair: Pubkey,
+    owner: Pubkey,
+    position_nft_mint: Pubkey,
+    token_program: Pubkey,
+    lower_bin_id: i32,
+    width: i32,
+    uri: String,
+) -> Instruction {
+    let (event_authority, _bump) = derive_event_authority_pda();
+    let (position, _bump) = derive_tokenized_position_pda(position_nft_mint);
+    let (position_nft_metadata, _bump) = Metadata::find_pda(&position_nft_mint);
+    let owner_position_nft_account =
+        get_associated_token_address_with_program_id(&owner, &position_nft_mint, &token_program);
+
+    let accounts = lb_clmm::accounts::InitializeTokenizedPosition {
+        payer: owner,
+        position_nft_mint,
+        position,
+        lb_pair,
+        owner,
+        owner_position_nft_account,
+        position_nft_metadata,
+        token_program,
+        associated_token_program: spl_associated_token_account::ID,
+        metadata_program: mpl_token_metadata::ID,
+        system_program: system_program::ID,
+        rent: sysvar::rent::ID,
+        event_authority,
+        program: lb_clmm::ID,
+    }
+    .to_account_metas(None);
+
+    let data = lb_clmm::instruction::InitializeTokenizedPosition {
+        lower_bin_id,
+        width,
+        uri,
+    }
+    .data();
+
+    Instruction {
+        program_id: lb_clmm::ID,
+        accounts,
+        data,
+    }
+}
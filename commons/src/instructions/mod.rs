@@ -0,0 +1,36 @@
+pub mod admin_config;
+pub mod claim_all_rewards;
+pub mod claim_fee_multiple;
+pub mod claim_referral_fee;
+pub mod close_bin_array;
+pub mod compound_fees;
+pub mod flash_borrow;
+pub mod flash_repay;
+pub mod initialize_referral_fee;
+pub mod initialize_referrer;
+pub mod initialize_tokenized_position;
+pub mod set_position_vesting;
+pub mod swap_route;
+pub mod swap_v2;
+pub mod transfer_position_owner;
+pub mod update_circuit_breaker;
+pub mod update_oracle_parameters;
+
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::{InstructionData, ToAccountMetas};
+
+/// Build a `lb_clmm` instruction from its typed accounts (`lb_clmm::accounts::*`) and parameters (`lb_clmm::instruction::*`)
+pub fn build_instruction(
+    accounts: impl ToAccountMetas,
+    data: impl InstructionData,
+    remaining_accounts: Vec<AccountMeta>,
+) -> Instruction {
+    let mut accounts = accounts.to_account_metas(None);
+    accounts.extend(remaining_accounts);
+
+    Instruction {
+        program_id: lb_clmm::ID,
+        accounts,
+        data: data.data(),
+    }
+}
@@ -0,0 +1,29 @@
+use anchor_lang::solana_program::{instruction::Instruction, pubkey::Pubkey};
+use anchor_lang::{InstructionData, ToAccountMetas};
+use lb_clmm::instructions::set_position_vesting::VestingParameter;
+use lb_clmm::utils::pda::derive_event_authority_pda;
+
+/// Build `set_position_vesting` instruction. Must be signed by the operator of the position, before any liquidity is deposited.
+pub fn set_position_vesting(
+    position: Pubkey,
+    operator: Pubkey,
+    parameter: VestingParameter,
+) -> Instruction {
+    let (event_authority, _bump) = derive_event_authority_pda();
+
+    let accounts = lb_clmm::accounts::SetPositionVesting {
+        position,
+        operator,
+        event_authority,
+        program: lb_clmm::ID,
+    }
+    .to_account_metas(None);
+
+    let data = lb_clmm::instruction::SetPositionVesting { parameter }.data();
+
+    Instruction {
+        program_id: lb_clmm::ID,
+        accounts,
+        data,
+    }
+}
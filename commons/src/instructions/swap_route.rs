@@ -0,0 +1,133 @@
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::pubkey::Pubkey;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use lb_clmm::instructions::swap_route::{SwapRouteHopParameter, SwapRouteParameters};
+use lb_clmm::state::lb_pair::LbPair;
+use lb_clmm::utils::pda::{derive_event_authority_pda, derive_referral_fee_pda};
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+
+/// Pair traversed by a multi-hop swap, and the bin arrays traversed in the pair
+#[derive(Debug, Clone)]
+pub struct SwapRouteHop {
+    pub lb_pair: Pubkey,
+    pub lb_pair_state: LbPair,
+    pub bin_array_bitmap_extension: Option<Pubkey>,
+    pub token_x_program: Pubkey,
+    pub token_y_program: Pubkey,
+    pub swap_for_y: bool,
+    pub bin_arrays: Vec<Pubkey>,
+    /// Extra accounts required by the transfer hooks of token in and token out of the hop, see `get_transfer_hook_account_metas`
+    pub transfer_hook_accounts: Vec<AccountMeta>,
+    /// Registered referrer earning the host fee of the hop, in its referral fee account of the pair
+    pub referrer: Option<Pubkey>,
+}
+
+impl SwapRouteHop {
+    fn user_token_in_and_out(&self, user: Pubkey) -> (Pubkey, Pubkey) {
+        let user_token_x = get_associated_token_address_with_program_id(
+            &user,
+            &self.lb_pair_state.token_x_mint,
+            &self.token_x_program,
+        );
+        let user_token_y = get_associated_token_address_with_program_id(
+            &user,
+            &self.lb_pair_state.token_y_mint,
+            &self.token_y_program,
+        );
+
+        if self.swap_for_y {
+            (user_token_x, user_token_y)
+        } else {
+            (user_token_y, user_token_x)
+        }
+    }
+}
+
+/// Build `swap_route` instruction, swapping `amount_in` through the pairs of `route` in order, from and to the associated token accounts of `user`.
+/// The accounts of each hop are the accounts of `swap`, followed by the bin arrays, the transfer hook accounts and the referral accounts of the hop.
+/// Use `quote_route_exact_in` to get the route.
+pub fn swap_route(
+    user: Pubkey,
+    route: &[SwapRouteHop],
+    amount_in: u64,
+    min_amount_out: u64,
+) -> anyhow::Result<Instruction> {
+    let (event_authority, _bump) = derive_event_authority_pda();
+
+    let (Some(first_hop), Some(last_hop)) = (route.first(), route.last()) else {
+        anyhow::bail!("Empty swap route");
+    };
+    let (user_token_in, _) = first_hop.user_token_in_and_out(user);
+    let (_, user_token_out) = last_hop.user_token_in_and_out(user);
+
+    let mut accounts = lb_clmm::accounts::SwapRoute {
+        user_token_in,
+        user_token_out,
+        user,
+    }
+    .to_account_metas(None);
+
+    for hop in route {
+        let (user_token_in, user_token_out) = hop.user_token_in_and_out(user);
+
+        accounts.extend(
+            lb_clmm::accounts::Swap {
+                lb_pair: hop.lb_pair,
+                bin_array_bitmap_extension: hop.bin_array_bitmap_extension.or(Some(lb_clmm::ID)),
+                reserve_x: hop.lb_pair_state.reserve_x,
+                reserve_y: hop.lb_pair_state.reserve_y,
+                user_token_in,
+                user_token_out,
+                token_x_mint: hop.lb_pair_state.token_x_mint,
+                token_y_mint: hop.lb_pair_state.token_y_mint,
+                oracle: hop.lb_pair_state.oracle,
+                host_fee_in: Some(lb_clmm::ID),
+                user,
+                token_x_program: hop.token_x_program,
+                token_y_program: hop.token_y_program,
+                event_authority,
+                program: lb_clmm::ID,
+            }
+            .to_account_metas(None),
+        );
+
+        accounts.extend(
+            hop.bin_arrays
+                .iter()
+                .map(|&key| AccountMeta::new(key, false)),
+        );
+        accounts.extend(hop.transfer_hook_accounts.iter().cloned());
+
+        if let Some(referrer) = hop.referrer {
+            let (referral_fee, _bump) = derive_referral_fee_pda(referrer, hop.lb_pair);
+            accounts.push(AccountMeta::new_readonly(referrer, false));
+            accounts.push(AccountMeta::new(referral_fee, false));
+        }
+    }
+
+    let hops = route
+        .iter()
+        .map(|hop| {
+            Ok(SwapRouteHopParameter {
+                bin_array_count: hop.bin_arrays.len().try_into()?,
+                transfer_hook_account_count: hop.transfer_hook_accounts.len().try_into()?,
+                referred: hop.referrer.is_some(),
+            })
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let data = lb_clmm::instruction::SwapRoute {
+        parameters: SwapRouteParameters {
+            amount_in,
+            min_amount_out,
+            hops,
+        },
+    }
+    .data();
+
+    Ok(Instruction {
+        program_id: lb_clmm::ID,
+        accounts,
+        data,
+    })
+}
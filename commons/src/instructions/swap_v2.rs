@@ -0,0 +1,71 @@
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::pubkey::Pubkey;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use lb_clmm::instructions::swap::SwapV2Parameters;
+use lb_clmm::state::lb_pair::LbPair;
+use lb_clmm::utils::pda::derive_event_authority_pda;
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+
+/// Build `swap_v2` instruction, swapping from and to the associated token accounts of `user`. `bin_arrays` are the bin arrays
+/// traversed by the swap, appended to the accounts of the instruction. Append the transfer hook accounts of token X and Y, if any, after them,
+/// then the referral accounts of `get_referral_account_metas` for a referred swap.
+#[allow(clippy::too_many_arguments)]
+pub fn swap_v2(
+    lb_pair: Pubkey,
+    lb_pair_state: &LbPair,
+    bin_array_bitmap_extension: Option<Pubkey>,
+    user: Pubkey,
+    token_x_program: Pubkey,
+    token_y_program: Pubkey,
+    swap_for_y: bool,
+    bin_arrays: &[Pubkey],
+    parameters: SwapV2Parameters,
+) -> Instruction {
+    let (event_authority, _bump) = derive_event_authority_pda();
+
+    let user_token_x = get_associated_token_address_with_program_id(
+        &user,
+        &lb_pair_state.token_x_mint,
+        &token_x_program,
+    );
+    let user_token_y = get_associated_token_address_with_program_id(
+        &user,
+        &lb_pair_state.token_y_mint,
+        &token_y_program,
+    );
+
+    let (user_token_in, user_token_out) = if swap_for_y {
+        (user_token_x, user_token_y)
+    } else {
+        (user_token_y, user_token_x)
+    };
+
+    let mut accounts = lb_clmm::accounts::Swap {
+        lb_pair,
+        bin_array_bitmap_extension: bin_array_bitmap_extension.or(Some(lb_clmm::ID)),
+        reserve_x: lb_pair_state.reserve_x,
+        reserve_y: lb_pair_state.reserve_y,
+        user_token_in,
+        user_token_out,
+        token_x_mint: lb_pair_state.token_x_mint,
+        token_y_mint: lb_pair_state.token_y_mint,
+        oracle: lb_pair_state.oracle,
+        host_fee_in: Some(lb_clmm::ID),
+        user,
+        token_x_program,
+        token_y_program,
+        event_authority,
+        program: lb_clmm::ID,
+    }
+    .to_account_metas(None);
+
+    accounts.extend(bin_arrays.iter().map(|&key| AccountMeta::new(key, false)));
+
+    let data = lb_clmm::instruction::SwapV2 { parameters }.data();
+
+    Instruction {
+        program_id: lb_clmm::ID,
+        accounts,
+        data,
+    }
+}
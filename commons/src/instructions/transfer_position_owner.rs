@@ -0,0 +1,30 @@
+use anchor_lang::solana_program::{instruction::Instruction, pubkey::Pubkey};
+use anchor_lang::{InstructionData, ToAccountMetas};
+use lb_clmm::utils::pda::derive_event_authority_pda;
+
+/// Build `transfer_position_owner` instruction. `sender` must be the position owner.
+pub fn transfer_position_owner(
+    position: Pubkey,
+    lb_pair: Pubkey,
+    sender: Pubkey,
+    new_owner: Pubkey,
+) -> Instruction {
+    let (event_authority, _bump) = derive_event_authority_pda();
+
+    let accounts = lb_clmm::accounts::TransferPositionOwner {
+        position,
+        lb_pair,
+        sender,
+        event_authority,
+        program: lb_clmm::ID,
+    }
+    .to_account_metas(None);
+
+    let data = lb_clmm::instruction::TransferPositionOwner { new_owner }.data();
+
+    Instruction {
+        program_id: lb_clmm::ID,
+        accounts,
+        data,
+    }
+}
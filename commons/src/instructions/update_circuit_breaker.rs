@@ -0,0 +1,39 @@
+use anchor_lang::solana_program::{instruction::Instruction, pubkey::Pubkey};
+use anchor_lang::{InstructionData, ToAccountMetas};
+use lb_clmm::instructions::admin::update_circuit_breaker::CircuitBreakerParameter;
+use lb_clmm::utils::pda::{derive_admin_config_pda, derive_event_authority_pda};
+
+/// Build `update_circuit_breaker` instruction. `max_bin_movement` = 0 disables the circuit breaker. A halted pair resume swap immediately.
+pub fn update_circuit_breaker(
+    lb_pair: Pubkey,
+    admin: Pubkey,
+    max_bin_movement: u16,
+    window: u16,
+    cooldown: u32,
+) -> Instruction {
+    let (event_authority, _bump) = derive_event_authority_pda();
+
+    let accounts = lb_clmm::accounts::UpdateCircuitBreaker {
+        lb_pair,
+        admin_config: derive_admin_config_pda().0,
+        admin,
+        event_authority,
+        program: lb_clmm::ID,
+    }
+    .to_account_metas(None);
+
+    let data = lb_clmm::instruction::UpdateCircuitBreaker {
+        parameter: CircuitBreakerParameter {
+            max_bin_movement,
+            window,
+            cooldown,
+        },
+    }
+    .data();
+
+    Instruction {
+        program_id: lb_clmm::ID,
+        accounts,
+        data,
+    }
+}
@@ -0,0 +1,39 @@
+use anchor_lang::solana_program::{instruction::Instruction, pubkey::Pubkey};
+use anchor_lang::{InstructionData, ToAccountMetas};
+use lb_clmm::instructions::admin::update_oracle_parameters::OracleParameter;
+use lb_clmm::utils::pda::{derive_admin_config_pda, derive_event_authority_pda};
+
+/// Build `update_oracle_parameters` instruction. With `auto_growth`, swaps grow the oracle until `target_history_duration` is covered.
+pub fn update_oracle_parameters(
+    lb_pair: Pubkey,
+    admin: Pubkey,
+    sample_lifetime: u16,
+    target_history_duration: u32,
+    auto_growth: bool,
+) -> Instruction {
+    let (event_authority, _bump) = derive_event_authority_pda();
+
+    let accounts = lb_clmm::accounts::UpdateOracleParameters {
+        lb_pair,
+        admin_config: derive_admin_config_pda().0,
+        admin,
+        event_authority,
+        program: lb_clmm::ID,
+    }
+    .to_account_metas(None);
+
+    let data = lb_clmm::instruction::UpdateOracleParameters {
+        parameter: OracleParameter {
+            sample_lifetime,
+            target_history_duration,
+            auto_growth,
+        },
+    }
+    .data();
+
+    Instruction {
+        program_id: lb_clmm::ID,
+        accounts,
+        data,
+    }
+}
@@ -0,0 +1,463 @@
+use crate::decode::decode_zero_copy;
+use crate::quote::{
+    get_bin_array_pubkeys_for_swap, quote_exact_in, quote_exact_out, validate_swap_activation,
+    SwapQuoteAccounts,
+};
+use anchor_client::anchor_lang::ToAccountMetas;
+use anchor_client::solana_sdk::{
+    account::Account, clock::Clock, instruction::AccountMeta, pubkey::Pubkey,
+};
+use anyhow::{Context, Result};
+use jupiter_amm_interface::{
+    AccountMap, Amm, AmmContext, ClockRef, KeyedAccount, Quote, QuoteParams, Swap,
+    SwapAndAccountMetas, SwapMode, SwapParams,
+};
+use lb_clmm::{
+    instructions::swap_route::SWAP_ACCOUNTS_LEN,
+    state::{bin::BinArray, bin_array_bitmap_extension::BinArrayBitmapExtension, lb_pair::LbPair},
+    utils::pda::{derive_bin_array_bitmap_extension, derive_event_authority_pda},
+};
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use std::sync::atomic::Ordering;
+
+/// Number of bin arrays loaded, and passed to the swap, in each direction
+pub const BIN_ARRAYS_PER_DIRECTION: u8 = 3;
+
+/// Jupiter AMM of a pair. The pair, its bitmap extension, mints, oracle and the bin arrays around the active bin are loaded by `update`.
+#[derive(Clone)]
+pub struct DlmmAmm {
+    key: Pubkey,
+    lb_pair: LbPair,
+    bitmap_extension: Option<BinArrayBitmapExtension>,
+    bin_arrays: HashMap<Pubkey, BinArray>,
+    mint_x_account: Option<Account>,
+    mint_y_account: Option<Account>,
+    oracle_account: Option<Account>,
+    clock_ref: ClockRef,
+}
+
+impl DlmmAmm {
+    fn clock(&self) -> Clock {
+        Clock {
+            slot: self.clock_ref.slot.load(Ordering::Relaxed),
+            epoch: self.clock_ref.epoch.load(Ordering::Relaxed),
+            unix_timestamp: self.clock_ref.unix_timestamp.load(Ordering::Relaxed),
+            ..Default::default()
+        }
+    }
+
+    fn bitmap_extension_key(&self) -> Pubkey {
+        derive_bin_array_bitmap_extension(self.key).0
+    }
+
+    /// Bin arrays with liquidity in the direction of the swap. None when the pair has no liquidity in the direction.
+    fn get_bin_array_pubkeys(&self, swap_for_y: bool) -> Option<Vec<Pubkey>> {
+        get_bin_array_pubkeys_for_swap(
+            self.key,
+            &self.lb_pair,
+            self.bitmap_extension.as_ref(),
+            swap_for_y,
+            BIN_ARRAYS_PER_DIRECTION,
+        )
+        .ok()
+    }
+
+    fn is_swap_for_y(&self, mint_in: Pubkey, mint_out: Pubkey) -> Result<bool> {
+        let (mint_x, mint_y) = (self.lb_pair.token_x_mint, self.lb_pair.token_y_mint);
+        if mint_in == mint_x && mint_out == mint_y {
+            Ok(true)
+        } else if mint_in == mint_y && mint_out == mint_x {
+            Ok(false)
+        } else {
+            anyhow::bail!(
+                "Pair {} does not swap {} to {}",
+                self.key,
+                mint_in,
+                mint_out
+            );
+        }
+    }
+}
+
+impl Amm for DlmmAmm {
+    fn from_keyed_account(keyed_account: &KeyedAccount, amm_context: &AmmContext) -> Result<Self> {
+        Ok(Self {
+            key: keyed_account.key,
+            lb_pair: decode_zero_copy(&keyed_account.account.data)?,
+            bitmap_extension: None,
+            bin_arrays: HashMap::new(),
+            mint_x_account: None,
+            mint_y_account: None,
+            oracle_account: None,
+            clock_ref: amm_context.clock_ref.clone(),
+        })
+    }
+
+    fn label(&self) -> String {
+        "DLMM".to_string()
+    }
+
+    fn program_id(&self) -> Pubkey {
+        lb_clmm::ID
+    }
+
+    fn key(&self) -> Pubkey {
+        self.key
+    }
+
+    fn get_reserve_mints(&self) -> Vec<Pubkey> {
+        vec![self.lb_pair.token_x_mint, self.lb_pair.token_y_mint]
+    }
+
+    fn get_accounts_to_update(&self) -> Vec<Pubkey> {
+        let mut accounts = vec![
+            self.key,
+            self.bitmap_extension_key(),
+            self.lb_pair.token_x_mint,
+            self.lb_pair.token_y_mint,
+            self.lb_pair.oracle,
+        ];
+        for swap_for_y in [true, false] {
+            accounts.extend(self.get_bin_array_pubkeys(swap_for_y).unwrap_or_default());
+        }
+        accounts
+    }
+
+    fn update(&mut self, account_map: &AccountMap) -> Result<()> {
+        let lb_pair_account = account_map.get(&self.key).context("Pair not found")?;
+        self.lb_pair = decode_zero_copy(&lb_pair_account.data)?;
+        self.bitmap_extension = account_map
+            .get(&self.bitmap_extension_key())
+            .map(|account| decode_zero_copy(&account.data))
+            .transpose()?;
+
+        self.mint_x_account = account_map.get(&self.lb_pair.token_x_mint).cloned();
+        self.mint_y_account = account_map.get(&self.lb_pair.token_y_mint).cloned();
+        self.oracle_account = account_map.get(&self.lb_pair.oracle).cloned();
+
+        // The bin arrays to load move along with the active bin, those not loaded yet are requested by the next update
+        let mut bin_arrays = HashMap::new();
+        for swap_for_y in [true, false] {
+            for bin_array_pubkey in self.get_bin_array_pubkeys(swap_for_y).unwrap_or_default() {
+                if let Some(account) = account_map.get(&bin_array_pubkey) {
+                    bin_arrays.insert(bin_array_pubkey, decode_zero_copy(&account.data)?);
+                }
+            }
+        }
+        self.bin_arrays = bin_arrays;
+
+        Ok(())
+    }
+
+    fn quote(&self, quote_params: &QuoteParams) -> Result<Quote> {
+        let swap_for_y = self.is_swap_for_y(quote_params.input_mint, quote_params.output_mint)?;
+        let mint_x_account = self.mint_x_account.as_ref().context("Mint x not loaded")?;
+        let mint_y_account = self.mint_y_account.as_ref().context("Mint y not loaded")?;
+        let oracle_account = self.oracle_account.as_ref().context("Oracle not loaded")?;
+        let clock = self.clock();
+
+        let (in_amount, out_amount, fee_amount) = match quote_params.swap_mode {
+            SwapMode::ExactIn => {
+                let quote = quote_exact_in(
+                    SwapQuoteAccounts {
+                        lb_pair_pubkey: self.key,
+                        lb_pair: &self.lb_pair,
+                        bin_arrays: self.bin_arrays.clone(),
+                        bitmap_extension: self.bitmap_extension.as_ref(),
+                        mint_x_account,
+                        mint_y_account,
+                        oracle_account,
+                    },
+                    quote_params.amount,
+                    swap_for_y,
+                    &clock,
+                    None,
+                )?;
+                (quote_params.amount, quote.amount_out, quote.fee)
+            }
+            SwapMode::ExactOut => {
+                let quote = quote_exact_out(
+                    SwapQuoteAccounts {
+                        lb_pair_pubkey: self.key,
+                        lb_pair: &self.lb_pair,
+                        bin_arrays: self.bin_arrays.clone(),
+                        bitmap_extension: self.bitmap_extension.as_ref(),
+                        mint_x_account,
+                        mint_y_account,
+                        oracle_account,
+                    },
+                    quote_params.amount,
+                    swap_for_y,
+                    &clock,
+                    None,
+                )?;
+                let in_amount = quote
+                    .amount_in
+                    .checked_add(quote.fee)
+                    .context("MathOverflow")?;
+                (in_amount, quote_params.amount, quote.fee)
+            }
+        };
+
+        let fee_pct = if in_amount > 0 {
+            Decimal::from(fee_amount) / Decimal::from(in_amount)
+        } else {
+            Decimal::ZERO
+        };
+
+        Ok(Quote {
+            in_amount,
+            out_amount,
+            fee_amount,
+            fee_mint: quote_params.input_mint,
+            fee_pct,
+            ..Default::default()
+        })
+    }
+
+    fn get_swap_and_account_metas(&self, swap_params: &SwapParams) -> Result<SwapAndAccountMetas> {
+        let swap_for_y =
+            self.is_swap_for_y(swap_params.source_mint, swap_params.destination_mint)?;
+        let mint_x_account = self.mint_x_account.as_ref().context("Mint x not loaded")?;
+        let mint_y_account = self.mint_y_account.as_ref().context("Mint y not loaded")?;
+        let bin_arrays = self
+            .get_bin_array_pubkeys(swap_for_y)
+            .context("Pool out of liquidity")?;
+        let (event_authority, _bump) = derive_event_authority_pda();
+
+        let mut account_metas = lb_clmm::accounts::Swap {
+            lb_pair: self.key,
+            bin_array_bitmap_extension: self
+                .bitmap_extension
+                .map(|_| self.bitmap_extension_key())
+                .or(Some(lb_clmm::ID)),
+            reserve_x: self.lb_pair.reserve_x,
+            reserve_y: self.lb_pair.reserve_y,
+            user_token_in: swap_params.source_token_account,
+            user_token_out: swap_params.destination_token_account,
+            token_x_mint: self.lb_pair.token_x_mint,
+            token_y_mint: self.lb_pair.token_y_mint,
+            oracle: self.lb_pair.oracle,
+            host_fee_in: Some(lb_clmm::ID),
+            user: swap_params.token_transfer_authority,
+            token_x_program: mint_x_account.owner,
+            token_y_program: mint_y_account.owner,
+            event_authority,
+            program: lb_clmm::ID,
+        }
+        .to_account_metas(None);
+
+        account_metas.extend(
+            bin_arrays
+                .into_iter()
+                .map(|key| AccountMeta::new(key, false)),
+        );
+
+        Ok(SwapAndAccountMetas {
+            swap: Swap::MeteoraDlmm,
+            account_metas,
+        })
+    }
+
+    fn clone_amm(&self) -> Box<dyn Amm + Send + Sync> {
+        Box::new(self.clone())
+    }
+
+    // The bin arrays passed to the swap move along with the active bin
+    fn has_dynamic_accounts(&self) -> bool {
+        true
+    }
+
+    fn supports_exact_out(&self) -> bool {
+        true
+    }
+
+    fn get_accounts_len(&self) -> usize {
+        SWAP_ACCOUNTS_LEN + BIN_ARRAYS_PER_DIRECTION as usize
+    }
+
+    /// Whether the pair is enabled, activated for permission pairs, and not halted by the circuit breaker
+    fn is_active(&self) -> bool {
+        let clock = self.clock();
+        validate_swap_activation(&self.lb_pair, clock.unix_timestamp as u64, clock.slot).is_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_client::anchor_lang::Discriminator;
+    use anchor_client::solana_sdk::program_pack::Pack;
+    use lb_clmm::{
+        constants::MAX_BIN_PER_ARRAY,
+        state::{bin::Bin, lb_pair::PairType},
+        utils::pda::derive_bin_array_pda,
+    };
+
+    fn zero_copy_account<T: Discriminator + bytemuck::Pod>(state: &T) -> Account {
+        let mut data = T::discriminator().to_vec();
+        data.extend_from_slice(bytemuck::bytes_of(state));
+        Account {
+            data,
+            owner: lb_clmm::ID,
+            ..Default::default()
+        }
+    }
+
+    fn mint_account() -> Account {
+        let mut data = vec![0u8; anchor_spl::token::spl_token::state::Mint::LEN];
+        anchor_spl::token::spl_token::state::Mint {
+            is_initialized: true,
+            decimals: 6,
+            ..Default::default()
+        }
+        .pack_into_slice(&mut data);
+        Account {
+            data,
+            owner: anchor_spl::token::ID,
+            ..Default::default()
+        }
+    }
+
+    /// Snapshot of a pair with token Y below the active bin and token X above
+    fn snapshot() -> (Pubkey, LbPair, AccountMap) {
+        let key = Pubkey::new_unique();
+        let mut lb_pair = LbPair {
+            bin_step: 10,
+            active_id: 35,
+            token_x_mint: Pubkey::new_unique(),
+            token_y_mint: Pubkey::new_unique(),
+            oracle: Pubkey::new_unique(),
+            ..Default::default()
+        };
+        lb_pair.parameters.base_factor = 10_000;
+        lb_pair.flip_bin_array_bit(&None, 0).unwrap();
+
+        let mut bin_array = BinArray {
+            index: 0,
+            version: 1,
+            _padding: [0u8; 7],
+            lb_pair: key,
+            bins: [Bin::default(); MAX_BIN_PER_ARRAY],
+        };
+        for (i, bin) in bin_array.bins.iter_mut().enumerate() {
+            if i <= 35 {
+                bin.amount_y = 1_000_000;
+            }
+            if i >= 35 {
+                bin.amount_x = 1_000_000;
+            }
+            bin.liquidity_supply = 1_000_000;
+        }
+
+        let mut account_map = AccountMap::default();
+        account_map.insert(key, zero_copy_account(&lb_pair));
+        account_map.insert(
+            derive_bin_array_pda(key, 0).0,
+            zero_copy_account(&bin_array),
+        );
+        account_map.insert(lb_pair.token_x_mint, mint_account());
+        account_map.insert(lb_pair.token_y_mint, mint_account());
+        account_map.insert(lb_pair.oracle, Account::default());
+
+        (key, lb_pair, account_map)
+    }
+
+    fn load(key: Pubkey, account_map: &AccountMap, clock: Clock) -> DlmmAmm {
+        let mut amm = DlmmAmm::from_keyed_account(
+            &KeyedAccount {
+                key,
+                account: account_map.get(&key).unwrap().clone(),
+                params: None,
+            },
+            &AmmContext {
+                clock_ref: ClockRef::from(clock),
+            },
+        )
+        .unwrap();
+
+        let accounts_to_update = amm.get_accounts_to_update();
+        assert!(accounts_to_update.contains(&derive_bin_array_pda(key, 0).0));
+        amm.update(account_map).unwrap();
+        amm
+    }
+
+    #[test]
+    fn test_quote_and_swap_accounts() {
+        let (key, lb_pair, account_map) = snapshot();
+        let amm = load(key, &account_map, Clock::default());
+
+        let quote = amm
+            .quote(&QuoteParams {
+                amount: 1_000_000,
+                input_mint: lb_pair.token_x_mint,
+                output_mint: lb_pair.token_y_mint,
+                swap_mode: SwapMode::ExactIn,
+            })
+            .unwrap();
+        assert_eq!(quote.in_amount, 1_000_000);
+        assert!(quote.out_amount > 0);
+        assert!(quote.fee_amount > 0);
+
+        let quote_exact_out = amm
+            .quote(&QuoteParams {
+                amount: quote.out_amount,
+                input_mint: lb_pair.token_x_mint,
+                output_mint: lb_pair.token_y_mint,
+                swap_mode: SwapMode::ExactOut,
+            })
+            .unwrap();
+        assert!(quote_exact_out.in_amount.abs_diff(quote.in_amount) <= 1);
+
+        let swap = amm
+            .get_swap_and_account_metas(&SwapParams {
+                swap_mode: SwapMode::ExactIn,
+                in_amount: 1_000_000,
+                out_amount: quote.out_amount,
+                source_mint: lb_pair.token_x_mint,
+                destination_mint: lb_pair.token_y_mint,
+                source_token_account: Pubkey::new_unique(),
+                destination_token_account: Pubkey::new_unique(),
+                token_transfer_authority: Pubkey::new_unique(),
+                quote_mint_to_referrer: None,
+                jupiter_program_id: &Pubkey::new_unique(),
+                missing_dynamic_accounts_as_default: false,
+            })
+            .unwrap();
+        assert_eq!(swap.account_metas.len(), SWAP_ACCOUNTS_LEN + 1);
+        assert_eq!(
+            swap.account_metas[SWAP_ACCOUNTS_LEN].pubkey,
+            derive_bin_array_pda(key, 0).0
+        );
+    }
+
+    #[test]
+    fn test_permission_pair_activation() {
+        let (key, mut lb_pair, mut account_map) = snapshot();
+        lb_pair.pair_type = PairType::Permission.into();
+        lb_pair.activation_point = 1_000;
+        account_map.insert(key, zero_copy_account(&lb_pair));
+
+        let amm = load(key, &account_map, Clock::default());
+        assert!(!amm.is_active());
+        assert!(amm
+            .quote(&QuoteParams {
+                amount: 1_000_000,
+                input_mint: lb_pair.token_x_mint,
+                output_mint: lb_pair.token_y_mint,
+                swap_mode: SwapMode::ExactIn,
+            })
+            .is_err());
+
+        let amm = load(
+            key,
+            &account_map,
+            Clock {
+                slot: 1_000,
+                ..Default::default()
+            },
+        );
+        assert!(amm.is_active());
+    }
+}
@@ -1 +1,25 @@
+pub mod analytics;
+pub mod decode;
+pub mod deposit;
+#[cfg(feature = "client")]
+pub mod fetcher;
+pub mod instructions;
+#[cfg(feature = "jupiter")]
+pub mod jupiter;
+#[cfg(feature = "quote")]
+pub mod oracle;
+pub mod position;
+pub mod price;
+#[cfg(feature = "python")]
+pub mod python;
+#[cfg(feature = "quote")]
 pub mod quote;
+#[cfg(feature = "quote")]
+pub mod quote_engine;
+#[cfg(feature = "client")]
+pub mod subscription;
+#[cfg(feature = "client")]
+pub mod token_2022;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+pub mod withdraw;
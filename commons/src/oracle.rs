@@ -0,0 +1,200 @@
+use anyhow::{ensure, Context, Result};
+use lb_clmm::instructions::swap_route::SWAP_ACCOUNTS_LEN;
+use lb_clmm::state::{
+    lb_pair::LbPair,
+    oracle::{Observation, Oracle},
+};
+use solana_sdk::{
+    account::Account,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    rent::Rent,
+    system_program,
+};
+
+#[derive(Debug)]
+pub struct OracleCoverage {
+    /// Number of observations of the oracle
+    pub length: u64,
+    /// Number of initialized observations
+    pub active_size: u64,
+    /// Duration, in seconds, before a new observation sample is created
+    pub sample_lifetime: i64,
+    /// Creation timestamp of the earliest observation sample. None when the oracle has no sample.
+    pub earliest_sample_created_at: Option<i64>,
+    /// Duration, in seconds, covered by the observation samples up to the current timestamp
+    pub history_duration: i64,
+    /// Duration, in seconds, the oracle can cover once every observation is initialized
+    pub capacity_duration: i64,
+    /// Duration, in seconds, of the history the oracle should cover. 0 = no target.
+    pub target_history_duration: u32,
+    /// Number of observations to add to cover the target history duration
+    pub missing_length: u64,
+    /// Rent, in lamports, of the missing observations
+    pub missing_length_rent: u64,
+    /// Whether swaps grow the oracle until the target history duration is covered
+    pub auto_growth: bool,
+}
+
+/// Decode the oracle account, including the observation samples
+pub fn decode_oracle(oracle_account: &Account) -> Result<(Oracle, Vec<Observation>)> {
+    crate::decode::decode_oracle(&oracle_account.data)
+}
+
+/// History covered by the oracle of the pair at `current_timestamp`, and the growth required to cover the target history duration of the pair
+pub fn get_oracle_coverage(
+    lb_pair: &LbPair,
+    oracle_account: &Account,
+    current_timestamp: i64,
+) -> Result<OracleCoverage> {
+    let (oracle, observations) = decode_oracle(oracle_account)?;
+    let oracle_parameters = lb_pair.oracle_parameters;
+    let sample_lifetime = oracle_parameters.get_sample_lifetime();
+
+    let earliest_sample_created_at = observations
+        .iter()
+        .filter(|observation| observation.initialized())
+        .map(|observation| observation.created_at)
+        .min();
+
+    let history_duration = earliest_sample_created_at
+        .map(|created_at| current_timestamp.saturating_sub(created_at).max(0))
+        .unwrap_or(0);
+
+    let capacity_duration = i64::try_from(oracle.length)?
+        .checked_mul(sample_lifetime)
+        .context("MathOverflow")?;
+
+    let missing_length = oracle_parameters
+        .get_target_length()?
+        .saturating_sub(oracle.length);
+
+    let rent = Rent::default();
+    let missing_length_rent = rent
+        .minimum_balance(Oracle::space(oracle.length + missing_length))
+        .saturating_sub(rent.minimum_balance(Oracle::space(oracle.length)));
+
+    Ok(OracleCoverage {
+        length: oracle.length,
+        active_size: oracle.active_size,
+        sample_lifetime,
+        earliest_sample_created_at,
+        history_duration,
+        capacity_duration,
+        target_history_duration: oracle_parameters.target_history_duration,
+        missing_length,
+        missing_length_rent,
+        auto_growth: oracle_parameters.is_auto_growth_enabled(),
+    })
+}
+
+/// Let a swap instruction of `user` grow the oracle when auto-growth is enabled on the pair. The user pay the rent top-up,
+/// so it is marked writable, and the system program is inserted as the leading remaining account, right after the swap accounts.
+pub fn with_oracle_auto_growth(mut instruction: Instruction, user: Pubkey) -> Result<Instruction> {
+    ensure!(
+        instruction.accounts.len() >= SWAP_ACCOUNTS_LEN,
+        "Not a swap instruction"
+    );
+
+    for account in instruction.accounts.iter_mut() {
+        if account.pubkey == user {
+            account.is_writable = true;
+        }
+    }
+
+    instruction.accounts.insert(
+        SWAP_ACCOUNTS_LEN,
+        AccountMeta::new_readonly(system_program::ID, false),
+    );
+
+    Ok(instruction)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::Discriminator;
+    use lb_clmm::state::parameters::OracleParameters;
+
+    fn oracle_account(idx: u64, length: u64, samples: &[(i64, i64)]) -> Account {
+        let mut data = vec![0u8; Oracle::space(length)];
+        data[..8].copy_from_slice(&Oracle::discriminator());
+        data[8..16].copy_from_slice(&idx.to_le_bytes());
+        data[16..24].copy_from_slice(&(samples.len() as u64).to_le_bytes());
+        data[24..32].copy_from_slice(&length.to_le_bytes());
+
+        for (i, (created_at, last_updated_at)) in samples.iter().enumerate() {
+            let offset = Oracle::metadata_len() + i * std::mem::size_of::<Observation>();
+            data[offset + 16..offset + 24].copy_from_slice(&created_at.to_le_bytes());
+            data[offset + 24..offset + 32].copy_from_slice(&last_updated_at.to_le_bytes());
+        }
+
+        Account {
+            data,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_get_oracle_coverage() {
+        let lb_pair = LbPair {
+            oracle_parameters: OracleParameters {
+                sample_lifetime: 60,
+                auto_growth: 1,
+                target_history_duration: 3600,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let oracle_account = oracle_account(1, 10, &[(1000, 1050), (1060, 1100)]);
+
+        let coverage = get_oracle_coverage(&lb_pair, &oracle_account, 1200).unwrap();
+
+        assert_eq!(coverage.active_size, 2);
+        assert_eq!(coverage.earliest_sample_created_at, Some(1000));
+        assert_eq!(coverage.history_duration, 200);
+        assert_eq!(coverage.capacity_duration, 600);
+        assert_eq!(coverage.missing_length, 50);
+        assert_eq!(
+            coverage.missing_length_rent,
+            Rent::default().minimum_balance(Oracle::space(60))
+                - Rent::default().minimum_balance(Oracle::space(10))
+        );
+        assert!(coverage.auto_growth);
+    }
+
+    #[test]
+    fn test_with_oracle_auto_growth() {
+        let user = Pubkey::new_unique();
+        let bin_arrays = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let swap_ix = crate::instructions::swap_v2::swap_v2(
+            Pubkey::new_unique(),
+            &LbPair::default(),
+            None,
+            user,
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            true,
+            &bin_arrays,
+            Default::default(),
+        );
+
+        let instruction = with_oracle_auto_growth(swap_ix, user).unwrap();
+
+        // The system program leads the remaining accounts, before the bin arrays
+        let remaining_accounts = &instruction.accounts[SWAP_ACCOUNTS_LEN..];
+        assert_eq!(remaining_accounts[0].pubkey, system_program::ID);
+        assert_eq!(
+            remaining_accounts[1..]
+                .iter()
+                .map(|account| account.pubkey)
+                .collect::<Vec<_>>(),
+            bin_arrays
+        );
+        assert!(instruction
+            .accounts
+            .iter()
+            .filter(|account| account.pubkey == user)
+            .all(|account| account.is_writable && account.is_signer));
+    }
+}
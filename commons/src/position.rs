@@ -0,0 +1,182 @@
+use anchor_lang::solana_program::{clock::Clock, pubkey::Pubkey};
+use anyhow::{Context, Result};
+use lb_clmm::{
+    constants::VESTING_RATIO_PRECISION,
+    pair_action_access::ActivationType,
+    state::{bin::BinArray, lb_pair::LbPair, position::PositionV2},
+    utils::pda::derive_bin_array_pda,
+};
+use std::collections::HashMap;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PositionAmounts {
+    pub amount_x: u64,
+    pub amount_y: u64,
+}
+
+#[derive(Debug)]
+pub struct PositionValuation {
+    /// Token amounts of the liquidity in the position
+    pub total: PositionAmounts,
+    /// Token amounts able to be withdrawn at the current point
+    pub withdrawable: PositionAmounts,
+    /// Token amounts held back by the lock release point or the vesting schedule
+    pub locked: PositionAmounts,
+}
+
+/// Get current point of the pair based on its activation type
+pub fn get_current_point(lb_pair: &LbPair, clock: &Clock) -> Result<u64> {
+    let current_point = match ActivationType::try_from(lb_pair.activation_type)? {
+        ActivationType::Slot => clock.slot,
+        ActivationType::Timestamp => clock.unix_timestamp as u64,
+    };
+    Ok(current_point)
+}
+
+/// Ratio of the current position liquidity which is withdrawable at the current point, in VESTING_RATIO_PRECISION
+pub fn get_withdrawable_ratio(position: &PositionV2, current_point: u64) -> Result<u64> {
+    if position.is_liquidity_locked(current_point) {
+        return Ok(0);
+    }
+
+    if !position.has_vesting() || position.vesting_remaining_ratio == 0 {
+        return Ok(VESTING_RATIO_PRECISION);
+    }
+
+    let locked_ratio = position.get_vesting_locked_ratio(current_point)?;
+    let unlocked_ratio = position
+        .vesting_remaining_ratio
+        .saturating_sub(locked_ratio);
+
+    // Remaining liquidity of the position is vesting_remaining_ratio of the vesting liquidity
+    let withdrawable_ratio = u128::from(unlocked_ratio)
+        .checked_mul(VESTING_RATIO_PRECISION.into())
+        .context("overflow")?
+        .checked_div(position.vesting_remaining_ratio.into())
+        .context("overflow")?;
+
+    Ok(withdrawable_ratio.try_into()?)
+}
+
+/// Value the position liquidity, splitting it into withdrawable now, and locked amounts. bin_arrays must contain the bin arrays covering the position.
+pub fn get_position_valuation(
+    position: &PositionV2,
+    lb_pair: &LbPair,
+    bin_arrays: &HashMap<Pubkey, BinArray>,
+    clock: &Clock,
+) -> Result<PositionValuation> {
+    let mut total = PositionAmounts::default();
+
+    for bin_id in position.lower_bin_id..=position.upper_bin_id {
+        let liquidity_share = position.get_liquidity_share_in_bin(bin_id)?;
+        if liquidity_share == 0 {
+            continue;
+        }
+
+        let bin_array_index = BinArray::bin_id_to_bin_array_index(bin_id)?;
+        let (bin_array_pubkey, _bump) =
+            derive_bin_array_pda(position.lb_pair, bin_array_index.into());
+        let bin_array = bin_arrays
+            .get(&bin_array_pubkey)
+            .context("Bin array not found")?;
+
+        let (amount_x, amount_y) = bin_array
+            .get_bin(bin_id)?
+            .calculate_out_amount(liquidity_share)?;
+
+        total.amount_x = total.amount_x.checked_add(amount_x).context("overflow")?;
+        total.amount_y = total.amount_y.checked_add(amount_y).context("overflow")?;
+    }
+
+    let current_point = get_current_point(lb_pair, clock)?;
+    let withdrawable_ratio = get_withdrawable_ratio(position, current_point)?;
+
+    let withdrawable = PositionAmounts {
+        amount_x: apply_ratio(total.amount_x, withdrawable_ratio)?,
+        amount_y: apply_ratio(total.amount_y, withdrawable_ratio)?,
+    };
+
+    let locked = PositionAmounts {
+        amount_x: total.amount_x - withdrawable.amount_x,
+        amount_y: total.amount_y - withdrawable.amount_y,
+    };
+
+    Ok(PositionValuation {
+        total,
+        withdrawable,
+        locked,
+    })
+}
+
+fn apply_ratio(amount: u64, ratio: u64) -> Result<u64> {
+    let amount = u128::from(amount)
+        .checked_mul(ratio.into())
+        .context("overflow")?
+        .checked_div(VESTING_RATIO_PRECISION.into())
+        .context("overflow")?;
+    Ok(amount.try_into()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vesting_position(start_point: u64, cliff_point: u64, end_point: u64) -> PositionV2 {
+        let mut position = PositionV2::default();
+        position
+            .set_vesting(start_point, cliff_point, end_point)
+            .unwrap();
+        position
+    }
+
+    #[test]
+    fn test_withdrawable_ratio_follow_vesting_schedule() {
+        let position = vesting_position(100, 150, 200);
+
+        assert_eq!(get_withdrawable_ratio(&position, 0).unwrap(), 0);
+        // Before cliff
+        assert_eq!(get_withdrawable_ratio(&position, 149).unwrap(), 0);
+        // Linear after cliff
+        assert_eq!(
+            get_withdrawable_ratio(&position, 150).unwrap(),
+            VESTING_RATIO_PRECISION / 2
+        );
+        assert_eq!(
+            get_withdrawable_ratio(&position, 200).unwrap(),
+            VESTING_RATIO_PRECISION
+        );
+    }
+
+    #[test]
+    fn test_withdrawable_ratio_after_partial_withdrawal() {
+        let mut position = vesting_position(100, 100, 200);
+
+        // Withdraw the vested half
+        position.withdraw_vesting_liquidity(5000, 150).unwrap();
+        assert_eq!(get_withdrawable_ratio(&position, 150).unwrap(), 0);
+
+        // Another quarter of the vesting liquidity is vested, which is half of the remaining liquidity
+        assert_eq!(
+            get_withdrawable_ratio(&position, 175).unwrap(),
+            VESTING_RATIO_PRECISION / 2
+        );
+
+        // Can't withdraw beyond the vested liquidity
+        assert!(position.withdraw_vesting_liquidity(5001, 175).is_err());
+        assert!(position.withdraw_vesting_liquidity(5000, 175).is_ok());
+    }
+
+    #[test]
+    fn test_withdrawable_ratio_with_lock_release_point() {
+        let position = PositionV2 {
+            lock_release_point: 100,
+            ..Default::default()
+        };
+
+        assert_eq!(get_withdrawable_ratio(&position, 99).unwrap(), 0);
+        assert_eq!(
+            get_withdrawable_ratio(&position, 100).unwrap(),
+            VESTING_RATIO_PRECISION
+        );
+    }
+}
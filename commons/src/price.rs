@@ -0,0 +1,111 @@
+use anyhow::{ensure, Context, Result};
+use lb_clmm::constants::{MAX_BIN_ID, MIN_BIN_ID};
+use lb_clmm::math::price_math::get_price_from_id;
+
+/// Bin id of the Q64.64 price. When the price is in between 2 bins, round down, or up when `round_up`.
+/// The bin prices are computed with the price math of the program, so the result is exact at the price of each bin.
+pub fn get_id_from_price(price: u128, bin_step: u16, round_up: bool) -> Result<i32> {
+    ensure!(price > 0, "Price must be positive");
+
+    // Bin price overflows above the supported range, and underflows below
+    let is_priced_at_or_below = |bin_id: i32| match get_price_from_id(bin_id, bin_step) {
+        Ok(bin_price) => bin_price <= price,
+        Err(_) => bin_id < 0,
+    };
+
+    // Highest bin priced at or below the price
+    let floor_bin_id = if is_priced_at_or_below(MIN_BIN_ID) {
+        let (mut low, mut high) = (MIN_BIN_ID, MAX_BIN_ID);
+        while low < high {
+            let mid = low + (high - low + 1) / 2;
+            if is_priced_at_or_below(mid) {
+                low = mid;
+            } else {
+                high = mid - 1;
+            }
+        }
+        Some(low)
+    } else {
+        None
+    };
+
+    if !round_up {
+        return floor_bin_id.context("Price below the minimum bin price");
+    }
+
+    match floor_bin_id {
+        Some(bin_id) if get_price_from_id(bin_id, bin_step).ok() == Some(price) => Ok(bin_id),
+        Some(bin_id) => {
+            ensure!(bin_id < MAX_BIN_ID, "Price above the maximum bin price");
+            Ok(bin_id + 1)
+        }
+        None => Ok(MIN_BIN_ID),
+    }
+}
+
+/// Bin id range of the bins priced within the Q64.64 price range
+pub fn get_bin_id_range_from_price_range(
+    bin_step: u16,
+    min_price: u128,
+    max_price: u128,
+) -> Result<(i32, i32)> {
+    let min_bin_id = get_id_from_price(min_price, bin_step, true)?;
+    let max_bin_id = get_id_from_price(max_price, bin_step, false)?;
+    ensure!(min_bin_id <= max_bin_id, "No bin within the price range");
+
+    Ok((min_bin_id, max_bin_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_id_from_price() {
+        for (bin_id, bin_step) in [(0, 10), (-1_234, 25), (5_000, 1), (-300, 100)] {
+            let price = get_price_from_id(bin_id, bin_step).unwrap();
+
+            assert_eq!(get_id_from_price(price, bin_step, false).unwrap(), bin_id);
+            assert_eq!(get_id_from_price(price, bin_step, true).unwrap(), bin_id);
+
+            // In between 2 bins
+            assert_eq!(
+                get_id_from_price(price + 1, bin_step, false).unwrap(),
+                bin_id
+            );
+            assert_eq!(
+                get_id_from_price(price + 1, bin_step, true).unwrap(),
+                bin_id + 1
+            );
+            assert_eq!(
+                get_id_from_price(price - 1, bin_step, false).unwrap(),
+                bin_id - 1
+            );
+            assert_eq!(
+                get_id_from_price(price - 1, bin_step, true).unwrap(),
+                bin_id
+            );
+        }
+
+        assert!(get_id_from_price(0, 10, false).is_err());
+    }
+
+    #[test]
+    fn test_get_bin_id_range_from_price_range() {
+        let bin_step = 10;
+        let price_at = |bin_id: i32| get_price_from_id(bin_id, bin_step).unwrap();
+
+        assert_eq!(
+            get_bin_id_range_from_price_range(bin_step, price_at(-5), price_at(5)).unwrap(),
+            (-5, 5)
+        );
+        // Only the bins priced within the range
+        assert_eq!(
+            get_bin_id_range_from_price_range(bin_step, price_at(-5) + 1, price_at(5) - 1).unwrap(),
+            (-4, 4)
+        );
+        assert!(
+            get_bin_id_range_from_price_range(bin_step, price_at(0) + 1, price_at(1) - 1).is_err()
+        );
+    }
+}
@@ -0,0 +1,457 @@
+use crate::decode::{decode_bin_array, decode_lb_pair, decode_position_v2};
+use crate::position::get_position_valuation;
+use crate::price::get_id_from_price as get_id_from_q64x64_price;
+use crate::quote::{SwapExactInQuote, SwapExactOutQuote};
+use crate::quote_engine::QuoteEngine;
+use lb_clmm::{
+    math::{price_math::get_price_from_id, u64x64_math::SCALE_OFFSET},
+    state::{bin::BinArray, lb_pair::LbPair, position::PositionV2},
+};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use solana_sdk::{clock::Clock, pubkey::Pubkey};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+fn to_py_error(err: anyhow::Error) -> PyErr {
+    PyValueError::new_err(format!("{err:#}"))
+}
+
+fn parse_pubkey(pubkey: &str) -> PyResult<Pubkey> {
+    Pubkey::from_str(pubkey).map_err(|_| PyValueError::new_err(format!("Invalid pubkey {pubkey}")))
+}
+
+#[pyclass(name = "LbPair", get_all)]
+#[derive(Clone)]
+pub struct PyLbPair {
+    pub active_id: i32,
+    pub bin_step: u16,
+    pub status: u8,
+    pub pair_type: u8,
+    pub activation_type: u8,
+    pub activation_point: u64,
+    pub base_factor: u16,
+    pub protocol_share: u16,
+    pub token_x_mint: String,
+    pub token_y_mint: String,
+    pub reserve_x: String,
+    pub reserve_y: String,
+    pub oracle: String,
+}
+
+impl From<&LbPair> for PyLbPair {
+    fn from(lb_pair: &LbPair) -> Self {
+        Self {
+            active_id: lb_pair.active_id,
+            bin_step: lb_pair.bin_step,
+            status: lb_pair.status,
+            pair_type: lb_pair.pair_type,
+            activation_type: lb_pair.activation_type,
+            activation_point: lb_pair.activation_point,
+            base_factor: lb_pair.parameters.base_factor,
+            protocol_share: lb_pair.parameters.protocol_share,
+            token_x_mint: lb_pair.token_x_mint.to_string(),
+            token_y_mint: lb_pair.token_y_mint.to_string(),
+            reserve_x: lb_pair.reserve_x.to_string(),
+            reserve_y: lb_pair.reserve_y.to_string(),
+            oracle: lb_pair.oracle.to_string(),
+        }
+    }
+}
+
+#[pyclass(name = "Bin", get_all)]
+#[derive(Clone)]
+pub struct PyBin {
+    pub bin_id: i32,
+    pub amount_x: u64,
+    pub amount_y: u64,
+    /// Q64.64 price of the bin
+    pub price: u128,
+    pub liquidity_supply: u128,
+}
+
+#[pyclass(name = "BinArray", get_all)]
+#[derive(Clone)]
+pub struct PyBinArray {
+    pub index: i64,
+    pub lb_pair: String,
+    pub bins: Vec<PyBin>,
+}
+
+impl TryFrom<&BinArray> for PyBinArray {
+    type Error = anyhow::Error;
+
+    fn try_from(bin_array: &BinArray) -> anyhow::Result<Self> {
+        let (lower_bin_id, _upper_bin_id) =
+            BinArray::get_bin_array_lower_upper_bin_id(bin_array.index.try_into()?)?;
+
+        let bins = bin_array
+            .bins
+            .iter()
+            .zip(lower_bin_id..)
+            .map(|(bin, bin_id)| PyBin {
+                bin_id,
+                amount_x: bin.amount_x,
+                amount_y: bin.amount_y,
+                price: bin.price,
+                liquidity_supply: bin.liquidity_supply,
+            })
+            .collect();
+
+        Ok(Self {
+            index: bin_array.index,
+            lb_pair: bin_array.lb_pair.to_string(),
+            bins,
+        })
+    }
+}
+
+#[pyclass(name = "PositionV2", get_all)]
+#[derive(Clone)]
+pub struct PyPositionV2 {
+    pub lb_pair: String,
+    pub owner: String,
+    pub fee_owner: String,
+    pub operator: String,
+    pub lower_bin_id: i32,
+    pub upper_bin_id: i32,
+    /// Liquidity shares of the position, from `lower_bin_id` to `upper_bin_id`
+    pub liquidity_shares: Vec<u128>,
+    pub total_claimed_fee_x_amount: u64,
+    pub total_claimed_fee_y_amount: u64,
+    pub lock_release_point: u64,
+}
+
+impl From<&PositionV2> for PyPositionV2 {
+    fn from(position: &PositionV2) -> Self {
+        let width = (position.upper_bin_id - position.lower_bin_id + 1).max(0) as usize;
+
+        Self {
+            lb_pair: position.lb_pair.to_string(),
+            owner: position.owner.to_string(),
+            fee_owner: position.fee_owner.to_string(),
+            operator: position.operator.to_string(),
+            lower_bin_id: position.lower_bin_id,
+            upper_bin_id: position.upper_bin_id,
+            liquidity_shares: position
+                .liquidity_shares
+                .iter()
+                .take(width)
+                .copied()
+                .collect(),
+            total_claimed_fee_x_amount: position.total_claimed_fee_x_amount,
+            total_claimed_fee_y_amount: position.total_claimed_fee_y_amount,
+            lock_release_point: position.lock_release_point,
+        }
+    }
+}
+
+#[pyclass(name = "SwapExactInQuote", get_all)]
+pub struct PySwapExactInQuote {
+    pub amount_out: u64,
+    pub fee: u64,
+    pub transfer_fee_in: u64,
+    pub transfer_fee_out: u64,
+    pub protocol_fee: u64,
+    pub host_fee: u64,
+    pub would_trip_circuit_breaker: bool,
+    pub bin_arrays: Vec<String>,
+}
+
+impl From<SwapExactInQuote> for PySwapExactInQuote {
+    fn from(quote: SwapExactInQuote) -> Self {
+        Self {
+            amount_out: quote.amount_out,
+            fee: quote.fee,
+            transfer_fee_in: quote.transfer_fee_in,
+            transfer_fee_out: quote.transfer_fee_out,
+            protocol_fee: quote.protocol_fee,
+            host_fee: quote.host_fee,
+            would_trip_circuit_breaker: quote.would_trip_circuit_breaker,
+            bin_arrays: quote.bin_arrays.iter().map(Pubkey::to_string).collect(),
+        }
+    }
+}
+
+#[pyclass(name = "SwapExactOutQuote", get_all)]
+pub struct PySwapExactOutQuote {
+    pub amount_in: u64,
+    pub fee: u64,
+    pub transfer_fee_in: u64,
+    pub transfer_fee_out: u64,
+    pub protocol_fee: u64,
+    pub host_fee: u64,
+    pub would_trip_circuit_breaker: bool,
+}
+
+impl From<SwapExactOutQuote> for PySwapExactOutQuote {
+    fn from(quote: SwapExactOutQuote) -> Self {
+        Self {
+            amount_in: quote.amount_in,
+            fee: quote.fee,
+            transfer_fee_in: quote.transfer_fee_in,
+            transfer_fee_out: quote.transfer_fee_out,
+            protocol_fee: quote.protocol_fee,
+            host_fee: quote.host_fee,
+            would_trip_circuit_breaker: quote.would_trip_circuit_breaker,
+        }
+    }
+}
+
+#[pyclass(name = "PositionValuation", get_all)]
+pub struct PyPositionValuation {
+    pub total_amount_x: u64,
+    pub total_amount_y: u64,
+    pub withdrawable_amount_x: u64,
+    pub withdrawable_amount_y: u64,
+    pub locked_amount_x: u64,
+    pub locked_amount_y: u64,
+}
+
+/// Quote engine of a pair, fed with the raw data of the pair accounts. The mints, oracle and clock must be set before quoting.
+#[pyclass(name = "QuoteEngine")]
+pub struct PyQuoteEngine(QuoteEngine);
+
+#[pymethods]
+impl PyQuoteEngine {
+    #[new]
+    fn new(lb_pair_pubkey: &str, lb_pair_data: &[u8]) -> PyResult<Self> {
+        let lb_pair_pubkey = parse_pubkey(lb_pair_pubkey)?;
+        let engine = QuoteEngine::new(lb_pair_pubkey, lb_pair_data).map_err(to_py_error)?;
+        Ok(Self(engine))
+    }
+
+    #[getter]
+    fn lb_pair(&self) -> PyLbPair {
+        self.0.lb_pair().into()
+    }
+
+    fn set_lb_pair(&mut self, lb_pair_data: &[u8]) -> PyResult<()> {
+        self.0.set_lb_pair(lb_pair_data).map_err(to_py_error)
+    }
+
+    fn set_bitmap_extension(&mut self, bitmap_extension_data: &[u8]) -> PyResult<()> {
+        self.0
+            .set_bitmap_extension(bitmap_extension_data)
+            .map_err(to_py_error)
+    }
+
+    fn set_bin_array(&mut self, bin_array_pubkey: &str, bin_array_data: &[u8]) -> PyResult<()> {
+        let bin_array_pubkey = parse_pubkey(bin_array_pubkey)?;
+        self.0
+            .set_bin_array(bin_array_pubkey, bin_array_data)
+            .map_err(to_py_error)
+    }
+
+    fn set_mints(&mut self, mint_x_data: Vec<u8>, mint_y_data: Vec<u8>) {
+        self.0.set_mints(mint_x_data, mint_y_data);
+    }
+
+    fn set_oracle(&mut self, oracle_data: Vec<u8>) {
+        self.0.set_oracle(oracle_data);
+    }
+
+    fn set_clock(&mut self, slot: u64, epoch: u64, unix_timestamp: i64) {
+        self.0.set_clock(Clock {
+            slot,
+            epoch,
+            unix_timestamp,
+            ..Default::default()
+        });
+    }
+
+    fn get_bin_array_pubkeys_for_swap(
+        &self,
+        swap_for_y: bool,
+        take_count: u8,
+    ) -> PyResult<Vec<String>> {
+        let bin_array_pubkeys = self
+            .0
+            .get_bin_array_pubkeys_for_swap(swap_for_y, take_count)
+            .map_err(to_py_error)?;
+
+        Ok(bin_array_pubkeys.iter().map(Pubkey::to_string).collect())
+    }
+
+    #[pyo3(signature = (amount_in, swap_for_y, host_fee_bps=None))]
+    fn quote_exact_in(
+        &self,
+        amount_in: u64,
+        swap_for_y: bool,
+        host_fee_bps: Option<u16>,
+    ) -> PyResult<PySwapExactInQuote> {
+        self.0
+            .quote_exact_in(amount_in, swap_for_y, host_fee_bps)
+            .map(Into::into)
+            .map_err(to_py_error)
+    }
+
+    #[pyo3(signature = (amount_out, swap_for_y, host_fee_bps=None))]
+    fn quote_exact_out(
+        &self,
+        amount_out: u64,
+        swap_for_y: bool,
+        host_fee_bps: Option<u16>,
+    ) -> PyResult<PySwapExactOutQuote> {
+        self.0
+            .quote_exact_out(amount_out, swap_for_y, host_fee_bps)
+            .map(Into::into)
+            .map_err(to_py_error)
+    }
+}
+
+#[pyfunction(name = "decode_lb_pair")]
+fn py_decode_lb_pair(data: &[u8]) -> PyResult<PyLbPair> {
+    Ok((&decode_lb_pair(data).map_err(to_py_error)?).into())
+}
+
+#[pyfunction(name = "decode_bin_array")]
+fn py_decode_bin_array(data: &[u8]) -> PyResult<PyBinArray> {
+    let bin_array = decode_bin_array(data).map_err(to_py_error)?;
+    PyBinArray::try_from(&bin_array).map_err(to_py_error)
+}
+
+#[pyfunction(name = "decode_position_v2")]
+fn py_decode_position_v2(data: &[u8]) -> PyResult<PyPositionV2> {
+    Ok((&decode_position_v2(data).map_err(to_py_error)?).into())
+}
+
+/// Q64.64 price of `bin_id`
+#[pyfunction(name = "get_price_from_id")]
+fn py_get_price_from_id(bin_id: i32, bin_step: u16) -> PyResult<u128> {
+    get_price_from_id(bin_id, bin_step).map_err(|err| to_py_error(err.into()))
+}
+
+/// Price per lamport of `bin_id`
+#[pyfunction]
+fn get_price_per_lamport(bin_id: i32, bin_step: u16) -> PyResult<f64> {
+    let price = py_get_price_from_id(bin_id, bin_step)?;
+    Ok(price as f64 / 2f64.powi(SCALE_OFFSET.into()))
+}
+
+/// Bin id of the price per lamport. When the price is in between 2 bins, round down, or up when `round_up`. The price is converted to
+/// Q64.64 and compared against the bin prices of the program.
+#[pyfunction]
+#[pyo3(signature = (price_per_lamport, bin_step, round_up=false))]
+fn get_id_from_price(price_per_lamport: f64, bin_step: u16, round_up: bool) -> PyResult<i32> {
+    if !price_per_lamport.is_finite() || price_per_lamport <= 0.0 {
+        return Err(PyValueError::new_err("Price must be positive"));
+    }
+
+    let price = price_per_lamport * 2f64.powi(SCALE_OFFSET.into());
+    if price >= u128::MAX as f64 {
+        return Err(PyValueError::new_err("Price above the maximum bin price"));
+    }
+
+    get_id_from_q64x64_price(price as u128, bin_step, round_up).map_err(to_py_error)
+}
+
+/// Value the position liquidity at the given clock. `bin_arrays` maps the bin arrays covering the position to their raw data.
+#[pyfunction(name = "get_position_valuation")]
+fn py_get_position_valuation(
+    position_data: &[u8],
+    lb_pair_data: &[u8],
+    bin_arrays: HashMap<String, Vec<u8>>,
+    slot: u64,
+    unix_timestamp: i64,
+) -> PyResult<PyPositionValuation> {
+    let position = decode_position_v2(position_data).map_err(to_py_error)?;
+    let lb_pair = decode_lb_pair(lb_pair_data).map_err(to_py_error)?;
+    let bin_arrays = bin_arrays
+        .iter()
+        .map(|(pubkey, data)| {
+            let bin_array = decode_bin_array(data).map_err(to_py_error)?;
+            Ok((parse_pubkey(pubkey)?, bin_array))
+        })
+        .collect::<PyResult<HashMap<_, _>>>()?;
+    let clock = Clock {
+        slot,
+        unix_timestamp,
+        ..Default::default()
+    };
+
+    let valuation =
+        get_position_valuation(&position, &lb_pair, &bin_arrays, &clock).map_err(to_py_error)?;
+
+    Ok(PyPositionValuation {
+        total_amount_x: valuation.total.amount_x,
+        total_amount_y: valuation.total.amount_y,
+        withdrawable_amount_x: valuation.withdrawable.amount_x,
+        withdrawable_amount_y: valuation.withdrawable.amount_y,
+        locked_amount_x: valuation.locked.amount_x,
+        locked_amount_y: valuation.locked.amount_y,
+    })
+}
+
+#[pymodule]
+#[pyo3(name = "dlmm_commons")]
+fn dlmm_commons(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyLbPair>()?;
+    m.add_class::<PyBin>()?;
+    m.add_class::<PyBinArray>()?;
+    m.add_class::<PyPositionV2>()?;
+    m.add_class::<PySwapExactInQuote>()?;
+    m.add_class::<PySwapExactOutQuote>()?;
+    m.add_class::<PyPositionValuation>()?;
+    m.add_class::<PyQuoteEngine>()?;
+    m.add_function(wrap_pyfunction!(py_decode_lb_pair, m)?)?;
+    m.add_function(wrap_pyfunction!(py_decode_bin_array, m)?)?;
+    m.add_function(wrap_pyfunction!(py_decode_position_v2, m)?)?;
+    m.add_function(wrap_pyfunction!(py_get_price_from_id, m)?)?;
+    m.add_function(wrap_pyfunction!(get_price_per_lamport, m)?)?;
+    m.add_function(wrap_pyfunction!(get_id_from_price, m)?)?;
+    m.add_function(wrap_pyfunction!(py_get_position_valuation, m)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_price_from_id() {
+        for bin_step in [1, 10, 25, 100] {
+            assert_eq!(py_get_price_from_id(0, bin_step).unwrap(), 1u128 << 64);
+            assert_eq!(get_price_per_lamport(0, bin_step).unwrap(), 1.0);
+        }
+
+        for (bin_id, bin_step, price) in [
+            (1, 10, 18_465_190_817_783_261_167),
+            (-1, 10, 18_428_315_757_951_600_016),
+            (100, 25, 23_678_699_809_202_413_098),
+            (-1_234, 25, 846_824_097_398_941_981),
+            (5_000, 1, 30_412_779_051_191_554_362),
+        ] {
+            assert_eq!(py_get_price_from_id(bin_id, bin_step).unwrap(), price);
+        }
+    }
+
+    #[test]
+    fn test_get_id_from_price() {
+        // Exactly at the price of the bin
+        assert_eq!(get_id_from_price(1.0, 10, false).unwrap(), 0);
+        assert_eq!(get_id_from_price(1.0, 10, true).unwrap(), 0);
+        assert_eq!(get_id_from_price(0.5, 100, false).unwrap(), -70);
+        assert_eq!(get_id_from_price(0.5, 100, true).unwrap(), -69);
+
+        assert!(get_id_from_price(0.0, 10, false).is_err());
+        assert!(get_id_from_price(f64::NAN, 10, false).is_err());
+        assert!(get_id_from_price(f64::INFINITY, 10, false).is_err());
+    }
+
+    #[test]
+    fn test_price_id_round_trip() {
+        for (bin_id, bin_step) in [(0, 10), (-1_234, 25), (5_000, 1), (-300, 100)] {
+            let price = get_price_per_lamport(bin_id, bin_step).unwrap();
+
+            assert_eq!(
+                get_id_from_price(price * 1.000_001, bin_step, false).unwrap(),
+                bin_id
+            );
+            assert_eq!(
+                get_id_from_price(price * 0.999_999, bin_step, true).unwrap(),
+                bin_id
+            );
+        }
+    }
+}
@@ -1,29 +1,104 @@
-use anchor_client::solana_sdk::pubkey::Pubkey;
+use crate::instructions::swap_route::SwapRouteHop;
+use crate::oracle::decode_oracle;
 use anyhow::{ensure, Context, Result};
 use lb_clmm::{
+    constants::{BASIS_POINT_MAX, MAX_SWAP_ROUTE_HOPS},
     pair_action_access::ActivationType,
     state::{
         bin::{Bin, BinArray, SwapResult},
         bin_array_bitmap_extension::BinArrayBitmapExtension,
         lb_pair::{LbPair, PairStatus, PairType},
+        oracle::get_time_weighted_active_id,
+    },
+    utils::{
+        pda::{derive_bin_array_bitmap_extension, derive_bin_array_pda},
+        token_2022::{
+            calculate_transfer_fee_excluded_amount, calculate_transfer_fee_included_amount,
+            TransferFeeExcludedAmount, TransferFeeIncludedAmount,
+        },
     },
-    utils::pda::derive_bin_array_pda,
 };
+use solana_sdk::{account::Account, clock::Clock, pubkey::Pubkey};
 use std::collections::HashMap;
 
 #[derive(Debug)]
 pub struct SwapExactInQuote {
+    /// Amount received by the user, excluding the transfer fee of token out
     pub amount_out: u64,
     pub fee: u64,
+    /// Transfer fee of token in, withheld before the amount reach the pool
+    pub transfer_fee_in: u64,
+    /// Transfer fee of token out, withheld before the amount reach the user
+    pub transfer_fee_out: u64,
+    /// Portion of the fee retained by the protocol, excluding the host fee
+    pub protocol_fee: u64,
+    /// Portion of the protocol fee given to the host or referrer
+    pub host_fee: u64,
+    /// Whether the swap would trip the circuit breaker of the pair, halting subsequent swaps
+    pub would_trip_circuit_breaker: bool,
+    /// Bin arrays traversed by the swap, in order
+    pub bin_arrays: Vec<Pubkey>,
+}
+
+#[derive(Debug)]
+pub struct SwapRouteQuote {
+    /// Amount received by the user at the last hop, excluding the transfer fee of token out
+    pub amount_out: u64,
+    /// Quote of each hop, in order
+    pub hops: Vec<SwapExactInQuote>,
+    /// Hops of the route, to be passed to the `swap_route` instruction builder
+    pub route: Vec<SwapRouteHop>,
+}
+
+/// Swap of exactly `amount_in` scheduled at `timestamp`
+#[derive(Debug, Clone, Copy)]
+pub struct TimedSwap {
+    pub amount_in: u64,
+    pub swap_for_y: bool,
+    pub timestamp: i64,
+}
+
+#[derive(Debug)]
+pub struct TimedSwapQuote {
+    pub timestamp: i64,
+    /// Base fee rate of the pair, in FEE_PRECISION
+    pub base_fee_rate: u128,
+    /// Variable fee rate at the active bin at the start of the swap, after the volatility decayed since the previous swap, in FEE_PRECISION
+    pub variable_fee_rate: u128,
+    /// Volatility accumulator at the end of the swap
+    pub volatility_accumulator: u32,
+    pub quote: SwapExactInQuote,
+}
+
+/// State of the pair to quote a swap from. The mint accounts account for token 2022 transfer fee, and the oracle is used to evaluate the circuit breaker.
+pub struct SwapQuoteAccounts<'a> {
+    pub lb_pair_pubkey: Pubkey,
+    pub lb_pair: &'a LbPair,
+    pub bin_arrays: HashMap<Pubkey, BinArray>,
+    pub bitmap_extension: Option<&'a BinArrayBitmapExtension>,
+    pub mint_x_account: &'a Account,
+    pub mint_y_account: &'a Account,
+    pub oracle_account: &'a Account,
 }
 
 #[derive(Debug)]
 pub struct SwapExactOutQuote {
+    /// Amount to be sent by the user, excluding the swap fee but including the transfer fee of token in
     pub amount_in: u64,
     pub fee: u64,
+    /// Transfer fee of token in, withheld before the amount reach the pool
+    pub transfer_fee_in: u64,
+    /// Transfer fee of token out, withheld before the amount reach the user
+    pub transfer_fee_out: u64,
+    /// Portion of the fee retained by the protocol, excluding the host fee
+    pub protocol_fee: u64,
+    /// Portion of the protocol fee given to the host or referrer
+    pub host_fee: u64,
+    /// Whether the swap would trip the circuit breaker of the pair, halting subsequent swaps
+    pub would_trip_circuit_breaker: bool,
 }
 
-fn validate_swap_activation(
+pub(crate) fn validate_swap_activation(
     lb_pair: &LbPair,
     current_timestamp: u64,
     current_slot: u64,
@@ -33,6 +108,11 @@ fn validate_swap_activation(
         "Pair is disabled"
     );
 
+    ensure!(
+        !lb_pair.circuit_breaker.is_halted(current_timestamp as i64),
+        "Pair is halted by circuit breaker"
+    );
+
     let pair_type = lb_pair.pair_type()?;
     if pair_type.eq(&PairType::Permission) {
         let activation_type = ActivationType::try_from(lb_pair.activation_type)?;
@@ -50,23 +130,104 @@ fn validate_swap_activation(
     Ok(())
 }
 
-pub fn quote_exact_out(
-    lb_pair_pubkey: Pubkey,
+/// Split the protocol fee of `fee` into the protocol portion after host fee, and the host fee. Same as `Bin::swap`.
+fn split_protocol_fee(lb_pair: &LbPair, fee: u64, host_fee_bps: Option<u16>) -> Result<(u64, u64)> {
+    let protocol_fee = lb_pair.compute_protocol_fee(fee)?;
+    let host_fee = match host_fee_bps {
+        Some(bps) => {
+            protocol_fee
+                .checked_mul(bps.into())
+                .context("MathOverflow")?
+                / BASIS_POINT_MAX as u64
+        }
+        None => 0,
+    };
+
+    Ok((protocol_fee - host_fee, host_fee))
+}
+
+/// Time weighted active bin id of the circuit breaker window, measured from the oracle of the pair. None when the oracle history is not enough.
+pub fn get_circuit_breaker_time_weighted_active_id(
+    lb_pair: &LbPair,
+    oracle_account: &Account,
+    current_timestamp: i64,
+) -> Result<Option<i32>> {
+    let (oracle, observations) = decode_oracle(oracle_account)?;
+
+    Ok(get_time_weighted_active_id(
+        &oracle,
+        &observations,
+        lb_pair.active_id,
+        current_timestamp,
+        lb_pair.circuit_breaker.window.into(),
+    )?)
+}
+
+/// Whether moving the active bin to `end_active_id` would trip the circuit breaker of the pair
+pub fn would_trip_circuit_breaker(
     lb_pair: &LbPair,
-    mut amount_out: u64,
+    oracle_account: &Account,
+    end_active_id: i32,
+    current_timestamp: i64,
+) -> Result<bool> {
+    if !lb_pair.circuit_breaker.is_enabled() {
+        return Ok(false);
+    }
+
+    let time_weighted_active_id =
+        get_circuit_breaker_time_weighted_active_id(lb_pair, oracle_account, current_timestamp)?;
+
+    Ok(time_weighted_active_id
+        .map(|time_weighted_active_id| {
+            lb_pair
+                .circuit_breaker
+                .is_exceeded(end_active_id, time_weighted_active_id)
+        })
+        .unwrap_or(false))
+}
+
+/// Quote for the user to receive exactly `amount_out` from the pair of `accounts`.
+/// `host_fee_bps` is the share of the protocol fee given to the host: the `fee_share` of the referrer, `HOST_FEE_BPS` when swapping with `host_fee_in`, or None.
+pub fn quote_exact_out(
+    accounts: SwapQuoteAccounts,
+    amount_out: u64,
     swap_for_y: bool,
-    bin_arrays: HashMap<Pubkey, BinArray>,
-    bitmap_extension: Option<&BinArrayBitmapExtension>,
-    current_timestamp: u64,
-    current_slot: u64,
+    clock: &Clock,
+    host_fee_bps: Option<u16>,
 ) -> Result<SwapExactOutQuote> {
-    validate_swap_activation(lb_pair, current_timestamp, current_slot)?;
+    let SwapQuoteAccounts {
+        lb_pair_pubkey,
+        lb_pair,
+        bin_arrays,
+        bitmap_extension,
+        mint_x_account,
+        mint_y_account,
+        oracle_account,
+    } = accounts;
+
+    let current_timestamp = clock.unix_timestamp as u64;
+    validate_swap_activation(lb_pair, current_timestamp, clock.slot)?;
+
+    let (mint_in_account, mint_out_account) = if swap_for_y {
+        (mint_x_account, mint_y_account)
+    } else {
+        (mint_y_account, mint_x_account)
+    };
+
+    // The pool must send more for the user to receive amount_out after transfer fee
+    let TransferFeeIncludedAmount {
+        amount: mut amount_out,
+        transfer_fee: transfer_fee_out,
+    } = calculate_transfer_fee_included_amount(&mint_out_account.data, amount_out, clock.epoch)?;
 
+    let initial_lb_pair = lb_pair;
     let mut lb_pair = *lb_pair;
     lb_pair.update_references(current_timestamp as i64)?;
 
     let mut total_amount_in: u64 = 0;
     let mut total_fee: u64 = 0;
+    let mut total_protocol_fee: u64 = 0;
+    let mut total_host_fee: u64 = 0;
 
     while amount_out > 0 {
         let active_bin_array_pubkey = get_bin_array_pubkeys_for_swap(
@@ -109,6 +270,14 @@ pub fn quote_exact_out(
                         .context("MathOverflow")?;
 
                     total_fee = total_fee.checked_add(max_fee).context("MathOverflow")?;
+                    let (protocol_fee, host_fee) =
+                        split_protocol_fee(&lb_pair, max_fee, host_fee_bps)?;
+                    total_protocol_fee = total_protocol_fee
+                        .checked_add(protocol_fee)
+                        .context("MathOverflow")?;
+                    total_host_fee = total_host_fee
+                        .checked_add(host_fee)
+                        .context("MathOverflow")?;
 
                     amount_out = amount_out
                         .checked_sub(bin_max_amount_out)
@@ -122,6 +291,13 @@ pub fn quote_exact_out(
                         .context("MathOverflow")?;
 
                     total_fee = total_fee.checked_add(fee).context("MathOverflow")?;
+                    let (protocol_fee, host_fee) = split_protocol_fee(&lb_pair, fee, host_fee_bps)?;
+                    total_protocol_fee = total_protocol_fee
+                        .checked_add(protocol_fee)
+                        .context("MathOverflow")?;
+                    total_host_fee = total_host_fee
+                        .checked_add(host_fee)
+                        .context("MathOverflow")?;
 
                     amount_out = 0;
                 }
@@ -133,44 +309,77 @@ pub fn quote_exact_out(
         }
     }
 
+    let amount_in_with_fee = total_amount_in
+        .checked_add(total_fee)
+        .context("MathOverflow")?;
+
+    let TransferFeeIncludedAmount {
+        amount: amount_in_with_fee,
+        transfer_fee: transfer_fee_in,
+    } = calculate_transfer_fee_included_amount(
+        &mint_in_account.data,
+        amount_in_with_fee,
+        clock.epoch,
+    )?;
+
+    let would_trip_circuit_breaker = would_trip_circuit_breaker(
+        initial_lb_pair,
+        oracle_account,
+        lb_pair.active_id,
+        current_timestamp as i64,
+    )?;
+
     Ok(SwapExactOutQuote {
-        amount_in: total_amount_in,
+        amount_in: amount_in_with_fee
+            .checked_sub(total_fee)
+            .context("MathOverflow")?,
         fee: total_fee,
+        transfer_fee_in,
+        transfer_fee_out,
+        protocol_fee: total_protocol_fee,
+        host_fee: total_host_fee,
+        would_trip_circuit_breaker,
     })
 }
 
-pub fn quote_exact_in(
+struct SimulatedSwap {
+    amount_out: u64,
+    fee: u64,
+    protocol_fee: u64,
+    host_fee: u64,
+    bin_arrays: Vec<Pubkey>,
+}
+
+/// Swap `amount_in`, after transfer fee, through the bins of the pair. `lb_pair` and `bin_arrays` are updated to the state after the swap.
+fn simulate_swap_exact_in(
     lb_pair_pubkey: Pubkey,
-    lb_pair: &LbPair,
+    lb_pair: &mut LbPair,
+    bin_arrays: &mut HashMap<Pubkey, BinArray>,
+    bitmap_extension: Option<&BinArrayBitmapExtension>,
     mut amount_in: u64,
     swap_for_y: bool,
-    bin_arrays: HashMap<Pubkey, BinArray>,
-    bitmap_extension: Option<&BinArrayBitmapExtension>,
-    current_timestamp: u64,
-    current_slot: u64,
-) -> Result<SwapExactInQuote> {
-    validate_swap_activation(lb_pair, current_timestamp, current_slot)?;
-
-    let mut lb_pair = *lb_pair;
-    lb_pair.update_references(current_timestamp as i64)?;
-
+    host_fee_bps: Option<u16>,
+) -> Result<SimulatedSwap> {
     let mut total_amount_out: u64 = 0;
     let mut total_fee: u64 = 0;
+    let mut total_protocol_fee: u64 = 0;
+    let mut total_host_fee: u64 = 0;
+    let mut traversed_bin_arrays = vec![];
 
     while amount_in > 0 {
         let active_bin_array_pubkey = get_bin_array_pubkeys_for_swap(
             lb_pair_pubkey,
-            &lb_pair,
+            lb_pair,
             bitmap_extension,
             swap_for_y,
             1,
         )?
         .pop()
         .context("Pool out of liquidity")?;
+        traversed_bin_arrays.push(active_bin_array_pubkey);
 
-        let mut active_bin_array = bin_arrays
-            .get(&active_bin_array_pubkey)
-            .cloned()
+        let active_bin_array = bin_arrays
+            .get_mut(&active_bin_array_pubkey)
             .context("Active bin array not found")?;
 
         loop {
@@ -192,8 +401,10 @@ pub fn quote_exact_in(
                     amount_in_with_fees,
                     amount_out,
                     fee,
+                    protocol_fee_after_host_fee,
+                    host_fee,
                     ..
-                } = active_bin.swap(amount_in, price, swap_for_y, &lb_pair, None)?;
+                } = active_bin.swap(amount_in, price, swap_for_y, lb_pair, host_fee_bps)?;
 
                 amount_in = amount_in
                     .checked_sub(amount_in_with_fees)
@@ -203,6 +414,12 @@ pub fn quote_exact_in(
                     .checked_add(amount_out)
                     .context("MathOverflow")?;
                 total_fee = total_fee.checked_add(fee).context("MathOverflow")?;
+                total_protocol_fee = total_protocol_fee
+                    .checked_add(protocol_fee_after_host_fee)
+                    .context("MathOverflow")?;
+                total_host_fee = total_host_fee
+                    .checked_add(host_fee)
+                    .context("MathOverflow")?;
             }
 
             if amount_in > 0 {
@@ -211,12 +428,284 @@ pub fn quote_exact_in(
         }
     }
 
-    Ok(SwapExactInQuote {
+    Ok(SimulatedSwap {
         amount_out: total_amount_out,
         fee: total_fee,
+        protocol_fee: total_protocol_fee,
+        host_fee: total_host_fee,
+        bin_arrays: traversed_bin_arrays,
     })
 }
 
+/// Quote for the user to send exactly `amount_in` to the pair of `accounts`.
+/// `host_fee_bps` is the share of the protocol fee given to the host: the `fee_share` of the referrer, `HOST_FEE_BPS` when swapping with `host_fee_in`, or None.
+pub fn quote_exact_in(
+    accounts: SwapQuoteAccounts,
+    amount_in: u64,
+    swap_for_y: bool,
+    clock: &Clock,
+    host_fee_bps: Option<u16>,
+) -> Result<SwapExactInQuote> {
+    let SwapQuoteAccounts {
+        lb_pair_pubkey,
+        lb_pair,
+        mut bin_arrays,
+        bitmap_extension,
+        mint_x_account,
+        mint_y_account,
+        oracle_account,
+    } = accounts;
+
+    let current_timestamp = clock.unix_timestamp as u64;
+    validate_swap_activation(lb_pair, current_timestamp, clock.slot)?;
+
+    let (mint_in_account, mint_out_account) = if swap_for_y {
+        (mint_x_account, mint_y_account)
+    } else {
+        (mint_y_account, mint_x_account)
+    };
+
+    // The pool only receive amount_in after transfer fee
+    let TransferFeeExcludedAmount {
+        amount: amount_in,
+        transfer_fee: transfer_fee_in,
+    } = calculate_transfer_fee_excluded_amount(&mint_in_account.data, amount_in, clock.epoch)?;
+
+    let initial_lb_pair = lb_pair;
+    let mut lb_pair = *lb_pair;
+    lb_pair.update_references(current_timestamp as i64)?;
+
+    let SimulatedSwap {
+        amount_out: total_amount_out,
+        fee: total_fee,
+        protocol_fee: total_protocol_fee,
+        host_fee: total_host_fee,
+        bin_arrays: traversed_bin_arrays,
+    } = simulate_swap_exact_in(
+        lb_pair_pubkey,
+        &mut lb_pair,
+        &mut bin_arrays,
+        bitmap_extension,
+        amount_in,
+        swap_for_y,
+        host_fee_bps,
+    )?;
+
+    let TransferFeeExcludedAmount {
+        amount: amount_out,
+        transfer_fee: transfer_fee_out,
+    } = calculate_transfer_fee_excluded_amount(
+        &mint_out_account.data,
+        total_amount_out,
+        clock.epoch,
+    )?;
+
+    let would_trip_circuit_breaker = would_trip_circuit_breaker(
+        initial_lb_pair,
+        oracle_account,
+        lb_pair.active_id,
+        current_timestamp as i64,
+    )?;
+
+    Ok(SwapExactInQuote {
+        amount_out,
+        fee: total_fee,
+        transfer_fee_in,
+        transfer_fee_out,
+        protocol_fee: total_protocol_fee,
+        host_fee: total_host_fee,
+        would_trip_circuit_breaker,
+        bin_arrays: traversed_bin_arrays,
+    })
+}
+
+/// Quote for the user to send exactly `amount_in` of `mint_in` through the pairs of `hops`, in order. The amount out of a hop is
+/// the amount in of the next hop. The returned route carries the exact accounts of each hop, including the traversed bin arrays.
+/// The transfer hook accounts and referrer of the hops are left empty, to be set by the caller.
+pub fn quote_route_exact_in(
+    hops: Vec<SwapQuoteAccounts>,
+    mint_in: Pubkey,
+    amount_in: u64,
+    clock: &Clock,
+) -> Result<SwapRouteQuote> {
+    ensure!(
+        !hops.is_empty() && hops.len() <= MAX_SWAP_ROUTE_HOPS,
+        "Invalid number of hops"
+    );
+
+    let mut hop_mint_in = mint_in;
+    let mut hop_amount_in = amount_in;
+    let mut quotes = Vec::with_capacity(hops.len());
+    let mut route = Vec::with_capacity(hops.len());
+
+    for hop in hops {
+        let SwapQuoteAccounts {
+            lb_pair_pubkey,
+            lb_pair,
+            bitmap_extension,
+            mint_x_account,
+            mint_y_account,
+            ..
+        } = hop;
+
+        let swap_for_y = if hop_mint_in == lb_pair.token_x_mint {
+            true
+        } else if hop_mint_in == lb_pair.token_y_mint {
+            false
+        } else {
+            anyhow::bail!(
+                "Pair {} does not contain mint {}",
+                lb_pair_pubkey,
+                hop_mint_in
+            );
+        };
+
+        let quote = quote_exact_in(hop, hop_amount_in, swap_for_y, clock, None)?;
+
+        route.push(SwapRouteHop {
+            lb_pair: lb_pair_pubkey,
+            lb_pair_state: *lb_pair,
+            bin_array_bitmap_extension: bitmap_extension
+                .map(|_| derive_bin_array_bitmap_extension(lb_pair_pubkey).0),
+            token_x_program: mint_x_account.owner,
+            token_y_program: mint_y_account.owner,
+            swap_for_y,
+            bin_arrays: quote.bin_arrays.clone(),
+            transfer_hook_accounts: vec![],
+            referrer: None,
+        });
+
+        hop_amount_in = quote.amount_out;
+        hop_mint_in = if swap_for_y {
+            lb_pair.token_y_mint
+        } else {
+            lb_pair.token_x_mint
+        };
+        quotes.push(quote);
+    }
+
+    Ok(SwapRouteQuote {
+        amount_out: hop_amount_in,
+        hops: quotes,
+        route,
+    })
+}
+
+/// Quote a sequence of swaps of `pair` scheduled in the future, such as the slices of a TWAP execution. Each swap sees the pair and bins
+/// left by the previous one, with the volatility reference decayed by `update_references` over the elapsed filter and decay periods.
+/// A single swap quote the variable fee at a future timestamp. Activation by slot is evaluated against the slot of `clock`.
+/// `host_fee_bps` is the share of the protocol fee given to the host of every swap, as in `quote_exact_in`.
+pub fn quote_timed_swaps(
+    pair: SwapQuoteAccounts,
+    swaps: &[TimedSwap],
+    clock: &Clock,
+    host_fee_bps: Option<u16>,
+) -> Result<Vec<TimedSwapQuote>> {
+    let SwapQuoteAccounts {
+        lb_pair_pubkey,
+        lb_pair,
+        mut bin_arrays,
+        bitmap_extension,
+        mint_x_account,
+        mint_y_account,
+        oracle_account,
+    } = pair;
+
+    let mut lb_pair = *lb_pair;
+    let mut last_timestamp = clock.unix_timestamp;
+    let mut quotes = Vec::with_capacity(swaps.len());
+
+    for swap in swaps {
+        ensure!(
+            swap.timestamp >= last_timestamp,
+            "Swaps must be in chronological order, not before the clock"
+        );
+        last_timestamp = swap.timestamp;
+
+        validate_swap_activation(&lb_pair, swap.timestamp as u64, clock.slot)?;
+
+        let (mint_in_account, mint_out_account) = if swap.swap_for_y {
+            (mint_x_account, mint_y_account)
+        } else {
+            (mint_y_account, mint_x_account)
+        };
+
+        let TransferFeeExcludedAmount {
+            amount: amount_in,
+            transfer_fee: transfer_fee_in,
+        } = calculate_transfer_fee_excluded_amount(
+            &mint_in_account.data,
+            swap.amount_in,
+            clock.epoch,
+        )?;
+
+        let initial_lb_pair = lb_pair;
+        lb_pair.update_references(swap.timestamp)?;
+
+        // Fee charged at the active bin, which account for the bins crossed since the index reference within the filter period
+        let mut active_bin_lb_pair = lb_pair;
+        active_bin_lb_pair.update_volatility_accumulator()?;
+        let variable_fee_rate = active_bin_lb_pair.get_variable_fee()?;
+
+        let SimulatedSwap {
+            amount_out,
+            fee,
+            protocol_fee,
+            host_fee,
+            bin_arrays: traversed_bin_arrays,
+        } = simulate_swap_exact_in(
+            lb_pair_pubkey,
+            &mut lb_pair,
+            &mut bin_arrays,
+            bitmap_extension,
+            amount_in,
+            swap.swap_for_y,
+            host_fee_bps,
+        )?;
+        // Swap instruction record the time of the swap, from which the next swap measure the elapsed time
+        lb_pair.v_parameters.last_update_timestamp = swap.timestamp;
+
+        let TransferFeeExcludedAmount {
+            amount: amount_out,
+            transfer_fee: transfer_fee_out,
+        } = calculate_transfer_fee_excluded_amount(
+            &mint_out_account.data,
+            amount_out,
+            clock.epoch,
+        )?;
+
+        let would_trip_circuit_breaker = would_trip_circuit_breaker(
+            &initial_lb_pair,
+            oracle_account,
+            lb_pair.active_id,
+            swap.timestamp,
+        )?;
+        // Swap instruction halt the pair after the swap tripping the circuit breaker
+        if would_trip_circuit_breaker {
+            lb_pair.circuit_breaker.trip(swap.timestamp)?;
+        }
+
+        quotes.push(TimedSwapQuote {
+            timestamp: swap.timestamp,
+            base_fee_rate: lb_pair.get_base_fee()?,
+            variable_fee_rate,
+            volatility_accumulator: lb_pair.v_parameters.volatility_accumulator,
+            quote: SwapExactInQuote {
+                amount_out,
+                fee,
+                transfer_fee_in,
+                transfer_fee_out,
+                protocol_fee,
+                host_fee,
+                would_trip_circuit_breaker,
+                bin_arrays: traversed_bin_arrays,
+            },
+        });
+    }
+
+    Ok(quotes)
+}
+
 pub fn get_bin_array_pubkeys_for_swap(
     lb_pair_pubkey: Pubkey,
     lb_pair: &LbPair,
@@ -274,7 +763,7 @@ pub fn get_bin_array_pubkeys_for_swap(
     Ok(bin_array_pubkeys)
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "client"))]
 mod tests {
     use super::*;
     use anchor_client::anchor_lang::AccountDeserialize;
@@ -344,6 +833,10 @@ mod tests {
             })
             .collect::<HashMap<_, _>>();
 
+        let mint_x_account = rpc_client.get_account(&lb_pair.token_x_mint).await.unwrap();
+        let mint_y_account = rpc_client.get_account(&lb_pair.token_y_mint).await.unwrap();
+        let oracle_account = rpc_client.get_account(&lb_pair.oracle).await.unwrap();
+
         let usdc_token_multiplier = 1_000_000.0;
         let sol_token_multiplier = 1_000_000_000.0;
 
@@ -351,14 +844,19 @@ mod tests {
         let clock = get_clock(rpc_client).await.unwrap();
 
         let quote_result = quote_exact_out(
-            SOL_USDC,
-            &lb_pair,
+            SwapQuoteAccounts {
+                lb_pair_pubkey: SOL_USDC,
+                lb_pair: &lb_pair,
+                bin_arrays: bin_arrays.clone(),
+                bitmap_extension: None,
+                mint_x_account: &mint_x_account,
+                mint_y_account: &mint_y_account,
+                oracle_account: &oracle_account,
+            },
             out_sol_amount,
             false,
-            bin_arrays.clone(),
+            &clock,
             None,
-            clock.unix_timestamp as u64,
-            clock.slot,
         )
         .unwrap();
 
@@ -370,14 +868,19 @@ mod tests {
         );
 
         let quote_result = quote_exact_in(
-            SOL_USDC,
-            &lb_pair,
+            SwapQuoteAccounts {
+                lb_pair_pubkey: SOL_USDC,
+                lb_pair: &lb_pair,
+                bin_arrays: bin_arrays.clone(),
+                bitmap_extension: None,
+                mint_x_account: &mint_x_account,
+                mint_y_account: &mint_y_account,
+                oracle_account: &oracle_account,
+            },
             in_amount,
             false,
-            bin_arrays.clone(),
+            &clock,
             None,
-            clock.unix_timestamp as u64,
-            clock.slot,
         )
         .unwrap();
 
@@ -390,14 +893,19 @@ mod tests {
         let out_usdc_amount = 200_000_000;
 
         let quote_result = quote_exact_out(
-            SOL_USDC,
-            &lb_pair,
+            SwapQuoteAccounts {
+                lb_pair_pubkey: SOL_USDC,
+                lb_pair: &lb_pair,
+                bin_arrays: bin_arrays.clone(),
+                bitmap_extension: None,
+                mint_x_account: &mint_x_account,
+                mint_y_account: &mint_y_account,
+                oracle_account: &oracle_account,
+            },
             out_usdc_amount,
             true,
-            bin_arrays.clone(),
+            &clock,
             None,
-            clock.unix_timestamp as u64,
-            clock.slot,
         )
         .unwrap();
 
@@ -409,14 +917,19 @@ mod tests {
         );
 
         let quote_result = quote_exact_in(
-            SOL_USDC,
-            &lb_pair,
+            SwapQuoteAccounts {
+                lb_pair_pubkey: SOL_USDC,
+                lb_pair: &lb_pair,
+                bin_arrays,
+                bitmap_extension: None,
+                mint_x_account: &mint_x_account,
+                mint_y_account: &mint_y_account,
+                oracle_account: &oracle_account,
+            },
             in_amount,
             true,
-            bin_arrays,
+            &clock,
             None,
-            clock.unix_timestamp as u64,
-            clock.slot,
         )
         .unwrap();
 
@@ -474,20 +987,29 @@ mod tests {
             })
             .collect::<HashMap<_, _>>();
 
+        let mint_x_account = rpc_client.get_account(&lb_pair.token_x_mint).await.unwrap();
+        let mint_y_account = rpc_client.get_account(&lb_pair.token_y_mint).await.unwrap();
+        let oracle_account = rpc_client.get_account(&lb_pair.oracle).await.unwrap();
+
         // 1 SOL -> USDC
         let in_sol_amount = 1_000_000_000;
 
         let clock = get_clock(rpc_client).await.unwrap();
 
         let quote_result = quote_exact_in(
-            SOL_USDC,
-            &lb_pair,
+            SwapQuoteAccounts {
+                lb_pair_pubkey: SOL_USDC,
+                lb_pair: &lb_pair,
+                bin_arrays: bin_arrays.clone(),
+                bitmap_extension: None,
+                mint_x_account: &mint_x_account,
+                mint_y_account: &mint_y_account,
+                oracle_account: &oracle_account,
+            },
             in_sol_amount,
             true,
-            bin_arrays.clone(),
+            &clock,
             None,
-            clock.unix_timestamp as u64,
-            clock.slot,
         )
         .unwrap();
 
@@ -500,14 +1022,19 @@ mod tests {
         let in_usdc_amount = 100_000_000;
 
         let quote_result = quote_exact_in(
-            SOL_USDC,
-            &lb_pair,
+            SwapQuoteAccounts {
+                lb_pair_pubkey: SOL_USDC,
+                lb_pair: &lb_pair,
+                bin_arrays: bin_arrays.clone(),
+                bitmap_extension: None,
+                mint_x_account: &mint_x_account,
+                mint_y_account: &mint_y_account,
+                oracle_account: &oracle_account,
+            },
             in_usdc_amount,
             false,
-            bin_arrays.clone(),
+            &clock,
             None,
-            clock.unix_timestamp as u64,
-            clock.slot,
         )
         .unwrap();
 
@@ -516,4 +1043,170 @@ mod tests {
             quote_result.amount_out as f64 / 1_000_000_000.0
         );
     }
+
+    #[test]
+    fn test_quote_timed_swaps() {
+        use anchor_client::anchor_lang::Discriminator;
+        use anchor_client::solana_sdk::program_pack::Pack;
+        use lb_clmm::constants::{HOST_FEE_BPS, MAX_BIN_PER_ARRAY};
+        use lb_clmm::state::oracle::{Observation, Oracle};
+
+        let lb_pair_pubkey = Pubkey::new_unique();
+        let mut lb_pair = LbPair {
+            bin_step: 10,
+            active_id: 69,
+            ..Default::default()
+        };
+        lb_pair.parameters.base_factor = 10_000;
+        lb_pair.parameters.filter_period = 30;
+        lb_pair.parameters.decay_period = 600;
+        lb_pair.parameters.reduction_factor = 5_000;
+        lb_pair.parameters.variable_fee_control = 40_000;
+        lb_pair.parameters.max_volatility_accumulator = 350_000;
+        lb_pair.parameters.protocol_share = 1_000;
+        lb_pair.flip_bin_array_bit(&None, 0).unwrap();
+
+        let mut bin_array = BinArray {
+            index: 0,
+            version: 1,
+            _padding: [0u8; 7],
+            lb_pair: lb_pair_pubkey,
+            bins: [Bin::default(); MAX_BIN_PER_ARRAY],
+        };
+        for bin in bin_array.bins.iter_mut() {
+            bin.amount_y = 1_000_000;
+            bin.liquidity_supply = 1_000_000;
+        }
+        let bin_arrays = HashMap::from([(derive_bin_array_pda(lb_pair_pubkey, 0).0, bin_array)]);
+
+        let mut mint_data = vec![0u8; anchor_spl::token::spl_token::state::Mint::LEN];
+        anchor_spl::token::spl_token::state::Mint {
+            is_initialized: true,
+            decimals: 6,
+            ..Default::default()
+        }
+        .pack_into_slice(&mut mint_data);
+        let mint_account = Account {
+            data: mint_data,
+            owner: anchor_spl::token::ID,
+            ..Default::default()
+        };
+
+        // Oracle sampled at bin 71 from 300 to 990, so the time weighted active bin of the window is 70
+        let mut oracle_data = vec![0u8; Oracle::space(2)];
+        oracle_data[..8].copy_from_slice(&Oracle::DISCRIMINATOR);
+        oracle_data[8..16].copy_from_slice(&1u64.to_le_bytes());
+        oracle_data[16..24].copy_from_slice(&2u64.to_le_bytes());
+        oracle_data[24..32].copy_from_slice(&2u64.to_le_bytes());
+        for (i, (cumulative_active_bin_id, created_at, last_updated_at)) in
+            [(0i128, 1i64, 300i64), (71 * 690, 300, 990)]
+                .into_iter()
+                .enumerate()
+        {
+            let offset = Oracle::metadata_len() + i * std::mem::size_of::<Observation>();
+            oracle_data[offset..offset + 16]
+                .copy_from_slice(&cumulative_active_bin_id.to_le_bytes());
+            oracle_data[offset + 16..offset + 24].copy_from_slice(&created_at.to_le_bytes());
+            oracle_data[offset + 24..offset + 32].copy_from_slice(&last_updated_at.to_le_bytes());
+        }
+        let oracle_account = Account {
+            data: oracle_data,
+            owner: lb_clmm::ID,
+            ..Default::default()
+        };
+
+        let clock = Clock {
+            unix_timestamp: 1_000,
+            ..Default::default()
+        };
+        let pair = |lb_pair| SwapQuoteAccounts {
+            lb_pair_pubkey,
+            lb_pair,
+            bin_arrays: bin_arrays.clone(),
+            bitmap_extension: None,
+            mint_x_account: &mint_account,
+            mint_y_account: &mint_account,
+            oracle_account: &oracle_account,
+        };
+        let swap = |timestamp| TimedSwap {
+            amount_in: 2_500_000,
+            swap_for_y: true,
+            timestamp,
+        };
+
+        // Within the filter period the bins crossed by the previous swap are charged, within the decay window the volatility of the
+        // previous swap is reduced by half, and fully decayed after it
+        let quotes = quote_timed_swaps(
+            pair(&lb_pair),
+            &[swap(1_000), swap(1_010), swap(1_070), swap(2_100)],
+            &clock,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(quotes.len(), 4);
+        assert_eq!(quotes[0].variable_fee_rate, 0);
+        assert!(quotes[0].volatility_accumulator > 0);
+        assert_eq!(
+            quotes[1].variable_fee_rate,
+            lb_pair
+                .compute_variable_fee(quotes[0].volatility_accumulator)
+                .unwrap()
+        );
+        assert_eq!(
+            quotes[2].variable_fee_rate,
+            lb_pair
+                .compute_variable_fee(quotes[1].volatility_accumulator / 2)
+                .unwrap()
+        );
+        assert_eq!(quotes[3].variable_fee_rate, 0);
+        assert!(quotes.iter().all(|q| !q.quote.would_trip_circuit_breaker));
+
+        // First swap match the quote at the clock
+        let quote = quote_exact_in(
+            SwapQuoteAccounts {
+                lb_pair_pubkey,
+                lb_pair: &lb_pair,
+                bin_arrays: bin_arrays.clone(),
+                bitmap_extension: None,
+                mint_x_account: &mint_account,
+                mint_y_account: &mint_account,
+                oracle_account: &oracle_account,
+            },
+            2_500_000,
+            true,
+            &clock,
+            Some(HOST_FEE_BPS),
+        )
+        .unwrap();
+        let quotes =
+            quote_timed_swaps(pair(&lb_pair), &[swap(1_000)], &clock, Some(HOST_FEE_BPS)).unwrap();
+        assert_eq!(quote.amount_out, quotes[0].quote.amount_out);
+        assert_eq!(quote.fee, quotes[0].quote.fee);
+        assert_eq!(quote.protocol_fee, quotes[0].quote.protocol_fee);
+        assert!(quotes[0].quote.host_fee > 0);
+        assert_eq!(quote.host_fee, quotes[0].quote.host_fee);
+
+        // Second swap move the active bin beyond 4 bins from the time weighted active bin, and halt the pair for the next swap
+        let mut guarded_lb_pair = lb_pair;
+        guarded_lb_pair.circuit_breaker.update(4, 600, 300).unwrap();
+        let quotes = quote_timed_swaps(
+            pair(&guarded_lb_pair),
+            &[swap(1_000), swap(1_010)],
+            &clock,
+            None,
+        )
+        .unwrap();
+        assert!(!quotes[0].quote.would_trip_circuit_breaker);
+        assert!(quotes[1].quote.would_trip_circuit_breaker);
+        assert!(quote_timed_swaps(
+            pair(&guarded_lb_pair),
+            &[swap(1_000), swap(1_010), swap(1_020)],
+            &clock,
+            None,
+        )
+        .is_err());
+
+        assert!(quote_timed_swaps(pair(&lb_pair), &[swap(999)], &clock, None).is_err());
+    }
 }
@@ -0,0 +1,253 @@
+use crate::decode::{decode_bin_array, decode_lb_pair, decode_zero_copy};
+use crate::quote::{
+    get_bin_array_pubkeys_for_swap, quote_exact_in, quote_exact_out, SwapExactInQuote,
+    SwapExactOutQuote, SwapQuoteAccounts,
+};
+use anyhow::{Context, Result};
+use lb_clmm::state::{
+    bin::BinArray, bin_array_bitmap_extension::BinArrayBitmapExtension, lb_pair::LbPair,
+};
+use solana_sdk::{account::Account, clock::Clock, pubkey::Pubkey};
+use std::collections::HashMap;
+
+/// Quote engine of a pair, fed with the raw data of the pair accounts. The mints, oracle and clock must be set before quoting.
+#[derive(Debug, Clone)]
+pub struct QuoteEngine {
+    lb_pair_pubkey: Pubkey,
+    lb_pair: LbPair,
+    bitmap_extension: Option<BinArrayBitmapExtension>,
+    bin_arrays: HashMap<Pubkey, BinArray>,
+    mint_x_account: Option<Account>,
+    mint_y_account: Option<Account>,
+    oracle_account: Option<Account>,
+    clock: Option<Clock>,
+}
+
+impl QuoteEngine {
+    pub fn new(lb_pair_pubkey: Pubkey, lb_pair_data: &[u8]) -> Result<Self> {
+        Ok(Self {
+            lb_pair_pubkey,
+            lb_pair: decode_lb_pair(lb_pair_data)?,
+            bitmap_extension: None,
+            bin_arrays: HashMap::new(),
+            mint_x_account: None,
+            mint_y_account: None,
+            oracle_account: None,
+            clock: None,
+        })
+    }
+
+    pub fn lb_pair(&self) -> &LbPair {
+        &self.lb_pair
+    }
+
+    /// Replace the pair state, keeping the other accounts
+    pub fn set_lb_pair(&mut self, lb_pair_data: &[u8]) -> Result<()> {
+        self.lb_pair = decode_lb_pair(lb_pair_data)?;
+        Ok(())
+    }
+
+    pub fn set_bitmap_extension(&mut self, bitmap_extension_data: &[u8]) -> Result<()> {
+        self.bitmap_extension = Some(decode_zero_copy(bitmap_extension_data)?);
+        Ok(())
+    }
+
+    pub fn set_bin_array(&mut self, bin_array_pubkey: Pubkey, bin_array_data: &[u8]) -> Result<()> {
+        self.bin_arrays
+            .insert(bin_array_pubkey, decode_bin_array(bin_array_data)?);
+        Ok(())
+    }
+
+    /// Token mint accounts of the pair, used to account for token 2022 transfer fee
+    pub fn set_mints(&mut self, mint_x_data: Vec<u8>, mint_y_data: Vec<u8>) {
+        self.mint_x_account = Some(Account {
+            data: mint_x_data,
+            ..Default::default()
+        });
+        self.mint_y_account = Some(Account {
+            data: mint_y_data,
+            ..Default::default()
+        });
+    }
+
+    /// Oracle account of the pair, used to evaluate the circuit breaker
+    pub fn set_oracle(&mut self, oracle_data: Vec<u8>) {
+        self.oracle_account = Some(Account {
+            data: oracle_data,
+            ..Default::default()
+        });
+    }
+
+    pub fn set_clock(&mut self, clock: Clock) {
+        self.clock = Some(clock);
+    }
+
+    /// Bin arrays with liquidity in the direction of the swap, to be passed to `set_bin_array` before quoting
+    pub fn get_bin_array_pubkeys_for_swap(
+        &self,
+        swap_for_y: bool,
+        take_count: u8,
+    ) -> Result<Vec<Pubkey>> {
+        get_bin_array_pubkeys_for_swap(
+            self.lb_pair_pubkey,
+            &self.lb_pair,
+            self.bitmap_extension.as_ref(),
+            swap_for_y,
+            take_count,
+        )
+    }
+
+    fn loaded_accounts(&self) -> Result<(&Account, &Account, &Account, &Clock)> {
+        Ok((
+            self.mint_x_account.as_ref().context("Mints not set")?,
+            self.mint_y_account.as_ref().context("Mints not set")?,
+            self.oracle_account.as_ref().context("Oracle not set")?,
+            self.clock.as_ref().context("Clock not set")?,
+        ))
+    }
+
+    /// Quote for the user to send exactly `amount_in`. `host_fee_bps` is the share of the protocol fee given to the host.
+    pub fn quote_exact_in(
+        &self,
+        amount_in: u64,
+        swap_for_y: bool,
+        host_fee_bps: Option<u16>,
+    ) -> Result<SwapExactInQuote> {
+        let (mint_x_account, mint_y_account, oracle_account, clock) = self.loaded_accounts()?;
+
+        quote_exact_in(
+            SwapQuoteAccounts {
+                lb_pair_pubkey: self.lb_pair_pubkey,
+                lb_pair: &self.lb_pair,
+                bin_arrays: self.bin_arrays.clone(),
+                bitmap_extension: self.bitmap_extension.as_ref(),
+                mint_x_account,
+                mint_y_account,
+                oracle_account,
+            },
+            amount_in,
+            swap_for_y,
+            clock,
+            host_fee_bps,
+        )
+    }
+
+    /// Quote for the user to receive exactly `amount_out`. `host_fee_bps` is the share of the protocol fee given to the host.
+    pub fn quote_exact_out(
+        &self,
+        amount_out: u64,
+        swap_for_y: bool,
+        host_fee_bps: Option<u16>,
+    ) -> Result<SwapExactOutQuote> {
+        let (mint_x_account, mint_y_account, oracle_account, clock) = self.loaded_accounts()?;
+
+        quote_exact_out(
+            SwapQuoteAccounts {
+                lb_pair_pubkey: self.lb_pair_pubkey,
+                lb_pair: &self.lb_pair,
+                bin_arrays: self.bin_arrays.clone(),
+                bitmap_extension: self.bitmap_extension.as_ref(),
+                mint_x_account,
+                mint_y_account,
+                oracle_account,
+            },
+            amount_out,
+            swap_for_y,
+            clock,
+            host_fee_bps,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::Discriminator;
+    use lb_clmm::{
+        constants::MAX_BIN_PER_ARRAY, state::bin::Bin, utils::pda::derive_bin_array_pda,
+    };
+    use solana_sdk::program_pack::Pack;
+
+    fn account_data<T: Discriminator + bytemuck::Pod>(state: &T) -> Vec<u8> {
+        let mut data = T::discriminator().to_vec();
+        data.extend_from_slice(bytemuck::bytes_of(state));
+        data
+    }
+
+    #[test]
+    fn test_quote_engine_from_account_data() {
+        let lb_pair_pubkey = Pubkey::new_unique();
+        let mut lb_pair = LbPair {
+            bin_step: 10,
+            active_id: 69,
+            ..Default::default()
+        };
+        lb_pair.parameters.base_factor = 10_000;
+        lb_pair.flip_bin_array_bit(&None, 0).unwrap();
+
+        let mut bin_array = BinArray {
+            index: 0,
+            version: 1,
+            _padding: [0u8; 7],
+            lb_pair: lb_pair_pubkey,
+            bins: [Bin::default(); MAX_BIN_PER_ARRAY],
+        };
+        for bin in bin_array.bins.iter_mut() {
+            bin.amount_y = 1_000_000;
+            bin.liquidity_supply = 1_000_000;
+        }
+        let bin_array_pubkey = derive_bin_array_pda(lb_pair_pubkey, 0).0;
+
+        let mut mint_data = vec![0u8; anchor_spl::token::spl_token::state::Mint::LEN];
+        anchor_spl::token::spl_token::state::Mint {
+            is_initialized: true,
+            decimals: 6,
+            ..Default::default()
+        }
+        .pack_into_slice(&mut mint_data);
+
+        let mut engine = QuoteEngine::new(lb_pair_pubkey, &account_data(&lb_pair)).unwrap();
+        assert!(engine.quote_exact_in(1_000_000, true, None).is_err());
+
+        engine
+            .set_bin_array(bin_array_pubkey, &account_data(&bin_array))
+            .unwrap();
+        engine.set_mints(mint_data.clone(), mint_data.clone());
+        engine.set_oracle(vec![]);
+        engine.set_clock(Clock {
+            unix_timestamp: 1_000,
+            ..Default::default()
+        });
+
+        let quote = engine.quote_exact_in(2_500_000, true, None).unwrap();
+
+        let mint_account = Account {
+            data: mint_data,
+            ..Default::default()
+        };
+        let expected = quote_exact_in(
+            SwapQuoteAccounts {
+                lb_pair_pubkey,
+                lb_pair: &lb_pair,
+                bin_arrays: HashMap::from([(bin_array_pubkey, bin_array)]),
+                bitmap_extension: None,
+                mint_x_account: &mint_account,
+                mint_y_account: &mint_account,
+                oracle_account: &Account::default(),
+            },
+            2_500_000,
+            true,
+            &Clock {
+                unix_timestamp: 1_000,
+                ..Default::default()
+            },
+            None,
+        )
+        .unwrap();
+
+        assert!(quote.amount_out > 0);
+        assert_eq!(quote.amount_out, expected.amount_out);
+        assert_eq!(quote.fee, expected.fee);
+        assert_eq!(quote.bin_arrays, vec![bin_array_pubkey]);
+    }
+}
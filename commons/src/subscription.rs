@@ -0,0 +1,607 @@
+use crate::decode::decode_zero_copy;
+use crate::fetcher::{AccountRpc, MAX_MULTIPLE_ACCOUNTS};
+use crate::quote::get_bin_array_pubkeys_for_swap;
+use anchor_client::anchor_lang::Discriminator;
+use anchor_client::solana_client::{
+    nonblocking::pubsub_client::PubsubClient,
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    rpc_filter::{Memcmp, RpcFilterType},
+};
+use anchor_client::solana_sdk::{
+    account::Account, commitment_config::CommitmentConfig, pubkey::Pubkey,
+};
+use anyhow::Result;
+use futures::StreamExt;
+use lb_clmm::{
+    state::{bin::BinArray, bin_array_bitmap_extension::BinArrayBitmapExtension, lb_pair::LbPair},
+    utils::pda::derive_bin_array_bitmap_extension,
+};
+use solana_account_decoder::UiAccountEncoding;
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use tokio::sync::{
+    mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender},
+    oneshot,
+};
+use tokio::task::JoinHandle;
+
+/// Offset of `lb_pair` in the bin array account data
+const BIN_ARRAY_LB_PAIR_OFFSET: usize = 8 + 8 + 1 + 7;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PairUpdate {
+    /// Active bin of the pair moved, by swap or by `go_to_a_bin`
+    ActiveBinMoved {
+        lb_pair: Pubkey,
+        from_active_id: i32,
+        to_active_id: i32,
+    },
+    /// Amounts or liquidity supply of bins of the pair changed, by swap, deposit or withdraw
+    LiquidityChanged {
+        lb_pair: Pubkey,
+        bin_array: Pubkey,
+        bin_ids: Vec<i32>,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct PairState {
+    pub lb_pair: LbPair,
+    pub bin_arrays: HashMap<Pubkey, BinArray>,
+}
+
+/// Latest state of a set of pairs, updated from account notifications
+#[derive(Default)]
+pub struct PairStates {
+    pairs: HashMap<Pubkey, Option<PairState>>,
+    /// Slot of the latest update of each account, older updates are discarded
+    slots: HashMap<Pubkey, u64>,
+    /// Latest bin array notified before the initial state of its pair is loaded, and its slot. Replayed once the pair is loaded.
+    pending_bin_arrays: HashMap<Pubkey, (u64, BinArray)>,
+}
+
+impl PairStates {
+    /// Track the pair. Updates of untracked pairs are ignored.
+    pub fn track(&mut self, lb_pair: Pubkey) {
+        self.pairs.entry(lb_pair).or_default();
+    }
+
+    pub fn get(&self, lb_pair: &Pubkey) -> Option<&PairState> {
+        self.pairs.get(lb_pair).and_then(|state| state.as_ref())
+    }
+
+    /// Apply the account data read at `slot`, returning the changes. Accounts other than the pairs tracked and their bin arrays are ignored.
+    pub fn apply_account(
+        &mut self,
+        pubkey: Pubkey,
+        slot: u64,
+        data: &[u8],
+    ) -> Result<Vec<PairUpdate>> {
+        if data.len() < 8 {
+            return Ok(vec![]);
+        }
+
+        let discriminator = &data[..8];
+        if discriminator == LbPair::discriminator() {
+            if !self.pairs.contains_key(&pubkey) || !is_newer(&self.slots, pubkey, slot) {
+                return Ok(vec![]);
+            }
+            let lb_pair: LbPair = decode_zero_copy(data)?;
+            self.slots.insert(pubkey, slot);
+
+            self.apply_lb_pair(pubkey, slot, lb_pair)
+        } else if discriminator == BinArray::discriminator() {
+            let bin_array: BinArray = decode_zero_copy(data)?;
+            match self.pairs.get(&bin_array.lb_pair) {
+                Some(Some(_)) => self.apply_bin_array(pubkey, slot, bin_array),
+                // Notified before the initial state of the pair is loaded
+                Some(None) => {
+                    let pending_slot = self
+                        .pending_bin_arrays
+                        .get(&pubkey)
+                        .map(|(pending_slot, _)| *pending_slot)
+                        .unwrap_or_default();
+                    if pending_slot <= slot {
+                        self.pending_bin_arrays.insert(pubkey, (slot, bin_array));
+                    }
+                    Ok(vec![])
+                }
+                None => Ok(vec![]),
+            }
+        } else {
+            Ok(vec![])
+        }
+    }
+
+    fn apply_lb_pair(
+        &mut self,
+        pubkey: Pubkey,
+        slot: u64,
+        lb_pair: LbPair,
+    ) -> Result<Vec<PairUpdate>> {
+        let state = self.pairs.entry(pubkey).or_default();
+
+        match state {
+            Some(state) => {
+                let from_active_id = state.lb_pair.active_id;
+                state.lb_pair = lb_pair;
+                if from_active_id != lb_pair.active_id {
+                    Ok(vec![PairUpdate::ActiveBinMoved {
+                        lb_pair: pubkey,
+                        from_active_id,
+                        to_active_id: lb_pair.active_id,
+                    }])
+                } else {
+                    Ok(vec![])
+                }
+            }
+            None => {
+                *state = Some(PairState {
+                    lb_pair,
+                    bin_arrays: HashMap::new(),
+                });
+
+                // Replay the bin arrays notified while loading, the ones not newer than the pair are covered by the initial load
+                let pending_bin_arrays: Vec<(Pubkey, u64, BinArray)> = self
+                    .pending_bin_arrays
+                    .iter()
+                    .filter(|(_, (_, bin_array))| bin_array.lb_pair == pubkey)
+                    .map(|(bin_array_pubkey, (slot, bin_array))| {
+                        (*bin_array_pubkey, *slot, *bin_array)
+                    })
+                    .collect();
+
+                let mut updates = vec![];
+                for (bin_array_pubkey, pending_slot, bin_array) in pending_bin_arrays {
+                    self.pending_bin_arrays.remove(&bin_array_pubkey);
+                    if pending_slot > slot {
+                        updates.extend(self.apply_bin_array(
+                            bin_array_pubkey,
+                            pending_slot,
+                            bin_array,
+                        )?);
+                    }
+                }
+                Ok(updates)
+            }
+        }
+    }
+
+    fn apply_bin_array(
+        &mut self,
+        pubkey: Pubkey,
+        slot: u64,
+        bin_array: BinArray,
+    ) -> Result<Vec<PairUpdate>> {
+        let Some(Some(state)) = self.pairs.get_mut(&bin_array.lb_pair) else {
+            return Ok(vec![]);
+        };
+        if !is_newer(&self.slots, pubkey, slot) {
+            return Ok(vec![]);
+        }
+        self.slots.insert(pubkey, slot);
+
+        let bin_ids = get_changed_bin_ids(state.bin_arrays.get(&pubkey), &bin_array)?;
+        state.bin_arrays.insert(pubkey, bin_array);
+
+        if bin_ids.is_empty() {
+            Ok(vec![])
+        } else {
+            Ok(vec![PairUpdate::LiquidityChanged {
+                lb_pair: bin_array.lb_pair,
+                bin_array: pubkey,
+                bin_ids,
+            }])
+        }
+    }
+}
+
+fn is_newer(slots: &HashMap<Pubkey, u64>, pubkey: Pubkey, slot: u64) -> bool {
+    slots.get(&pubkey).copied().unwrap_or_default() <= slot
+}
+
+/// Bins of `bin_array` whose amounts or liquidity supply differ from `previous`. Every bin with liquidity when there's no previous state.
+fn get_changed_bin_ids(previous: Option<&BinArray>, bin_array: &BinArray) -> Result<Vec<i32>> {
+    let (lower_bin_id, _upper_bin_id) =
+        BinArray::get_bin_array_lower_upper_bin_id(bin_array.index as i32)?;
+
+    Ok(bin_array
+        .bins
+        .iter()
+        .enumerate()
+        .filter(|(i, bin)| match previous {
+            Some(previous) => {
+                let previous = &previous.bins[*i];
+                previous.amount_x != bin.amount_x
+                    || previous.amount_y != bin.amount_y
+                    || previous.liquidity_supply != bin.liquidity_supply
+            }
+            None => bin.liquidity_supply > 0,
+        })
+        .map(|(i, _)| lower_bin_id + i as i32)
+        .collect())
+}
+
+/// Subscription to a set of pairs. The tasks listening to the notifications are aborted on drop.
+pub struct PairSubscription {
+    pub states: Arc<Mutex<PairStates>>,
+    handles: Vec<JoinHandle<()>>,
+}
+
+impl Drop for PairSubscription {
+    fn drop(&mut self) {
+        for handle in self.handles.iter() {
+            handle.abort();
+        }
+    }
+}
+
+fn apply_and_notify(
+    states: &Mutex<PairStates>,
+    sender: &UnboundedSender<PairUpdate>,
+    pubkey: Pubkey,
+    slot: u64,
+    account: Option<Account>,
+) {
+    let Some(account) = account else {
+        return;
+    };
+    let Ok(updates) = states
+        .lock()
+        .unwrap()
+        .apply_account(pubkey, slot, &account.data)
+    else {
+        return;
+    };
+    for update in updates {
+        let _ = sender.send(update);
+    }
+}
+
+#[derive(Clone)]
+struct ListenerContext {
+    client: Arc<PubsubClient>,
+    states: Arc<Mutex<PairStates>>,
+    sender: UnboundedSender<PairUpdate>,
+    commitment: CommitmentConfig,
+}
+
+/// Apply the notifications of the pair account until the stream end. `ready` is resolved once subscribed.
+async fn listen_lb_pair(
+    context: ListenerContext,
+    lb_pair: Pubkey,
+    ready: oneshot::Sender<Result<()>>,
+) {
+    let subscription = context
+        .client
+        .account_subscribe(&lb_pair, Some(account_config(context.commitment)))
+        .await;
+    let (mut stream, _unsubscribe) = match subscription {
+        Ok(subscription) => subscription,
+        Err(err) => {
+            let _ = ready.send(Err(err.into()));
+            return;
+        }
+    };
+    let _ = ready.send(Ok(()));
+
+    while let Some(response) = stream.next().await {
+        apply_and_notify(
+            &context.states,
+            &context.sender,
+            lb_pair,
+            response.context.slot,
+            response.value.decode::<Account>(),
+        );
+    }
+}
+
+/// Apply the notifications of the bin arrays of the pair until the stream end. `ready` is resolved once subscribed.
+async fn listen_bin_arrays(
+    context: ListenerContext,
+    lb_pair: Pubkey,
+    ready: oneshot::Sender<Result<()>>,
+) {
+    let config = RpcProgramAccountsConfig {
+        filters: Some(vec![
+            RpcFilterType::Memcmp(Memcmp::new_raw_bytes(0, BinArray::discriminator().to_vec())),
+            RpcFilterType::Memcmp(Memcmp::new_raw_bytes(
+                BIN_ARRAY_LB_PAIR_OFFSET,
+                lb_pair.to_bytes().to_vec(),
+            )),
+        ]),
+        account_config: account_config(context.commitment),
+        with_context: Some(true),
+    };
+    let subscription = context
+        .client
+        .program_subscribe(&lb_clmm::ID, Some(config))
+        .await;
+    let (mut stream, _unsubscribe) = match subscription {
+        Ok(subscription) => subscription,
+        Err(err) => {
+            let _ = ready.send(Err(err.into()));
+            return;
+        }
+    };
+    let _ = ready.send(Ok(()));
+
+    while let Some(response) = stream.next().await {
+        let Ok(pubkey) = Pubkey::from_str(&response.value.pubkey) else {
+            continue;
+        };
+        apply_and_notify(
+            &context.states,
+            &context.sender,
+            pubkey,
+            response.context.slot,
+            response.value.account.decode::<Account>(),
+        );
+    }
+}
+
+fn account_config(commitment: CommitmentConfig) -> RpcAccountInfoConfig {
+    RpcAccountInfoConfig {
+        encoding: Some(UiAccountEncoding::Base64),
+        commitment: Some(commitment),
+        ..Default::default()
+    }
+}
+
+/// Subscribe to the pairs, and their bin arrays, over the websocket `ws_url` with `accountSubscribe` and `programSubscribe`. The pairs and
+/// up to `bin_array_count` bin arrays with liquidity on each side of the active bin are loaded from `rpc` after subscribing, so no update is missed.
+/// The receiver yield the changes of the pairs, and is closed when every subscription stream ended.
+pub async fn subscribe_pairs<R: AccountRpc>(
+    ws_url: &str,
+    rpc: &R,
+    lb_pairs: &[Pubkey],
+    bin_array_count: u8,
+    commitment: CommitmentConfig,
+) -> Result<(PairSubscription, UnboundedReceiver<PairUpdate>)> {
+    let client = Arc::new(PubsubClient::new(ws_url).await?);
+    let states = Arc::new(Mutex::new(PairStates::default()));
+    let (sender, receiver) = unbounded_channel();
+    let mut handles = vec![];
+
+    let mut ready_receivers = vec![];
+    for &lb_pair in lb_pairs {
+        states.lock().unwrap().track(lb_pair);
+
+        let context = ListenerContext {
+            client: client.clone(),
+            states: states.clone(),
+            sender: sender.clone(),
+            commitment,
+        };
+        let (ready_sender, ready_receiver) = oneshot::channel();
+        handles.push(tokio::spawn(listen_lb_pair(
+            context.clone(),
+            lb_pair,
+            ready_sender,
+        )));
+        ready_receivers.push(ready_receiver);
+
+        let (ready_sender, ready_receiver) = oneshot::channel();
+        handles.push(tokio::spawn(listen_bin_arrays(
+            context,
+            lb_pair,
+            ready_sender,
+        )));
+        ready_receivers.push(ready_receiver);
+    }
+
+    let subscription = PairSubscription {
+        states: states.clone(),
+        handles,
+    };
+    for ready_receiver in ready_receivers {
+        ready_receiver.await??;
+    }
+
+    // Initial state, not notified
+    let mut pubkeys = lb_pairs.to_vec();
+    pubkeys.extend(
+        lb_pairs
+            .iter()
+            .map(|&lb_pair| derive_bin_array_bitmap_extension(lb_pair).0),
+    );
+    let (slot, accounts) = get_multiple_accounts(rpc, &pubkeys).await?;
+    let (lb_pair_accounts, bitmap_extension_accounts) = accounts.split_at(lb_pairs.len());
+
+    let mut bin_array_pubkeys = vec![];
+    for ((&lb_pair_pubkey, lb_pair_account), bitmap_extension_account) in lb_pairs
+        .iter()
+        .zip(lb_pair_accounts)
+        .zip(bitmap_extension_accounts)
+    {
+        let Some(lb_pair_account) = lb_pair_account else {
+            anyhow::bail!("Pair {} not found", lb_pair_pubkey);
+        };
+        let lb_pair: LbPair = decode_zero_copy(&lb_pair_account.data)?;
+        let bitmap_extension: Option<BinArrayBitmapExtension> = bitmap_extension_account
+            .as_ref()
+            .map(|account| decode_zero_copy(&account.data))
+            .transpose()?;
+
+        for swap_for_y in [true, false] {
+            bin_array_pubkeys.extend(get_bin_array_pubkeys_for_swap(
+                lb_pair_pubkey,
+                &lb_pair,
+                bitmap_extension.as_ref(),
+                swap_for_y,
+                bin_array_count,
+            )?);
+        }
+
+        states
+            .lock()
+            .unwrap()
+            .apply_account(lb_pair_pubkey, slot, &lb_pair_account.data)?;
+    }
+
+    bin_array_pubkeys.sort();
+    bin_array_pubkeys.dedup();
+    let (slot, accounts) = get_multiple_accounts(rpc, &bin_array_pubkeys).await?;
+    for (pubkey, account) in bin_array_pubkeys.into_iter().zip(accounts) {
+        if let Some(account) = account {
+            states
+                .lock()
+                .unwrap()
+                .apply_account(pubkey, slot, &account.data)?;
+        }
+    }
+
+    Ok((subscription, receiver))
+}
+
+/// Accounts of `pubkeys`, by batch of `MAX_MULTIPLE_ACCOUNTS`, and the oldest slot at which they were read
+async fn get_multiple_accounts<R: AccountRpc>(
+    rpc: &R,
+    pubkeys: &[Pubkey],
+) -> Result<(u64, Vec<Option<Account>>)> {
+    let mut min_slot: Option<u64> = None;
+    let mut accounts = Vec::with_capacity(pubkeys.len());
+
+    for chunk in pubkeys.chunks(MAX_MULTIPLE_ACCOUNTS) {
+        let (slot, chunk_accounts) = rpc.get_multiple_accounts(chunk).await?;
+        min_slot = Some(min_slot.map_or(slot, |min_slot| min_slot.min(slot)));
+        accounts.extend(chunk_accounts);
+    }
+
+    Ok((min_slot.unwrap_or_default(), accounts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lb_clmm::{constants::MAX_BIN_PER_ARRAY, state::bin::Bin};
+
+    fn account_data<T: Discriminator + bytemuck::Pod>(state: &T) -> Vec<u8> {
+        let mut data = T::discriminator().to_vec();
+        data.extend_from_slice(bytemuck::bytes_of(state));
+        data
+    }
+
+    #[test]
+    fn test_apply_account() {
+        let lb_pair_pubkey = Pubkey::new_unique();
+        let bin_array_pubkey = Pubkey::new_unique();
+        let mut lb_pair = LbPair::default();
+        let mut bin_array = BinArray {
+            index: 0,
+            version: 1,
+            _padding: [0u8; 7],
+            lb_pair: lb_pair_pubkey,
+            bins: [Bin::default(); MAX_BIN_PER_ARRAY],
+        };
+
+        let mut states = PairStates::default();
+
+        // Untracked pair
+        assert!(states
+            .apply_account(lb_pair_pubkey, 1, &account_data(&lb_pair))
+            .unwrap()
+            .is_empty());
+        assert!(states.get(&lb_pair_pubkey).is_none());
+
+        states.track(lb_pair_pubkey);
+        states
+            .apply_account(lb_pair_pubkey, 1, &account_data(&lb_pair))
+            .unwrap();
+        states
+            .apply_account(bin_array_pubkey, 1, &account_data(&bin_array))
+            .unwrap();
+
+        lb_pair.active_id = 5;
+        assert_eq!(
+            states
+                .apply_account(lb_pair_pubkey, 2, &account_data(&lb_pair))
+                .unwrap(),
+            vec![PairUpdate::ActiveBinMoved {
+                lb_pair: lb_pair_pubkey,
+                from_active_id: 0,
+                to_active_id: 5,
+            }]
+        );
+
+        bin_array.bins[3].amount_x = 100;
+        bin_array.bins[3].liquidity_supply = 100;
+        assert_eq!(
+            states
+                .apply_account(bin_array_pubkey, 2, &account_data(&bin_array))
+                .unwrap(),
+            vec![PairUpdate::LiquidityChanged {
+                lb_pair: lb_pair_pubkey,
+                bin_array: bin_array_pubkey,
+                bin_ids: vec![3],
+            }]
+        );
+
+        // Outdated update is discarded
+        lb_pair.active_id = 0;
+        assert!(states
+            .apply_account(lb_pair_pubkey, 1, &account_data(&lb_pair))
+            .unwrap()
+            .is_empty());
+        assert_eq!(states.get(&lb_pair_pubkey).unwrap().lb_pair.active_id, 5);
+    }
+
+    #[test]
+    fn test_apply_bin_array_before_initial_load() {
+        let lb_pair_pubkey = Pubkey::new_unique();
+        let lb_pair = LbPair::default();
+        let mut bin_array = BinArray {
+            index: 0,
+            version: 1,
+            _padding: [0u8; 7],
+            lb_pair: lb_pair_pubkey,
+            bins: [Bin::default(); MAX_BIN_PER_ARRAY],
+        };
+        let outdated_bin_array_pubkey = Pubkey::new_unique();
+        let bin_array_pubkey = Pubkey::new_unique();
+
+        let mut states = PairStates::default();
+        states.track(lb_pair_pubkey);
+
+        // Notified before the pair is loaded at slot 5
+        states
+            .apply_account(outdated_bin_array_pubkey, 4, &account_data(&bin_array))
+            .unwrap();
+        bin_array.bins[1].liquidity_supply = 100;
+        states
+            .apply_account(bin_array_pubkey, 7, &account_data(&bin_array))
+            .unwrap();
+        bin_array.bins[2].liquidity_supply = 100;
+        assert!(states
+            .apply_account(bin_array_pubkey, 6, &account_data(&bin_array))
+            .unwrap()
+            .is_empty());
+
+        // Only the latest notification newer than the pair is replayed
+        assert_eq!(
+            states
+                .apply_account(lb_pair_pubkey, 5, &account_data(&lb_pair))
+                .unwrap(),
+            vec![PairUpdate::LiquidityChanged {
+                lb_pair: lb_pair_pubkey,
+                bin_array: bin_array_pubkey,
+                bin_ids: vec![1],
+            }]
+        );
+        let state = states.get(&lb_pair_pubkey).unwrap();
+        assert_eq!(state.bin_arrays.len(), 1);
+        assert!(state.bin_arrays.contains_key(&bin_array_pubkey));
+
+        // Initial load of the bin array older than the replayed notification is discarded
+        assert!(states
+            .apply_account(bin_array_pubkey, 5, &account_data(&bin_array))
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            states.get(&lb_pair_pubkey).unwrap().bin_arrays[&bin_array_pubkey].bins[2]
+                .liquidity_supply,
+            0
+        );
+    }
+}
@@ -0,0 +1,71 @@
+use anchor_client::solana_client::nonblocking::rpc_client::RpcClient;
+use anchor_client::solana_sdk::{account::Account, instruction::AccountMeta, pubkey::Pubkey};
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{transfer_hook, StateWithExtensions},
+    offchain::AccountFetchError,
+    state::Mint,
+};
+use anyhow::{anyhow, Result};
+
+/// Token program of the mint. SPL token or token 2022.
+pub fn get_token_program(mint_account: &Account) -> Pubkey {
+    mint_account.owner
+}
+
+pub fn has_transfer_hook(mint_account: &Account) -> Result<bool> {
+    if mint_account.owner != spl_token_2022::ID {
+        return Ok(false);
+    }
+
+    let mint = StateWithExtensions::<Mint>::unpack(&mint_account.data)?;
+    Ok(transfer_hook::get_program_id(&mint).is_some())
+}
+
+/// Get the extra accounts required by the transfer hook of the mint, for transferring `amount` from `source` to `destination`.
+/// The accounts must be passed as remaining accounts, after the bin arrays for swap. Empty if the mint has no transfer hook.
+pub async fn get_transfer_hook_account_metas(
+    rpc_client: &RpcClient,
+    mint: Pubkey,
+    mint_account: &Account,
+    source: Pubkey,
+    destination: Pubkey,
+    authority: Pubkey,
+    amount: u64,
+) -> Result<Vec<AccountMeta>> {
+    if !has_transfer_hook(mint_account)? {
+        return Ok(vec![]);
+    }
+
+    let decimals = StateWithExtensions::<Mint>::unpack(&mint_account.data)?
+        .base
+        .decimals;
+
+    let mut transfer_ix = spl_token_2022::instruction::transfer_checked(
+        &spl_token_2022::ID,
+        &source,
+        &mint,
+        &destination,
+        &authority,
+        &[],
+        amount,
+        decimals,
+    )?;
+
+    spl_token_2022::offchain::resolve_extra_transfer_account_metas(
+        &mut transfer_ix,
+        |address| async move {
+            rpc_client
+                .get_account_with_commitment(&address, rpc_client.commitment())
+                .await
+                .map(|response| response.value.map(|account| account.data))
+                .map_err(|e| Box::new(e) as AccountFetchError)
+        },
+        &mint,
+    )
+    .await
+    .map_err(|e| anyhow!("Failed to resolve transfer hook accounts: {}", e))?;
+
+    // Skip source, mint, destination and authority
+    Ok(transfer_ix.accounts.split_off(4))
+}
@@ -0,0 +1,181 @@
+use crate::quote::{SwapExactInQuote, SwapExactOutQuote};
+use crate::quote_engine::QuoteEngine;
+use anyhow::{Context, Result};
+use lb_clmm::math::price_math::get_price_from_id;
+use solana_sdk::{clock::Clock, pubkey::Pubkey};
+use std::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+fn to_js_error(err: anyhow::Error) -> JsError {
+    JsError::new(&format!("{err:#}"))
+}
+
+fn parse_pubkey(pubkey: &str) -> Result<Pubkey> {
+    Pubkey::from_str(pubkey).with_context(|| format!("Invalid pubkey {pubkey}"))
+}
+
+/// Q64.64 price of `bin_id`, as a decimal string
+#[wasm_bindgen(js_name = getPriceFromId)]
+pub fn js_get_price_from_id(bin_id: i32, bin_step: u16) -> Result<String, JsError> {
+    let price = get_price_from_id(bin_id, bin_step).map_err(|err| to_js_error(err.into()))?;
+    Ok(price.to_string())
+}
+
+#[wasm_bindgen(js_name = SwapExactInQuote, getter_with_clone)]
+pub struct JsSwapExactInQuote {
+    /// Amount received by the user, excluding the transfer fee of token out
+    pub amount_out: u64,
+    pub fee: u64,
+    pub transfer_fee_in: u64,
+    pub transfer_fee_out: u64,
+    pub protocol_fee: u64,
+    pub host_fee: u64,
+    pub would_trip_circuit_breaker: bool,
+    /// Bin arrays traversed by the swap, in order
+    pub bin_arrays: Vec<String>,
+}
+
+impl From<SwapExactInQuote> for JsSwapExactInQuote {
+    fn from(quote: SwapExactInQuote) -> Self {
+        Self {
+            amount_out: quote.amount_out,
+            fee: quote.fee,
+            transfer_fee_in: quote.transfer_fee_in,
+            transfer_fee_out: quote.transfer_fee_out,
+            protocol_fee: quote.protocol_fee,
+            host_fee: quote.host_fee,
+            would_trip_circuit_breaker: quote.would_trip_circuit_breaker,
+            bin_arrays: quote.bin_arrays.iter().map(Pubkey::to_string).collect(),
+        }
+    }
+}
+
+#[wasm_bindgen(js_name = SwapExactOutQuote)]
+pub struct JsSwapExactOutQuote {
+    /// Amount to be sent by the user, excluding the swap fee but including the transfer fee of token in
+    pub amount_in: u64,
+    pub fee: u64,
+    pub transfer_fee_in: u64,
+    pub transfer_fee_out: u64,
+    pub protocol_fee: u64,
+    pub host_fee: u64,
+    pub would_trip_circuit_breaker: bool,
+}
+
+impl From<SwapExactOutQuote> for JsSwapExactOutQuote {
+    fn from(quote: SwapExactOutQuote) -> Self {
+        Self {
+            amount_in: quote.amount_in,
+            fee: quote.fee,
+            transfer_fee_in: quote.transfer_fee_in,
+            transfer_fee_out: quote.transfer_fee_out,
+            protocol_fee: quote.protocol_fee,
+            host_fee: quote.host_fee,
+            would_trip_circuit_breaker: quote.would_trip_circuit_breaker,
+        }
+    }
+}
+
+/// Quote engine of a pair, fed with the raw data of the pair accounts. The mints, oracle and clock must be set before quoting.
+#[wasm_bindgen(js_name = QuoteEngine)]
+pub struct JsQuoteEngine(QuoteEngine);
+
+#[wasm_bindgen(js_class = QuoteEngine)]
+impl JsQuoteEngine {
+    #[wasm_bindgen(constructor)]
+    pub fn new(lb_pair_pubkey: &str, lb_pair_data: &[u8]) -> Result<JsQuoteEngine, JsError> {
+        let lb_pair_pubkey = parse_pubkey(lb_pair_pubkey).map_err(to_js_error)?;
+        let engine = QuoteEngine::new(lb_pair_pubkey, lb_pair_data).map_err(to_js_error)?;
+        Ok(Self(engine))
+    }
+
+    /// Replace the pair state, keeping the other accounts
+    #[wasm_bindgen(js_name = setLbPair)]
+    pub fn set_lb_pair(&mut self, lb_pair_data: &[u8]) -> Result<(), JsError> {
+        self.0.set_lb_pair(lb_pair_data).map_err(to_js_error)
+    }
+
+    #[wasm_bindgen(js_name = setBitmapExtension)]
+    pub fn set_bitmap_extension(&mut self, bitmap_extension_data: &[u8]) -> Result<(), JsError> {
+        self.0
+            .set_bitmap_extension(bitmap_extension_data)
+            .map_err(to_js_error)
+    }
+
+    #[wasm_bindgen(js_name = setBinArray)]
+    pub fn set_bin_array(
+        &mut self,
+        bin_array_pubkey: &str,
+        bin_array_data: &[u8],
+    ) -> Result<(), JsError> {
+        let bin_array_pubkey = parse_pubkey(bin_array_pubkey).map_err(to_js_error)?;
+        self.0
+            .set_bin_array(bin_array_pubkey, bin_array_data)
+            .map_err(to_js_error)
+    }
+
+    /// Token mint accounts of the pair, used to account for token 2022 transfer fee
+    #[wasm_bindgen(js_name = setMints)]
+    pub fn set_mints(&mut self, mint_x_data: Vec<u8>, mint_y_data: Vec<u8>) {
+        self.0.set_mints(mint_x_data, mint_y_data);
+    }
+
+    /// Oracle account of the pair, used to evaluate the circuit breaker
+    #[wasm_bindgen(js_name = setOracle)]
+    pub fn set_oracle(&mut self, oracle_data: Vec<u8>) {
+        self.0.set_oracle(oracle_data);
+    }
+
+    #[wasm_bindgen(js_name = setClock)]
+    pub fn set_clock(&mut self, slot: u64, epoch: u64, unix_timestamp: i64) {
+        self.0.set_clock(Clock {
+            slot,
+            epoch,
+            unix_timestamp,
+            ..Default::default()
+        });
+    }
+
+    /// Bin arrays with liquidity in the direction of the swap, to be fetched and passed to `setBinArray` before quoting
+    #[wasm_bindgen(js_name = getBinArrayPubkeysForSwap)]
+    pub fn get_bin_array_pubkeys_for_swap(
+        &self,
+        swap_for_y: bool,
+        take_count: u8,
+    ) -> Result<Vec<String>, JsError> {
+        let bin_array_pubkeys = self
+            .0
+            .get_bin_array_pubkeys_for_swap(swap_for_y, take_count)
+            .map_err(to_js_error)?;
+
+        Ok(bin_array_pubkeys.iter().map(Pubkey::to_string).collect())
+    }
+
+    /// Quote for the user to send exactly `amount_in`. `host_fee_bps` is the share of the protocol fee given to the host.
+    #[wasm_bindgen(js_name = quoteExactIn)]
+    pub fn quote_exact_in(
+        &self,
+        amount_in: u64,
+        swap_for_y: bool,
+        host_fee_bps: Option<u16>,
+    ) -> Result<JsSwapExactInQuote, JsError> {
+        self.0
+            .quote_exact_in(amount_in, swap_for_y, host_fee_bps)
+            .map(Into::into)
+            .map_err(to_js_error)
+    }
+
+    /// Quote for the user to receive exactly `amount_out`. `host_fee_bps` is the share of the protocol fee given to the host.
+    #[wasm_bindgen(js_name = quoteExactOut)]
+    pub fn quote_exact_out(
+        &self,
+        amount_out: u64,
+        swap_for_y: bool,
+        host_fee_bps: Option<u16>,
+    ) -> Result<JsSwapExactOutQuote, JsError> {
+        self.0
+            .quote_exact_out(amount_out, swap_for_y, host_fee_bps)
+            .map(Into::into)
+            .map_err(to_js_error)
+    }
+}
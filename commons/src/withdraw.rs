@@ -0,0 +1,374 @@
+use crate::price::get_bin_id_range_from_price_range;
+use anchor_lang::solana_program::pubkey::Pubkey;
+use anyhow::{ensure, Context, Result};
+use lb_clmm::{
+    constants::BASIS_POINT_MAX,
+    instructions::withdraw::remove_liquidity::{calculate_shares_to_remove, BinLiquidityReduction},
+    state::{
+        bin::{Bin, BinArray},
+        position::PositionV2,
+    },
+    utils::pda::derive_bin_array_pda,
+};
+use std::collections::HashMap;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct BinWithdrawPreview {
+    pub bin_id: i32,
+    pub bps_to_remove: u16,
+    /// Liquidity share burned from the position
+    pub liquidity_share: u128,
+    /// Amount of token X withdrawn from the bin
+    pub amount_x: u64,
+    /// Amount of token Y withdrawn from the bin
+    pub amount_y: u64,
+}
+
+#[derive(Debug, Default)]
+pub struct WithdrawPreview {
+    /// Withdrawal of each bin with liquidity, in the order of the reductions
+    pub bins: Vec<BinWithdrawPreview>,
+    /// Amount of token X withdrawn, before the transfer fee of token X
+    pub amount_x: u64,
+    /// Amount of token Y withdrawn, before the transfer fee of token Y
+    pub amount_y: u64,
+}
+
+#[derive(Debug)]
+pub struct WithdrawPlan {
+    /// Reductions to be passed to `remove_liquidity`
+    pub reductions: Vec<BinLiquidityReduction>,
+    /// Amounts withdrawn by the reductions
+    pub preview: WithdrawPreview,
+}
+
+fn get_bin(lb_pair: Pubkey, bin_arrays: &HashMap<Pubkey, BinArray>, bin_id: i32) -> Result<&Bin> {
+    let bin_array_index = BinArray::bin_id_to_bin_array_index(bin_id)?;
+    let (bin_array_pubkey, _bump) = derive_bin_array_pda(lb_pair, bin_array_index.into());
+    let bin_array = bin_arrays
+        .get(&bin_array_pubkey)
+        .context("Bin array not found")?;
+
+    Ok(bin_array.get_bin(bin_id)?)
+}
+
+/// Preview `remove_liquidity` of the position. `bin_arrays` must contain the bin arrays covering the reductions.
+pub fn preview_remove_liquidity(
+    position: &PositionV2,
+    bin_arrays: &HashMap<Pubkey, BinArray>,
+    reductions: &[BinLiquidityReduction],
+) -> Result<WithdrawPreview> {
+    let mut preview = WithdrawPreview::default();
+
+    for reduction in reductions {
+        ensure!(
+            u64::from(reduction.bps_to_remove) <= BASIS_POINT_MAX as u64,
+            "Invalid bps to remove"
+        );
+
+        let liquidity_share =
+            calculate_shares_to_remove(reduction.bps_to_remove, reduction.bin_id, position)?;
+        if liquidity_share == 0 {
+            continue;
+        }
+
+        let (amount_x, amount_y) = get_bin(position.lb_pair, bin_arrays, reduction.bin_id)?
+            .calculate_out_amount(liquidity_share)?;
+
+        preview.amount_x = preview.amount_x.checked_add(amount_x).context("overflow")?;
+        preview.amount_y = preview.amount_y.checked_add(amount_y).context("overflow")?;
+        preview.bins.push(BinWithdrawPreview {
+            bin_id: reduction.bin_id,
+            bps_to_remove: reduction.bps_to_remove,
+            liquidity_share,
+            amount_x,
+            amount_y,
+        });
+    }
+
+    Ok(preview)
+}
+
+/// Reductions removing `bps_to_remove` from every bin with liquidity of the position within `min_bin_id` and `max_bin_id`
+pub fn get_reductions_by_range(
+    position: &PositionV2,
+    min_bin_id: i32,
+    max_bin_id: i32,
+    bps_to_remove: u16,
+) -> Result<Vec<BinLiquidityReduction>> {
+    let mut reductions = vec![];
+
+    for bin_id in min_bin_id.max(position.lower_bin_id)..=max_bin_id.min(position.upper_bin_id) {
+        if position.get_liquidity_share_in_bin(bin_id)? > 0 {
+            reductions.push(BinLiquidityReduction {
+                bin_id,
+                bps_to_remove,
+            });
+        }
+    }
+
+    Ok(reductions)
+}
+
+/// Preview the removal of `bps_to_remove` from every bin of the position within the range
+pub fn preview_remove_liquidity_by_range(
+    position: &PositionV2,
+    bin_arrays: &HashMap<Pubkey, BinArray>,
+    min_bin_id: i32,
+    max_bin_id: i32,
+    bps_to_remove: u16,
+) -> Result<WithdrawPreview> {
+    let reductions = get_reductions_by_range(position, min_bin_id, max_bin_id, bps_to_remove)?;
+    preview_remove_liquidity(position, bin_arrays, &reductions)
+}
+
+/// Reductions removing `bps_to_remove` from every bin with liquidity of the position priced within the Q64.64 price range
+pub fn get_reductions_by_price_range(
+    position: &PositionV2,
+    bin_step: u16,
+    min_price: u128,
+    max_price: u128,
+    bps_to_remove: u16,
+) -> Result<Vec<BinLiquidityReduction>> {
+    let (min_bin_id, max_bin_id) =
+        get_bin_id_range_from_price_range(bin_step, min_price, max_price)?;
+    get_reductions_by_range(position, min_bin_id, max_bin_id, bps_to_remove)
+}
+
+/// Preview the removal of `bps_to_remove` from every bin of the position priced within the Q64.64 price range
+pub fn preview_remove_liquidity_by_price_range(
+    position: &PositionV2,
+    bin_arrays: &HashMap<Pubkey, BinArray>,
+    bin_step: u16,
+    min_price: u128,
+    max_price: u128,
+    bps_to_remove: u16,
+) -> Result<WithdrawPreview> {
+    let reductions =
+        get_reductions_by_price_range(position, bin_step, min_price, max_price, bps_to_remove)?;
+    preview_remove_liquidity(position, bin_arrays, &reductions)
+}
+
+/// Lowest bps in [0, `max_bps`] satisfying `predicate`, which must hold at `max_bps` and be monotonic in the bps
+fn get_lowest_bps(max_bps: u16, predicate: impl Fn(u16) -> Result<bool>) -> Result<u16> {
+    let (mut low, mut high) = (0u16, max_bps);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if predicate(mid)? {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+
+    Ok(low)
+}
+
+/// Plan the reductions withdrawing at least `amount` of token X (`is_x`) or Y from the position, with minimum disturbance to the rest of the position.
+/// The same bps is removed from every bin holding the token, keeping the shape of the position, at the lowest bps reaching the amount.
+/// The overshoot is then trimmed bin by bin, starting from the bin closest to the active bin, which earns the most fees.
+/// Positions with vesting must be withdrawn by the same bps from every bin, so the overshoot is not trimmed.
+pub fn plan_withdraw_amount(
+    position: &PositionV2,
+    bin_arrays: &HashMap<Pubkey, BinArray>,
+    amount: u64,
+    is_x: bool,
+) -> Result<WithdrawPlan> {
+    let has_vesting = position.has_vesting();
+
+    let mut bins = vec![];
+    for bin_id in position.lower_bin_id..=position.upper_bin_id {
+        if position.get_liquidity_share_in_bin(bin_id)? == 0 {
+            continue;
+        }
+        let bin = get_bin(position.lb_pair, bin_arrays, bin_id)?;
+        if has_vesting || !bin.is_empty(is_x) {
+            bins.push((bin_id, bin));
+        }
+    }
+
+    // Token X is above the active bin, token Y below
+    if !is_x {
+        bins.reverse();
+    }
+
+    // The amount withdrawn from a bin only depends on the bps removed from the bin
+    let bin_withdrawn_amount = |(bin_id, bin): &(i32, &Bin), bps_to_remove: u16| -> Result<u64> {
+        let liquidity_share = calculate_shares_to_remove(bps_to_remove, *bin_id, position)?;
+        let (amount_x, amount_y) = bin.calculate_out_amount(liquidity_share)?;
+        Ok(if is_x { amount_x } else { amount_y })
+    };
+    let withdrawn_amount = |bps_to_remove: u16| -> Result<u64> {
+        bins.iter().try_fold(0u64, |total, bin| {
+            total
+                .checked_add(bin_withdrawn_amount(bin, bps_to_remove)?)
+                .context("overflow")
+        })
+    };
+
+    let max_bps = BASIS_POINT_MAX as u16;
+    ensure!(
+        withdrawn_amount(max_bps)? >= amount,
+        "Insufficient liquidity in the position"
+    );
+
+    // Lowest uniform bps reaching the amount
+    let uniform_bps = get_lowest_bps(max_bps, |bps| Ok(withdrawn_amount(bps)? >= amount))?;
+    let mut bps_to_remove = vec![uniform_bps; bins.len()];
+
+    if !has_vesting {
+        let mut total = withdrawn_amount(uniform_bps)?;
+        for (bin, bps) in bins.iter().zip(bps_to_remove.iter_mut()) {
+            // Lowest bps of the bin keeping the total at the amount, with the other bins unchanged
+            let total_without_bin = total - bin_withdrawn_amount(bin, *bps)?;
+            *bps = get_lowest_bps(*bps, |bps| {
+                Ok(total_without_bin + bin_withdrawn_amount(bin, bps)? >= amount)
+            })?;
+            total = total_without_bin + bin_withdrawn_amount(bin, *bps)?;
+        }
+    }
+
+    let reductions: Vec<BinLiquidityReduction> = bins
+        .iter()
+        .zip(bps_to_remove)
+        .filter(|(_, bps_to_remove)| *bps_to_remove > 0)
+        .map(|(&(bin_id, _), bps_to_remove)| BinLiquidityReduction {
+            bin_id,
+            bps_to_remove,
+        })
+        .collect();
+    let preview = preview_remove_liquidity(position, bin_arrays, &reductions)?;
+
+    Ok(WithdrawPlan {
+        reductions,
+        preview,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lb_clmm::constants::MAX_BIN_PER_ARRAY;
+    use lb_clmm::math::price_math::get_price_from_id;
+
+    /// Position over bin 0 to 4, active bin 2. Bins 0 and 1 hold token Y, bin 2 both, bins 3 and 4 token X.
+    fn setup() -> (PositionV2, HashMap<Pubkey, BinArray>) {
+        let lb_pair = Pubkey::new_unique();
+        let mut position = PositionV2 {
+            lb_pair,
+            lower_bin_id: 0,
+            upper_bin_id: 4,
+            ..Default::default()
+        };
+        let mut bin_array = BinArray {
+            index: 0,
+            version: 1,
+            _padding: [0u8; 7],
+            lb_pair,
+            bins: [Bin::default(); MAX_BIN_PER_ARRAY],
+        };
+
+        for (bin_id, amount_x, amount_y) in [
+            (0, 0, 1_000_000),
+            (1, 0, 2_000_000),
+            (2, 1_500_000, 1_500_000),
+            (3, 2_000_000, 0),
+            (4, 1_000_000, 0),
+        ] {
+            let bin = bin_array.get_bin_mut(bin_id).unwrap();
+            bin.amount_x = amount_x;
+            bin.amount_y = amount_y;
+            bin.liquidity_supply = 2_000_000;
+            // Position owns half of each bin
+            position.liquidity_shares[bin_id as usize] = 1_000_000;
+        }
+
+        let bin_arrays = HashMap::from([(derive_bin_array_pda(lb_pair, 0).0, bin_array)]);
+        (position, bin_arrays)
+    }
+
+    #[test]
+    fn test_preview_remove_liquidity_by_range() {
+        let (position, bin_arrays) = setup();
+
+        let preview =
+            preview_remove_liquidity_by_range(&position, &bin_arrays, 2, 10, 5_000).unwrap();
+
+        assert_eq!(preview.bins.len(), 3);
+        assert_eq!(preview.amount_x, 375_000 + 500_000 + 250_000);
+        assert_eq!(preview.amount_y, 375_000);
+    }
+
+    #[test]
+    fn test_plan_withdraw_amount() {
+        let (position, bin_arrays) = setup();
+        let amount = 1_000_003;
+
+        let plan = plan_withdraw_amount(&position, &bin_arrays, amount, true).unwrap();
+
+        assert!(plan.preview.amount_x >= amount);
+        assert!(plan.preview.amount_x - amount < 100);
+        // Only bins holding token X are touched
+        assert!(plan
+            .reductions
+            .iter()
+            .all(|reduction| reduction.bin_id >= 2));
+
+        assert!(plan_withdraw_amount(&position, &bin_arrays, 2_250_001, true).is_err());
+    }
+
+    #[test]
+    fn test_plan_withdraw_amount_y() {
+        let (position, bin_arrays) = setup();
+        let amount = 1_250_000;
+
+        let plan = plan_withdraw_amount(&position, &bin_arrays, amount, false).unwrap();
+
+        assert!(plan.preview.amount_y >= amount);
+        // Only bins holding token Y are touched, starting from the active bin
+        assert_eq!(
+            plan.reductions
+                .iter()
+                .map(|reduction| reduction.bin_id)
+                .collect::<Vec<_>>(),
+            vec![2, 1, 0]
+        );
+
+        // Removing less from any bin falls short of the amount
+        for i in 0..plan.reductions.len() {
+            let mut reductions = plan.reductions.clone();
+            reductions[i].bps_to_remove -= 1;
+            let preview = preview_remove_liquidity(&position, &bin_arrays, &reductions).unwrap();
+            assert!(preview.amount_y < amount);
+        }
+    }
+
+    #[test]
+    fn test_preview_remove_liquidity_by_price_range() {
+        let (position, bin_arrays) = setup();
+        let bin_step = 10;
+        let price_at = |bin_id: i32| get_price_from_id(bin_id, bin_step).unwrap();
+
+        // Bins 2 to 3, the prices in between 2 bins round inward
+        let preview = preview_remove_liquidity_by_price_range(
+            &position,
+            &bin_arrays,
+            bin_step,
+            price_at(1) + 1,
+            price_at(4) - 1,
+            10_000,
+        )
+        .unwrap();
+
+        assert_eq!(
+            preview
+                .bins
+                .iter()
+                .map(|bin| bin.bin_id)
+                .collect::<Vec<_>>(),
+            vec![2, 3]
+        );
+        assert_eq!(preview.amount_x, 750_000 + 1_000_000);
+        assert_eq!(preview.amount_y, 750_000);
+    }
+}
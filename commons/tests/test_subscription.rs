@@ -0,0 +1,350 @@
+//! Pair subscription against a local `solana-test-validator`. Build the program with `cargo build-sbf` (or `anchor build`) first, then
+//! run with `cargo test -p commons --test test_subscription -- --ignored`. `LB_CLMM_SO` overrides the path of the program.
+use anchor_client::solana_client::nonblocking::rpc_client::RpcClient;
+use anchor_client::solana_sdk::{
+    account::Account, commitment_config::CommitmentConfig,
+    compute_budget::ComputeBudgetInstruction, instruction::AccountMeta, instruction::Instruction,
+    program_pack::Pack, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer,
+    system_program, transaction::Transaction,
+};
+use anchor_lang::{Discriminator, InstructionData, ToAccountMetas};
+use anchor_spl::token::spl_token;
+use commons::subscription::{subscribe_pairs, PairUpdate};
+use lb_clmm::constants::{DEFAULT_OBSERVATION_LENGTH, MAX_BIN_ID, MIN_BIN_ID};
+use lb_clmm::state::bin::BinArray;
+use lb_clmm::state::lb_pair::{LbPair, PairStatus, PairType};
+use lb_clmm::state::oracle::{Observation, Oracle};
+use lb_clmm::state::parameters::StaticParameters;
+use lb_clmm::utils::pda::{
+    derive_bin_array_pda, derive_event_authority_pda, derive_lb_pair_pda2, derive_oracle_pda,
+};
+use solana_account_decoder::{UiAccount, UiAccountEncoding};
+use std::path::PathBuf;
+use std::process::{Child, Command, Stdio};
+use std::time::Duration;
+
+const RPC_PORT: u16 = 18899;
+const BIN_STEP: u16 = 10;
+const BASE_FACTOR: u16 = 10_000;
+const BIN_AMOUNT_Y: u64 = 1_000_000;
+
+/// Validator process, killed on drop with its ledger removed
+struct TestValidator {
+    process: Child,
+    dir: PathBuf,
+}
+
+impl TestValidator {
+    /// Start the validator with lb_clmm deployed and `accounts` preloaded, and wait until the program is served
+    async fn start(accounts: &[(Pubkey, Account)]) -> (Self, RpcClient) {
+        let dir =
+            std::env::temp_dir().join(format!("lb_clmm_subscription_{}", Pubkey::new_unique()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let program = std::env::var("LB_CLMM_SO")
+            .map(PathBuf::from)
+            .unwrap_or_else(|_| {
+                PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../target/deploy/lb_clmm.so")
+            });
+
+        let mut command = Command::new("solana-test-validator");
+        command
+            .arg("--reset")
+            .arg("--quiet")
+            .arg("--ledger")
+            .arg(dir.join("ledger"))
+            .arg("--rpc-port")
+            .arg(RPC_PORT.to_string())
+            .arg("--faucet-port")
+            .arg((RPC_PORT + 1_000).to_string())
+            .arg("--bpf-program")
+            .arg(lb_clmm::ID.to_string())
+            .arg(program);
+
+        for (pubkey, account) in accounts {
+            let file = dir.join(format!("{}.json", pubkey));
+            let ui_account = serde_json::json!({
+                "pubkey": pubkey.to_string(),
+                "account": UiAccount::encode(pubkey, account, UiAccountEncoding::Base64, None, None),
+            });
+            std::fs::write(&file, ui_account.to_string()).unwrap();
+            command.arg("--account").arg(pubkey.to_string()).arg(file);
+        }
+
+        let process = command
+            .stdout(Stdio::null())
+            .spawn()
+            .expect("solana-test-validator not found");
+        let validator = Self { process, dir };
+
+        let rpc_client = RpcClient::new_with_commitment(
+            format!("http://127.0.0.1:{}", RPC_PORT),
+            CommitmentConfig::confirmed(),
+        );
+        for _ in 0..120 {
+            if let Ok(program) = rpc_client.get_account(&lb_clmm::ID).await {
+                if program.executable {
+                    return (validator, rpc_client);
+                }
+            }
+            tokio::time::sleep(Duration::from_millis(500)).await;
+        }
+        panic!("solana-test-validator did not start");
+    }
+
+    fn ws_url(&self) -> String {
+        format!("ws://127.0.0.1:{}", RPC_PORT + 1)
+    }
+}
+
+impl Drop for TestValidator {
+    fn drop(&mut self) {
+        let _ = self.process.kill();
+        let _ = self.process.wait();
+        let _ = std::fs::remove_dir_all(&self.dir);
+    }
+}
+
+fn rent_exempt_account(data: Vec<u8>, owner: Pubkey) -> Account {
+    Account {
+        lamports: Rent::default().minimum_balance(data.len()),
+        data,
+        owner,
+        ..Default::default()
+    }
+}
+
+fn zero_copy_account<T: Discriminator + bytemuck::Pod>(state: &T) -> Account {
+    let mut data = T::DISCRIMINATOR.to_vec();
+    data.extend_from_slice(bytemuck::bytes_of(state));
+    rent_exempt_account(data, lb_clmm::ID)
+}
+
+fn mint_account() -> Account {
+    let mut data = vec![0u8; spl_token::state::Mint::LEN];
+    spl_token::state::Mint {
+        decimals: 6,
+        is_initialized: true,
+        ..Default::default()
+    }
+    .pack_into_slice(&mut data);
+    rent_exempt_account(data, spl_token::ID)
+}
+
+fn token_account(mint: Pubkey, owner: Pubkey, amount: u64) -> Account {
+    let mut data = vec![0u8; spl_token::state::Account::LEN];
+    spl_token::state::Account {
+        mint,
+        owner,
+        amount,
+        state: spl_token::state::AccountState::Initialized,
+        ..Default::default()
+    }
+    .pack_into_slice(&mut data);
+    rent_exempt_account(data, spl_token::ID)
+}
+
+#[tokio::test]
+#[ignore = "requires solana-test-validator and the lb_clmm program built"]
+async fn test_subscribe_pairs_swap() {
+    let user = Keypair::new();
+    let token_x_mint = Pubkey::new_unique();
+    let token_y_mint = Pubkey::new_unique();
+    let (lb_pair, bump) = derive_lb_pair_pda2(token_x_mint, token_y_mint, BIN_STEP, BASE_FACTOR);
+    let (oracle, _bump) = derive_oracle_pda(lb_pair);
+    let reserve_x = Pubkey::new_unique();
+    let reserve_y = Pubkey::new_unique();
+    let user_token_x = Pubkey::new_unique();
+    let user_token_y = Pubkey::new_unique();
+
+    let mut lb_pair_state = LbPair::default();
+    lb_pair_state
+        .initialize(
+            bump,
+            0,
+            BIN_STEP,
+            token_x_mint,
+            token_y_mint,
+            reserve_x,
+            reserve_y,
+            oracle,
+            StaticParameters {
+                base_factor: BASE_FACTOR,
+                filter_period: 30,
+                decay_period: 600,
+                reduction_factor: 5000,
+                variable_fee_control: 40_000,
+                max_volatility_accumulator: 350_000,
+                min_bin_id: MIN_BIN_ID,
+                max_bin_id: MAX_BIN_ID,
+                protocol_share: 1000,
+                _padding: [0u8; 6],
+            },
+            PairType::Permissionless,
+            PairStatus::Enabled.into(),
+            Pubkey::default(),
+            user.pubkey(),
+            0,
+            0,
+            Pubkey::default(),
+            0,
+        )
+        .unwrap();
+
+    // Bin 0 and the bins of the bin array -1 hold token Y
+    let mut accounts = vec![];
+    let mut bin_arrays = vec![];
+    for index in [-1, 0] {
+        let (bin_array, _bump) = derive_bin_array_pda(lb_pair, index);
+        let mut bin_array_state: BinArray = bytemuck::Zeroable::zeroed();
+        bin_array_state.initialize(index, lb_pair).unwrap();
+        let bins = if index == 0 {
+            &mut bin_array_state.bins[..1]
+        } else {
+            &mut bin_array_state.bins[..]
+        };
+        for bin in bins.iter_mut() {
+            bin.amount_y = BIN_AMOUNT_Y;
+            bin.liquidity_supply = BIN_AMOUNT_Y.into();
+        }
+        lb_pair_state
+            .flip_bin_array_bit(&None, index as i32)
+            .unwrap();
+        accounts.push((bin_array, zero_copy_account(&bin_array_state)));
+        bin_arrays.push(bin_array);
+    }
+    accounts.push((lb_pair, zero_copy_account(&lb_pair_state)));
+
+    let mut oracle_data = Oracle::DISCRIMINATOR.to_vec();
+    oracle_data.extend_from_slice(bytemuck::bytes_of(&Oracle {
+        idx: 0,
+        active_size: 0,
+        length: DEFAULT_OBSERVATION_LENGTH,
+    }));
+    for _ in 0..DEFAULT_OBSERVATION_LENGTH {
+        oracle_data.extend_from_slice(bytemuck::bytes_of(&Observation::default()));
+    }
+    accounts.push((oracle, rent_exempt_account(oracle_data, lb_clmm::ID)));
+
+    accounts.extend([
+        (token_x_mint, mint_account()),
+        (token_y_mint, mint_account()),
+        (reserve_x, token_account(token_x_mint, lb_pair, 0)),
+        (
+            reserve_y,
+            token_account(token_y_mint, lb_pair, 71 * BIN_AMOUNT_Y),
+        ),
+        (
+            user_token_x,
+            token_account(token_x_mint, user.pubkey(), 10 * BIN_AMOUNT_Y),
+        ),
+        (user_token_y, token_account(token_y_mint, user.pubkey(), 0)),
+        (
+            user.pubkey(),
+            Account {
+                lamports: 10_000_000_000,
+                owner: system_program::ID,
+                ..Default::default()
+            },
+        ),
+    ]);
+
+    let (validator, rpc_client) = TestValidator::start(&accounts).await;
+
+    let (subscription, mut updates) = subscribe_pairs(
+        &validator.ws_url(),
+        &rpc_client,
+        &[lb_pair],
+        1,
+        CommitmentConfig::confirmed(),
+    )
+    .await
+    .unwrap();
+    {
+        let states = subscription.states.lock().unwrap();
+        let state = states.get(&lb_pair).unwrap();
+        assert_eq!(state.lb_pair.active_id, 0);
+        assert_eq!(state.bin_arrays.len(), 2);
+    }
+
+    // Swap through the bin 0 into the bin -1
+    let (event_authority, _bump) = derive_event_authority_pda();
+    let mut swap_accounts = lb_clmm::accounts::Swap {
+        lb_pair,
+        bin_array_bitmap_extension: None,
+        reserve_x,
+        reserve_y,
+        user_token_in: user_token_x,
+        user_token_out: user_token_y,
+        token_x_mint,
+        token_y_mint,
+        oracle,
+        host_fee_in: None,
+        user: user.pubkey(),
+        token_x_program: spl_token::ID,
+        token_y_program: spl_token::ID,
+        event_authority,
+        program: lb_clmm::ID,
+    }
+    .to_account_metas(None);
+    swap_accounts.extend([
+        AccountMeta::new(bin_arrays[1], false),
+        AccountMeta::new(bin_arrays[0], false),
+    ]);
+    let instructions = [
+        ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+        Instruction {
+            program_id: lb_clmm::ID,
+            accounts: swap_accounts,
+            data: lb_clmm::instruction::Swap {
+                amount_in: 3 * BIN_AMOUNT_Y / 2,
+                min_amount_out: 0,
+            }
+            .data(),
+        },
+    ];
+    let blockhash = rpc_client.get_latest_blockhash().await.unwrap();
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&user.pubkey()),
+        &[&user],
+        blockhash,
+    );
+    rpc_client
+        .send_and_confirm_transaction(&transaction)
+        .await
+        .unwrap();
+
+    // Both the pair and the bin arrays traversed are notified
+    let mut received = vec![];
+    let is_notified = |received: &[PairUpdate]| {
+        received.contains(&PairUpdate::ActiveBinMoved {
+            lb_pair,
+            from_active_id: 0,
+            to_active_id: -1,
+        }) && bin_arrays.iter().all(|bin_array| {
+            received.iter().any(|update| {
+                matches!(update, PairUpdate::LiquidityChanged { bin_array: changed, .. } if changed == bin_array)
+            })
+        })
+    };
+    while !is_notified(&received) {
+        let update = tokio::time::timeout(Duration::from_secs(30), updates.recv())
+            .await
+            .expect("Pair update not notified")
+            .expect("Pair subscription closed");
+        received.push(update);
+    }
+
+    // Subscribed state match the state read from the RPC
+    let bin_array_accounts = rpc_client.get_multiple_accounts(&bin_arrays).await.unwrap();
+    let states = subscription.states.lock().unwrap();
+    let state = states.get(&lb_pair).unwrap();
+    assert_eq!(state.lb_pair.active_id, -1);
+    for (bin_array, account) in bin_arrays.iter().zip(bin_array_accounts) {
+        assert_eq!(
+            bytemuck::bytes_of(&state.bin_arrays[bin_array]),
+            &account.unwrap().data[8..]
+        );
+    }
+}
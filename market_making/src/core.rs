@@ -26,14 +26,16 @@ use anchor_spl::token::Mint;
 use anchor_spl::token::TokenAccount;
 use anyhow::Ok;
 use anyhow::*;
+use commons::fetcher::AccountFetcher;
 use lb_clmm::accounts;
 use lb_clmm::constants::MAX_BIN_PER_ARRAY;
 use lb_clmm::constants::MAX_BIN_PER_POSITION;
 use lb_clmm::events::Swap as SwapEvent;
 use lb_clmm::instruction;
+use lb_clmm::instructions::compound_fees::CompoundFeeParameter;
 use lb_clmm::instructions::deposit::*;
 use lb_clmm::math::safe_math::SafeMath;
-use lb_clmm::state::{bin::BinArray, lb_pair::LbPair, position::PositionV2};
+use lb_clmm::state::{bin::BinArray, position::PositionV2};
 use lb_clmm::utils::pda;
 use lb_clmm::utils::pda::*;
 use std::collections::HashMap;
@@ -57,9 +59,11 @@ impl Core {
             Arc::new(Keypair::new()),
         )?;
 
+        let mut fetcher = AccountFetcher::new(program.async_rpc(), 0);
+
         for pair in self.config.iter() {
             let pair_address = Pubkey::from_str(&pair.pair_address).unwrap();
-            let lb_pair_state: LbPair = program.account(pair_address).await?;
+            let lb_pair_state = fetcher.get_lb_pair(pair_address).await?;
             // let token_x: Mint = program.account(lb_pair_state.token_x_mint).await?;
             // let token_y: Mint = program.account(lb_pair_state.token_y_mint).await?;
             // get all position with an user
@@ -93,6 +97,7 @@ impl Core {
                     positions.push(position.1);
                 }
                 let mut bin_arrays_indexes = vec![];
+                let mut bin_array_pks = vec![];
                 for (_pk, position) in position_states.iter() {
                     for i in position.lower_bin_id..=position.upper_bin_id {
                         let bin_array_index = BinArray::bin_id_to_bin_array_index(i)?;
@@ -103,12 +108,10 @@ impl Core {
 
                         let (bin_array_pk, _bump) =
                             pda::derive_bin_array_pda(pair_address, bin_array_index.into());
-
-                        let bin_array_state: BinArray = program.account(bin_array_pk).await?;
-
-                        bin_arrays.insert(bin_array_pk, bin_array_state);
+                        bin_array_pks.push(bin_array_pk);
                     }
                 }
+                bin_arrays = fetcher.get_bin_arrays(&bin_array_pks).await?;
             }
 
             let mut all_state = self.state.lock().unwrap();
@@ -234,6 +237,7 @@ impl Core {
                         user_token_y,
                         token_x_program: anchor_spl::token::ID,
                         token_y_program: anchor_spl::token::ID,
+                        position_nft_account: None,
                         event_authority,
                         program: lb_clmm::ID,
                     }
@@ -255,6 +259,8 @@ impl Core {
                         token_y_mint: lb_pair_state.token_y_mint,
                         user_token_x,
                         user_token_y,
+                        position_nft_account: None,
+                        admin_config: None,
                         event_authority,
                         program: lb_clmm::ID,
                     }
@@ -270,6 +276,7 @@ impl Core {
                         bin_array_upper,
                         rent_receiver: payer.pubkey(),
                         sender: payer.pubkey(),
+                        position_nft_account: None,
                         event_authority,
                         program: lb_clmm::ID,
                     }
@@ -462,14 +469,15 @@ impl Core {
             if program.rpc().get_account_data(&bin_array).is_err() {
                 instructions.push(Instruction {
                     program_id: lb_clmm::ID,
-                    accounts: accounts::InitializeBinArray {
+                    accounts: accounts::InitializeBinArrayWithFunder {
                         bin_array,
+                        bin_array_funder: derive_bin_array_funder_pda(bin_array).0,
                         funder: payer.pubkey(),
                         lb_pair,
                         system_program: anchor_client::solana_sdk::system_program::ID,
                     }
                     .to_account_metas(None),
-                    data: instruction::InitializeBinArray { index: idx.into() }.data(),
+                    data: instruction::InitializeBinArrayWithFunder { index: idx.into() }.data(),
                 })
             }
         }
@@ -527,6 +535,7 @@ impl Core {
                 bin_array_lower,
                 bin_array_upper,
                 sender: payer.pubkey(),
+                position_nft_account: None,
                 event_authority,
                 program: lb_clmm::ID,
                 reserve_x: lb_pair_state.reserve_x,
@@ -670,6 +679,77 @@ impl Core {
         Ok(())
     }
 
+    /// Compound the pending fee of every position of the market making pairs into liquidity.
+    pub async fn compound_fees(&self) -> Result<()> {
+        let (event_authority, _bump) = derive_event_authority_pda();
+        let payer = read_keypair_file(self.wallet.clone().unwrap())
+            .map_err(|_| Error::msg("Requires a keypair file"))?;
+        let program: Program<Arc<Keypair>> = create_program(
+            self.provider.to_string(),
+            self.provider.to_string(),
+            lb_clmm::ID,
+            Arc::new(Keypair::new()),
+        )?;
+
+        let all_positions = self.get_all_positions();
+        for state in all_positions.iter() {
+            let pair_config = get_pair_config(&self.config, state.lb_pair);
+            if pair_config.mode == MarketMakingMode::ModeView {
+                continue;
+            }
+
+            for (i, &position) in state.position_pks.iter().enumerate() {
+                let position_state = state.positions[i];
+                let lower_bin_array_idx =
+                    BinArray::bin_id_to_bin_array_index(position_state.lower_bin_id)?;
+                let upper_bin_array_idx =
+                    BinArray::bin_id_to_bin_array_index(position_state.upper_bin_id)?;
+
+                let (bin_array_lower, _bump) =
+                    derive_bin_array_pda(state.lb_pair, lower_bin_array_idx.into());
+                let (bin_array_upper, _bump) =
+                    derive_bin_array_pda(state.lb_pair, upper_bin_array_idx.into());
+
+                let instructions = vec![
+                    ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                    Instruction {
+                        program_id: lb_clmm::ID,
+                        accounts: accounts::CompoundFees {
+                            position,
+                            lb_pair: state.lb_pair,
+                            bin_array_bitmap_extension: None,
+                            bin_array_lower,
+                            bin_array_upper,
+                            sender: payer.pubkey(),
+                            position_nft_account: None,
+                            event_authority,
+                            program: lb_clmm::ID,
+                        }
+                        .to_account_metas(None),
+                        data: instruction::CompoundFees {
+                            parameter: CompoundFeeParameter {
+                                active_id: state.lb_pair_state.active_id,
+                                max_active_bin_slippage: 3,
+                                strategy_type: StrategyType::SpotBalanced,
+                            },
+                        }
+                        .data(),
+                    },
+                ];
+
+                let builder = program.request();
+                let builder = instructions
+                    .into_iter()
+                    .fold(builder, |bld, ix| bld.instruction(ix));
+
+                let signature = send_tx(vec![&payer], payer.pubkey(), &program, &builder)?;
+                info!("compound fees {position} {signature}");
+            }
+        }
+
+        Ok(())
+    }
+
     async fn shift_right(&self, state: &SinglePosition) -> Result<()> {
         let pair_config = get_pair_config(&self.config, state.lb_pair);
         // validate that y amount is zero
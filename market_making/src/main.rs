@@ -4,11 +4,14 @@ pub mod pair_config;
 pub mod router;
 pub mod state;
 pub mod utils;
+use anchor_client::solana_client::nonblocking::rpc_client::RpcClient;
+use anchor_client::solana_sdk::commitment_config::CommitmentConfig;
 use anchor_client::solana_sdk::pubkey::Pubkey;
 use anchor_client::solana_sdk::signature::read_keypair_file;
 use anchor_client::solana_sdk::signer::Signer;
 use anchor_client::Cluster;
 use clap::Parser;
+use commons::subscription::{subscribe_pairs, PairUpdate};
 use core::Core;
 use hyper::Server;
 use pair_config::{get_config_from_file, should_market_making};
@@ -28,6 +31,9 @@ extern crate log;
 
 use tokio::time::interval;
 
+/// Delay to gather the active bin moves notified in a burst, such as by the swaps of the same slot, into a single price range check
+const SUBSCRIBE_DEBOUNCE: Duration = Duration::from_secs(2);
+
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub enum MarketMakingMode {
     ModeRight,
@@ -75,6 +81,12 @@ pub struct Args {
     /// config path
     #[clap(long)]
     config_file: String,
+    /// Interval in seconds to compound the fee of the positions into liquidity. Disabled when not set.
+    #[clap(long)]
+    compound_fee_interval: Option<u64>,
+    /// Check the price range as soon as the active bin of a pair move, through websocket subscription, in addition to the periodic check.
+    #[clap(long)]
+    subscribe: bool,
     // /// public key pair address,
     // #[clap(long)]
     // pair_address: Pubkey,
@@ -97,6 +109,8 @@ async fn main() {
         wallet,
         user_public_key,
         config_file,
+        compound_fee_interval,
+        subscribe,
     } = Args::parse();
 
     let config = get_config_from_file(&config_file).unwrap();
@@ -143,9 +157,12 @@ async fn main() {
     }
 
     if should_market_making(&config) {
+        // Periodic and subscribed checks, and the fee compounding, modify the same positions, one at a time
+        let position_lock = Arc::new(tokio::sync::Mutex::new(()));
         {
             // crawl epoch down
             let core = core.clone();
+            let position_lock = position_lock.clone();
 
             // init user ata
             core.init_user_ata().await.unwrap();
@@ -156,6 +173,7 @@ async fn main() {
                 loop {
                     interval.tick().await;
                     info!("check shift price range");
+                    let _guard = position_lock.lock().await;
                     match core.check_shift_price_range().await {
                         Ok(_) => {}
                         Err(err) => error!("check shift price err {}", err),
@@ -164,6 +182,75 @@ async fn main() {
             });
             handles.push(handle);
         }
+
+        if subscribe {
+            let core = core.clone();
+            let position_lock = position_lock.clone();
+            let handle = tokio::spawn(async move {
+                let lb_pairs: Vec<Pubkey> = core
+                    .config
+                    .iter()
+                    .map(|pair| Pubkey::from_str(&pair.pair_address).unwrap())
+                    .collect();
+                let rpc_client = RpcClient::new(core.provider.url().to_string());
+                let (_subscription, mut updates) = match subscribe_pairs(
+                    core.provider.ws_url(),
+                    &rpc_client,
+                    &lb_pairs,
+                    1,
+                    CommitmentConfig::confirmed(),
+                )
+                .await
+                {
+                    Ok(subscription) => subscription,
+                    Err(err) => {
+                        error!("subscribe pairs err {}", err);
+                        return;
+                    }
+                };
+
+                while let Some(update) = updates.recv().await {
+                    let PairUpdate::ActiveBinMoved { lb_pair, .. } = update else {
+                        continue;
+                    };
+                    info!("active bin moved {}, check shift price range", lb_pair);
+
+                    // Moves notified meanwhile are covered by the same check
+                    tokio::time::sleep(SUBSCRIBE_DEBOUNCE).await;
+                    while updates.try_recv().is_ok() {}
+
+                    let _guard = position_lock.lock().await;
+                    if let Err(err) = core.refresh_state().await {
+                        error!("refresh_state err {}", err);
+                        continue;
+                    }
+                    match core.check_shift_price_range().await {
+                        Ok(_) => {}
+                        Err(err) => error!("check shift price err {}", err),
+                    }
+                }
+                error!("pair subscription closed");
+            });
+            handles.push(handle);
+        }
+
+        if let Some(duration) = compound_fee_interval {
+            let core = core.clone();
+            let position_lock = position_lock.clone();
+            let handle = tokio::spawn(async move {
+                let mut interval = interval(Duration::from_secs(duration));
+                loop {
+                    interval.tick().await;
+                    info!("compound fees");
+                    let _guard = position_lock.lock().await;
+                    match core.compound_fees().await {
+                        Ok(_) => {}
+                        Err(err) => error!("compound fees err {}", err),
+                    }
+                }
+            });
+            handles.push(handle);
+        }
     }
 
     // let mut handles = vec![];
@@ -37,6 +37,22 @@ pub const MAX_PROTOCOL_SHARE: u16 = 2_500;
 #[constant]
 pub const HOST_FEE_BPS: u16 = 2_000;
 
+/// Maximum share of the protocol fee for a registered referrer. 50%
+#[constant]
+pub const MAX_REFERRER_FEE_SHARE: u16 = 5_000;
+
+/// Precision of the vesting ratio of a position
+#[constant]
+pub const VESTING_RATIO_PRECISION: u64 = 1_000_000_000_000;
+
+/// Flash loan fee. 0.05%
+#[constant]
+pub const FLASH_LOAN_FEE_BPS: u16 = 5;
+
+/// Maximum number of pairs a swap route can go through
+#[constant]
+pub const MAX_SWAP_ROUTE_HOPS: usize = 3;
+
 pub const U24_MAX: u32 = 0xffffff;
 
 // Number of rewards supported by pool
@@ -52,6 +68,10 @@ pub const MAX_REWARD_DURATION: u64 = 31536000; // 1 year = 365 * 24 * 3600
 
 pub const DEFAULT_OBSERVATION_LENGTH: u64 = 100;
 pub const SAMPLE_LIFETIME: u64 = 120; // 2
+// Maximum number of observations the target history duration of the oracle parameters can require
+pub const MAX_ORACLE_TARGET_LENGTH: u64 = 10_000;
+// Number of observations added to the oracle by a swap, when auto-growth is enabled
+pub const ORACLE_AUTO_GROWTH_LENGTH: u64 = 1;
 #[constant]
 pub const EXTENSION_BINARRAY_BITMAP_SIZE: usize = 12;
 
@@ -200,4 +200,67 @@ pub enum LBError {
 
     #[msg("Already pass pre-activation swap point")]
     AlreadyPassPreActivationSwapPoint,
+
+    #[msg("Cannot transfer because owners are the same")]
+    OwnersAreTheSame,
+
+    #[msg("Position NFT account is missing or not holding the position NFT")]
+    InvalidPositionNftAccount,
+
+    #[msg("Tokenized position can only be transferred through the position NFT")]
+    PositionIsTokenized,
+
+    #[msg("Invalid position NFT metadata uri")]
+    InvalidPositionNftUri,
+
+    #[msg("Unsupported mint extension")]
+    UnsupportedMintExtension,
+
+    #[msg("Flash loan is not repaid in the same transaction")]
+    FlashLoanNotRepaid,
+
+    #[msg("Invalid flash borrow or flash repay instruction")]
+    InvalidFlashLoanInstruction,
+
+    #[msg("Flash loan is not allowed through CPI")]
+    FlashLoanCpiNotAllowed,
+
+    #[msg("Swap is halted by circuit breaker")]
+    CircuitBreakerHalted,
+
+    #[msg("Invalid circuit breaker parameters")]
+    InvalidCircuitBreakerParameters,
+
+    #[msg("Invalid referrer fee share")]
+    InvalidReferrerFeeShare,
+
+    #[msg("Referral fee account does not belong to the referrer")]
+    InvalidReferralFeeAccount,
+
+    #[msg("Swap deadline exceeded")]
+    SwapDeadlineExceeded,
+
+    #[msg("Exceeded price impact tolerance")]
+    ExceededPriceImpactTolerance,
+
+    #[msg("Bin array is not empty")]
+    NonEmptyBinArray,
+
+    #[msg("Bin array bitmap is inconsistent with the bin array liquidity")]
+    InconsistentBinArrayBitmap,
+
+    #[msg("Invalid vesting schedule")]
+    InvalidVestingSchedule,
+
+    #[msg("Vesting liquidity must be withdrawn evenly from every bin of the position")]
+    UnevenVestingWithdrawal,
+
+    #[msg("Invalid swap route")]
+    InvalidSwapRoute,
+
+    #[msg("Invalid oracle parameters")]
+    InvalidOracleParameters,
+
+    #[msg("Bin array has a recorded funder")]
+    BinArrayFunderRecorded,
 }
@@ -217,6 +217,16 @@ pub struct UpdatePositionOperator {
     pub new_operator: Pubkey,
 }
 
+#[event]
+pub struct TransferPositionOwner {
+    // Position public key
+    pub position: Pubkey,
+    // Old owner
+    pub old_owner: Pubkey,
+    // New owner
+    pub new_owner: Pubkey,
+}
+
 #[event]
 pub struct UpdatePositionLockReleasePoint {
     // Position public key
@@ -230,3 +240,155 @@ pub struct UpdatePositionLockReleasePoint {
     // Sender public key
     pub sender: Pubkey,
 }
+
+#[event]
+pub struct FlashLoan {
+    // Liquidity pool pair
+    pub lb_pair: Pubkey,
+    // Borrower
+    pub user: Pubkey,
+    // Amount of token X borrowed
+    pub amount_x: u64,
+    // Amount of token Y borrowed
+    pub amount_y: u64,
+    // Flash loan fee of token X, including protocol fee
+    pub fee_x: u64,
+    // Flash loan fee of token Y, including protocol fee
+    pub fee_y: u64,
+    // Protocol share of the token X fee
+    pub protocol_fee_x: u64,
+    // Protocol share of the token Y fee
+    pub protocol_fee_y: u64,
+}
+
+#[event]
+pub struct CircuitBreakerParametersUpdate {
+    // Liquidity pool pair
+    pub lb_pair: Pubkey,
+    // Maximum number of bins the active bin can move away from the time weighted active bin
+    pub max_bin_movement: u16,
+    // Time window of the time weighted active bin, in seconds
+    pub window: u16,
+    // Halt duration after tripped, in seconds
+    pub cooldown: u32,
+}
+
+#[event]
+pub struct CircuitBreakerTripped {
+    // Liquidity pool pair
+    pub lb_pair: Pubkey,
+    // Active bin id which tripped the circuit breaker
+    pub active_id: i32,
+    // Time weighted active bin id of the window
+    pub time_weighted_active_id: i32,
+    // Swap is halted until this timestamp
+    pub halted_until: i64,
+}
+
+#[event]
+pub struct ReferrerUpdate {
+    // Referrer public key
+    pub referrer: Pubkey,
+    // Owner of the referrer
+    pub owner: Pubkey,
+    // Share of the protocol fee in BPS
+    pub fee_share: u16,
+}
+
+#[event]
+pub struct ClaimReferralFee {
+    // Liquidity pool pair
+    pub lb_pair: Pubkey,
+    // Referrer public key
+    pub referrer: Pubkey,
+    // Owner of the referrer
+    pub owner: Pubkey,
+    // Amount of token X claimed
+    pub fee_x: u64,
+    // Amount of token Y claimed
+    pub fee_y: u64,
+}
+
+#[event]
+pub struct CompoundFee {
+    // Liquidity pool pair
+    pub lb_pair: Pubkey,
+    // Address of the position
+    pub position: Pubkey,
+    // Owner of the position
+    pub owner: Pubkey,
+    // Amount of token X, and Y fee compounded into liquidity
+    pub amounts: [u64; 2],
+    // Pair active bin during compounding
+    pub active_bin_id: i32,
+}
+
+#[event]
+pub struct BinArrayClose {
+    // Liquidity pool pair
+    pub lb_pair: Pubkey,
+    // Address of the bin array
+    pub bin_array: Pubkey,
+    // Index of the bin array
+    pub index: i64,
+    // Funder refunded with the rent
+    pub funder: Pubkey,
+}
+
+#[event]
+pub struct UpdatePositionVesting {
+    // Position public key
+    pub position: Pubkey,
+    // Point which the linear vesting starts
+    pub start_point: u64,
+    // Point before which none of the liquidity can be withdrawn
+    pub cliff_point: u64,
+    // Point which the liquidity is fully vested
+    pub end_point: u64,
+    // Sender public key
+    pub sender: Pubkey,
+}
+
+#[event]
+pub struct InitializeAdminConfig {
+    // Authority of the fee admin role
+    pub fee_admin: Pubkey,
+    // Authority of the pause guardian role
+    pub pause_guardian: Pubkey,
+    // Authority of the reward admin role
+    pub reward_admin: Pubkey,
+    // Authority of the launch pool admin role
+    pub launch_pool_admin: Pubkey,
+}
+
+#[event]
+pub struct ProposeAdminRole {
+    // Admin role, see AdminRole
+    pub role: u8,
+    // Current authority of the role
+    pub authority: Pubkey,
+    // Proposed authority of the role. Default when the pending change is cancelled.
+    pub pending_authority: Pubkey,
+}
+
+#[event]
+pub struct AcceptAdminRole {
+    // Admin role, see AdminRole
+    pub role: u8,
+    // Previous authority of the role
+    pub old_authority: Pubkey,
+    // New authority of the role
+    pub new_authority: Pubkey,
+}
+
+#[event]
+pub struct OracleParametersUpdate {
+    // Liquidity pool pair
+    pub lb_pair: Pubkey,
+    // Duration of an observation sample, in seconds
+    pub sample_lifetime: u16,
+    // History duration the oracle should cover, in seconds
+    pub target_history_duration: u32,
+    // Whether swap grow the oracle until the target history duration is covered
+    pub auto_growth: bool,
+}
@@ -0,0 +1,39 @@
+use crate::errors::LBError;
+use crate::events::AcceptAdminRole as AcceptAdminRoleEvent;
+use crate::state::admin_config::{AdminConfig, AdminRole};
+use crate::utils::seeds::ADMIN_CONFIG;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptAdminRole<'info> {
+    #[account(
+        mut,
+        seeds = [ADMIN_CONFIG],
+        bump,
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+
+    /// Pending authority of the role. Can be a PDA, such as a multisig vault, signing through CPI.
+    pub pending_authority: Signer<'info>,
+}
+
+pub fn handle(ctx: Context<AcceptAdminRole>, role: AdminRole) -> Result<()> {
+    let admin_config = &mut ctx.accounts.admin_config;
+    let pending_authority = admin_config.get_pending_authority(role);
+    require!(
+        pending_authority.ne(&Pubkey::default())
+            && pending_authority.eq(&ctx.accounts.pending_authority.key()),
+        LBError::InvalidAdmin
+    );
+
+    let old_authority = admin_config.accept(role);
+
+    emit_cpi!(AcceptAdminRoleEvent {
+        role: role.into(),
+        old_authority,
+        new_authority: pending_authority,
+    });
+
+    Ok(())
+}
@@ -1,6 +1,8 @@
-use crate::assert_eq_admin;
+use crate::assert_eq_role_admin;
 use crate::errors::LBError;
+use crate::state::admin_config::AdminRole;
 use crate::state::preset_parameters::PresetParameter;
+use crate::utils::seeds::ADMIN_CONFIG;
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
@@ -13,13 +15,17 @@ pub struct ClosePresetParameter<'info> {
 
     #[account(
         mut,
-        constraint = assert_eq_admin(admin.key()) @ LBError::InvalidAdmin
+        constraint = assert_eq_role_admin(&admin_config, AdminRole::FeeAdmin, admin.key()) @ LBError::InvalidAdmin
     )]
     pub admin: Signer<'info>,
 
     /// CHECK: Account to receive closed account rental SOL
     #[account(mut)]
     pub rent_receiver: UncheckedAccount<'info>,
+
+    /// CHECK: Admin config. The predefined admins are used until it is initialized.
+    #[account(seeds = [ADMIN_CONFIG], bump)]
+    pub admin_config: UncheckedAccount<'info>,
 }
 
 pub fn handle(_ctx: Context<ClosePresetParameter>) -> Result<()> {
@@ -0,0 +1,72 @@
+use crate::assert_eq_admin;
+use crate::errors::LBError;
+use crate::events::InitializeAdminConfig as InitializeAdminConfigEvent;
+use crate::state::admin_config::AdminConfig;
+use crate::utils::seeds::ADMIN_CONFIG;
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy)]
+pub struct InitAdminConfigParameter {
+    /// Authority of the fee admin role
+    pub fee_admin: Pubkey,
+    /// Authority of the pause guardian role
+    pub pause_guardian: Pubkey,
+    /// Authority of the reward admin role
+    pub reward_admin: Pubkey,
+    /// Authority of the launch pool admin role
+    pub launch_pool_admin: Pubkey,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializeAdminConfig<'info> {
+    #[account(
+        init,
+        seeds = [ADMIN_CONFIG],
+        bump,
+        payer = admin,
+        space = 8 + AdminConfig::INIT_SPACE,
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+
+    #[account(
+        mut,
+        constraint = assert_eq_admin(admin.key()) @ LBError::InvalidAdmin,
+    )]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Initialize the admin config with the authority of each role. From then on, the predefined admins no longer have access to the admin endpoints.
+pub fn handle(
+    ctx: Context<InitializeAdminConfig>,
+    parameter: InitAdminConfigParameter,
+) -> Result<()> {
+    let InitAdminConfigParameter {
+        fee_admin,
+        pause_guardian,
+        reward_admin,
+        launch_pool_admin,
+    } = parameter;
+
+    // Indexed by AdminRole
+    let authorities = [fee_admin, pause_guardian, reward_admin, launch_pool_admin];
+    require!(
+        authorities
+            .iter()
+            .all(|authority| authority.ne(&Pubkey::default())),
+        LBError::InvalidInput
+    );
+
+    ctx.accounts.admin_config.init(authorities);
+
+    emit_cpi!(InitializeAdminConfigEvent {
+        fee_admin,
+        pause_guardian,
+        reward_admin,
+        launch_pool_admin,
+    });
+
+    Ok(())
+}
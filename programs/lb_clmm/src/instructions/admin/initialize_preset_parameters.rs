@@ -1,6 +1,8 @@
-use crate::assert_eq_admin;
+use crate::assert_eq_role_admin;
 use crate::errors::LBError;
+use crate::state::admin_config::AdminRole;
 use crate::state::preset_parameters::PresetParameter;
+use crate::utils::seeds::ADMIN_CONFIG;
 use crate::utils::seeds::PRESET_PARAMETER;
 use anchor_lang::prelude::*;
 
@@ -46,12 +48,16 @@ pub struct InitializePresetParameter<'info> {
 
     #[account(
         mut,
-        constraint = assert_eq_admin(admin.key()) @ LBError::InvalidAdmin
+        constraint = assert_eq_role_admin(&admin_config, AdminRole::FeeAdmin, admin.key()) @ LBError::InvalidAdmin
     )]
     pub admin: Signer<'info>,
 
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
+
+    /// CHECK: Admin config. The predefined admins are used until it is initialized.
+    #[account(seeds = [ADMIN_CONFIG], bump)]
+    pub admin_config: UncheckedAccount<'info>,
 }
 
 pub fn handle(ctx: Context<InitializePresetParameter>, ix: InitPresetParametersIx) -> Result<()> {
@@ -0,0 +1,52 @@
+use crate::assert_eq_role_admin;
+use crate::errors::LBError;
+use crate::events::ReferrerUpdate;
+use crate::state::admin_config::AdminRole;
+use crate::state::referral::Referrer;
+use crate::utils::seeds::ADMIN_CONFIG;
+use crate::utils::seeds::REFERRER;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializeReferrer<'info> {
+    #[account(
+        init,
+        seeds = [
+            REFERRER,
+            owner.key().as_ref(),
+        ],
+        bump,
+        payer = admin,
+        space = 8 + Referrer::INIT_SPACE,
+    )]
+    pub referrer: Account<'info, Referrer>,
+
+    /// CHECK: Owner of the referrer, allowed to claim the referral fee
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = assert_eq_role_admin(&admin_config, AdminRole::FeeAdmin, admin.key()) @ LBError::InvalidAdmin,
+    )]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Admin config. The predefined admins are used until it is initialized.
+    #[account(seeds = [ADMIN_CONFIG], bump)]
+    pub admin_config: UncheckedAccount<'info>,
+}
+
+pub fn handle(ctx: Context<InitializeReferrer>, fee_share: u16) -> Result<()> {
+    let owner = ctx.accounts.owner.key();
+    ctx.accounts.referrer.init(owner, fee_share)?;
+
+    emit_cpi!(ReferrerUpdate {
+        referrer: ctx.accounts.referrer.key(),
+        owner,
+        fee_share,
+    });
+
+    Ok(())
+}
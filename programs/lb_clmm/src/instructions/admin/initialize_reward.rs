@@ -1,6 +1,8 @@
-use crate::assert_eq_admin;
+use crate::assert_eq_role_admin;
 use crate::errors::LBError;
+use crate::state::admin_config::AdminRole;
 use crate::state::lb_pair::LbPair;
+use crate::utils::seeds::ADMIN_CONFIG;
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
@@ -28,13 +30,17 @@ pub struct InitializeReward<'info> {
 
     #[account(
         mut,
-        constraint = assert_eq_admin(admin.key()) @ LBError::InvalidAdmin,
+        constraint = assert_eq_role_admin(&admin_config, AdminRole::RewardAdmin, admin.key()) @ LBError::InvalidAdmin,
     )]
     pub admin: Signer<'info>,
 
     pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
+
+    /// CHECK: Admin config. The predefined admins are used until it is initialized.
+    #[account(seeds = [ADMIN_CONFIG], bump)]
+    pub admin_config: UncheckedAccount<'info>,
 }
 
 pub fn handle(
@@ -1,21 +1,35 @@
+pub mod accept_admin_role;
 pub mod close_preset_parameter;
+pub mod initialize_admin_config;
 pub mod initialize_preset_parameters;
+pub mod initialize_referrer;
 pub mod initialize_reward;
+pub mod propose_admin_role;
 pub mod set_activation_point;
 pub mod set_pre_activation_duration;
 pub mod set_pre_activation_swap_address;
 pub mod toggle_pair_status;
+pub mod update_circuit_breaker;
 pub mod update_fee_parameters;
+pub mod update_oracle_parameters;
+pub mod update_referrer_fee_share;
 pub mod update_reward_duration;
 pub mod update_reward_funder;
 
+pub use accept_admin_role::*;
 pub use close_preset_parameter::*;
+pub use initialize_admin_config::*;
 pub use initialize_preset_parameters::*;
+pub use initialize_referrer::*;
 pub use initialize_reward::*;
+pub use propose_admin_role::*;
 pub use set_activation_point::*;
 pub use set_pre_activation_duration::*;
 pub use set_pre_activation_swap_address::*;
 pub use toggle_pair_status::*;
+pub use update_circuit_breaker::*;
 pub use update_fee_parameters::*;
+pub use update_oracle_parameters::*;
+pub use update_referrer_fee_share::*;
 pub use update_reward_duration::*;
 pub use update_reward_funder::*;
@@ -0,0 +1,43 @@
+use crate::errors::LBError;
+use crate::events::ProposeAdminRole as ProposeAdminRoleEvent;
+use crate::state::admin_config::{AdminConfig, AdminRole};
+use crate::utils::seeds::ADMIN_CONFIG;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ProposeAdminRole<'info> {
+    #[account(
+        mut,
+        seeds = [ADMIN_CONFIG],
+        bump,
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Propose a new authority for the role. The change take effect once accepted by the new authority. Proposing the default pubkey cancels the pending change.
+pub fn handle(
+    ctx: Context<ProposeAdminRole>,
+    role: AdminRole,
+    new_authority: Pubkey,
+) -> Result<()> {
+    let admin_config = &mut ctx.accounts.admin_config;
+    require!(
+        admin_config
+            .get_authority(role)
+            .eq(&ctx.accounts.authority.key()),
+        LBError::InvalidAdmin
+    );
+
+    admin_config.propose(role, new_authority);
+
+    emit_cpi!(ProposeAdminRoleEvent {
+        role: role.into(),
+        authority: ctx.accounts.authority.key(),
+        pending_authority: new_authority,
+    });
+
+    Ok(())
+}
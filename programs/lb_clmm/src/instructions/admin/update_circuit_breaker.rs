@@ -0,0 +1,54 @@
+use crate::assert_eq_role_admin;
+use crate::errors::LBError;
+use crate::events::CircuitBreakerParametersUpdate;
+use crate::state::admin_config::AdminRole;
+use crate::state::lb_pair::LbPair;
+use crate::utils::seeds::ADMIN_CONFIG;
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CircuitBreakerParameter {
+    /// Maximum number of bins the active bin can move away from the time weighted active bin of the window. 0 = disabled.
+    pub max_bin_movement: u16,
+    /// Time window, in seconds, of the time weighted active bin measured from the oracle
+    pub window: u16,
+    /// Duration, in seconds, swap stay halted after the circuit breaker tripped
+    pub cooldown: u32,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateCircuitBreaker<'info> {
+    #[account(mut)]
+    pub lb_pair: AccountLoader<'info, LbPair>,
+
+    #[account(constraint = assert_eq_role_admin(&admin_config, AdminRole::PauseGuardian, admin.key()) @ LBError::InvalidAdmin)]
+    pub admin: Signer<'info>,
+
+    /// CHECK: Admin config. The predefined admins are used until it is initialized.
+    #[account(seeds = [ADMIN_CONFIG], bump)]
+    pub admin_config: UncheckedAccount<'info>,
+}
+
+/// Configure the circuit breaker of the pair. A halted pair resume swap immediately.
+pub fn handle(
+    ctx: Context<UpdateCircuitBreaker>,
+    parameter: CircuitBreakerParameter,
+) -> Result<()> {
+    let mut lb_pair = ctx.accounts.lb_pair.load_mut()?;
+
+    lb_pair.circuit_breaker.update(
+        parameter.max_bin_movement,
+        parameter.window,
+        parameter.cooldown,
+    )?;
+
+    emit_cpi!(CircuitBreakerParametersUpdate {
+        lb_pair: ctx.accounts.lb_pair.key(),
+        max_bin_movement: parameter.max_bin_movement,
+        window: parameter.window,
+        cooldown: parameter.cooldown,
+    });
+
+    Ok(())
+}
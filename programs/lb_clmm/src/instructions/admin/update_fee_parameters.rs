@@ -1,6 +1,8 @@
-use crate::assert_eq_admin;
+use crate::assert_eq_role_admin;
 use crate::errors::LBError;
+use crate::state::admin_config::AdminRole;
 use crate::state::lb_pair::LbPair;
+use crate::utils::seeds::ADMIN_CONFIG;
 use anchor_lang::prelude::*;
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
@@ -17,8 +19,12 @@ pub struct UpdateFeeParameters<'info> {
     #[account(mut)]
     pub lb_pair: AccountLoader<'info, LbPair>,
 
-    #[account(constraint = assert_eq_admin(admin.key()) @ LBError::InvalidAdmin)]
+    #[account(constraint = assert_eq_role_admin(&admin_config, AdminRole::FeeAdmin, admin.key()) @ LBError::InvalidAdmin)]
     pub admin: Signer<'info>,
+
+    /// CHECK: Admin config. The predefined admins are used until it is initialized.
+    #[account(seeds = [ADMIN_CONFIG], bump)]
+    pub admin_config: UncheckedAccount<'info>,
 }
 
 pub fn handle(ctx: Context<UpdateFeeParameters>, fee_parameter: FeeParameter) -> Result<()> {
@@ -0,0 +1,51 @@
+use crate::assert_eq_role_admin;
+use crate::errors::LBError;
+use crate::events::OracleParametersUpdate;
+use crate::state::admin_config::AdminRole;
+use crate::state::lb_pair::LbPair;
+use crate::utils::seeds::ADMIN_CONFIG;
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct OracleParameter {
+    /// Duration, in seconds, before a new observation sample is created
+    pub sample_lifetime: u16,
+    /// Duration, in seconds, of the history the oracle should cover
+    pub target_history_duration: u32,
+    /// Whether swap grow the oracle until the target history duration is covered
+    pub auto_growth: bool,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateOracleParameters<'info> {
+    #[account(mut)]
+    pub lb_pair: AccountLoader<'info, LbPair>,
+
+    #[account(constraint = assert_eq_role_admin(&admin_config, AdminRole::FeeAdmin, admin.key()) @ LBError::InvalidAdmin)]
+    pub admin: Signer<'info>,
+
+    /// CHECK: Admin config. The predefined admins are used until it is initialized.
+    #[account(seeds = [ADMIN_CONFIG], bump)]
+    pub admin_config: UncheckedAccount<'info>,
+}
+
+/// Configure the oracle sampling of the pair. The new sample lifetime apply from the next observation sample.
+pub fn handle(ctx: Context<UpdateOracleParameters>, parameter: OracleParameter) -> Result<()> {
+    let mut lb_pair = ctx.accounts.lb_pair.load_mut()?;
+
+    lb_pair.oracle_parameters.update(
+        parameter.sample_lifetime,
+        parameter.target_history_duration,
+        parameter.auto_growth,
+    )?;
+
+    emit_cpi!(OracleParametersUpdate {
+        lb_pair: ctx.accounts.lb_pair.key(),
+        sample_lifetime: parameter.sample_lifetime,
+        target_history_duration: parameter.target_history_duration,
+        auto_growth: parameter.auto_growth,
+    });
+
+    Ok(())
+}
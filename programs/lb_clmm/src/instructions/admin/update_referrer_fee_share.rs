@@ -0,0 +1,33 @@
+use crate::assert_eq_role_admin;
+use crate::errors::LBError;
+use crate::events::ReferrerUpdate;
+use crate::state::admin_config::AdminRole;
+use crate::state::referral::Referrer;
+use crate::utils::seeds::ADMIN_CONFIG;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateReferrerFeeShare<'info> {
+    #[account(mut)]
+    pub referrer: Account<'info, Referrer>,
+
+    #[account(constraint = assert_eq_role_admin(&admin_config, AdminRole::FeeAdmin, admin.key()) @ LBError::InvalidAdmin)]
+    pub admin: Signer<'info>,
+
+    /// CHECK: Admin config. The predefined admins are used until it is initialized.
+    #[account(seeds = [ADMIN_CONFIG], bump)]
+    pub admin_config: UncheckedAccount<'info>,
+}
+
+pub fn handle(ctx: Context<UpdateReferrerFeeShare>, fee_share: u16) -> Result<()> {
+    ctx.accounts.referrer.update_fee_share(fee_share)?;
+
+    emit_cpi!(ReferrerUpdate {
+        referrer: ctx.accounts.referrer.key(),
+        owner: ctx.accounts.referrer.owner,
+        fee_share,
+    });
+
+    Ok(())
+}
@@ -1,7 +1,9 @@
-use crate::assert_eq_admin;
+use crate::assert_eq_role_admin;
 use crate::errors::LBError;
+use crate::state::admin_config::AdminRole;
 use crate::state::bin::BinArray;
 use crate::state::lb_pair::LbPair;
+use crate::utils::seeds::ADMIN_CONFIG;
 use anchor_lang::prelude::*;
 
 #[event_cpi]
@@ -12,7 +14,7 @@ pub struct UpdateRewardDuration<'info> {
     pub lb_pair: AccountLoader<'info, LbPair>,
 
     #[account(
-        constraint = assert_eq_admin(admin.key()) @ LBError::InvalidAdmin,
+        constraint = assert_eq_role_admin(&admin_config, AdminRole::RewardAdmin, admin.key()) @ LBError::InvalidAdmin,
     )]
     pub admin: Signer<'info>,
 
@@ -21,6 +23,10 @@ pub struct UpdateRewardDuration<'info> {
         has_one = lb_pair
     )]
     pub bin_array: AccountLoader<'info, BinArray>,
+
+    /// CHECK: Admin config. The predefined admins are used until it is initialized.
+    #[account(seeds = [ADMIN_CONFIG], bump)]
+    pub admin_config: UncheckedAccount<'info>,
 }
 
 pub fn handle(
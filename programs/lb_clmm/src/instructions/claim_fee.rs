@@ -1,5 +1,6 @@
 use crate::authorize_claim_fee_position;
 use crate::state::{bin::BinArray, lb_pair::LbPair, position::PositionV2};
+use crate::utils::seeds::ADMIN_CONFIG;
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 #[event_cpi]
@@ -17,7 +18,7 @@ pub struct ClaimFee<'info> {
     #[account(
         mut,
         has_one = lb_pair,
-        constraint = authorize_claim_fee_position(&position, sender.key())?
+        constraint = authorize_claim_fee_position(&position, &position_nft_account, sender.key(), admin_config.as_deref())?
     )]
     pub position: AccountLoader<'info, PositionV2>,
 
@@ -48,6 +49,13 @@ pub struct ClaimFee<'info> {
     pub token_y_mint: Box<InterfaceAccount<'info, Mint>>,
 
     pub token_program: Interface<'info, TokenInterface>,
+
+    /// Token account holding the position NFT. Only required for tokenized position.
+    pub position_nft_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// CHECK: Admin config. Only required for the launch pool admin to claim the fee of a position with a fee owner.
+    #[account(seeds = [ADMIN_CONFIG], bump)]
+    pub admin_config: Option<UncheckedAccount<'info>>,
 }
 
 pub fn handle(ctx: Context<ClaimFee>) -> Result<()> {
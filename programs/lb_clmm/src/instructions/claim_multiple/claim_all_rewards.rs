@@ -0,0 +1,107 @@
+use super::{load_and_update_position, split_position_accounts, ACCOUNTS_PER_POSITION};
+use crate::authorize_modify_position;
+use crate::get_position_owner;
+use crate::constants::NUM_REWARDS;
+use crate::errors::LBError;
+use crate::events::ClaimReward as ClaimRewardEvent;
+use crate::math::safe_math::SafeMath;
+use crate::state::lb_pair::LbPair;
+use crate::utils::token_2022::transfer_checked_with_hook;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(reward_index: u64)]
+pub struct ClaimAllRewards<'info> {
+    #[account(mut)]
+    pub lb_pair: AccountLoader<'info, LbPair>,
+
+    pub sender: Signer<'info>,
+
+    #[account(mut)]
+    pub reward_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        constraint = user_token_account.mint == reward_mint.key() @ LBError::InvalidTokenMint,
+    )]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Claim the reward of `reward_index` of `position_count` positions of the pair. The remaining accounts are [position, bin_array_lower, bin_array_upper]
+/// of each position, followed by the transfer hook accounts of the reward mint.
+pub fn handle<'a, 'b, 'c: 'info, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, ClaimAllRewards<'info>>,
+    reward_index: u64,
+    position_count: u8,
+) -> Result<()> {
+    let index: usize = reward_index
+        .try_into()
+        .map_err(|_| LBError::TypeCastFailed)?;
+    require!(index < NUM_REWARDS, LBError::InvalidRewardIndex);
+
+    {
+        let lb_pair = ctx.accounts.lb_pair.load()?;
+        let reward_info = &lb_pair.reward_infos[index];
+        require!(reward_info.initialized(), LBError::RewardUninitialized);
+        require!(
+            reward_info.vault == ctx.accounts.reward_vault.key(),
+            LBError::InvalidRewardVault
+        );
+        require!(
+            reward_info.mint == ctx.accounts.reward_mint.key(),
+            LBError::InvalidTokenMint
+        );
+    }
+
+    let (position_accounts, transfer_hook_accounts) =
+        split_position_accounts(ctx.remaining_accounts, position_count)?;
+
+    let mut total_reward = 0u64;
+
+    for accounts in position_accounts.chunks(ACCOUNTS_PER_POSITION) {
+        let position = load_and_update_position(
+            &ctx.accounts.lb_pair,
+            accounts,
+            ctx.accounts.sender.key(),
+            |position, sender| authorize_modify_position(position, &None, sender),
+        )?;
+
+        let mut position_state = position.load_mut()?;
+        let position_reward = position_state.get_total_reward(index)?;
+        position_state.reset_all_pending_reward(index);
+        position_state.accumulate_total_claimed_rewards(index, position_reward);
+
+        total_reward = total_reward.safe_add(position_reward)?;
+
+        emit_cpi!(ClaimRewardEvent {
+            lb_pair: ctx.accounts.lb_pair.key(),
+            position: position.key(),
+            owner: get_position_owner(&position_state, &None)?,
+            reward_index,
+            total_reward: position_reward,
+        });
+    }
+
+    if total_reward > 0 {
+        let lb_pair = ctx.accounts.lb_pair.load()?;
+        let signer_seeds = &[&lb_pair.seeds()?[..]];
+
+        transfer_checked_with_hook(
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.reward_vault.to_account_info(),
+            &ctx.accounts.reward_mint,
+            ctx.accounts.user_token_account.to_account_info(),
+            ctx.accounts.lb_pair.to_account_info(),
+            transfer_hook_accounts,
+            total_reward,
+            signer_seeds,
+        )?;
+    }
+
+    Ok(())
+}
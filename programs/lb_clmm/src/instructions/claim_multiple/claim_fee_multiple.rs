@@ -0,0 +1,127 @@
+use super::{load_and_update_position, split_position_accounts, ACCOUNTS_PER_POSITION};
+use crate::authorize_claim_fee_position;
+use crate::get_position_owner;
+use crate::errors::LBError;
+use crate::events::ClaimFee as ClaimFeeEvent;
+use crate::math::safe_math::SafeMath;
+use crate::state::lb_pair::LbPair;
+use crate::utils::seeds::ADMIN_CONFIG;
+use crate::utils::token_2022::transfer_checked_with_hook;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimFeeMultiple<'info> {
+    #[account(
+        mut,
+        has_one = reserve_x,
+        has_one = reserve_y,
+        has_one = token_x_mint,
+        has_one = token_y_mint,
+    )]
+    pub lb_pair: AccountLoader<'info, LbPair>,
+
+    pub sender: Signer<'info>,
+
+    #[account(mut)]
+    pub reserve_x: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub reserve_y: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = user_token_x.mint == token_x_mint.key() @ LBError::InvalidTokenMint,
+    )]
+    pub user_token_x: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = user_token_y.mint == token_y_mint.key() @ LBError::InvalidTokenMint,
+    )]
+    pub user_token_y: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_x_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub token_y_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_x_program: Interface<'info, TokenInterface>,
+    pub token_y_program: Interface<'info, TokenInterface>,
+
+    /// CHECK: Admin config. Only required for the launch pool admin to claim the fee of positions with a fee owner.
+    #[account(seeds = [ADMIN_CONFIG], bump)]
+    pub admin_config: Option<UncheckedAccount<'info>>,
+}
+
+/// Claim the fee of `position_count` positions of the pair. The remaining accounts are [position, bin_array_lower, bin_array_upper]
+/// of each position, followed by the transfer hook accounts of token X and Y.
+pub fn handle<'a, 'b, 'c: 'info, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, ClaimFeeMultiple<'info>>,
+    position_count: u8,
+) -> Result<()> {
+    let (position_accounts, transfer_hook_accounts) =
+        split_position_accounts(ctx.remaining_accounts, position_count)?;
+
+    let mut total_fee_x = 0u64;
+    let mut total_fee_y = 0u64;
+
+    for accounts in position_accounts.chunks(ACCOUNTS_PER_POSITION) {
+        let position = load_and_update_position(
+            &ctx.accounts.lb_pair,
+            accounts,
+            ctx.accounts.sender.key(),
+            |position, sender| {
+                authorize_claim_fee_position(
+                    position,
+                    &None,
+                    sender,
+                    ctx.accounts.admin_config.as_deref(),
+                )
+            },
+        )?;
+
+        let mut position_state = position.load_mut()?;
+        let (fee_x, fee_y) = position_state.claim_fee()?;
+        position_state.accumulate_total_claimed_fees(fee_x, fee_y);
+
+        total_fee_x = total_fee_x.safe_add(fee_x)?;
+        total_fee_y = total_fee_y.safe_add(fee_y)?;
+
+        emit_cpi!(ClaimFeeEvent {
+            lb_pair: ctx.accounts.lb_pair.key(),
+            position: position.key(),
+            owner: get_position_owner(&position_state, &None)?,
+            fee_x,
+            fee_y,
+        });
+    }
+
+    let lb_pair = ctx.accounts.lb_pair.load()?;
+    let signer_seeds = &[&lb_pair.seeds()?[..]];
+
+    if total_fee_x > 0 {
+        transfer_checked_with_hook(
+            ctx.accounts.token_x_program.to_account_info(),
+            ctx.accounts.reserve_x.to_account_info(),
+            &ctx.accounts.token_x_mint,
+            ctx.accounts.user_token_x.to_account_info(),
+            ctx.accounts.lb_pair.to_account_info(),
+            transfer_hook_accounts,
+            total_fee_x,
+            signer_seeds,
+        )?;
+    }
+
+    if total_fee_y > 0 {
+        transfer_checked_with_hook(
+            ctx.accounts.token_y_program.to_account_info(),
+            ctx.accounts.reserve_y.to_account_info(),
+            &ctx.accounts.token_y_mint,
+            ctx.accounts.user_token_y.to_account_info(),
+            ctx.accounts.lb_pair.to_account_info(),
+            transfer_hook_accounts,
+            total_fee_y,
+            signer_seeds,
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+pub mod claim_all_rewards;
+pub mod claim_fee_multiple;
+
+pub use claim_all_rewards::*;
+pub use claim_fee_multiple::*;
+
+use crate::errors::LBError;
+use crate::manager::bin_array_manager::BinArrayManager;
+use crate::math::safe_math::SafeMath;
+use crate::state::bin::BinArray;
+use crate::state::lb_pair::LbPair;
+use crate::state::position::PositionV2;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+/// Number of remaining accounts of each position. [position, bin_array_lower, bin_array_upper]
+pub const ACCOUNTS_PER_POSITION: usize = 3;
+
+/// Split the remaining accounts into the accounts of the positions, and the transfer hook accounts following them
+pub fn split_position_accounts<'c, 'info>(
+    remaining_accounts: &'c [AccountInfo<'info>],
+    position_count: u8,
+) -> Result<(&'c [AccountInfo<'info>], &'c [AccountInfo<'info>])> {
+    let position_accounts_len = usize::from(position_count).safe_mul(ACCOUNTS_PER_POSITION)?;
+
+    require!(
+        position_count > 0 && remaining_accounts.len() >= position_accounts_len,
+        LBError::InvalidInput
+    );
+
+    Ok(remaining_accounts.split_at(position_accounts_len))
+}
+
+/// Load the position of the accounts, and update its fee and reward earning from its bin arrays.
+/// Tokenized positions are not supported, as the position NFT account is not part of the accounts.
+pub fn load_and_update_position<'info>(
+    lb_pair: &AccountLoader<'info, LbPair>,
+    accounts: &'info [AccountInfo<'info>],
+    sender: Pubkey,
+    authorize: impl Fn(&AccountLoader<'info, PositionV2>, Pubkey) -> Result<bool>,
+) -> Result<AccountLoader<'info, PositionV2>> {
+    let position = AccountLoader::<PositionV2>::try_from(&accounts[0])?;
+    require!(
+        position.load()?.lb_pair == lb_pair.key(),
+        LBError::InvalidPosition
+    );
+    require!(authorize(&position, sender)?, LBError::UnauthorizedAccess);
+
+    let bin_array_lower = AccountLoader::<BinArray>::try_from(&accounts[1])?;
+    let bin_array_upper = AccountLoader::<BinArray>::try_from(&accounts[2])?;
+    require!(
+        bin_array_lower.load()?.lb_pair == lb_pair.key()
+            && bin_array_upper.load()?.lb_pair == lb_pair.key(),
+        LBError::InvalidBinArray
+    );
+
+    // Both bin arrays are the same account when the position is within a single bin array
+    let mut bin_arrays = if bin_array_lower.key() == bin_array_upper.key() {
+        vec![bin_array_lower.load_mut()?]
+    } else {
+        vec![bin_array_lower.load_mut()?, bin_array_upper.load_mut()?]
+    };
+
+    {
+        let mut lb_pair = lb_pair.load_mut()?;
+        let mut position_state = position.load_mut()?;
+
+        let mut bin_array_manager = BinArrayManager::new(&mut bin_arrays)?;
+        bin_array_manager.validate_bin_arrays(position_state.lower_bin_id)?;
+        bin_array_manager.update_rewards(&mut lb_pair)?;
+
+        position_state.update_earning_per_token_stored(&bin_array_manager)?;
+        position_state.set_last_updated_at(Clock::get()?.unix_timestamp);
+    }
+
+    drop(bin_arrays);
+
+    Ok(position)
+}
@@ -0,0 +1,91 @@
+use crate::events::ClaimReferralFee as ClaimReferralFeeEvent;
+use crate::state::lb_pair::LbPair;
+use crate::state::referral::{ReferralFee, Referrer};
+use crate::utils::token_2022::transfer_checked_with_hook;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimReferralFee<'info> {
+    #[account(
+        has_one = reserve_x,
+        has_one = reserve_y,
+        has_one = token_x_mint,
+        has_one = token_y_mint,
+    )]
+    pub lb_pair: AccountLoader<'info, LbPair>,
+
+    #[account(
+        mut,
+        has_one = lb_pair,
+        has_one = referrer,
+    )]
+    pub referral_fee: Account<'info, ReferralFee>,
+
+    #[account(has_one = owner)]
+    pub referrer: Account<'info, Referrer>,
+
+    #[account(mut)]
+    pub reserve_x: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub reserve_y: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub receiver_token_x: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub receiver_token_y: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_x_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub token_y_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub owner: Signer<'info>,
+
+    pub token_x_program: Interface<'info, TokenInterface>,
+    pub token_y_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handle<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, ClaimReferralFee<'info>>,
+) -> Result<()> {
+    let (fee_x, fee_y) = ctx.accounts.referral_fee.claim();
+
+    let lb_pair = ctx.accounts.lb_pair.load()?;
+    let signer_seeds = &[&lb_pair.seeds()?[..]];
+
+    if fee_x > 0 {
+        transfer_checked_with_hook(
+            ctx.accounts.token_x_program.to_account_info(),
+            ctx.accounts.reserve_x.to_account_info(),
+            &ctx.accounts.token_x_mint,
+            ctx.accounts.receiver_token_x.to_account_info(),
+            ctx.accounts.lb_pair.to_account_info(),
+            ctx.remaining_accounts,
+            fee_x,
+            signer_seeds,
+        )?;
+    }
+
+    if fee_y > 0 {
+        transfer_checked_with_hook(
+            ctx.accounts.token_y_program.to_account_info(),
+            ctx.accounts.reserve_y.to_account_info(),
+            &ctx.accounts.token_y_mint,
+            ctx.accounts.receiver_token_y.to_account_info(),
+            ctx.accounts.lb_pair.to_account_info(),
+            ctx.remaining_accounts,
+            fee_y,
+            signer_seeds,
+        )?;
+    }
+
+    emit_cpi!(ClaimReferralFeeEvent {
+        lb_pair: ctx.accounts.lb_pair.key(),
+        referrer: ctx.accounts.referrer.key(),
+        owner: ctx.accounts.owner.key(),
+        fee_x,
+        fee_y,
+    });
+
+    Ok(())
+}
@@ -13,7 +13,7 @@ pub struct ClaimReward<'info> {
     #[account(
         mut,
         has_one = lb_pair,
-        constraint = authorize_modify_position(&position, sender.key())?
+        constraint = authorize_modify_position(&position, &position_nft_account, sender.key())?
     )]
     pub position: AccountLoader<'info, PositionV2>,
 
@@ -38,6 +38,9 @@ pub struct ClaimReward<'info> {
     pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     pub token_program: Interface<'info, TokenInterface>,
+
+    /// Token account holding the position NFT. Only required for tokenized position.
+    pub position_nft_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 }
 
 // TODO: Should we pass in range of bin we are going to collect reward ? It could help us in heap / compute unit issue by chunking into multiple tx.
@@ -0,0 +1,147 @@
+use crate::assert_eq_role_admin;
+use crate::errors::LBError;
+use crate::events::BinArrayClose as BinArrayCloseEvent;
+use crate::state::admin_config::AdminRole;
+use crate::state::bin_array_bitmap_extension::BinArrayBitmapExtension;
+use crate::state::bin_array_funder::BinArrayFunder;
+use crate::state::{bin::BinArray, lb_pair::LbPair};
+use crate::utils::seeds::{ADMIN_CONFIG, BIN_ARRAY_FUNDER};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseBinArray<'info> {
+    pub lb_pair: AccountLoader<'info, LbPair>,
+
+    #[account(
+        has_one = lb_pair,
+    )]
+    pub bin_array_bitmap_extension: Option<AccountLoader<'info, BinArrayBitmapExtension>>,
+
+    #[account(
+        mut,
+        has_one = lb_pair,
+        close = funder
+    )]
+    pub bin_array: AccountLoader<'info, BinArray>,
+
+    #[account(
+        mut,
+        has_one = bin_array,
+        has_one = funder,
+        close = funder
+    )]
+    pub bin_array_funder: Account<'info, BinArrayFunder>,
+
+    /// CHECK: Receive the rent of the bin array, checked against the recorded funder
+    #[account(mut)]
+    pub funder: UncheckedAccount<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseLegacyBinArray<'info> {
+    pub lb_pair: AccountLoader<'info, LbPair>,
+
+    #[account(
+        has_one = lb_pair,
+    )]
+    pub bin_array_bitmap_extension: Option<AccountLoader<'info, BinArrayBitmapExtension>>,
+
+    #[account(
+        mut,
+        has_one = lb_pair,
+        close = rent_receiver
+    )]
+    pub bin_array: AccountLoader<'info, BinArray>,
+
+    /// CHECK: Funder record of the bin array, which must not exist. Bin array with a recorded funder is closed by close_bin_array.
+    #[account(
+        seeds = [
+            BIN_ARRAY_FUNDER,
+            bin_array.key().as_ref(),
+        ],
+        bump,
+        constraint = bin_array_funder.data_is_empty() @ LBError::BinArrayFunderRecorded,
+    )]
+    pub bin_array_funder: UncheckedAccount<'info>,
+
+    /// Creator of the pair, or the fee admin
+    #[account(
+        constraint = authority.key() == lb_pair.load()?.creator
+            || assert_eq_role_admin(&admin_config, AdminRole::FeeAdmin, authority.key()) @ LBError::InvalidAdmin,
+    )]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Account to receive the rent of the bin array
+    #[account(mut)]
+    pub rent_receiver: UncheckedAccount<'info>,
+
+    /// CHECK: Admin config. The predefined admins are used until it is initialized.
+    #[account(seeds = [ADMIN_CONFIG], bump)]
+    pub admin_config: UncheckedAccount<'info>,
+}
+
+/// The bin array must be empty, not the active bin array, and unset in the bitmap of the pair
+fn validate_close_bin_array(
+    lb_pair: &AccountLoader<LbPair>,
+    bin_array_bitmap_extension: &Option<AccountLoader<BinArrayBitmapExtension>>,
+    bin_array: &BinArray,
+) -> Result<()> {
+    require!(bin_array.is_zero_liquidity(), LBError::NonEmptyBinArray);
+
+    let bin_array_index = i32::try_from(bin_array.index).map_err(|_| LBError::TypeCastFailed)?;
+    let lb_pair = lb_pair.load()?;
+
+    // The active bin array is kept, swap and flash loan start from it
+    require!(
+        BinArray::bin_id_to_bin_array_index(lb_pair.active_id)? != bin_array_index,
+        LBError::InvalidBinArray
+    );
+
+    require!(
+        !lb_pair.is_bin_array_bit_set(bin_array_bitmap_extension, bin_array_index)?,
+        LBError::InconsistentBinArrayBitmap
+    );
+
+    Ok(())
+}
+
+/// Close a bin array without liquidity. Permissionless, the rent always goes back to the funder of the bin array.
+pub fn handle(ctx: Context<CloseBinArray>) -> Result<()> {
+    let bin_array = ctx.accounts.bin_array.load()?;
+    validate_close_bin_array(
+        &ctx.accounts.lb_pair,
+        &ctx.accounts.bin_array_bitmap_extension,
+        &bin_array,
+    )?;
+
+    emit_cpi!(BinArrayCloseEvent {
+        lb_pair: ctx.accounts.lb_pair.key(),
+        bin_array: ctx.accounts.bin_array.key(),
+        index: bin_array.index,
+        funder: ctx.accounts.funder.key(),
+    });
+
+    Ok(())
+}
+
+/// Close a bin array without liquidity initialized without funder record. The funder is unknown, so only the pair creator or
+/// the fee admin can close it, sending the rent to the rent receiver.
+pub fn handle_legacy(ctx: Context<CloseLegacyBinArray>) -> Result<()> {
+    let bin_array = ctx.accounts.bin_array.load()?;
+    validate_close_bin_array(
+        &ctx.accounts.lb_pair,
+        &ctx.accounts.bin_array_bitmap_extension,
+        &bin_array,
+    )?;
+
+    emit_cpi!(BinArrayCloseEvent {
+        lb_pair: ctx.accounts.lb_pair.key(),
+        bin_array: ctx.accounts.bin_array.key(),
+        index: bin_array.index,
+        funder: ctx.accounts.rent_receiver.key(),
+    });
+
+    Ok(())
+}
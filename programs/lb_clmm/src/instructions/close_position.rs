@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 
 use crate::authorize_modify_position;
 use crate::state::{bin::BinArray, lb_pair::LbPair, position::PositionV2};
+use anchor_spl::token_interface::TokenAccount;
 
 #[event_cpi]
 #[derive(Accounts)]
@@ -9,7 +10,7 @@ pub struct ClosePosition<'info> {
     #[account(
         mut,
         has_one = lb_pair,
-        constraint = authorize_modify_position(&position, sender.key())?,
+        constraint = authorize_modify_position(&position, &position_nft_account, sender.key())?,
         close = rent_receiver
     )]
     pub position: AccountLoader<'info, PositionV2>,
@@ -33,6 +34,9 @@ pub struct ClosePosition<'info> {
     /// CHECK: Account to receive closed account rental SOL
     #[account(mut)]
     pub rent_receiver: UncheckedAccount<'info>,
+
+    /// Token account holding the position NFT. Only required for tokenized position.
+    pub position_nft_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 }
 
 pub fn handle(ctx: Context<ClosePosition>) -> Result<()> {
@@ -0,0 +1,182 @@
+use crate::authorize_modify_position;
+use crate::get_position_owner;
+use crate::errors::LBError;
+use crate::events::CompoundFee as CompoundFeeEvent;
+use crate::instructions::deposit::{
+    validate_add_liquidity_by_strategy_params, LiquidityParameterByStrategy, StrategyParameters,
+    StrategyType,
+};
+use crate::manager::bin_array_manager::BinArrayManager;
+use crate::math::bin_math::get_liquidity;
+use crate::math::safe_math::SafeMath;
+use crate::state::bin::get_liquidity_share;
+use crate::state::bin_array_bitmap_extension::BinArrayBitmapExtension;
+use crate::state::{bin::BinArray, lb_pair::LbPair, position::PositionV2};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Eq, PartialEq, Clone, Debug)]
+pub struct CompoundFeeParameter {
+    /// Active bin that integrator observe off-chain
+    pub active_id: i32,
+    /// max active bin slippage allowed
+    pub max_active_bin_slippage: i32,
+    /// Strategy to distribute the fee into the bins of the position. Only balanced strategies are supported.
+    pub strategy_type: StrategyType,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CompoundFees<'info> {
+    #[account(
+        mut,
+        has_one = lb_pair,
+        constraint = authorize_modify_position(&position, &position_nft_account, sender.key())?
+    )]
+    pub position: AccountLoader<'info, PositionV2>,
+
+    #[account(mut)]
+    pub lb_pair: AccountLoader<'info, LbPair>,
+
+    #[account(
+        mut,
+        has_one = lb_pair,
+    )]
+    pub bin_array_bitmap_extension: Option<AccountLoader<'info, BinArrayBitmapExtension>>,
+
+    #[account(
+        mut,
+        has_one = lb_pair
+    )]
+    pub bin_array_lower: AccountLoader<'info, BinArray>,
+    #[account(
+        mut,
+        has_one = lb_pair
+    )]
+    pub bin_array_upper: AccountLoader<'info, BinArray>,
+
+    pub sender: Signer<'info>,
+
+    /// Token account holding the position NFT. Only required for tokenized position.
+    pub position_nft_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+}
+
+/// Convert the pending fee of the position into liquidity of its bins. The fee is already in the reserves, therefore no token is transferred.
+/// Balanced strategies deposit into the active bin following its composition, so no composition fee is charged.
+pub fn handle(ctx: Context<CompoundFees>, parameter: CompoundFeeParameter) -> Result<()> {
+    require!(
+        matches!(
+            parameter.strategy_type,
+            StrategyType::SpotBalanced | StrategyType::CurveBalanced | StrategyType::BidAskBalanced
+        ),
+        LBError::InvalidStrategyParameters
+    );
+
+    let mut lb_pair = ctx.accounts.lb_pair.load_mut()?;
+    let mut position = ctx.accounts.position.load_mut()?;
+
+    let strategy_parameters = StrategyParameters {
+        min_bin_id: position.lower_bin_id,
+        max_bin_id: position.upper_bin_id,
+        strategy_type: parameter.strategy_type,
+        ..Default::default()
+    };
+    validate_add_liquidity_by_strategy_params(
+        parameter.active_id,
+        lb_pair.active_id,
+        parameter.max_active_bin_slippage,
+        &strategy_parameters,
+    )?;
+
+    // Both bin arrays are the same account when the position is within a single bin array
+    let mut bin_arrays = if ctx.accounts.bin_array_lower.key() == ctx.accounts.bin_array_upper.key()
+    {
+        vec![ctx.accounts.bin_array_lower.load_mut()?]
+    } else {
+        vec![
+            ctx.accounts.bin_array_lower.load_mut()?,
+            ctx.accounts.bin_array_upper.load_mut()?,
+        ]
+    };
+    let mut bin_array_manager = BinArrayManager::new(&mut bin_arrays)?;
+    bin_array_manager.validate_bin_arrays(position.lower_bin_id)?;
+    bin_array_manager.update_rewards(&mut lb_pair)?;
+
+    position.update_earning_per_token_stored(&bin_array_manager)?;
+    let (fee_x, fee_y) = position.claim_fee()?;
+
+    let (amount_x_in_active_bin, amount_y_in_active_bin) =
+        match bin_array_manager.get_bin(lb_pair.active_id) {
+            Ok(active_bin) => (active_bin.amount_x, active_bin.amount_y),
+            Err(_) => (0, 0),
+        };
+
+    let liquidity_parameter = LiquidityParameterByStrategy {
+        amount_x: fee_x,
+        amount_y: fee_y,
+        active_id: parameter.active_id,
+        max_active_bin_slippage: parameter.max_active_bin_slippage,
+        strategy_parameters,
+    };
+    let amounts_in_bin = liquidity_parameter.to_amounts_into_bin(
+        lb_pair.active_id,
+        lb_pair.bin_step,
+        amount_x_in_active_bin,
+        amount_y_in_active_bin,
+    )?;
+
+    let zero_liquidity_flags = bin_array_manager.get_zero_liquidity_flags();
+
+    let mut total_amount_x = 0u64;
+    let mut total_amount_y = 0u64;
+
+    for (bin_id, amount_x, amount_y) in amounts_in_bin {
+        if amount_x == 0 && amount_y == 0 {
+            continue;
+        }
+
+        let bin = bin_array_manager.get_bin_mut(bin_id)?;
+        let price = bin.get_or_store_bin_price(bin_id, lb_pair.bin_step)?;
+        let in_liquidity = get_liquidity(amount_x, amount_y, price)?;
+
+        let liquidity_share = if bin.is_zero_liquidity() {
+            in_liquidity
+        } else {
+            let bin_liquidity = get_liquidity(bin.amount_x, bin.amount_y, price)?;
+            get_liquidity_share(in_liquidity, bin_liquidity, bin.liquidity_supply)?
+        };
+
+        bin.deposit(amount_x, amount_y, liquidity_share)?;
+        position.deposit(bin_id, liquidity_share)?;
+
+        total_amount_x = total_amount_x.safe_add(amount_x)?;
+        total_amount_y = total_amount_y.safe_add(amount_y)?;
+    }
+
+    // Rounding leftover stay claimable
+    position.restore_pending_fee(
+        fee_x.safe_sub(total_amount_x)?,
+        fee_y.safe_sub(total_amount_y)?,
+    )?;
+    position.accumulate_total_claimed_fees(total_amount_x, total_amount_y);
+    position.set_last_updated_at(Clock::get()?.unix_timestamp);
+
+    for (idx, is_zero_liquidity) in zero_liquidity_flags.into_iter().enumerate() {
+        if is_zero_liquidity && !bin_array_manager.get_zero_liquidity_flags()[idx] {
+            lb_pair.flip_bin_array_bit(
+                &ctx.accounts.bin_array_bitmap_extension,
+                bin_array_manager.get_bin_array_index(idx)?,
+            )?;
+        }
+    }
+
+    emit_cpi!(CompoundFeeEvent {
+        lb_pair: ctx.accounts.lb_pair.key(),
+        position: ctx.accounts.position.key(),
+        owner: get_position_owner(&position, &ctx.accounts.position_nft_account)?,
+        amounts: [total_amount_x, total_amount_y],
+        active_bin_id: lb_pair.active_id,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,180 @@
+use crate::constants::MAX_BIN_PER_POSITION;
+use crate::errors::LBError;
+use crate::events::PositionCreate;
+use crate::math::safe_math::SafeMath;
+use crate::state::lb_pair::LbPair;
+use crate::state::position::PositionV2;
+use crate::utils::seeds;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_2022::spl_token_2022::instruction::AuthorityType;
+use anchor_spl::token_interface::{
+    mint_to, set_authority, Mint, MintTo, SetAuthority, TokenAccount, TokenInterface,
+};
+use mpl_token_metadata::accounts::Metadata;
+use mpl_token_metadata::instructions::CreateMetadataAccountV3CpiBuilder;
+use mpl_token_metadata::types::DataV2;
+use mpl_token_metadata::MAX_URI_LENGTH;
+
+pub const POSITION_NFT_SYMBOL: &str = "DLMM-POS";
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializeTokenizedPosition<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        mint::decimals = 0,
+        mint::authority = position,
+        mint::token_program = token_program,
+    )]
+    pub position_nft_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        seeds = [
+            seeds::POSITION.as_ref(),
+            position_nft_mint.key().as_ref(),
+        ],
+        bump,
+        payer = payer,
+        space = 8 + PositionV2::INIT_SPACE,
+    )]
+    pub position: AccountLoader<'info, PositionV2>,
+
+    pub lb_pair: AccountLoader<'info, LbPair>,
+
+    /// CHECK: Initial holder of the position NFT
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = position_nft_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub owner_position_nft_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Metaplex metadata of the position NFT, validated by the token metadata program
+    #[account(
+        mut,
+        address = Metadata::find_pda(&position_nft_mint.key()).0
+    )]
+    pub position_nft_metadata: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// CHECK: Metaplex token metadata program
+    #[account(address = mpl_token_metadata::ID)]
+    pub metadata_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handle(
+    ctx: Context<InitializeTokenizedPosition>,
+    lower_bin_id: i32,
+    width: i32,
+    uri: String,
+) -> Result<()> {
+    require!(
+        width > 0 && width as usize <= MAX_BIN_PER_POSITION,
+        LBError::InvalidPositionWidth
+    );
+    require!(uri.len() <= MAX_URI_LENGTH, LBError::InvalidPositionNftUri);
+
+    let upper_bin_id = lower_bin_id.safe_add(width)?.safe_sub(1)?;
+    let lb_pair = ctx.accounts.lb_pair.key();
+    let position_nft_mint = ctx.accounts.position_nft_mint.key();
+
+    {
+        let mut position = ctx.accounts.position.load_init()?;
+        // Ownership is resolved from the position NFT holder, therefore no owner and operator
+        position.init(
+            lb_pair,
+            Pubkey::default(),
+            Pubkey::default(),
+            lower_bin_id,
+            upper_bin_id,
+            Clock::get()?.unix_timestamp,
+            0,
+            Pubkey::default(),
+        )?;
+        position.position_nft_mint = position_nft_mint;
+    }
+
+    let position_bump = [ctx.bumps.position];
+    let signer_seeds = &[&[
+        seeds::POSITION.as_ref(),
+        position_nft_mint.as_ref(),
+        &position_bump,
+    ][..]];
+
+    mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.position_nft_mint.to_account_info(),
+                to: ctx.accounts.owner_position_nft_account.to_account_info(),
+                authority: ctx.accounts.position.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        1,
+    )?;
+
+    let lb_pair_address = lb_pair.to_string();
+    CreateMetadataAccountV3CpiBuilder::new(&ctx.accounts.metadata_program.to_account_info())
+        .metadata(&ctx.accounts.position_nft_metadata.to_account_info())
+        .mint(&ctx.accounts.position_nft_mint.to_account_info())
+        .mint_authority(&ctx.accounts.position.to_account_info())
+        .payer(&ctx.accounts.payer.to_account_info())
+        .update_authority(&ctx.accounts.position.to_account_info(), true)
+        .system_program(&ctx.accounts.system_program.to_account_info())
+        .rent(Some(&ctx.accounts.rent.to_account_info()))
+        .data(DataV2 {
+            // Eg: DLMM 5rCf [-443636,443636], always within the 32 bytes name limit
+            name: format!(
+                "DLMM {} [{},{}]",
+                &lb_pair_address[..4],
+                lower_bin_id,
+                upper_bin_id
+            ),
+            symbol: POSITION_NFT_SYMBOL.to_string(),
+            uri,
+            seller_fee_basis_points: 0,
+            creators: None,
+            collection: None,
+            uses: None,
+        })
+        .is_mutable(false)
+        .invoke_signed(signer_seeds)?;
+
+    // Fix the supply to 1
+    set_authority(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            SetAuthority {
+                current_authority: ctx.accounts.position.to_account_info(),
+                account_or_mint: ctx.accounts.position_nft_mint.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        AuthorityType::MintTokens,
+        None,
+    )?;
+
+    emit_cpi!(PositionCreate {
+        lb_pair,
+        position: ctx.accounts.position.key(),
+        owner: ctx.accounts.owner.key(),
+    });
+
+    Ok(())
+}
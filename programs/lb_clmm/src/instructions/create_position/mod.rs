@@ -1,7 +1,9 @@
 pub mod initialize_position;
 pub mod initialize_position_by_operator;
 pub mod initialize_position_pda;
+pub mod initialize_tokenized_position;
 
 pub use initialize_position::*;
 pub use initialize_position_by_operator::*;
 pub use initialize_position_pda::*;
+pub use initialize_tokenized_position::*;
@@ -1,12 +1,27 @@
 use crate::authorize_modify_position;
+use crate::constants::BASIS_POINT_MAX;
+use crate::errors::LBError;
+use crate::events::{AddLiquidity as AddLiquidityEvent, CompositionFee as CompositionFeeEvent};
+use crate::manager::bin_array_manager::BinArrayManager;
+use crate::math::bin_math::get_liquidity;
+use crate::math::safe_math::SafeMath;
+use crate::pair_action_access::get_lb_pair_type_access_validator;
+use crate::state::bin::{get_liquidity_share, get_out_amount, Bin};
 use crate::state::bin_array_bitmap_extension::BinArrayBitmapExtension;
 use crate::state::position::PositionV2;
 use crate::state::{bin::BinArray, lb_pair::LbPair};
+use crate::utils::token_2022::{
+    calculate_transfer_fee_included_amount, transfer_checked_with_hook,
+};
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use std::cell::RefMut;
 
+#[derive(Debug, Default)]
 pub struct CompositeDepositInfo {
     pub liquidity_share: u128,
+    pub token_x_fee_amount: u64,
+    pub token_y_fee_amount: u64,
     pub protocol_token_x_fee_amount: u64,
     pub protocol_token_y_fee_amount: u64,
 }
@@ -37,7 +52,7 @@ pub struct ModifyLiquidity<'info> {
     #[account(
         mut,
         has_one = lb_pair,
-        constraint = authorize_modify_position(&position, sender.key())?
+        constraint = authorize_modify_position(&position, &position_nft_account, sender.key())?
     )]
     pub position: AccountLoader<'info, PositionV2>,
 
@@ -89,11 +104,443 @@ pub struct ModifyLiquidity<'info> {
     pub sender: Signer<'info>,
     pub token_x_program: Interface<'info, TokenInterface>,
     pub token_y_program: Interface<'info, TokenInterface>,
+
+    /// Token account holding the position NFT. Only required for tokenized position.
+    pub position_nft_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+}
+
+impl<'info> ModifyLiquidity<'info> {
+    /// Transfer from the user to the reserves, with the transfer fee on top for the reserves to receive the amounts.
+    fn transfer_to_reserves(
+        &self,
+        amount_x: u64,
+        amount_y: u64,
+        transfer_hook_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        let epoch = Clock::get()?.epoch;
+
+        for (amount, token_program, user_token, mint, reserve) in [
+            (
+                amount_x,
+                &self.token_x_program,
+                &self.user_token_x,
+                &self.token_x_mint,
+                &self.reserve_x,
+            ),
+            (
+                amount_y,
+                &self.token_y_program,
+                &self.user_token_y,
+                &self.token_y_mint,
+                &self.reserve_y,
+            ),
+        ] {
+            if amount == 0 {
+                continue;
+            }
+
+            let amount = calculate_transfer_fee_included_amount(
+                &mint.to_account_info().try_borrow_data()?,
+                amount,
+                epoch,
+            )?
+            .amount;
+
+            transfer_checked_with_hook(
+                token_program.to_account_info(),
+                user_token.to_account_info(),
+                mint,
+                reserve.to_account_info(),
+                self.sender.to_account_info(),
+                transfer_hook_accounts,
+                amount,
+                &[],
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Transfer from the reserves to the user, signed by the pair. The user receive the amounts less the transfer fee.
+    pub fn transfer_to_user(
+        &self,
+        amount_x: u64,
+        amount_y: u64,
+        transfer_hook_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        let lb_pair = self.lb_pair.load()?;
+        let signer_seeds = &[&lb_pair.seeds()?[..]];
+
+        for (amount, token_program, reserve, mint, user_token) in [
+            (
+                amount_x,
+                &self.token_x_program,
+                &self.reserve_x,
+                &self.token_x_mint,
+                &self.user_token_x,
+            ),
+            (
+                amount_y,
+                &self.token_y_program,
+                &self.reserve_y,
+                &self.token_y_mint,
+                &self.user_token_y,
+            ),
+        ] {
+            if amount == 0 {
+                continue;
+            }
+
+            transfer_checked_with_hook(
+                token_program.to_account_info(),
+                reserve.to_account_info(),
+                mint,
+                user_token.to_account_info(),
+                self.lb_pair.to_account_info(),
+                transfer_hook_accounts,
+                amount,
+                signer_seeds,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Load the bin arrays covering the position. Both are the same account when the position is within a single bin array.
+pub fn load_position_bin_arrays<'a, 'info>(
+    bin_array_lower: &'a AccountLoader<'info, BinArray>,
+    bin_array_upper: &'a AccountLoader<'info, BinArray>,
+) -> Result<Vec<RefMut<'a, BinArray>>> {
+    if bin_array_lower.key() == bin_array_upper.key() {
+        Ok(vec![bin_array_lower.load_mut()?])
+    } else {
+        Ok(vec![
+            bin_array_lower.load_mut()?,
+            bin_array_upper.load_mut()?,
+        ])
+    }
+}
+
+/// Amounts of token X and Y in the active bin. Zero when the active bin is not covered by the bin arrays.
+pub fn get_amounts_in_active_bin(
+    lb_pair: &LbPair,
+    bin_array_manager: &BinArrayManager,
+) -> (u64, u64) {
+    match bin_array_manager.get_bin(lb_pair.active_id) {
+        Ok(active_bin) => (active_bin.amount_x, active_bin.amount_y),
+        Err(_) => (0, 0),
+    }
+}
+
+/// Liquidity share of a deposit into the bin. A deposit changing the composition of a bin with liquidity is an internal swap
+/// to the composition of the bin, charged with composition fee. The fee, less the protocol share, is added to the bin before the deposit.
+pub fn get_composite_deposit_info(
+    lb_pair: &LbPair,
+    bin: &Bin,
+    price: u128,
+    amount_x: u64,
+    amount_y: u64,
+) -> Result<CompositeDepositInfo> {
+    let in_liquidity = get_liquidity(amount_x, amount_y, price)?;
+    if bin.is_zero_liquidity() {
+        return Ok(CompositeDepositInfo {
+            liquidity_share: in_liquidity,
+            ..Default::default()
+        });
+    }
+
+    let bin_liquidity = get_liquidity(bin.amount_x, bin.amount_y, price)?;
+    let liquidity_share = get_liquidity_share(in_liquidity, bin_liquidity, bin.liquidity_supply)?;
+
+    // Amounts withdrawable right after the deposit
+    let liquidity_supply = bin.liquidity_supply.safe_add(liquidity_share)?;
+    let amount_x_withdrawable = get_out_amount(
+        liquidity_share,
+        bin.amount_x.safe_add(amount_x)?,
+        liquidity_supply,
+    )?;
+    let amount_y_withdrawable = get_out_amount(
+        liquidity_share,
+        bin.amount_y.safe_add(amount_y)?,
+        liquidity_supply,
+    )?;
+
+    let (token_x_fee_amount, token_y_fee_amount) =
+        if amount_x_withdrawable > amount_x && amount_y > amount_y_withdrawable {
+            (
+                0,
+                lb_pair.compute_composition_fee(amount_y.safe_sub(amount_y_withdrawable)?)?,
+            )
+        } else if amount_y_withdrawable > amount_y && amount_x > amount_x_withdrawable {
+            (
+                lb_pair.compute_composition_fee(amount_x.safe_sub(amount_x_withdrawable)?)?,
+                0,
+            )
+        } else {
+            (0, 0)
+        };
+
+    if token_x_fee_amount == 0 && token_y_fee_amount == 0 {
+        return Ok(CompositeDepositInfo {
+            liquidity_share,
+            ..Default::default()
+        });
+    }
+
+    let protocol_token_x_fee_amount = lb_pair.compute_protocol_fee(token_x_fee_amount)?;
+    let protocol_token_y_fee_amount = lb_pair.compute_protocol_fee(token_y_fee_amount)?;
+
+    let in_liquidity = get_liquidity(
+        amount_x.safe_sub(token_x_fee_amount)?,
+        amount_y.safe_sub(token_y_fee_amount)?,
+        price,
+    )?;
+    let bin_liquidity = get_liquidity(
+        bin.amount_x
+            .safe_add(token_x_fee_amount.safe_sub(protocol_token_x_fee_amount)?)?,
+        bin.amount_y
+            .safe_add(token_y_fee_amount.safe_sub(protocol_token_y_fee_amount)?)?,
+        price,
+    )?;
+    let liquidity_share = get_liquidity_share(in_liquidity, bin_liquidity, bin.liquidity_supply)?;
+
+    Ok(CompositeDepositInfo {
+        liquidity_share,
+        token_x_fee_amount,
+        token_y_fee_amount,
+        protocol_token_x_fee_amount,
+        protocol_token_y_fee_amount,
+    })
+}
+
+/// Deposit the amounts of token X and Y into the bins of the position. Return the composition fee charged per bin.
+pub fn deposit_in_bins(
+    lb_pair: &mut LbPair,
+    position: &mut PositionV2,
+    bin_array_manager: &mut BinArrayManager,
+    amounts_in_bin: &[(i32, u64, u64)],
+    sender: Pubkey,
+) -> Result<Vec<CompositionFeeEvent>> {
+    let mut composition_fees = vec![];
+
+    for &(bin_id, amount_x, amount_y) in amounts_in_bin {
+        if amount_x == 0 && amount_y == 0 {
+            continue;
+        }
+
+        let bin = bin_array_manager.get_bin_mut(bin_id)?;
+        let price = bin.get_or_store_bin_price(bin_id, lb_pair.bin_step)?;
+
+        let CompositeDepositInfo {
+            liquidity_share,
+            token_x_fee_amount,
+            token_y_fee_amount,
+            protocol_token_x_fee_amount,
+            protocol_token_y_fee_amount,
+        } = if bin_id == lb_pair.active_id {
+            get_composite_deposit_info(lb_pair, bin, price, amount_x, amount_y)?
+        } else {
+            CompositeDepositInfo {
+                liquidity_share: get_liquidity(amount_x, amount_y, price)?,
+                ..Default::default()
+            }
+        };
+        require!(liquidity_share > 0, LBError::ZeroLiquidity);
+
+        if token_x_fee_amount > 0 || token_y_fee_amount > 0 {
+            bin.deposit_composition_fee(
+                token_x_fee_amount.safe_sub(protocol_token_x_fee_amount)?,
+                token_y_fee_amount.safe_sub(protocol_token_y_fee_amount)?,
+            )?;
+            lb_pair.accumulate_protocol_fees(
+                protocol_token_x_fee_amount,
+                protocol_token_y_fee_amount,
+            )?;
+
+            composition_fees.push(CompositionFeeEvent {
+                from: sender,
+                bin_id: bin_id.try_into().map_err(|_| LBError::TypeCastFailed)?,
+                token_x_fee_amount,
+                token_y_fee_amount,
+                protocol_token_x_fee_amount,
+                protocol_token_y_fee_amount,
+            });
+        }
+
+        bin.deposit(
+            amount_x.safe_sub(token_x_fee_amount)?,
+            amount_y.safe_sub(token_y_fee_amount)?,
+            liquidity_share,
+        )?;
+        position.deposit(bin_id, liquidity_share)?;
+    }
+
+    Ok(composition_fees)
+}
+
+/// Result of a deposit into the bins of a position
+pub struct PositionDeposit {
+    pub amount_x: u64,
+    pub amount_y: u64,
+    pub active_id: i32,
+    pub composition_fees: Vec<CompositionFeeEvent>,
+}
+
+/// Deposit into the bins of the position. `get_amounts_into_bin` returns the amounts of token X and Y for each bin,
+/// given the pair and the bin arrays after the rewards update.
+#[allow(clippy::too_many_arguments)]
+pub fn deposit_in_position_bins<'info, F>(
+    lb_pair: &AccountLoader<'info, LbPair>,
+    position: &AccountLoader<'info, PositionV2>,
+    bin_array_bitmap_extension: &Option<AccountLoader<'info, BinArrayBitmapExtension>>,
+    bin_array_lower: &AccountLoader<'info, BinArray>,
+    bin_array_upper: &AccountLoader<'info, BinArray>,
+    sender: Pubkey,
+    get_amounts_into_bin: F,
+) -> Result<PositionDeposit>
+where
+    F: FnOnce(&LbPair, &BinArrayManager) -> Result<Vec<(i32, u64, u64)>>,
+{
+    let mut lb_pair = lb_pair.load_mut()?;
+    let mut position = position.load_mut()?;
+
+    let mut bin_arrays = load_position_bin_arrays(bin_array_lower, bin_array_upper)?;
+    let mut bin_array_manager = BinArrayManager::new(&mut bin_arrays)?;
+    bin_array_manager.validate_bin_arrays(position.lower_bin_id)?;
+    bin_array_manager.update_rewards(&mut lb_pair)?;
+
+    position.update_earning_per_token_stored(&bin_array_manager)?;
+
+    let amounts_in_bin = get_amounts_into_bin(&lb_pair, &bin_array_manager)?;
+
+    {
+        let pair_access_validator = get_lb_pair_type_access_validator(&lb_pair)?;
+        require!(
+            pair_access_validator.validate_add_liquidity_access(),
+            LBError::PoolDisabled
+        );
+        require!(
+            pair_access_validator.validate_deposit_quote_token_in_active_bin()
+                || amounts_in_bin
+                    .iter()
+                    .all(|&(bin_id, _, amount_y)| bin_id != lb_pair.active_id || amount_y == 0),
+            LBError::InvalidInput
+        );
+    }
+
+    let zero_liquidity_flags = bin_array_manager.get_zero_liquidity_flags();
+    let composition_fees = deposit_in_bins(
+        &mut lb_pair,
+        &mut position,
+        &mut bin_array_manager,
+        &amounts_in_bin,
+        sender,
+    )?;
+
+    for (idx, is_zero_liquidity) in zero_liquidity_flags.into_iter().enumerate() {
+        if is_zero_liquidity && !bin_array_manager.get_zero_liquidity_flags()[idx] {
+            lb_pair.flip_bin_array_bit(
+                bin_array_bitmap_extension,
+                bin_array_manager.get_bin_array_index(idx)?,
+            )?;
+        }
+    }
+
+    position.set_last_updated_at(Clock::get()?.unix_timestamp);
+
+    let (mut amount_x, mut amount_y) = (0u64, 0u64);
+    for &(_, bin_amount_x, bin_amount_y) in amounts_in_bin.iter() {
+        amount_x = amount_x.safe_add(bin_amount_x)?;
+        amount_y = amount_y.safe_add(bin_amount_y)?;
+    }
+
+    Ok(PositionDeposit {
+        amount_x,
+        amount_y,
+        active_id: lb_pair.active_id,
+        composition_fees,
+    })
+}
+
+/// Deposit into the bins of the position, and transfer the total amounts from the user to the reserves.
+/// Remaining accounts are the extra accounts required by the transfer hook of token X and Y.
+pub fn deposit_in_position<'a, 'b, 'c, 'info, F>(
+    ctx: &Context<'a, 'b, 'c, 'info, ModifyLiquidity<'info>>,
+    get_amounts_into_bin: F,
+) -> Result<()>
+where
+    F: FnOnce(&LbPair, &BinArrayManager) -> Result<Vec<(i32, u64, u64)>>,
+{
+    let PositionDeposit {
+        amount_x,
+        amount_y,
+        active_id,
+        composition_fees,
+    } = deposit_in_position_bins(
+        &ctx.accounts.lb_pair,
+        &ctx.accounts.position,
+        &ctx.accounts.bin_array_bitmap_extension,
+        &ctx.accounts.bin_array_lower,
+        &ctx.accounts.bin_array_upper,
+        ctx.accounts.sender.key(),
+        get_amounts_into_bin,
+    )?;
+
+    ctx.accounts
+        .transfer_to_reserves(amount_x, amount_y, ctx.remaining_accounts)?;
+
+    for composition_fee in composition_fees {
+        emit_cpi!(composition_fee);
+    }
+
+    emit_cpi!(AddLiquidityEvent {
+        lb_pair: ctx.accounts.lb_pair.key(),
+        from: ctx.accounts.sender.key(),
+        position: ctx.accounts.position.key(),
+        amounts: [amount_x, amount_y],
+        active_bin_id: active_id,
+    });
+
+    Ok(())
+}
+
+impl LiquidityParameter {
+    /// Amounts of token X and Y deposited into each bin, following the distribution
+    pub fn to_amounts_into_bin(&self) -> Result<Vec<(i32, u64, u64)>> {
+        let (mut total_distribution_x, mut total_distribution_y) = (0u64, 0u64);
+
+        self.bin_liquidity_dist
+            .iter()
+            .map(|dist| {
+                total_distribution_x = total_distribution_x.safe_add(dist.distribution_x.into())?;
+                total_distribution_y = total_distribution_y.safe_add(dist.distribution_y.into())?;
+                require!(
+                    total_distribution_x <= BASIS_POINT_MAX as u64
+                        && total_distribution_y <= BASIS_POINT_MAX as u64,
+                    LBError::InvalidBps
+                );
+
+                let amount_x = u128::from(self.amount_x)
+                    .safe_mul(dist.distribution_x.into())?
+                    .safe_div(BASIS_POINT_MAX as u128)?;
+                let amount_y = u128::from(self.amount_y)
+                    .safe_mul(dist.distribution_y.into())?
+                    .safe_div(BASIS_POINT_MAX as u128)?;
+
+                Ok((
+                    dist.bin_id,
+                    amount_x.try_into().map_err(|_| LBError::TypeCastFailed)?,
+                    amount_y.try_into().map_err(|_| LBError::TypeCastFailed)?,
+                ))
+            })
+            .collect()
+    }
 }
 
 pub fn handle<'a, 'b, 'c, 'info>(
     ctx: Context<'a, 'b, 'c, 'info, ModifyLiquidity<'info>>,
     liquidity_parameter: LiquidityParameter,
 ) -> Result<()> {
-    Ok(())
+    deposit_in_position(&ctx, |_, _| liquidity_parameter.to_amounts_into_bin())
 }
@@ -3,7 +3,7 @@ use crate::errors::LBError;
 use crate::manager::bin_array_manager::BinArrayManager;
 use crate::math::safe_math::SafeMath;
 use crate::math::weight_to_amounts::{to_amount_ask_side, to_amount_bid_side, to_amount_both_side};
-use crate::ModifyLiquidity;
+use crate::{deposit_in_position, get_amounts_in_active_bin, ModifyLiquidity};
 use anchor_lang::prelude::*;
 
 const DEFAULT_MIN_WEIGHT: u16 = 200;
@@ -231,7 +231,24 @@ pub fn handle<'a, 'b, 'c, 'info>(
     ctx: Context<'a, 'b, 'c, 'info, ModifyLiquidity<'info>>,
     liquidity_parameter: &LiquidityParameterByStrategy,
 ) -> Result<()> {
-    Ok(())
+    deposit_in_position(&ctx, |lb_pair, bin_array_manager| {
+        validate_add_liquidity_by_strategy_params(
+            liquidity_parameter.active_id,
+            lb_pair.active_id,
+            liquidity_parameter.max_active_bin_slippage,
+            &liquidity_parameter.strategy_parameters,
+        )?;
+
+        let (amount_x_in_active_bin, amount_y_in_active_bin) =
+            get_amounts_in_active_bin(lb_pair, bin_array_manager);
+
+        liquidity_parameter.to_amounts_into_bin(
+            lb_pair.active_id,
+            lb_pair.bin_step,
+            amount_x_in_active_bin,
+            amount_y_in_active_bin,
+        )
+    })
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Eq, PartialEq, Clone, Debug)]
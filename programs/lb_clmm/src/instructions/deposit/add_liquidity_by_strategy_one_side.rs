@@ -1,4 +1,7 @@
-use super::add_liquidity_by_strategy::StrategyParameters;
+use super::add_liquidity_by_strategy::{
+    validate_add_liquidity_by_strategy_params, StrategyParameters,
+};
+use super::deposit_in_position_one_side;
 use super::to_weight_ascending_order;
 use super::to_weight_descending_order;
 use super::to_weight_spot_balanced;
@@ -66,5 +69,13 @@ pub fn handle<'a, 'b, 'c, 'info>(
     ctx: Context<'a, 'b, 'c, 'info, ModifyLiquidityOneSide<'info>>,
     liquidity_parameter: &LiquidityParameterByStrategyOneSide,
 ) -> Result<()> {
-    Ok(())
+    deposit_in_position_one_side(&ctx, |lb_pair, _, deposit_for_y| {
+        validate_add_liquidity_by_strategy_params(
+            liquidity_parameter.active_id,
+            lb_pair.active_id,
+            liquidity_parameter.max_active_bin_slippage,
+            &liquidity_parameter.strategy_parameters,
+        )?;
+        liquidity_parameter.to_amounts_into_bin(lb_pair.active_id, lb_pair.bin_step, deposit_for_y)
+    })
 }
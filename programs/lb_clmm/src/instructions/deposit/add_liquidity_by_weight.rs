@@ -1,7 +1,7 @@
 use crate::constants::MAX_BIN_PER_POSITION;
 use crate::errors::LBError;
 use crate::math::weight_to_amounts::{to_amount_ask_side, to_amount_bid_side, to_amount_both_side};
-use crate::ModifyLiquidity;
+use crate::{deposit_in_position, get_amounts_in_active_bin, ModifyLiquidity};
 use anchor_lang::prelude::*;
 
 #[derive(AnchorSerialize, AnchorDeserialize, Eq, PartialEq, Clone, Debug, Default)]
@@ -103,5 +103,17 @@ pub fn handle<'a, 'b, 'c, 'info>(
     ctx: &Context<'a, 'b, 'c, 'info, ModifyLiquidity<'info>>,
     liquidity_parameter: &LiquidityParameterByWeight,
 ) -> Result<()> {
-    Ok(())
+    deposit_in_position(ctx, |lb_pair, bin_array_manager| {
+        liquidity_parameter.validate(lb_pair.active_id)?;
+
+        let (amount_x_in_active_bin, amount_y_in_active_bin) =
+            get_amounts_in_active_bin(lb_pair, bin_array_manager);
+
+        liquidity_parameter.to_amounts_into_bin(
+            lb_pair.active_id,
+            lb_pair.bin_step,
+            amount_x_in_active_bin,
+            amount_y_in_active_bin,
+        )
+    })
 }
@@ -1,11 +1,17 @@
+use super::add_liquidity::{deposit_in_position_bins, PositionDeposit};
 use crate::authorize_modify_position;
 use crate::constants::MAX_BIN_PER_POSITION;
 use crate::errors::LBError;
+use crate::events::AddLiquidity as AddLiquidityEvent;
+use crate::manager::bin_array_manager::BinArrayManager;
 use crate::math::weight_to_amounts::to_amount_ask_side;
 use crate::math::weight_to_amounts::to_amount_bid_side;
 use crate::state::bin_array_bitmap_extension::BinArrayBitmapExtension;
 use crate::state::position::PositionV2;
 use crate::state::{bin::BinArray, lb_pair::LbPair};
+use crate::utils::token_2022::{
+    calculate_transfer_fee_included_amount, transfer_checked_with_hook,
+};
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
@@ -102,7 +108,7 @@ pub struct ModifyLiquidityOneSide<'info> {
     #[account(
         mut,
         has_one = lb_pair,
-        constraint = authorize_modify_position(&position, sender.key())?
+        constraint = authorize_modify_position(&position, &position_nft_account, sender.key())?
     )]
     pub position: AccountLoader<'info, PositionV2>,
 
@@ -136,11 +142,107 @@ pub struct ModifyLiquidityOneSide<'info> {
 
     pub sender: Signer<'info>,
     pub token_program: Interface<'info, TokenInterface>,
+
+    /// Token account holding the position NFT. Only required for tokenized position.
+    pub position_nft_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+}
+
+/// Deposit a single token into the bins of the position, and transfer the total amount from the user to the reserve.
+/// `get_amounts_into_bin` receives whether token Y is deposited. Remaining accounts are the extra accounts required by the transfer hook of the token.
+pub fn deposit_in_position_one_side<'a, 'b, 'c, 'info, F>(
+    ctx: &Context<'a, 'b, 'c, 'info, ModifyLiquidityOneSide<'info>>,
+    get_amounts_into_bin: F,
+) -> Result<()>
+where
+    F: FnOnce(&LbPair, &BinArrayManager, bool) -> Result<Vec<(i32, u64)>>,
+{
+    let deposit_for_y = {
+        let lb_pair = ctx.accounts.lb_pair.load()?;
+        let token_mint = ctx.accounts.token_mint.key();
+        let reserve = ctx.accounts.reserve.key();
+
+        if token_mint == lb_pair.token_y_mint && reserve == lb_pair.reserve_y {
+            true
+        } else if token_mint == lb_pair.token_x_mint && reserve == lb_pair.reserve_x {
+            false
+        } else {
+            return Err(LBError::InvalidAccountForSingleDeposit.into());
+        }
+    };
+
+    let PositionDeposit {
+        amount_x,
+        amount_y,
+        active_id,
+        composition_fees,
+    } = deposit_in_position_bins(
+        &ctx.accounts.lb_pair,
+        &ctx.accounts.position,
+        &ctx.accounts.bin_array_bitmap_extension,
+        &ctx.accounts.bin_array_lower,
+        &ctx.accounts.bin_array_upper,
+        ctx.accounts.sender.key(),
+        |lb_pair, bin_array_manager| {
+            Ok(
+                get_amounts_into_bin(lb_pair, bin_array_manager, deposit_for_y)?
+                    .into_iter()
+                    .map(|(bin_id, amount)| {
+                        if deposit_for_y {
+                            (bin_id, 0, amount)
+                        } else {
+                            (bin_id, amount, 0)
+                        }
+                    })
+                    .collect(),
+            )
+        },
+    )?;
+
+    let amount = if deposit_for_y { amount_y } else { amount_x };
+    let transfer_amount = calculate_transfer_fee_included_amount(
+        &ctx.accounts
+            .token_mint
+            .to_account_info()
+            .try_borrow_data()?,
+        amount,
+        Clock::get()?.epoch,
+    )?
+    .amount;
+
+    if transfer_amount > 0 {
+        transfer_checked_with_hook(
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.user_token.to_account_info(),
+            &ctx.accounts.token_mint,
+            ctx.accounts.reserve.to_account_info(),
+            ctx.accounts.sender.to_account_info(),
+            ctx.remaining_accounts,
+            transfer_amount,
+            &[],
+        )?;
+    }
+
+    for composition_fee in composition_fees {
+        emit_cpi!(composition_fee);
+    }
+
+    emit_cpi!(AddLiquidityEvent {
+        lb_pair: ctx.accounts.lb_pair.key(),
+        from: ctx.accounts.sender.key(),
+        position: ctx.accounts.position.key(),
+        amounts: [amount_x, amount_y],
+        active_bin_id: active_id,
+    });
+
+    Ok(())
 }
 
 pub fn handle<'a, 'b, 'c, 'info>(
     ctx: &Context<'a, 'b, 'c, 'info, ModifyLiquidityOneSide<'info>>,
     liquidity_parameter: &LiquidityOneSideParameter,
 ) -> Result<()> {
-    Ok(())
+    deposit_in_position_one_side(ctx, |lb_pair, _, deposit_for_y| {
+        liquidity_parameter.validate(lb_pair.active_id)?;
+        liquidity_parameter.to_amounts_into_bin(lb_pair.active_id, lb_pair.bin_step, deposit_for_y)
+    })
 }
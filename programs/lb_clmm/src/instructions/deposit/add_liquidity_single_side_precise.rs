@@ -1,4 +1,6 @@
-use super::ModifyLiquidityOneSide;
+use super::{deposit_in_position_one_side, ModifyLiquidityOneSide};
+use crate::errors::LBError;
+use crate::math::safe_math::SafeMath;
 use anchor_lang::prelude::*;
 
 #[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone)]
@@ -17,5 +19,18 @@ pub fn handle<'a, 'b, 'c, 'info>(
     ctx: Context<'a, 'b, 'c, 'info, ModifyLiquidityOneSide<'info>>,
     parameter: AddLiquiditySingleSidePreciseParameter,
 ) -> Result<()> {
-    Ok(())
+    require!(!parameter.bins.is_empty(), LBError::InvalidInput);
+
+    deposit_in_position_one_side(&ctx, |_, _, _| {
+        parameter
+            .bins
+            .iter()
+            .map(|bin| {
+                Ok((
+                    bin.bin_id,
+                    u64::from(bin.amount).safe_mul(parameter.decompress_multiplier)?,
+                ))
+            })
+            .collect()
+    })
 }
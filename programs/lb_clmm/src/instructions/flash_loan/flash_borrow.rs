@@ -0,0 +1,140 @@
+use super::{is_flash_loan_instruction_of, require_top_level_instruction};
+use crate::errors::LBError;
+use crate::instruction::{
+    FlashBorrow as FlashBorrowInstruction, FlashRepay as FlashRepayInstruction,
+};
+use crate::pair_action_access::get_lb_pair_type_access_validator;
+use crate::state::lb_pair::LbPair;
+use crate::utils::token_2022::transfer_checked_with_hook;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+use anchor_lang::Discriminator;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+#[derive(Accounts)]
+pub struct FlashBorrow<'info> {
+    #[account(
+        has_one = reserve_x,
+        has_one = reserve_y,
+        has_one = token_x_mint,
+        has_one = token_y_mint,
+    )]
+    pub lb_pair: AccountLoader<'info, LbPair>,
+
+    #[account(mut)]
+    pub reserve_x: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub reserve_y: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = user_token_x.mint == token_x_mint.key() @ LBError::InvalidTokenMint,
+    )]
+    pub user_token_x: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = user_token_y.mint == token_y_mint.key() @ LBError::InvalidTokenMint,
+    )]
+    pub user_token_y: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_x_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub token_y_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub user: Signer<'info>,
+
+    pub token_x_program: Interface<'info, TokenInterface>,
+    pub token_y_program: Interface<'info, TokenInterface>,
+
+    /// CHECK: Instructions sysvar, used to verify the repayment
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
+
+/// Verify there's a flash repay of the pair referring to this borrow later in the transaction, without any other borrow of the pair in between.
+fn verify_repayment(instructions_sysvar: &AccountInfo, lb_pair: Pubkey) -> Result<()> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    let mut index = usize::from(current_index) + 1;
+
+    while let Ok(instruction) = load_instruction_at_checked(index, instructions_sysvar) {
+        if is_flash_loan_instruction_of(
+            &instruction,
+            &FlashBorrowInstruction::DISCRIMINATOR,
+            lb_pair,
+        ) {
+            return Err(LBError::InvalidFlashLoanInstruction.into());
+        }
+
+        if is_flash_loan_instruction_of(
+            &instruction,
+            &FlashRepayInstruction::DISCRIMINATOR,
+            lb_pair,
+        ) {
+            let FlashRepayInstruction {
+                borrow_instruction_index,
+            } = FlashRepayInstruction::try_from_slice(&instruction.data[8..])?;
+
+            require!(
+                borrow_instruction_index == current_index,
+                LBError::InvalidFlashLoanInstruction
+            );
+
+            return Ok(());
+        }
+
+        index += 1;
+    }
+
+    Err(LBError::FlashLoanNotRepaid.into())
+}
+
+pub fn handle<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, FlashBorrow<'info>>,
+    amount_x: u64,
+    amount_y: u64,
+) -> Result<()> {
+    require!(amount_x > 0 || amount_y > 0, LBError::InvalidInput);
+    require_top_level_instruction()?;
+
+    let lb_pair = ctx.accounts.lb_pair.load()?;
+    require!(
+        get_lb_pair_type_access_validator(&lb_pair)?.validate_swap_access(ctx.accounts.user.key()),
+        LBError::PoolDisabled
+    );
+
+    verify_repayment(
+        &ctx.accounts.instructions_sysvar.to_account_info(),
+        ctx.accounts.lb_pair.key(),
+    )?;
+
+    let signer_seeds = &[&lb_pair.seeds()?[..]];
+
+    if amount_x > 0 {
+        transfer_checked_with_hook(
+            ctx.accounts.token_x_program.to_account_info(),
+            ctx.accounts.reserve_x.to_account_info(),
+            &ctx.accounts.token_x_mint,
+            ctx.accounts.user_token_x.to_account_info(),
+            ctx.accounts.lb_pair.to_account_info(),
+            ctx.remaining_accounts,
+            amount_x,
+            signer_seeds,
+        )?;
+    }
+
+    if amount_y > 0 {
+        transfer_checked_with_hook(
+            ctx.accounts.token_y_program.to_account_info(),
+            ctx.accounts.reserve_y.to_account_info(),
+            &ctx.accounts.token_y_mint,
+            ctx.accounts.user_token_y.to_account_info(),
+            ctx.accounts.lb_pair.to_account_info(),
+            ctx.remaining_accounts,
+            amount_y,
+            signer_seeds,
+        )?;
+    }
+
+    Ok(())
+}
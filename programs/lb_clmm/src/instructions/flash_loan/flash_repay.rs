@@ -0,0 +1,191 @@
+use super::{compute_flash_loan_fee, is_flash_loan_instruction_of, require_top_level_instruction};
+use crate::errors::LBError;
+use crate::events::FlashLoan as FlashLoanEvent;
+use crate::instruction::FlashBorrow as FlashBorrowInstruction;
+use crate::math::safe_math::SafeMath;
+use crate::math::u64x64_math::SCALE_OFFSET;
+use crate::state::bin::BinArray;
+use crate::state::lb_pair::LbPair;
+use crate::utils::token_2022::{
+    calculate_transfer_fee_included_amount, transfer_checked_with_hook,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+use anchor_lang::Discriminator;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FlashRepay<'info> {
+    #[account(
+        mut,
+        has_one = reserve_x,
+        has_one = reserve_y,
+        has_one = token_x_mint,
+        has_one = token_y_mint,
+    )]
+    pub lb_pair: AccountLoader<'info, LbPair>,
+
+    /// Bin array of the active bin, which receive the LP share of the fee
+    #[account(
+        mut,
+        has_one = lb_pair,
+    )]
+    pub bin_array: AccountLoader<'info, BinArray>,
+
+    #[account(mut)]
+    pub reserve_x: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub reserve_y: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = user_token_x.mint == token_x_mint.key() @ LBError::InvalidTokenMint,
+    )]
+    pub user_token_x: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = user_token_y.mint == token_y_mint.key() @ LBError::InvalidTokenMint,
+    )]
+    pub user_token_y: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_x_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub token_y_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub user: Signer<'info>,
+
+    pub token_x_program: Interface<'info, TokenInterface>,
+    pub token_y_program: Interface<'info, TokenInterface>,
+
+    /// CHECK: Instructions sysvar, used to read the borrowed amount
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
+
+/// Read the borrowed amount from the flash borrow instruction of the pair, which must be executed earlier in the transaction.
+fn get_borrowed_amount(
+    instructions_sysvar: &AccountInfo,
+    lb_pair: Pubkey,
+    borrow_instruction_index: u16,
+) -> Result<(u64, u64)> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    require!(
+        borrow_instruction_index < current_index,
+        LBError::InvalidFlashLoanInstruction
+    );
+
+    let instruction =
+        load_instruction_at_checked(borrow_instruction_index.into(), instructions_sysvar)?;
+    require!(
+        is_flash_loan_instruction_of(
+            &instruction,
+            &FlashBorrowInstruction::DISCRIMINATOR,
+            lb_pair
+        ),
+        LBError::InvalidFlashLoanInstruction
+    );
+
+    let FlashBorrowInstruction { amount_x, amount_y } =
+        FlashBorrowInstruction::try_from_slice(&instruction.data[8..])?;
+
+    Ok((amount_x, amount_y))
+}
+
+pub fn handle<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, FlashRepay<'info>>,
+    borrow_instruction_index: u16,
+) -> Result<()> {
+    require_top_level_instruction()?;
+
+    let (amount_x, amount_y) = get_borrowed_amount(
+        &ctx.accounts.instructions_sysvar.to_account_info(),
+        ctx.accounts.lb_pair.key(),
+        borrow_instruction_index,
+    )?;
+
+    let fee_x = compute_flash_loan_fee(amount_x)?;
+    let fee_y = compute_flash_loan_fee(amount_y)?;
+
+    let epoch = Clock::get()?.epoch;
+    // The reserve must receive the borrowed amount plus fee, excluding token 2022 transfer fee
+    let repay_amount_x = calculate_transfer_fee_included_amount(
+        &ctx.accounts
+            .token_x_mint
+            .to_account_info()
+            .try_borrow_data()?,
+        amount_x.safe_add(fee_x)?,
+        epoch,
+    )?
+    .amount;
+    let repay_amount_y = calculate_transfer_fee_included_amount(
+        &ctx.accounts
+            .token_y_mint
+            .to_account_info()
+            .try_borrow_data()?,
+        amount_y.safe_add(fee_y)?,
+        epoch,
+    )?
+    .amount;
+
+    if repay_amount_x > 0 {
+        transfer_checked_with_hook(
+            ctx.accounts.token_x_program.to_account_info(),
+            ctx.accounts.user_token_x.to_account_info(),
+            &ctx.accounts.token_x_mint,
+            ctx.accounts.reserve_x.to_account_info(),
+            ctx.accounts.user.to_account_info(),
+            ctx.remaining_accounts,
+            repay_amount_x,
+            &[],
+        )?;
+    }
+
+    if repay_amount_y > 0 {
+        transfer_checked_with_hook(
+            ctx.accounts.token_y_program.to_account_info(),
+            ctx.accounts.user_token_y.to_account_info(),
+            &ctx.accounts.token_y_mint,
+            ctx.accounts.reserve_y.to_account_info(),
+            ctx.accounts.user.to_account_info(),
+            ctx.remaining_accounts,
+            repay_amount_y,
+            &[],
+        )?;
+    }
+
+    let mut lb_pair = ctx.accounts.lb_pair.load_mut()?;
+    let mut bin_array = ctx.accounts.bin_array.load_mut()?;
+
+    bin_array.is_bin_id_within_range(lb_pair.active_id)?;
+    let active_bin = bin_array.get_bin_mut(lb_pair.active_id)?;
+
+    let mut protocol_fee_x = lb_pair.compute_protocol_fee(fee_x)?;
+    let mut protocol_fee_y = lb_pair.compute_protocol_fee(fee_y)?;
+
+    if active_bin.liquidity_supply >> SCALE_OFFSET == 0 {
+        // No LP to distribute to, the whole fee goes to protocol
+        protocol_fee_x = fee_x;
+        protocol_fee_y = fee_y;
+    } else {
+        // Same as swap fee, token X fee is stored at swap_for_y = true
+        active_bin.update_fee_per_token_stored(fee_x.safe_sub(protocol_fee_x)?, true)?;
+        active_bin.update_fee_per_token_stored(fee_y.safe_sub(protocol_fee_y)?, false)?;
+    }
+
+    lb_pair.accumulate_protocol_fees(protocol_fee_x, protocol_fee_y)?;
+
+    emit_cpi!(FlashLoanEvent {
+        lb_pair: ctx.accounts.lb_pair.key(),
+        user: ctx.accounts.user.key(),
+        amount_x,
+        amount_y,
+        fee_x,
+        fee_y,
+        protocol_fee_x,
+        protocol_fee_y,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,105 @@
+pub mod flash_borrow;
+pub mod flash_repay;
+
+pub use flash_borrow::*;
+pub use flash_repay::*;
+
+use crate::constants::{BASIS_POINT_MAX, FLASH_LOAN_FEE_BPS};
+use crate::errors::LBError;
+use crate::math::safe_math::SafeMath;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{
+    get_stack_height, Instruction, TRANSACTION_LEVEL_STACK_HEIGHT,
+};
+
+/// Flash loan relies on instruction introspection, which only see top level instructions. Therefore borrow and repay through CPI is not allowed.
+pub fn require_top_level_instruction() -> Result<()> {
+    require!(
+        get_stack_height() == TRANSACTION_LEVEL_STACK_HEIGHT,
+        LBError::FlashLoanCpiNotAllowed
+    );
+    Ok(())
+}
+
+/// Check whether the instruction is the flash loan instruction of the pair. The pair is always the first account.
+pub fn is_flash_loan_instruction_of(
+    instruction: &Instruction,
+    discriminator: &[u8],
+    lb_pair: Pubkey,
+) -> bool {
+    instruction.program_id.eq(&crate::ID)
+        && instruction.data.starts_with(discriminator)
+        && instruction
+            .accounts
+            .first()
+            .map(|account| account.pubkey.eq(&lb_pair))
+            .unwrap_or(false)
+}
+
+/// Flash loan fee of the borrowed amount. The result is ceil-ed.
+pub fn compute_flash_loan_fee(amount: u64) -> Result<u64> {
+    let fee = u128::from(amount)
+        .safe_mul(FLASH_LOAN_FEE_BPS.into())?
+        .safe_add((BASIS_POINT_MAX - 1) as u128)?
+        .safe_div(BASIS_POINT_MAX as u128)?;
+
+    Ok(fee.try_into().map_err(|_| LBError::TypeCastFailed)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::lb_pair::LbPair;
+
+    #[test]
+    fn test_compute_flash_loan_fee_zero_amount() {
+        assert_eq!(compute_flash_loan_fee(0).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_compute_flash_loan_fee_round_up() {
+        // 5 bps, exact when the amount is a multiple of 2000
+        assert_eq!(compute_flash_loan_fee(2000).unwrap(), 1);
+        assert_eq!(compute_flash_loan_fee(1_000_000).unwrap(), 500);
+
+        // Any remainder is charged a full unit
+        assert_eq!(compute_flash_loan_fee(1).unwrap(), 1);
+        assert_eq!(compute_flash_loan_fee(1999).unwrap(), 1);
+        assert_eq!(compute_flash_loan_fee(2001).unwrap(), 2);
+        assert_eq!(compute_flash_loan_fee(1_000_001).unwrap(), 501);
+    }
+
+    #[test]
+    fn test_compute_flash_loan_fee_max_amount() {
+        let fee = compute_flash_loan_fee(u64::MAX).unwrap();
+        let expected_fee = (u128::from(u64::MAX) * u128::from(FLASH_LOAN_FEE_BPS))
+            .div_ceil(BASIS_POINT_MAX as u128);
+        assert_eq!(u128::from(fee), expected_fee);
+
+        // Borrowing the whole u64 range cannot be repaid
+        assert!(u64::MAX.safe_add(fee).is_err());
+    }
+
+    #[test]
+    fn test_flash_loan_fee_repaid() {
+        let mut lb_pair = LbPair::default();
+        lb_pair.parameters.protocol_share = 1000;
+
+        for amount in [1u64, 1999, 2000, 123_456_789, u64::MAX / 2] {
+            let fee = compute_flash_loan_fee(amount).unwrap();
+            let repay_amount = amount.safe_add(fee).unwrap();
+
+            // The fee is never under FLASH_LOAN_FEE_BPS of the borrowed amount
+            assert!(
+                u128::from(repay_amount - amount) * BASIS_POINT_MAX as u128
+                    >= u128::from(amount) * u128::from(FLASH_LOAN_FEE_BPS)
+            );
+
+            // Protocol and LP share add up to the repaid fee
+            let protocol_fee = lb_pair.compute_protocol_fee(fee).unwrap();
+            let lp_fee = fee.safe_sub(protocol_fee).unwrap();
+            assert_eq!(protocol_fee + lp_fee, fee);
+            assert!(protocol_fee <= fee / 10);
+        }
+    }
+}
@@ -1,5 +1,6 @@
+use crate::state::bin_array_funder::BinArrayFunder;
 use crate::state::{bin::BinArray, lb_pair::LbPair};
-use crate::utils::seeds::BIN_ARRAY;
+use crate::utils::seeds::{BIN_ARRAY, BIN_ARRAY_FUNDER};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
@@ -26,6 +27,56 @@ pub struct InitializeBinArray<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+#[instruction(index: i64)]
+pub struct InitializeBinArrayWithFunder<'info> {
+    pub lb_pair: AccountLoader<'info, LbPair>,
+
+    #[account(
+        init,
+        payer = funder,
+        seeds = [
+            BIN_ARRAY,
+            lb_pair.key().as_ref(),
+            &index.to_le_bytes()
+        ],
+        bump,
+        space = 8 + BinArray::INIT_SPACE
+    )]
+    pub bin_array: AccountLoader<'info, BinArray>,
+
+    /// Record the funder, refunded when the bin array is closed
+    #[account(
+        init,
+        payer = funder,
+        seeds = [
+            BIN_ARRAY_FUNDER,
+            bin_array.key().as_ref(),
+        ],
+        bump,
+        space = 8 + BinArrayFunder::INIT_SPACE
+    )]
+    pub bin_array_funder: Account<'info, BinArrayFunder>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
 pub fn handle(ctx: Context<InitializeBinArray>, index: i64) -> Result<()> {
+    let mut bin_array = ctx.accounts.bin_array.load_init()?;
+    bin_array.initialize(index, ctx.accounts.lb_pair.key())
+}
+
+/// Initialize the bin array, recording the funder to refund the rent through `close_bin_array`
+pub fn handle_with_funder(ctx: Context<InitializeBinArrayWithFunder>, index: i64) -> Result<()> {
+    let mut bin_array = ctx.accounts.bin_array.load_init()?;
+    bin_array.initialize(index, ctx.accounts.lb_pair.key())?;
+
+    ctx.accounts
+        .bin_array_funder
+        .init(ctx.accounts.bin_array.key(), ctx.accounts.funder.key());
+
     Ok(())
 }
@@ -1,14 +1,17 @@
-use crate::assert_eq_launch_pool_admin;
+use crate::assert_eq_role_admin;
 use crate::constants::DEFAULT_OBSERVATION_LENGTH;
 use crate::errors::LBError;
 use crate::events::LbPairCreate;
+use crate::state::admin_config::AdminRole;
 use crate::state::bin_array_bitmap_extension::BinArrayBitmapExtension;
 use crate::state::lb_pair::LbPair;
 use crate::state::lb_pair::PairType;
 use crate::state::oracle::Oracle;
 use crate::state::preset_parameters::PresetParameter;
+use crate::utils::seeds::ADMIN_CONFIG;
 use crate::utils::seeds::BIN_ARRAY_BITMAP_SEED;
 use crate::utils::seeds::ORACLE;
+use crate::utils::token_2022::is_supported_mint;
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use std::cmp::{max, min};
@@ -56,8 +59,14 @@ pub struct InitializePermissionLbPair<'info> {
     )]
     pub bin_array_bitmap_extension: Option<AccountLoader<'info, BinArrayBitmapExtension>>,
 
-    #[account(constraint = token_mint_x.key() != token_mint_y.key())]
+    #[account(
+        constraint = token_mint_x.key() != token_mint_y.key(),
+        constraint = is_supported_mint(&token_mint_x)? @ LBError::UnsupportedMintExtension,
+    )]
     pub token_mint_x: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        constraint = is_supported_mint(&token_mint_y)? @ LBError::UnsupportedMintExtension,
+    )]
     pub token_mint_y: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
@@ -99,13 +108,22 @@ pub struct InitializePermissionLbPair<'info> {
 
     #[account(
         mut,
-        constraint = assert_eq_launch_pool_admin(admin.key()) @ LBError::InvalidAdmin,
+        constraint = assert_eq_role_admin(&admin_config, AdminRole::LaunchPoolAdmin, admin.key()) @ LBError::InvalidAdmin,
     )]
     pub admin: Signer<'info>,
 
+    // SPL token or token 2022, both mints must belong to it as the reserves are created with it
+    #[account(
+        constraint = token_mint_x.to_account_info().owner == token_program.key,
+        constraint = token_mint_y.to_account_info().owner == token_program.key,
+    )]
     pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
+
+    /// CHECK: Admin config. The predefined admins are used until it is initialized.
+    #[account(seeds = [ADMIN_CONFIG], bump)]
+    pub admin_config: UncheckedAccount<'info>,
 }
 
 pub fn handle(
@@ -6,6 +6,7 @@ use crate::state::oracle::Oracle;
 use crate::state::preset_parameters::PresetParameter;
 use crate::utils::seeds::BIN_ARRAY_BITMAP_SEED;
 use crate::utils::seeds::ORACLE;
+use crate::utils::token_2022::is_supported_mint;
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use std::cmp::{max, min};
@@ -40,8 +41,14 @@ pub struct InitializeLbPair<'info> {
     )]
     pub bin_array_bitmap_extension: Option<AccountLoader<'info, BinArrayBitmapExtension>>,
 
-    #[account(constraint = token_mint_x.key() != token_mint_y.key())]
+    #[account(
+        constraint = token_mint_x.key() != token_mint_y.key(),
+        constraint = is_supported_mint(&token_mint_x)? @ LBError::UnsupportedMintExtension,
+    )]
     pub token_mint_x: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        constraint = is_supported_mint(&token_mint_y)? @ LBError::UnsupportedMintExtension,
+    )]
     pub token_mint_y: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
@@ -89,7 +96,11 @@ pub struct InitializeLbPair<'info> {
     #[account(mut)]
     pub funder: Signer<'info>,
 
-    // #[account(address = Token2022::id())]
+    // SPL token or token 2022, both mints must belong to it as the reserves are created with it
+    #[account(
+        constraint = token_mint_x.to_account_info().owner == token_program.key,
+        constraint = token_mint_y.to_account_info().owner == token_program.key,
+    )]
     pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
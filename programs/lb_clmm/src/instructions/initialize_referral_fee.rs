@@ -0,0 +1,37 @@
+use crate::state::lb_pair::LbPair;
+use crate::state::referral::{ReferralFee, Referrer};
+use crate::utils::seeds::REFERRAL_FEE;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct InitializeReferralFee<'info> {
+    #[account(
+        init,
+        seeds = [
+            REFERRAL_FEE,
+            referrer.key().as_ref(),
+            lb_pair.key().as_ref(),
+        ],
+        bump,
+        payer = payer,
+        space = 8 + ReferralFee::INIT_SPACE,
+    )]
+    pub referral_fee: Account<'info, ReferralFee>,
+
+    pub referrer: Account<'info, Referrer>,
+
+    pub lb_pair: AccountLoader<'info, LbPair>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle(ctx: Context<InitializeReferralFee>) -> Result<()> {
+    ctx.accounts
+        .referral_fee
+        .init(ctx.accounts.referrer.key(), ctx.accounts.lb_pair.key());
+
+    Ok(())
+}
@@ -1,18 +1,27 @@
 pub mod admin;
 pub mod claim_fee;
+pub mod claim_multiple;
+pub mod claim_referral_fee;
 pub mod claim_reward;
+pub mod close_bin_array;
 pub mod close_position;
+pub mod compound_fees;
 pub mod create_position;
 pub mod deposit;
+pub mod flash_loan;
 pub mod fund_reward;
 pub mod increase_oracle_length;
 pub mod initialize_bin_array;
 pub mod initialize_bin_array_bitmap_extension;
 pub mod initialize_pool;
+pub mod initialize_referral_fee;
 pub mod migrate_bin_array;
 pub mod migrate_position;
 pub mod position_authorize;
+pub mod set_position_vesting;
 pub mod swap;
+pub mod swap_route;
+pub mod transfer_position_owner;
 pub mod update_fees_and_rewards;
 pub mod update_position_operator;
 pub mod withdraw;
@@ -1,30 +1,168 @@
-use crate::{assert_eq_launch_pool_admin, state::position::PositionV2};
+use crate::{
+    assert_eq_role_admin,
+    errors::LBError,
+    state::{admin_config::AdminRole, position::PositionV2},
+};
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+/// Resolve the wallet owning the position. For tokenized position, it's the current holder of the position NFT.
+pub fn get_position_owner<'info>(
+    position: &PositionV2,
+    position_nft_account: &Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+) -> Result<Pubkey> {
+    if !position.is_tokenized() {
+        return Ok(position.owner);
+    }
+
+    let position_nft_account = position_nft_account
+        .as_ref()
+        .ok_or(LBError::InvalidPositionNftAccount)?;
+
+    require!(
+        position_nft_account.mint == position.position_nft_mint && position_nft_account.amount == 1,
+        LBError::InvalidPositionNftAccount
+    );
+
+    Ok(position_nft_account.owner)
+}
 
 pub fn authorize_modify_position<'info>(
     position: &AccountLoader<'info, PositionV2>,
+    position_nft_account: &Option<Box<InterfaceAccount<'info, TokenAccount>>>,
     sender: Pubkey,
 ) -> Result<bool> {
     let position = position.load()?;
-    return Ok(position.owner == sender || position.operator == sender);
+    let owner = get_position_owner(&position, position_nft_account)?;
+
+    // Operator was assigned by a previous holder, it doesn't follow the position NFT
+    if position.is_tokenized() {
+        return Ok(owner == sender);
+    }
+
+    return Ok(owner == sender || position.operator == sender);
 }
 
 pub fn authorize_claim_fee_position<'info>(
     position: &AccountLoader<'info, PositionV2>,
+    position_nft_account: &Option<Box<InterfaceAccount<'info, TokenAccount>>>,
     sender: Pubkey,
+    admin_config: Option<&AccountInfo<'info>>,
 ) -> Result<bool> {
     let position = position.load()?;
+    let owner = get_position_owner(&position, position_nft_account)?;
+
+    Ok(is_claim_fee_authority(
+        &position,
+        owner,
+        sender,
+        admin_config,
+    ))
+}
+
+/// The launch pool admin can claim the fee of a position with a fee owner, only when the admin config is passed in.
+pub fn is_claim_fee_authority(
+    position: &PositionV2,
+    owner: Pubkey,
+    sender: Pubkey,
+    admin_config: Option<&AccountInfo>,
+) -> bool {
+    let operator = if position.is_tokenized() {
+        Pubkey::default()
+    } else {
+        position.operator
+    };
 
     if position.fee_owner == Pubkey::default() {
-        Ok(position.owner == sender || position.operator == sender)
+        owner == sender || operator == sender
     } else {
-        Ok(position.owner == sender
-            || position.operator == sender
+        owner == sender
+            || operator == sender
             || position.fee_owner == sender
-            || assert_eq_launch_pool_admin(sender))
+            || admin_config.is_some_and(|admin_config| {
+                assert_eq_role_admin(admin_config, AdminRole::LaunchPoolAdmin, sender)
+            })
     }
 }
 
 pub trait PositionLiquidityFlowValidator {
     fn validate_outflow_to_ata_of_position_owner(&self, owner: Pubkey) -> Result<()>;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::admin_config::test_utils::with_admin_config_account;
+    use crate::state::admin_config::ADMIN_ROLE_COUNT;
+
+    fn position_with_fee_owner(owner: Pubkey, fee_owner: Pubkey) -> PositionV2 {
+        PositionV2 {
+            owner,
+            fee_owner,
+            ..bytemuck::Zeroable::zeroed()
+        }
+    }
+
+    #[test]
+    fn test_claim_fee_authority_before_admin_config_initialized() {
+        let owner = Pubkey::new_unique();
+        let launch_pool_admin = crate::launch_pool_config_admins::ADMINS[0];
+
+        with_admin_config_account(None, |admin_config| {
+            let position = position_with_fee_owner(owner, Pubkey::new_unique());
+            assert!(is_claim_fee_authority(
+                &position,
+                owner,
+                launch_pool_admin,
+                Some(admin_config)
+            ));
+            // Without the admin config, the launch pool admin is not an authority
+            assert!(!is_claim_fee_authority(
+                &position,
+                owner,
+                launch_pool_admin,
+                None
+            ));
+
+            // Nor of a position without fee owner
+            let position = position_with_fee_owner(owner, Pubkey::default());
+            assert!(!is_claim_fee_authority(
+                &position,
+                owner,
+                launch_pool_admin,
+                Some(admin_config)
+            ));
+        });
+    }
+
+    #[test]
+    fn test_claim_fee_authority_after_admin_config_initialized() {
+        let owner = Pubkey::new_unique();
+        let fee_owner = Pubkey::new_unique();
+        let launch_pool_admin = crate::launch_pool_config_admins::ADMINS[0];
+        let authority = Pubkey::new_unique();
+
+        let mut authorities = [Pubkey::new_unique(); ADMIN_ROLE_COUNT];
+        authorities[usize::from(u8::from(AdminRole::LaunchPoolAdmin))] = authority;
+
+        with_admin_config_account(Some(authorities), |admin_config| {
+            let position = position_with_fee_owner(owner, fee_owner);
+            assert!(is_claim_fee_authority(
+                &position,
+                owner,
+                authority,
+                Some(admin_config)
+            ));
+            assert!(!is_claim_fee_authority(
+                &position,
+                owner,
+                launch_pool_admin,
+                Some(admin_config)
+            ));
+
+            // Owner and fee owner don't depend on the admin config
+            assert!(is_claim_fee_authority(&position, owner, owner, None));
+            assert!(is_claim_fee_authority(&position, owner, fee_owner, None));
+        });
+    }
+}
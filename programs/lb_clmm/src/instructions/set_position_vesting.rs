@@ -0,0 +1,50 @@
+use crate::errors::LBError;
+use crate::events::UpdatePositionVesting as UpdatePositionVestingEvent;
+use crate::state::position::PositionV2;
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy)]
+pub struct VestingParameter {
+    /// Point which the linear vesting starts
+    pub start_point: u64,
+    /// Point before which none of the liquidity can be withdrawn
+    pub cliff_point: u64,
+    /// Point which the liquidity is fully vested
+    pub end_point: u64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetPositionVesting<'info> {
+    #[account(
+        mut,
+        constraint = position.load()?.operator.eq(&operator.key()) @ LBError::UnauthorizedAccess
+    )]
+    pub position: AccountLoader<'info, PositionV2>,
+
+    pub operator: Signer<'info>,
+}
+
+/// Set the vesting schedule of a position created by operator. The schedule can only be changed before the position receive any liquidity.
+pub fn handle(ctx: Context<SetPositionVesting>, parameter: VestingParameter) -> Result<()> {
+    let mut position = ctx.accounts.position.load_mut()?;
+    require!(position.is_empty(), LBError::NonEmptyPosition);
+
+    let VestingParameter {
+        start_point,
+        cliff_point,
+        end_point,
+    } = parameter;
+
+    position.set_vesting(start_point, cliff_point, end_point)?;
+
+    emit_cpi!(UpdatePositionVestingEvent {
+        position: ctx.accounts.position.key(),
+        start_point,
+        cliff_point,
+        end_point,
+        sender: ctx.accounts.operator.key(),
+    });
+
+    Ok(())
+}
@@ -1,8 +1,21 @@
+use crate::constants::{BASIS_POINT_MAX, FEE_PRECISION, HOST_FEE_BPS};
 use crate::errors::LBError;
+use crate::events::{CircuitBreakerTripped, IncreaseObservation, Swap as SwapEvent};
+use crate::math::price_math::get_price_from_id;
+use crate::math::safe_math::SafeMath;
+use crate::pair_action_access::get_lb_pair_type_access_validator;
+use crate::state::bin::{BinArray, SwapResult};
 use crate::state::bin_array_bitmap_extension::BinArrayBitmapExtension;
 use crate::state::lb_pair::*;
-use crate::state::oracle::Oracle;
+use crate::state::oracle::{Oracle, OracleContentLoader};
+use crate::state::referral::{ReferralFee, Referrer};
+use crate::utils::token_2022::{
+    calculate_transfer_fee_excluded_amount, calculate_transfer_fee_included_amount,
+    split_transfer_hook_accounts, transfer_checked_with_hook,
+};
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_lang::Discriminator;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 #[event_cpi]
@@ -54,26 +67,679 @@ pub struct Swap<'info> {
     pub token_y_program: Interface<'info, TokenInterface>,
 }
 
-pub fn handle_exact_in<'a, 'b, 'c, 'info>(
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, Default)]
+pub struct SwapV2Parameters {
+    /// Amount of token to be swapped in
+    pub amount_in: u64,
+    /// Minimum amount of token to be swapped out
+    pub min_amount_out: u64,
+    /// Last point the swap can be executed. Slot or timestamp based on the activation type of the pair
+    pub deadline: Option<u64>,
+    /// Maximum price movement of the active bin, in basis point
+    pub max_price_impact_bps: Option<u16>,
+    /// Maximum number of bins the active bin can move
+    pub max_bin_movement: Option<u16>,
+}
+
+/// Price impact of moving the active bin from start_active_id to end_active_id, in basis point
+pub fn get_price_impact_bps(
+    start_active_id: i32,
+    end_active_id: i32,
+    bin_step: u16,
+) -> Result<u128> {
+    let start_price = get_price_from_id(start_active_id, bin_step)?;
+    let end_price = get_price_from_id(end_active_id, bin_step)?;
+    let delta_price = start_price.abs_diff(end_price);
+
+    let price_impact_bps = delta_price
+        .safe_mul(BASIS_POINT_MAX as u128)?
+        .safe_div(start_price)?;
+
+    Ok(price_impact_bps)
+}
+
+/// Reject the swap when the current point of the pair passed the deadline
+fn validate_deadline(lb_pair: &AccountLoader<LbPair>, deadline: Option<u64>) -> Result<()> {
+    if let Some(deadline) = deadline {
+        require!(
+            lb_pair.load()?.current_point()? <= deadline,
+            LBError::SwapDeadlineExceeded
+        );
+    }
+    Ok(())
+}
+
+/// Check the amount out, the price impact and the active bin movement of the swap against the limits of the parameters
+fn validate_swap_v2_result(
+    parameters: &SwapV2Parameters,
+    amount_out: u64,
+    start_active_id: i32,
+    end_active_id: i32,
+    bin_step: u16,
+) -> Result<()> {
+    require!(
+        amount_out >= parameters.min_amount_out,
+        LBError::ExceededAmountSlippageTolerance
+    );
+
+    if let Some(max_bin_movement) = parameters.max_bin_movement {
+        require!(
+            start_active_id.abs_diff(end_active_id) <= max_bin_movement.into(),
+            LBError::ExceededBinSlippageTolerance
+        );
+    }
+
+    if let Some(max_price_impact_bps) = parameters.max_price_impact_bps {
+        let price_impact_bps = get_price_impact_bps(start_active_id, end_active_id, bin_step)?;
+        require!(
+            price_impact_bps <= max_price_impact_bps.into(),
+            LBError::ExceededPriceImpactTolerance
+        );
+    }
+
+    Ok(())
+}
+
+/// Split the referrer and its referral fee account of the pair, passed as the last two remaining accounts of a referred swap,
+/// from the other remaining accounts.
+fn split_referral_accounts<'c, 'info>(
+    remaining_accounts: &'c [AccountInfo<'info>],
+) -> (&'c [AccountInfo<'info>], Option<&'c [AccountInfo<'info>]>) {
+    let is_referrer = |account: &AccountInfo| {
+        account.owner.eq(&crate::ID)
+            && account
+                .try_borrow_data()
+                .map(|data| data.starts_with(&Referrer::DISCRIMINATOR))
+                .unwrap_or(false)
+    };
+
+    match remaining_accounts.len().checked_sub(2) {
+        Some(referral_index) if is_referrer(&remaining_accounts[referral_index]) => {
+            let (accounts, referral_accounts) = remaining_accounts.split_at(referral_index);
+            (accounts, Some(referral_accounts))
+        }
+        _ => (remaining_accounts, None),
+    }
+}
+
+/// Split the system program, leading the remaining accounts when the swap may grow the oracle
+fn split_system_program<'c, 'info>(
+    remaining_accounts: &'c [AccountInfo<'info>],
+) -> (Option<&'c AccountInfo<'info>>, &'c [AccountInfo<'info>]) {
+    match remaining_accounts.split_first() {
+        Some((account, accounts)) if account.key() == system_program::ID => {
+            (Some(account), accounts)
+        }
+        _ => (None, remaining_accounts),
+    }
+}
+
+/// Bin arrays and transfer hook accounts of the remaining accounts, between the leading system program and the trailing referral accounts
+fn split_bin_array_and_hook_accounts<'c, 'info>(
+    remaining_accounts: &'c [AccountInfo<'info>],
+) -> (&'c [AccountInfo<'info>], &'c [AccountInfo<'info>]) {
+    let (remaining_accounts, _) = split_referral_accounts(remaining_accounts);
+    let (_, remaining_accounts) = split_system_program(remaining_accounts);
+    split_transfer_hook_accounts(remaining_accounts)
+}
+
+/// Host of the swap, receiving a share of the protocol fee
+struct SwapHost<'info> {
+    /// Share of the protocol fee given to the host, in bps
+    fee_bps: Option<u16>,
+    /// Referral fee account of a registered referrer, accumulating the host fee
+    referral_fee: Option<Account<'info, ReferralFee>>,
+}
+
+/// A registered referrer earns its fee share, accumulated in its referral fee account. It takes precedence over host_fee_in.
+/// Otherwise, host_fee_in receive HOST_FEE_BPS of the protocol fee.
+fn load_swap_host<'a, 'b, 'c: 'info, 'info>(
+    ctx: &Context<'a, 'b, 'c, 'info, Swap<'info>>,
+) -> Result<SwapHost<'info>> {
+    let Some(referral_accounts) = split_referral_accounts(ctx.remaining_accounts).1 else {
+        return Ok(SwapHost {
+            fee_bps: ctx.accounts.host_fee_in.as_ref().map(|_| HOST_FEE_BPS),
+            referral_fee: None,
+        });
+    };
+
+    let referrer = Account::<Referrer>::try_from(&referral_accounts[0])?;
+    let referral_fee = Account::<ReferralFee>::try_from(&referral_accounts[1])
+        .map_err(|_| LBError::InvalidReferralFeeAccount)?;
+    require!(
+        referral_accounts[1].is_writable
+            && referral_fee.referrer == referrer.key()
+            && referral_fee.lb_pair == ctx.accounts.lb_pair.key(),
+        LBError::InvalidReferralFeeAccount
+    );
+
+    Ok(SwapHost {
+        fee_bps: Some(referrer.fee_share),
+        referral_fee: Some(referral_fee),
+    })
+}
+
+/// Reject the swap when the pair is halted by the circuit breaker
+fn validate_circuit_breaker(lb_pair: &AccountLoader<LbPair>, current_timestamp: i64) -> Result<()> {
+    require!(
+        !lb_pair.load()?.circuit_breaker.is_halted(current_timestamp),
+        LBError::CircuitBreakerHalted
+    );
+    Ok(())
+}
+
+/// Trip the circuit breaker when the active bin after the swap moved beyond the allowed bins from the time weighted active bin of the oracle.
+/// The swap which trip the circuit breaker is not reverted, subsequent swaps are halted until the cooldown ends.
+fn update_circuit_breaker<'a, 'b, 'c, 'info>(
+    ctx: &Context<'a, 'b, 'c, 'info, Swap<'info>>,
+    current_timestamp: i64,
+) -> Result<()> {
+    let mut lb_pair = ctx.accounts.lb_pair.load_mut()?;
+    if !lb_pair.circuit_breaker.is_enabled() {
+        return Ok(());
+    }
+
+    let oracle = ctx.accounts.oracle.load_content()?;
+    let Some(time_weighted_active_id) = oracle.get_time_weighted_active_id(
+        lb_pair.active_id,
+        current_timestamp,
+        lb_pair.circuit_breaker.window.into(),
+    )?
+    else {
+        return Ok(());
+    };
+
+    if lb_pair
+        .circuit_breaker
+        .is_exceeded(lb_pair.active_id, time_weighted_active_id)
+    {
+        lb_pair.circuit_breaker.trip(current_timestamp)?;
+
+        emit_cpi!(CircuitBreakerTripped {
+            lb_pair: ctx.accounts.lb_pair.key(),
+            active_id: lb_pair.active_id,
+            time_weighted_active_id,
+            halted_until: lb_pair.circuit_breaker.halted_until,
+        });
+    }
+
+    Ok(())
+}
+
+/// Grow the oracle by the auto-growth length of the pair, with the rent top-up paid by the user. Skipped unless the user is writable
+/// and the system program leads the remaining accounts, so the swap never fail for lack of the top-up.
+fn grow_oracle<'a, 'b, 'c, 'info>(ctx: &Context<'a, 'b, 'c, 'info, Swap<'info>>) -> Result<()> {
+    let length_to_add = {
+        let oracle_parameters = ctx.accounts.lb_pair.load()?.oracle_parameters;
+        oracle_parameters.get_auto_growth_length(ctx.accounts.oracle.load()?.length)?
+    };
+    if length_to_add == 0 || !ctx.accounts.user.is_writable {
+        return Ok(());
+    }
+
+    let (Some(system_program), _) = split_system_program(ctx.remaining_accounts) else {
+        return Ok(());
+    };
+
+    let oracle_info = ctx.accounts.oracle.to_account_info();
+    let new_space = Oracle::new_space(length_to_add, &ctx.accounts.oracle)?;
+    let top_up = Rent::get()?
+        .minimum_balance(new_space)
+        .saturating_sub(oracle_info.lamports());
+
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: ctx.accounts.user.to_account_info(),
+                    to: oracle_info.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+    oracle_info.realloc(new_space, false)?;
+
+    let mut oracle = ctx.accounts.oracle.load_mut()?;
+    oracle.increase_length(length_to_add)?;
+
+    emit_cpi!(IncreaseObservation {
+        oracle: ctx.accounts.oracle.key(),
+        new_observation_length: oracle.length,
+    });
+
+    Ok(())
+}
+
+/// Amounts of a swap through the bins of the pair, excluding the transfer fee
+struct BinSwapResult {
+    start_bin_id: i32,
+    end_bin_id: i32,
+    /// Amount swapped into the bins, including the swap fee
+    amount_in: u64,
+    /// Amount swapped out from the bins
+    amount_out: u64,
+    fee: u64,
+    /// Part of fee, excluding the host fee
+    protocol_fee: u64,
+    /// Part of protocol fee
+    host_fee: u64,
+    /// Total fee rate at the end of the swap, in basis point
+    fee_bps: u128,
+}
+
+/// Amounts of a swap from the point of view of the user
+pub struct SwapOutcome {
+    pub start_bin_id: i32,
+    pub end_bin_id: i32,
+    /// Amount sent by the user, including the swap fee and the transfer fee
+    pub amount_in: u64,
+    /// Amount received by the user, excluding the transfer fee
+    pub amount_out: u64,
+}
+
+/// Swap through the bins of the pair, starting from the active bin. `amount` is the amount in for exact in swap, or the amount out for exact out swap.
+/// Bin arrays are the leading remaining accounts.
+fn swap_in_bins<'a, 'b, 'c: 'info, 'info>(
+    ctx: &Context<'a, 'b, 'c, 'info, Swap<'info>>,
+    amount: u64,
+    swap_for_y: bool,
+    exact_out: bool,
+    host_fee_bps: Option<u16>,
+    current_timestamp: i64,
+) -> Result<BinSwapResult> {
+    let (bin_array_infos, _) = split_bin_array_and_hook_accounts(ctx.remaining_accounts);
+    let bin_arrays = bin_array_infos
+        .iter()
+        .map(|account_info| {
+            let bin_array = AccountLoader::<BinArray>::try_from(account_info)?;
+            require!(
+                bin_array.load()?.lb_pair == ctx.accounts.lb_pair.key(),
+                LBError::InvalidBinArray
+            );
+            Ok(bin_array)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut lb_pair = ctx.accounts.lb_pair.load_mut()?;
+    require!(
+        get_lb_pair_type_access_validator(&lb_pair)?.validate_swap_access(ctx.accounts.user.key()),
+        LBError::PoolDisabled
+    );
+
+    lb_pair.update_references(current_timestamp)?;
+
+    // Sample the active bin before the swap moves it
+    ctx.accounts.oracle.load_content_mut()?.update(
+        lb_pair.active_id,
+        current_timestamp,
+        lb_pair.oracle_parameters.get_sample_lifetime(),
+    )?;
+
+    let start_bin_id = lb_pair.active_id;
+    let mut amount_left = amount;
+    let (mut total_amount_in, mut total_amount_out) = (0u64, 0u64);
+    let (mut total_fee, mut total_protocol_fee, mut total_host_fee) = (0u64, 0u64, 0u64);
+
+    while amount_left > 0 {
+        lb_pair.next_bin_array_index_with_liquidity(
+            swap_for_y,
+            &ctx.accounts.bin_array_bitmap_extension,
+        )?;
+
+        let bin_array_index = i64::from(BinArray::bin_id_to_bin_array_index(lb_pair.active_id)?);
+        let bin_array = bin_arrays
+            .iter()
+            .find(|bin_array| {
+                bin_array
+                    .load()
+                    .map(|bin_array| bin_array.index == bin_array_index)
+                    .unwrap_or(false)
+            })
+            .ok_or(LBError::BinArrayNotFound)?;
+        let mut bin_array = bin_array.load_mut()?;
+
+        bin_array.update_all_rewards(&mut lb_pair, current_timestamp as u64)?;
+
+        while amount_left > 0 && bin_array.is_bin_id_within_range(lb_pair.active_id).is_ok() {
+            lb_pair.update_volatility_accumulator()?;
+
+            let active_bin = bin_array.get_bin_mut(lb_pair.active_id)?;
+            let price = active_bin.get_or_store_bin_price(lb_pair.active_id, lb_pair.bin_step)?;
+
+            if !active_bin.is_empty(!swap_for_y) {
+                let SwapResult {
+                    amount_in_with_fees,
+                    amount_out,
+                    fee,
+                    protocol_fee_after_host_fee,
+                    host_fee,
+                    ..
+                } = if exact_out {
+                    active_bin.swap_exact_out(
+                        u64::MAX,
+                        price,
+                        swap_for_y,
+                        &lb_pair,
+                        host_fee_bps,
+                        amount_left,
+                    )?
+                } else {
+                    active_bin.swap(amount_left, price, swap_for_y, &lb_pair, host_fee_bps)?
+                };
+
+                amount_left = if exact_out {
+                    amount_left.saturating_sub(amount_out)
+                } else {
+                    amount_left.safe_sub(amount_in_with_fees)?
+                };
+
+                // Fee of the liquidity providers, claimable by the positions
+                let lp_fee = fee
+                    .safe_sub(protocol_fee_after_host_fee)?
+                    .safe_sub(host_fee)?;
+                active_bin.update_fee_per_token_stored(lp_fee, swap_for_y)?;
+
+                if swap_for_y {
+                    lb_pair.accumulate_protocol_fees(protocol_fee_after_host_fee, 0)?;
+                    active_bin.accumulate_amounts_in(amount_in_with_fees, 0);
+                } else {
+                    lb_pair.accumulate_protocol_fees(0, protocol_fee_after_host_fee)?;
+                    active_bin.accumulate_amounts_in(0, amount_in_with_fees);
+                }
+
+                total_amount_in = total_amount_in.safe_add(amount_in_with_fees)?;
+                total_amount_out = total_amount_out.safe_add(amount_out)?;
+                total_fee = total_fee.safe_add(fee)?;
+                total_protocol_fee = total_protocol_fee.safe_add(protocol_fee_after_host_fee)?;
+                total_host_fee = total_host_fee.safe_add(host_fee)?;
+            }
+
+            if amount_left > 0 {
+                lb_pair.advance_active_bin(swap_for_y)?;
+            }
+        }
+    }
+
+    let fee_bps = lb_pair
+        .get_total_fee()?
+        .safe_mul(BASIS_POINT_MAX as u128)?
+        .safe_div(FEE_PRECISION.into())?;
+
+    Ok(BinSwapResult {
+        start_bin_id,
+        end_bin_id: lb_pair.active_id,
+        amount_in: total_amount_in,
+        amount_out: total_amount_out,
+        fee: total_fee,
+        protocol_fee: total_protocol_fee,
+        host_fee: total_host_fee,
+        fee_bps,
+    })
+}
+
+/// Swap through the bins of the pair, then transfer the amount in from the user to the reserve, the amount out from the reserve to the user,
+/// and the host fee from the reserve to host_fee_in, or to the referral fee of the referrer. Remaining accounts are the bin arrays, followed
+/// by the extra accounts required by the transfer hook of token X and Y, and the referrer and its referral fee account for a referred swap.
+fn execute_swap<'a, 'b, 'c: 'info, 'info>(
+    ctx: &Context<'a, 'b, 'c, 'info, Swap<'info>>,
+    amount: u64,
+    exact_out: bool,
+    host: &mut SwapHost<'info>,
+    current_timestamp: i64,
+) -> Result<SwapOutcome> {
+    let accounts = &ctx.accounts;
+    let swap_for_y = accounts
+        .lb_pair
+        .load()?
+        .swap_for_y(accounts.user_token_out.mint);
+
+    let (
+        token_in_mint,
+        token_out_mint,
+        reserve_in,
+        reserve_out,
+        token_in_program,
+        token_out_program,
+    ) = if swap_for_y {
+        (
+            &accounts.token_x_mint,
+            &accounts.token_y_mint,
+            &accounts.reserve_x,
+            &accounts.reserve_y,
+            &accounts.token_x_program,
+            &accounts.token_y_program,
+        )
+    } else {
+        (
+            &accounts.token_y_mint,
+            &accounts.token_x_mint,
+            &accounts.reserve_y,
+            &accounts.reserve_x,
+            &accounts.token_y_program,
+            &accounts.token_x_program,
+        )
+    };
+
+    if let Some(host_fee_in) = &accounts.host_fee_in {
+        require!(
+            host_fee_in.mint == token_in_mint.key(),
+            LBError::InvalidTokenMint
+        );
+    }
+
+    let epoch = Clock::get()?.epoch;
+    let token_in_mint_info = token_in_mint.to_account_info();
+    let token_out_mint_info = token_out_mint.to_account_info();
+
+    // The reserve receive the amount in less the transfer fee, and must send the amount out plus the transfer fee
+    let bin_swap_amount = if exact_out {
+        calculate_transfer_fee_included_amount(
+            &token_out_mint_info.try_borrow_data()?,
+            amount,
+            epoch,
+        )?
+        .amount
+    } else {
+        calculate_transfer_fee_excluded_amount(
+            &token_in_mint_info.try_borrow_data()?,
+            amount,
+            epoch,
+        )?
+        .amount
+    };
+
+    let bin_swap_result = swap_in_bins(
+        ctx,
+        bin_swap_amount,
+        swap_for_y,
+        exact_out,
+        host.fee_bps,
+        current_timestamp,
+    )?;
+
+    let amount_in = if exact_out {
+        calculate_transfer_fee_included_amount(
+            &token_in_mint_info.try_borrow_data()?,
+            bin_swap_result.amount_in,
+            epoch,
+        )?
+        .amount
+    } else {
+        amount
+    };
+    let amount_out = calculate_transfer_fee_excluded_amount(
+        &token_out_mint_info.try_borrow_data()?,
+        bin_swap_result.amount_out,
+        epoch,
+    )?
+    .amount;
+
+    let (_, transfer_hook_accounts) = split_bin_array_and_hook_accounts(ctx.remaining_accounts);
+    let lb_pair = accounts.lb_pair.load()?;
+    let signer_seeds = &[&lb_pair.seeds()?[..]];
+
+    transfer_checked_with_hook(
+        token_in_program.to_account_info(),
+        accounts.user_token_in.to_account_info(),
+        token_in_mint,
+        reserve_in.to_account_info(),
+        accounts.user.to_account_info(),
+        transfer_hook_accounts,
+        amount_in,
+        &[],
+    )?;
+
+    transfer_checked_with_hook(
+        token_out_program.to_account_info(),
+        reserve_out.to_account_info(),
+        token_out_mint,
+        accounts.user_token_out.to_account_info(),
+        accounts.lb_pair.to_account_info(),
+        transfer_hook_accounts,
+        bin_swap_result.amount_out,
+        signer_seeds,
+    )?;
+
+    if let Some(referral_fee) = host.referral_fee.as_mut() {
+        // The referral fee stay in the reserve until claimed by the referrer
+        referral_fee.accumulate(bin_swap_result.host_fee, swap_for_y)?;
+        referral_fee.exit(&crate::ID)?;
+    } else if let Some(host_fee_in) = &accounts.host_fee_in {
+        if bin_swap_result.host_fee > 0 {
+            transfer_checked_with_hook(
+                token_in_program.to_account_info(),
+                reserve_in.to_account_info(),
+                token_in_mint,
+                host_fee_in.to_account_info(),
+                accounts.lb_pair.to_account_info(),
+                transfer_hook_accounts,
+                bin_swap_result.host_fee,
+                signer_seeds,
+            )?;
+        }
+    }
+
+    emit_cpi!(SwapEvent {
+        lb_pair: accounts.lb_pair.key(),
+        from: accounts.user.key(),
+        start_bin_id: bin_swap_result.start_bin_id,
+        end_bin_id: bin_swap_result.end_bin_id,
+        amount_in: bin_swap_result.amount_in,
+        amount_out: bin_swap_result.amount_out,
+        swap_for_y,
+        fee: bin_swap_result.fee,
+        protocol_fee: bin_swap_result.protocol_fee,
+        fee_bps: bin_swap_result.fee_bps,
+        host_fee: bin_swap_result.host_fee,
+    });
+
+    Ok(SwapOutcome {
+        start_bin_id: bin_swap_result.start_bin_id,
+        end_bin_id: bin_swap_result.end_bin_id,
+        amount_in,
+        amount_out,
+    })
+}
+
+pub fn handle_exact_in<'a, 'b, 'c: 'info, 'info>(
     ctx: Context<'a, 'b, 'c, 'info, Swap<'info>>,
     amount_in: u64,
     min_amount_out: u64,
 ) -> Result<()> {
-    Ok(())
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    validate_circuit_breaker(&ctx.accounts.lb_pair, current_timestamp)?;
+    let mut host = load_swap_host(&ctx)?;
+
+    let SwapOutcome { amount_out, .. } =
+        execute_swap(&ctx, amount_in, false, &mut host, current_timestamp)?;
+    require!(
+        amount_out >= min_amount_out,
+        LBError::ExceededAmountSlippageTolerance
+    );
+
+    grow_oracle(&ctx)?;
+    update_circuit_breaker(&ctx, current_timestamp)
 }
 
-pub fn handle_exact_out<'a, 'b, 'c, 'info>(
+pub fn handle_exact_out<'a, 'b, 'c: 'info, 'info>(
     ctx: Context<'a, 'b, 'c, 'info, Swap<'info>>,
     max_in_amount: u64,
     exact_out_amount: u64,
 ) -> Result<()> {
-    Ok(())
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    validate_circuit_breaker(&ctx.accounts.lb_pair, current_timestamp)?;
+    let mut host = load_swap_host(&ctx)?;
+
+    let SwapOutcome { amount_in, .. } =
+        execute_swap(&ctx, exact_out_amount, true, &mut host, current_timestamp)?;
+    require!(
+        amount_in <= max_in_amount,
+        LBError::ExceededAmountSlippageTolerance
+    );
+
+    grow_oracle(&ctx)?;
+    update_circuit_breaker(&ctx, current_timestamp)
 }
-pub fn handle_exact_in_with_price_impact<'a, 'b, 'c, 'info>(
+
+pub fn handle_exact_in_with_price_impact<'a, 'b, 'c: 'info, 'info>(
     ctx: Context<'a, 'b, 'c, 'info, Swap<'info>>,
     amount_in: u64,
     active_id: Option<i32>,
     max_price_impact_bps: u16,
 ) -> Result<()> {
-    Ok(())
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    validate_circuit_breaker(&ctx.accounts.lb_pair, current_timestamp)?;
+    let mut host = load_swap_host(&ctx)?;
+
+    let SwapOutcome {
+        start_bin_id,
+        end_bin_id,
+        ..
+    } = execute_swap(&ctx, amount_in, false, &mut host, current_timestamp)?;
+
+    // The price impact is measured from the active bin observed off-chain, when provided
+    let bin_step = ctx.accounts.lb_pair.load()?.bin_step;
+    let price_impact_bps =
+        get_price_impact_bps(active_id.unwrap_or(start_bin_id), end_bin_id, bin_step)?;
+    require!(
+        price_impact_bps <= max_price_impact_bps.into(),
+        LBError::ExceededPriceImpactTolerance
+    );
+
+    grow_oracle(&ctx)?;
+    update_circuit_breaker(&ctx, current_timestamp)
+}
+
+/// Swap exact in with every protection of the parameters enforced together. The deadline is checked before swapping,
+/// the amount out, price impact and active bin movement after swapping.
+pub fn handle_exact_in_v2<'a, 'b, 'c: 'info, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, Swap<'info>>,
+    parameters: SwapV2Parameters,
+) -> Result<()> {
+    validate_deadline(&ctx.accounts.lb_pair, parameters.deadline)?;
+
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    validate_circuit_breaker(&ctx.accounts.lb_pair, current_timestamp)?;
+    let mut host = load_swap_host(&ctx)?;
+
+    let SwapOutcome {
+        start_bin_id,
+        end_bin_id,
+        amount_out,
+        ..
+    } = execute_swap(
+        &ctx,
+        parameters.amount_in,
+        false,
+        &mut host,
+        current_timestamp,
+    )?;
+
+    let bin_step = ctx.accounts.lb_pair.load()?.bin_step;
+    validate_swap_v2_result(&parameters, amount_out, start_bin_id, end_bin_id, bin_step)?;
+
+    grow_oracle(&ctx)?;
+    update_circuit_breaker(&ctx, current_timestamp)
 }
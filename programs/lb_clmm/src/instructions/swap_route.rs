@@ -0,0 +1,210 @@
+use crate::constants::MAX_SWAP_ROUTE_HOPS;
+use crate::errors::LBError;
+use crate::instructions::swap::{handle_exact_in, Swap, SwapBumps};
+use crate::math::safe_math::SafeMath;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use std::collections::BTreeSet;
+
+/// Number of accounts of the Swap accounts, which lead the accounts of each hop in the remaining accounts
+pub const SWAP_ACCOUNTS_LEN: usize = 15;
+
+/// Number of referral accounts of a referred swap, the referrer and its referral fee account of the pair
+pub const REFERRAL_ACCOUNTS_LEN: usize = 2;
+
+#[derive(Accounts)]
+pub struct SwapRoute<'info> {
+    /// Token account the route swap from. Must be the user_token_in of the first hop.
+    #[account(mut)]
+    pub user_token_in: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Token account the route swap to. Must be the user_token_out of the last hop.
+    #[account(
+        mut,
+        constraint = user_token_in.mint != user_token_out.mint @ LBError::InvalidTokenMint,
+    )]
+    pub user_token_out: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub user: Signer<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Default)]
+pub struct SwapRouteHopParameter {
+    /// Number of bin arrays of the hop, following the Swap accounts of the hop
+    pub bin_array_count: u8,
+    /// Number of extra accounts required by the transfer hooks of token X and Y of the hop, following the bin arrays
+    pub transfer_hook_account_count: u8,
+    /// Whether the referrer and its referral fee account of the pair follow the transfer hook accounts
+    pub referred: bool,
+}
+
+impl SwapRouteHopParameter {
+    /// Number of accounts of the hop in the remaining accounts
+    pub fn accounts_len(&self) -> Result<usize> {
+        let referral_accounts_len = if self.referred {
+            REFERRAL_ACCOUNTS_LEN
+        } else {
+            0
+        };
+
+        let accounts_len = SWAP_ACCOUNTS_LEN
+            .safe_add(self.bin_array_count.into())?
+            .safe_add(self.transfer_hook_account_count.into())?
+            .safe_add(referral_accounts_len)?;
+        Ok(accounts_len)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Default)]
+pub struct SwapRouteParameters {
+    /// Amount of token to be swapped in at the first hop
+    pub amount_in: u64,
+    /// Minimum amount of token to be swapped out at the last hop
+    pub min_amount_out: u64,
+    /// Accounts of each hop, in the order of the route
+    pub hops: Vec<SwapRouteHopParameter>,
+}
+
+/// Swap exact in through the pairs of the route, in order. The remaining accounts of each hop are the accounts of `swap`, followed by
+/// the remaining accounts of `swap`: the bin arrays, the transfer hook accounts and the referral accounts of the hop.
+/// The output of a hop is the input of the next hop, only the final amount out is checked.
+pub fn handle<'a, 'b, 'c: 'info, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, SwapRoute<'info>>,
+    parameters: SwapRouteParameters,
+) -> Result<()> {
+    let SwapRouteParameters {
+        amount_in,
+        min_amount_out,
+        hops,
+    } = parameters;
+
+    require!(
+        !hops.is_empty() && hops.len() <= MAX_SWAP_ROUTE_HOPS,
+        LBError::InvalidSwapRoute
+    );
+
+    let user = ctx.accounts.user.key();
+    let amount_out_before = ctx.accounts.user_token_out.amount;
+
+    let mut remaining_accounts: &'info [AccountInfo<'info>] = ctx.remaining_accounts;
+    let mut hop_token_in = ctx.accounts.user_token_in.key();
+    let mut hop_amount_in = amount_in;
+
+    for hop in hops {
+        let hop_accounts_len = hop.accounts_len()?;
+        require!(
+            remaining_accounts.len() >= hop_accounts_len,
+            LBError::InvalidSwapRoute
+        );
+
+        let (hop_account_infos, rest) = remaining_accounts.split_at(hop_accounts_len);
+        remaining_accounts = rest;
+
+        let (mut swap_account_infos, hop_remaining_accounts) =
+            hop_account_infos.split_at(SWAP_ACCOUNTS_LEN);
+
+        let mut bumps = SwapBumps::default();
+        let mut swap_accounts = Swap::try_accounts(
+            ctx.program_id,
+            &mut swap_account_infos,
+            &[],
+            &mut bumps,
+            &mut BTreeSet::new(),
+        )?;
+
+        require!(
+            swap_accounts.user.key() == user && swap_accounts.user_token_in.key() == hop_token_in,
+            LBError::InvalidSwapRoute
+        );
+
+        let hop_amount_out_before = swap_accounts.user_token_out.amount;
+
+        handle_exact_in(
+            Context::new(
+                ctx.program_id,
+                &mut swap_accounts,
+                hop_remaining_accounts,
+                bumps,
+            ),
+            hop_amount_in,
+            0,
+        )?;
+        swap_accounts.exit(ctx.program_id)?;
+
+        swap_accounts.user_token_out.reload()?;
+        hop_amount_in = swap_accounts
+            .user_token_out
+            .amount
+            .safe_sub(hop_amount_out_before)?;
+        hop_token_in = swap_accounts.user_token_out.key();
+    }
+
+    // Every remaining account must belong to a hop
+    require!(remaining_accounts.is_empty(), LBError::InvalidSwapRoute);
+    require!(
+        hop_token_in == ctx.accounts.user_token_out.key(),
+        LBError::InvalidSwapRoute
+    );
+
+    ctx.accounts.user_token_out.reload()?;
+    let amount_out = ctx
+        .accounts
+        .user_token_out
+        .amount
+        .safe_sub(amount_out_before)?;
+
+    require!(
+        amount_out >= min_amount_out,
+        LBError::ExceededAmountSlippageTolerance
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_swap_accounts_len() {
+        let accounts = crate::accounts::Swap {
+            lb_pair: Pubkey::new_unique(),
+            bin_array_bitmap_extension: None,
+            reserve_x: Pubkey::new_unique(),
+            reserve_y: Pubkey::new_unique(),
+            user_token_in: Pubkey::new_unique(),
+            user_token_out: Pubkey::new_unique(),
+            token_x_mint: Pubkey::new_unique(),
+            token_y_mint: Pubkey::new_unique(),
+            oracle: Pubkey::new_unique(),
+            host_fee_in: None,
+            user: Pubkey::new_unique(),
+            token_x_program: Pubkey::new_unique(),
+            token_y_program: Pubkey::new_unique(),
+            event_authority: Pubkey::new_unique(),
+            program: crate::ID,
+        };
+
+        // Optional accounts are passed as the program id placeholder, they always take a slot
+        assert_eq!(accounts.to_account_metas(None).len(), SWAP_ACCOUNTS_LEN);
+    }
+
+    #[test]
+    fn test_hop_accounts_len() {
+        let hop = SwapRouteHopParameter {
+            bin_array_count: 2,
+            transfer_hook_account_count: 3,
+            referred: false,
+        };
+        assert_eq!(hop.accounts_len().unwrap(), SWAP_ACCOUNTS_LEN + 5);
+
+        let hop = SwapRouteHopParameter {
+            referred: true,
+            ..hop
+        };
+        assert_eq!(
+            hop.accounts_len().unwrap(),
+            SWAP_ACCOUNTS_LEN + 5 + REFERRAL_ACCOUNTS_LEN
+        );
+    }
+}
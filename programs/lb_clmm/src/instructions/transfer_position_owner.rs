@@ -0,0 +1,51 @@
+use crate::errors::LBError;
+use crate::events::TransferPositionOwner as TransferPositionOwnerEvent;
+use crate::pair_action_access::get_lb_pair_type_access_validator;
+use crate::state::{lb_pair::LbPair, position::PositionV2};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct TransferPositionOwner<'info> {
+    #[account(
+        mut,
+        has_one = lb_pair,
+        constraint = position.load()?.owner == sender.key() @ LBError::UnauthorizedAccess
+    )]
+    pub position: AccountLoader<'info, PositionV2>,
+
+    pub lb_pair: AccountLoader<'info, LbPair>,
+
+    /// Only the owner can hand over the position. The operator is not allowed to.
+    pub sender: Signer<'info>,
+}
+
+pub fn handle(ctx: Context<TransferPositionOwner>, new_owner: Pubkey) -> Result<()> {
+    let mut position = ctx.accounts.position.load_mut()?;
+    let lb_pair = ctx.accounts.lb_pair.load()?;
+
+    // Ownership of tokenized position follows the position NFT
+    require!(!position.is_tokenized(), LBError::PositionIsTokenized);
+    require!(new_owner.ne(&Pubkey::default()), LBError::InvalidInput);
+    require!(new_owner.ne(&position.owner), LBError::OwnersAreTheSame);
+
+    // Locked liquidity is a commitment of the current owner. It can't be handed over until released.
+    let pair_access_validator = get_lb_pair_type_access_validator(&lb_pair)?;
+    let current_point = pair_access_validator.get_current_point();
+    require!(
+        !position.is_liquidity_locked(current_point)
+            && position.get_vesting_locked_ratio(current_point)? == 0,
+        LBError::LiquidityLocked
+    );
+
+    let old_owner = position.owner;
+    position.transfer_owner(new_owner);
+
+    emit_cpi!(TransferPositionOwnerEvent {
+        position: ctx.accounts.position.key(),
+        old_owner,
+        new_owner,
+    });
+
+    Ok(())
+}
@@ -1,13 +1,14 @@
 use crate::authorize_modify_position;
 use crate::state::{bin::BinArray, lb_pair::LbPair, position::PositionV2};
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
 
 #[derive(Accounts)]
 pub struct UpdateFeesAndRewards<'info> {
     #[account(
         mut,
         has_one = lb_pair,
-        constraint = authorize_modify_position(&position, owner.key())?
+        constraint = authorize_modify_position(&position, &position_nft_account, owner.key())?
     )]
     pub position: AccountLoader<'info, PositionV2>,
 
@@ -26,6 +27,9 @@ pub struct UpdateFeesAndRewards<'info> {
     pub bin_array_upper: AccountLoader<'info, BinArray>,
 
     pub owner: Signer<'info>,
+
+    /// Token account holding the position NFT. Only required for tokenized position.
+    pub position_nft_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 }
 
 pub fn handle(ctx: Context<UpdateFeesAndRewards>) -> Result<()> {
@@ -1,9 +1,26 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::BASIS_POINT_MAX;
 use crate::ModifyLiquidity;
 
+use super::{withdraw_from_position, BinLiquidityReduction};
+
 pub fn handle<'a, 'b, 'c, 'info>(
     ctx: Context<'a, 'b, 'c, 'info, ModifyLiquidity<'info>>,
 ) -> Result<()> {
-    Ok(())
+    let bin_liquidity_reduction = {
+        let position = ctx.accounts.position.load()?;
+        let mut bin_liquidity_reduction = vec![];
+        for bin_id in position.lower_bin_id..=position.upper_bin_id {
+            if position.get_liquidity_share_in_bin(bin_id)? > 0 {
+                bin_liquidity_reduction.push(BinLiquidityReduction {
+                    bin_id,
+                    bps_to_remove: BASIS_POINT_MAX as u16,
+                });
+            }
+        }
+        bin_liquidity_reduction
+    };
+
+    withdraw_from_position(&ctx, &bin_liquidity_reduction)
 }
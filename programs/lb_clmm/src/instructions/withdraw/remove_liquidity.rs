@@ -1,4 +1,8 @@
 use crate::constants::BASIS_POINT_MAX;
+use crate::events::RemoveLiquidity as RemoveLiquidityEvent;
+use crate::instructions::deposit::load_position_bin_arrays;
+use crate::manager::bin_array_manager::BinArrayManager;
+use crate::pair_action_access::get_lb_pair_type_access_validator;
 use crate::ModifyLiquidity;
 use crate::{errors::LBError, math::safe_math::SafeMath, state::position::PositionV2};
 use anchor_lang::prelude::*;
@@ -20,9 +24,133 @@ pub fn calculate_shares_to_remove(bps: u16, bin_id: i32, position: &PositionV2)
     Ok(share_to_remove)
 }
 
+/// Vesting liquidity is tracked by a single remaining ratio, therefore it must be withdrawn by the same bps from every bin with liquidity.
+/// Each bin can only be reduced once, as every reduction of the same bin is applied on the remaining liquidity.
+pub fn get_vesting_bps_to_remove(
+    bin_liquidity_reduction: &[BinLiquidityReduction],
+    position: &PositionV2,
+) -> Result<u16> {
+    let bps_to_remove = bin_liquidity_reduction
+        .first()
+        .map(|reduction| reduction.bps_to_remove)
+        .ok_or(LBError::InvalidInput)?;
+
+    let mut bin_ids: Vec<i32> = bin_liquidity_reduction
+        .iter()
+        .map(|reduction| reduction.bin_id)
+        .collect();
+    bin_ids.sort_unstable();
+    require!(
+        bin_ids.windows(2).all(|pair| pair[0] != pair[1]),
+        LBError::UnevenVestingWithdrawal
+    );
+
+    for bin_id in position.lower_bin_id..=position.upper_bin_id {
+        if position.get_liquidity_share_in_bin(bin_id)? == 0 {
+            continue;
+        }
+        let reduction = bin_liquidity_reduction
+            .iter()
+            .find(|reduction| reduction.bin_id == bin_id)
+            .ok_or(LBError::UnevenVestingWithdrawal)?;
+        require!(
+            reduction.bps_to_remove == bps_to_remove,
+            LBError::UnevenVestingWithdrawal
+        );
+    }
+
+    Ok(bps_to_remove)
+}
+
 pub fn handle<'a, 'b, 'c, 'info>(
     ctx: Context<'a, 'b, 'c, 'info, ModifyLiquidity<'info>>,
     bin_liquidity_reduction: Vec<BinLiquidityReduction>,
 ) -> Result<()> {
+    withdraw_from_position(&ctx, &bin_liquidity_reduction)
+}
+
+/// Withdraw the liquidity from the bins of the position, and transfer the amounts from the reserves to the user.
+/// Remaining accounts are the extra accounts required by the transfer hook of token X and Y.
+pub fn withdraw_from_position<'a, 'b, 'c, 'info>(
+    ctx: &Context<'a, 'b, 'c, 'info, ModifyLiquidity<'info>>,
+    bin_liquidity_reduction: &[BinLiquidityReduction],
+) -> Result<()> {
+    let (amount_x, amount_y, active_id) = {
+        let mut lb_pair = ctx.accounts.lb_pair.load_mut()?;
+        let mut position = ctx.accounts.position.load_mut()?;
+
+        let current_point = lb_pair.current_point()?;
+        require!(
+            !position.is_liquidity_locked(current_point),
+            LBError::LiquidityLocked
+        );
+
+        if position.has_vesting() {
+            let bps_to_remove = get_vesting_bps_to_remove(bin_liquidity_reduction, &position)?;
+            position.withdraw_vesting_liquidity(bps_to_remove, current_point)?;
+        }
+
+        let mut bin_arrays =
+            load_position_bin_arrays(&ctx.accounts.bin_array_lower, &ctx.accounts.bin_array_upper)?;
+        let mut bin_array_manager = BinArrayManager::new(&mut bin_arrays)?;
+        bin_array_manager.validate_bin_arrays(position.lower_bin_id)?;
+        bin_array_manager.update_rewards(&mut lb_pair)?;
+
+        position.update_earning_per_token_stored(&bin_array_manager)?;
+
+        let zero_liquidity_flags = bin_array_manager.get_zero_liquidity_flags();
+
+        let (mut amount_x, mut amount_y) = (0u64, 0u64);
+        for reduction in bin_liquidity_reduction {
+            require!(
+                reduction.bps_to_remove <= BASIS_POINT_MAX as u16,
+                LBError::InvalidBps
+            );
+
+            let shares_to_remove =
+                calculate_shares_to_remove(reduction.bps_to_remove, reduction.bin_id, &position)?;
+            if shares_to_remove == 0 {
+                continue;
+            }
+
+            let bin = bin_array_manager.get_bin_mut(reduction.bin_id)?;
+            let (out_amount_x, out_amount_y) = bin.withdraw(shares_to_remove)?;
+            position.withdraw(reduction.bin_id, shares_to_remove)?;
+
+            amount_x = amount_x.safe_add(out_amount_x)?;
+            amount_y = amount_y.safe_add(out_amount_y)?;
+        }
+
+        require!(
+            get_lb_pair_type_access_validator(&lb_pair)?
+                .validate_remove_liquidity_access(amount_x > 0)?,
+            LBError::PoolDisabled
+        );
+
+        for (idx, is_zero_liquidity) in zero_liquidity_flags.into_iter().enumerate() {
+            if !is_zero_liquidity && bin_array_manager.get_zero_liquidity_flags()[idx] {
+                lb_pair.flip_bin_array_bit(
+                    &ctx.accounts.bin_array_bitmap_extension,
+                    bin_array_manager.get_bin_array_index(idx)?,
+                )?;
+            }
+        }
+
+        position.set_last_updated_at(Clock::get()?.unix_timestamp);
+
+        (amount_x, amount_y, lb_pair.active_id)
+    };
+
+    ctx.accounts
+        .transfer_to_user(amount_x, amount_y, ctx.remaining_accounts)?;
+
+    emit_cpi!(RemoveLiquidityEvent {
+        lb_pair: ctx.accounts.lb_pair.key(),
+        from: ctx.accounts.sender.key(),
+        position: ctx.accounts.position.key(),
+        amounts: [amount_x, amount_y],
+        active_bin_id: active_id,
+    });
+
     Ok(())
 }
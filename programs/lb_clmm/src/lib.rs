@@ -14,24 +14,34 @@ pub mod utils;
 
 use instructions::admin::*;
 use instructions::claim_fee::*;
+use instructions::claim_multiple::*;
+use instructions::claim_referral_fee::*;
 use instructions::claim_reward::*;
+use instructions::close_bin_array::*;
 use instructions::close_position::*;
+use instructions::compound_fees::*;
 use instructions::create_position::*;
 use instructions::deposit::*;
+use instructions::flash_loan::*;
 use instructions::fund_reward::*;
 use instructions::increase_oracle_length::*;
 use instructions::initialize_bin_array::*;
 use instructions::initialize_bin_array_bitmap_extension::*;
 use instructions::initialize_pool::*;
+use instructions::initialize_referral_fee::*;
 use instructions::migrate_bin_array::*;
 use instructions::migrate_position::*;
 use instructions::position_authorize::*;
+use instructions::set_position_vesting::*;
 use instructions::swap::*;
+use instructions::swap_route::*;
+use instructions::transfer_position_owner::*;
 use instructions::update_fees_and_rewards::*;
 use instructions::update_position_operator::*;
 use instructions::withdraw::*;
 use instructions::withdraw_ineligible_reward::*;
 use instructions::withdraw_protocol_fee::*;
+use state::admin_config::{AdminConfig, AdminRole};
 
 #[cfg(feature = "localnet")]
 declare_id!("LbVRzDTvBDEcrthxfZ4RL6yiq3uZw8bS6MwtdY6UhFQ");
@@ -96,6 +106,24 @@ pub fn assert_eq_launch_pool_admin(admin: Pubkey) -> bool {
         .any(|predefined_launch_pool_admin| predefined_launch_pool_admin.eq(&admin))
 }
 
+/// Check the admin against the authority of the role. Predefined admins are used until the admin config is initialized.
+pub fn assert_eq_role_admin(admin_config: &AccountInfo, role: AdminRole, admin: Pubkey) -> bool {
+    if admin_config.owner.ne(&crate::ID) {
+        return match role {
+            AdminRole::LaunchPoolAdmin => assert_eq_launch_pool_admin(admin),
+            _ => assert_eq_admin(admin),
+        };
+    }
+
+    let Ok(data) = admin_config.try_borrow_data() else {
+        return false;
+    };
+
+    AdminConfig::try_deserialize(&mut data.as_ref())
+        .map(|admin_config| admin_config.get_authority(role).eq(&admin))
+        .unwrap_or(false)
+}
+
 #[program]
 pub mod lb_clmm {
     use super::*;
@@ -136,6 +164,13 @@ pub mod lb_clmm {
         instructions::initialize_bin_array::handle(ctx, index)
     }
 
+    pub fn initialize_bin_array_with_funder(
+        ctx: Context<InitializeBinArrayWithFunder>,
+        index: i64,
+    ) -> Result<()> {
+        instructions::initialize_bin_array::handle_with_funder(ctx, index)
+    }
+
     pub fn add_liquidity<'a, 'b, 'c, 'info>(
         ctx: Context<'a, 'b, 'c, 'info, ModifyLiquidity<'info>>,
         liquidity_parameter: LiquidityParameter,
@@ -209,6 +244,20 @@ pub mod lb_clmm {
         )
     }
 
+    pub fn initialize_tokenized_position(
+        ctx: Context<InitializeTokenizedPosition>,
+        lower_bin_id: i32,
+        width: i32,
+        uri: String,
+    ) -> Result<()> {
+        instructions::create_position::initialize_tokenized_position::handle(
+            ctx,
+            lower_bin_id,
+            width,
+            uri,
+        )
+    }
+
     pub fn update_position_operator(
         ctx: Context<UpdatePositionOperator>,
         operator: Pubkey,
@@ -216,7 +265,14 @@ pub mod lb_clmm {
         instructions::update_position_operator::handle(ctx, operator)
     }
 
-    pub fn swap<'a, 'b, 'c, 'info>(
+    pub fn transfer_position_owner(
+        ctx: Context<TransferPositionOwner>,
+        new_owner: Pubkey,
+    ) -> Result<()> {
+        instructions::transfer_position_owner::handle(ctx, new_owner)
+    }
+
+    pub fn swap<'a, 'b, 'c: 'info, 'info>(
         ctx: Context<'a, 'b, 'c, 'info, Swap<'info>>,
         amount_in: u64,
         min_amount_out: u64,
@@ -363,7 +419,7 @@ pub mod lb_clmm {
         )
     }
 
-    pub fn swap_exact_out<'a, 'b, 'c, 'info>(
+    pub fn swap_exact_out<'a, 'b, 'c: 'info, 'info>(
         ctx: Context<'a, 'b, 'c, 'info, Swap<'info>>,
         max_in_amount: u64,
         out_amount: u64,
@@ -371,7 +427,7 @@ pub mod lb_clmm {
         instructions::swap::handle_exact_out(ctx, max_in_amount, out_amount)
     }
 
-    pub fn swap_with_price_impact<'a, 'b, 'c, 'info>(
+    pub fn swap_with_price_impact<'a, 'b, 'c: 'info, 'info>(
         ctx: Context<'a, 'b, 'c, 'info, Swap<'info>>,
         amount_in: u64,
         active_id: Option<i32>,
@@ -384,4 +440,124 @@ pub mod lb_clmm {
             max_price_impact_bps,
         )
     }
+
+    pub fn flash_borrow<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, FlashBorrow<'info>>,
+        amount_x: u64,
+        amount_y: u64,
+    ) -> Result<()> {
+        instructions::flash_loan::flash_borrow::handle(ctx, amount_x, amount_y)
+    }
+
+    pub fn flash_repay<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, FlashRepay<'info>>,
+        borrow_instruction_index: u16,
+    ) -> Result<()> {
+        instructions::flash_loan::flash_repay::handle(ctx, borrow_instruction_index)
+    }
+
+    pub fn update_circuit_breaker(
+        ctx: Context<UpdateCircuitBreaker>,
+        parameter: CircuitBreakerParameter,
+    ) -> Result<()> {
+        instructions::admin::update_circuit_breaker::handle(ctx, parameter)
+    }
+
+    pub fn initialize_referrer(ctx: Context<InitializeReferrer>, fee_share: u16) -> Result<()> {
+        instructions::admin::initialize_referrer::handle(ctx, fee_share)
+    }
+
+    pub fn update_referrer_fee_share(
+        ctx: Context<UpdateReferrerFeeShare>,
+        fee_share: u16,
+    ) -> Result<()> {
+        instructions::admin::update_referrer_fee_share::handle(ctx, fee_share)
+    }
+
+    pub fn initialize_referral_fee(ctx: Context<InitializeReferralFee>) -> Result<()> {
+        instructions::initialize_referral_fee::handle(ctx)
+    }
+
+    pub fn claim_referral_fee<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, ClaimReferralFee<'info>>,
+    ) -> Result<()> {
+        instructions::claim_referral_fee::handle(ctx)
+    }
+
+    pub fn swap_v2<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, Swap<'info>>,
+        parameters: SwapV2Parameters,
+    ) -> Result<()> {
+        instructions::swap::handle_exact_in_v2(ctx, parameters)
+    }
+
+    pub fn claim_fee_multiple<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, ClaimFeeMultiple<'info>>,
+        position_count: u8,
+    ) -> Result<()> {
+        instructions::claim_multiple::claim_fee_multiple::handle(ctx, position_count)
+    }
+
+    pub fn claim_all_rewards<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, ClaimAllRewards<'info>>,
+        reward_index: u64,
+        position_count: u8,
+    ) -> Result<()> {
+        instructions::claim_multiple::claim_all_rewards::handle(ctx, reward_index, position_count)
+    }
+
+    pub fn compound_fees(
+        ctx: Context<CompoundFees>,
+        parameter: CompoundFeeParameter,
+    ) -> Result<()> {
+        instructions::compound_fees::handle(ctx, parameter)
+    }
+
+    pub fn close_bin_array(ctx: Context<CloseBinArray>) -> Result<()> {
+        instructions::close_bin_array::handle(ctx)
+    }
+
+    pub fn close_legacy_bin_array(ctx: Context<CloseLegacyBinArray>) -> Result<()> {
+        instructions::close_bin_array::handle_legacy(ctx)
+    }
+
+    pub fn set_position_vesting(
+        ctx: Context<SetPositionVesting>,
+        parameter: VestingParameter,
+    ) -> Result<()> {
+        instructions::set_position_vesting::handle(ctx, parameter)
+    }
+
+    pub fn initialize_admin_config(
+        ctx: Context<InitializeAdminConfig>,
+        parameter: InitAdminConfigParameter,
+    ) -> Result<()> {
+        instructions::admin::initialize_admin_config::handle(ctx, parameter)
+    }
+
+    pub fn propose_admin_role(
+        ctx: Context<ProposeAdminRole>,
+        role: AdminRole,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        instructions::admin::propose_admin_role::handle(ctx, role, new_authority)
+    }
+
+    pub fn accept_admin_role(ctx: Context<AcceptAdminRole>, role: AdminRole) -> Result<()> {
+        instructions::admin::accept_admin_role::handle(ctx, role)
+    }
+
+    pub fn swap_route<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, SwapRoute<'info>>,
+        parameters: SwapRouteParameters,
+    ) -> Result<()> {
+        instructions::swap_route::handle(ctx, parameters)
+    }
+
+    pub fn update_oracle_parameters(
+        ctx: Context<UpdateOracleParameters>,
+        parameter: OracleParameter,
+    ) -> Result<()> {
+        instructions::admin::update_oracle_parameters::handle(ctx, parameter)
+    }
 }
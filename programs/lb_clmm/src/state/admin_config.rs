@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+
+/// Number of admin roles
+pub const ADMIN_ROLE_COUNT: usize = 4;
+
+#[derive(
+    AnchorSerialize,
+    AnchorDeserialize,
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    IntoPrimitive,
+    TryFromPrimitive,
+)]
+#[repr(u8)]
+/// Admin role. 0 = FeeAdmin, 1 = PauseGuardian, 2 = RewardAdmin, 3 = LaunchPoolAdmin
+pub enum AdminRole {
+    // Manage fee parameters, preset parameters and referrers
+    FeeAdmin,
+    // Toggle pair status and configure the circuit breaker
+    PauseGuardian,
+    // Initialize and manage farming rewards
+    RewardAdmin,
+    // Initialize permissioned launch pairs
+    LaunchPoolAdmin,
+}
+
+#[account]
+#[derive(InitSpace, Debug)]
+/// Authorities of the admin roles. Once initialized, it replaces the predefined admins of the program.
+pub struct AdminConfig {
+    /// Authority of each role, indexed by AdminRole
+    pub authorities: [Pubkey; ADMIN_ROLE_COUNT],
+    /// Authority proposed for each role, pending to be accepted. Default when there is no pending change.
+    pub pending_authorities: [Pubkey; ADMIN_ROLE_COUNT],
+}
+
+impl AdminConfig {
+    pub fn init(&mut self, authorities: [Pubkey; ADMIN_ROLE_COUNT]) {
+        self.authorities = authorities;
+        self.pending_authorities = [Pubkey::default(); ADMIN_ROLE_COUNT];
+    }
+
+    pub fn get_authority(&self, role: AdminRole) -> Pubkey {
+        self.authorities[usize::from(u8::from(role))]
+    }
+
+    pub fn get_pending_authority(&self, role: AdminRole) -> Pubkey {
+        self.pending_authorities[usize::from(u8::from(role))]
+    }
+
+    /// Propose a new authority for the role. Proposing the default pubkey cancels the pending change.
+    pub fn propose(&mut self, role: AdminRole, new_authority: Pubkey) {
+        self.pending_authorities[usize::from(u8::from(role))] = new_authority;
+    }
+
+    /// Hand over the role to the pending authority. Return the old authority.
+    pub fn accept(&mut self, role: AdminRole) -> Pubkey {
+        let index = usize::from(u8::from(role));
+        let old_authority = self.authorities[index];
+        self.authorities[index] = self.pending_authorities[index];
+        self.pending_authorities[index] = Pubkey::default();
+        old_authority
+    }
+}
+
+#[cfg(test)]
+pub mod test_utils {
+    use super::*;
+
+    /// Run `f` with the admin config account. No authorities for an admin config not initialized yet, which is not owned by the program.
+    pub fn with_admin_config_account(
+        authorities: Option<[Pubkey; ADMIN_ROLE_COUNT]>,
+        f: impl FnOnce(&AccountInfo),
+    ) {
+        let key = crate::utils::pda::derive_admin_config_pda().0;
+        let mut lamports = 0;
+        let mut data = vec![];
+        let owner = match authorities {
+            Some(authorities) => {
+                let mut admin_config = AdminConfig {
+                    authorities: [Pubkey::default(); ADMIN_ROLE_COUNT],
+                    pending_authorities: [Pubkey::default(); ADMIN_ROLE_COUNT],
+                };
+                admin_config.init(authorities);
+                admin_config.try_serialize(&mut data).unwrap();
+                crate::ID
+            }
+            None => anchor_lang::system_program::ID,
+        };
+
+        let account = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+        f(&account);
+    }
+}
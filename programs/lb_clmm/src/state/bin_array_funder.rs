@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace, Debug)]
+/// Record the funder of a bin array, which receive the rent back when the bin array is closed.
+pub struct BinArrayFunder {
+    /// Bin array funded
+    pub bin_array: Pubkey,
+    /// Address paid the rent of the bin array
+    pub funder: Pubkey,
+}
+
+impl BinArrayFunder {
+    pub fn init(&mut self, bin_array: Pubkey, funder: Pubkey) {
+        self.bin_array = bin_array;
+        self.funder = funder;
+    }
+}
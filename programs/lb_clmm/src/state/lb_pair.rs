@@ -1,6 +1,6 @@
 use std::cmp::min;
 
-use crate::assert_eq_admin;
+use crate::assert_eq_role_admin;
 use crate::constants::{
     BASIS_POINT_MAX, BIN_ARRAY_BITMAP_SIZE, FEE_PRECISION, MAX_BIN_ID, MAX_FEE_RATE,
     MAX_FEE_UPDATE_WINDOW, MIN_BIN_ID,
@@ -9,9 +9,13 @@ use crate::instructions::admin::update_fee_parameters::FeeParameter;
 use crate::math::u128x128_math::Rounding;
 use crate::math::u64x64_math::SCALE_OFFSET;
 use crate::math::utils_math::{one, safe_mul_div_cast, safe_mul_shr_cast, safe_shl_div_cast};
+use crate::pair_action_access::ActivationType;
+use crate::state::admin_config::AdminRole;
 use crate::state::bin::BinArray;
 use crate::state::bin_array_bitmap_extension::BinArrayBitmapExtension;
-use crate::state::parameters::{StaticParameters, VariableParameters};
+use crate::state::parameters::{
+    CircuitBreakerParameters, OracleParameters, StaticParameters, VariableParameters,
+};
 use crate::{errors::LBError, math::safe_math::SafeMath};
 use anchor_lang::prelude::*;
 use num_enum::{IntoPrimitive, TryFromPrimitive};
@@ -99,8 +103,10 @@ pub mod hack {
         pub _padding_4: u64,
         /// Pool creator
         pub creator: Pubkey,
-        /// Reserved space for future use
-        pub _reserved: [u8; 24],
+        /// Circuit breaker parameters
+        pub circuit_breaker: super::CircuitBreakerParameters,
+        /// Oracle parameters
+        pub oracle_parameters: super::OracleParameters,
     }
 
     impl LbPair {
@@ -243,8 +249,10 @@ pub struct LbPair {
     pub _padding_4: u64,
     /// Pool creator
     pub creator: Pubkey,
-    /// Reserved space for future use
-    pub _reserved: [u8; 24],
+    /// Circuit breaker parameters
+    pub circuit_breaker: CircuitBreakerParameters,
+    /// Oracle parameters
+    pub oracle_parameters: OracleParameters,
 }
 
 impl Default for LbPair {
@@ -280,7 +288,8 @@ impl Default for LbPair {
             _padding_1: [0u8; 32],
             _padding_2: [0u8; 32],
             _padding_3: [0u8; 8],
-            _reserved: [0u8; 24],
+            circuit_breaker: CircuitBreakerParameters::default(),
+            oracle_parameters: OracleParameters::default(),
         }
     }
 }
@@ -314,8 +323,9 @@ impl RewardInfo {
         self.mint.ne(&Pubkey::default())
     }
 
-    pub fn is_valid_funder(&self, funder: Pubkey) -> bool {
-        assert_eq_admin(funder) || funder.eq(&self.funder)
+    pub fn is_valid_funder(&self, admin_config: &AccountInfo, funder: Pubkey) -> bool {
+        assert_eq_role_admin(admin_config, AdminRole::RewardAdmin, funder)
+            || funder.eq(&self.funder)
     }
 
     pub fn init_reward(
@@ -477,6 +487,17 @@ impl LbPair {
         Ok(pair_type)
     }
 
+    /// Current point of the pair. Slot or timestamp based on the activation type.
+    pub fn current_point(&self) -> Result<u64> {
+        let activation_type = ActivationType::try_from(self.activation_type)
+            .map_err(|_| LBError::InvalidActivationType)?;
+        let current_point = match activation_type {
+            ActivationType::Slot => Clock::get()?.slot,
+            ActivationType::Timestamp => Clock::get()?.unix_timestamp as u64,
+        };
+        Ok(current_point)
+    }
+
     pub fn is_permission_pair(&self) -> Result<bool> {
         let pair_type = self.pair_type()?;
         Ok(pair_type.eq(&PairType::Permission))
@@ -745,6 +766,24 @@ impl LbPair {
         Ok(())
     }
 
+    /// Whether the bin array is flagged as having liquidity in the bitmap of the pair, or in the bitmap extension when out of the default range.
+    pub fn is_bin_array_bit_set(
+        &self,
+        bin_array_bitmap_extension: &Option<AccountLoader<BinArrayBitmapExtension>>,
+        bin_array_index: i32,
+    ) -> Result<bool> {
+        if self.is_overflow_default_bin_array_bitmap(bin_array_index) {
+            match bin_array_bitmap_extension {
+                Some(bitmap_ext) => bitmap_ext.load()?.bit(bin_array_index),
+                None => Err(LBError::BitmapExtensionAccountIsNotProvided.into()),
+            }
+        } else {
+            let bin_array_offset = Self::get_bin_array_offset(bin_array_index);
+            let bin_array_bitmap = U1024::from_limbs(self.bin_array_bitmap);
+            Ok(bin_array_bitmap.bit(bin_array_offset))
+        }
+    }
+
     pub fn is_overflow_default_bin_array_bitmap(&self, bin_array_index: i32) -> bool {
         let (min_bitmap_id, max_bitmap_id) = LbPair::bitmap_range();
         bin_array_index > max_bitmap_id || bin_array_index < min_bitmap_id
@@ -896,3 +935,45 @@ impl LbPair {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::admin_config::test_utils::with_admin_config_account;
+    use crate::state::admin_config::ADMIN_ROLE_COUNT;
+
+    #[test]
+    fn test_reward_funder_before_admin_config_initialized() {
+        let funder = Pubkey::new_unique();
+        let reward_info = RewardInfo {
+            funder,
+            ..RewardInfo::default()
+        };
+
+        with_admin_config_account(None, |admin_config| {
+            assert!(reward_info.is_valid_funder(admin_config, funder));
+            assert!(reward_info.is_valid_funder(admin_config, crate::admin::ADMINS[0]));
+            assert!(!reward_info.is_valid_funder(admin_config, Pubkey::new_unique()));
+        });
+    }
+
+    #[test]
+    fn test_reward_funder_after_admin_config_initialized() {
+        let funder = Pubkey::new_unique();
+        let reward_info = RewardInfo {
+            funder,
+            ..RewardInfo::default()
+        };
+        let reward_admin = Pubkey::new_unique();
+
+        let mut authorities = [Pubkey::new_unique(); ADMIN_ROLE_COUNT];
+        authorities[usize::from(u8::from(AdminRole::RewardAdmin))] = reward_admin;
+
+        with_admin_config_account(Some(authorities), |admin_config| {
+            assert!(reward_info.is_valid_funder(admin_config, funder));
+            assert!(reward_info.is_valid_funder(admin_config, reward_admin));
+            // The predefined admins are replaced by the admin config
+            assert!(!reward_info.is_valid_funder(admin_config, crate::admin::ADMINS[0]));
+        });
+    }
+}
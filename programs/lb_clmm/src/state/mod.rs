@@ -1,7 +1,10 @@
+pub mod admin_config;
 pub mod bin;
 pub mod bin_array_bitmap_extension;
+pub mod bin_array_funder;
 pub mod lb_pair;
 pub mod oracle;
 pub mod parameters;
 pub mod position;
 pub mod preset_parameters;
+pub mod referral;
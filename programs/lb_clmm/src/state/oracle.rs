@@ -5,13 +5,14 @@ use crate::{constants::DEFAULT_OBSERVATION_LENGTH, math::safe_math::SafeMath};
 use anchor_lang::prelude::*;
 use std::cell::RefMut;
 
+/// Sample lifetime of the pairs without oracle parameters
 #[cfg(not(feature = "localnet"))]
-fn get_sample_lifetime() -> i64 {
+pub fn get_default_sample_lifetime() -> i64 {
     SAMPLE_LIFETIME as i64
 }
 
 #[cfg(feature = "localnet")]
-fn get_sample_lifetime() -> i64 {
+pub fn get_default_sample_lifetime() -> i64 {
     5
 }
 
@@ -59,9 +60,9 @@ impl Observation {
     }
 
     /// Calculate the timestamp for the next observation sampling
-    pub fn compute_next_sampling_timestamp(&self) -> Option<i64> {
+    pub fn compute_next_sampling_timestamp(&self, sample_lifetime: i64) -> Option<i64> {
         if self.initialized() {
-            self.created_at.checked_add(get_sample_lifetime())
+            self.created_at.checked_add(sample_lifetime)
         } else {
             None
         }
@@ -191,7 +192,12 @@ impl<'a> DynamicOracle<'a> {
     }
 
     /// Update existing observation sample / create a new observation sample based on sample lifetime expiration
-    pub fn update(&mut self, active_id: i32, current_timestamp: i64) -> Result<()> {
+    pub fn update(
+        &mut self,
+        active_id: i32,
+        current_timestamp: i64,
+        sample_lifetime: i64,
+    ) -> Result<()> {
         if Self::is_initial_sampling(&self.metadata) {
             self.metadata.active_size += 1;
         }
@@ -203,7 +209,9 @@ impl<'a> DynamicOracle<'a> {
         let cumulative_active_bin_id =
             latest_sample.accumulate_active_bin_id(active_id, current_timestamp)?;
 
-        if let Some(next_sampling_timestamp) = latest_sample.compute_next_sampling_timestamp() {
+        if let Some(next_sampling_timestamp) =
+            latest_sample.compute_next_sampling_timestamp(sample_lifetime)
+        {
             if current_timestamp >= next_sampling_timestamp {
                 latest_sample = self.next_reset().ok_or_else(|| LBError::MathOverflow)?;
             }
@@ -212,6 +220,71 @@ impl<'a> DynamicOracle<'a> {
 
         Ok(())
     }
+
+    /// Time weighted active bin id of the window ending at current_timestamp. See [`get_time_weighted_active_id`].
+    pub fn get_time_weighted_active_id(
+        &self,
+        active_id: i32,
+        current_timestamp: i64,
+        window: i64,
+    ) -> Result<Option<i32>> {
+        get_time_weighted_active_id(
+            &self.metadata,
+            &self.observations,
+            active_id,
+            current_timestamp,
+            window,
+        )
+    }
+}
+
+/// Time weighted active bin id of the window ending at current_timestamp, measured from the latest observation sample updated before the window start.
+/// Fallback to the earliest observation sample when the oracle history is shorter than the window. Return None when there is not enough history.
+pub fn get_time_weighted_active_id(
+    metadata: &Oracle,
+    observations: &[Observation],
+    active_id: i32,
+    current_timestamp: i64,
+    window: i64,
+) -> Result<Option<i32>> {
+    let active_size = metadata.active_size as usize;
+    if active_size == 0 {
+        return Ok(None);
+    }
+
+    let latest_sample = &observations[metadata.idx as usize];
+    let cumulative_active_bin_id =
+        latest_sample.accumulate_active_bin_id(active_id, current_timestamp)?;
+
+    let window_start = current_timestamp.safe_sub(window)?;
+    let mut start_sample = latest_sample;
+    // Walk backward from the latest sample
+    for offset in 0..active_size {
+        let idx = (metadata.idx as usize + active_size - offset) % active_size;
+        let sample = &observations[idx];
+        if !sample.initialized() {
+            break;
+        }
+        start_sample = sample;
+        if sample.last_updated_at <= window_start {
+            break;
+        }
+    }
+
+    let elapsed = current_timestamp.safe_sub(start_sample.last_updated_at)?;
+    if elapsed <= 0 {
+        return Ok(None);
+    }
+
+    let time_weighted_active_id = cumulative_active_bin_id
+        .safe_sub(start_sample.cumulative_active_bin_id)?
+        .safe_div(elapsed.into())?;
+
+    Ok(Some(
+        time_weighted_active_id
+            .try_into()
+            .map_err(|_| LBError::TypeCastFailed)?,
+    ))
 }
 
 fn oracle_account_split<'a, 'info>(
@@ -1,5 +1,9 @@
-use crate::constants::{BASIS_POINT_MAX, MAX_BASE_FACTOR_STEP, MAX_PROTOCOL_SHARE};
+use crate::constants::{
+    BASIS_POINT_MAX, MAX_BASE_FACTOR_STEP, MAX_ORACLE_TARGET_LENGTH, MAX_PROTOCOL_SHARE,
+    ORACLE_AUTO_GROWTH_LENGTH,
+};
 use crate::instructions::admin::update_fee_parameters::FeeParameter;
+use crate::state::oracle::get_default_sample_lifetime;
 use crate::{errors::LBError, math::safe_math::SafeMath};
 use anchor_lang::prelude::*;
 
@@ -191,3 +195,299 @@ impl VariableParameters {
         self.update_volatility_accumulator(active_id, static_params)
     }
 }
+
+#[zero_copy]
+#[derive(InitSpace, Default, Debug)]
+/// Circuit breaker halting swap when the active bin move too far away from the time weighted active bin of the oracle
+pub struct CircuitBreakerParameters {
+    /// Maximum number of bins the active bin can move away from the time weighted active bin of the window. 0 = disabled.
+    pub max_bin_movement: u16,
+    /// Time window, in seconds, of the time weighted active bin.
+    pub window: u16,
+    /// Duration, in seconds, swap stay halted after the circuit breaker tripped.
+    pub cooldown: u32,
+    /// Timestamp until which swap is halted.
+    pub halted_until: i64,
+}
+
+impl CircuitBreakerParameters {
+    pub fn is_enabled(&self) -> bool {
+        self.max_bin_movement > 0
+    }
+
+    pub fn is_halted(&self, current_timestamp: i64) -> bool {
+        current_timestamp < self.halted_until
+    }
+
+    /// Whether the active bin moved beyond max_bin_movement from the time weighted active bin
+    pub fn is_exceeded(&self, active_id: i32, time_weighted_active_id: i32) -> bool {
+        self.is_enabled()
+            && i64::from(active_id).abs_diff(time_weighted_active_id.into())
+                > self.max_bin_movement.into()
+    }
+
+    /// Halt swap for the cooldown duration
+    pub fn trip(&mut self, current_timestamp: i64) -> Result<()> {
+        self.halted_until = current_timestamp.safe_add(self.cooldown.into())?;
+        Ok(())
+    }
+
+    pub fn update(&mut self, max_bin_movement: u16, window: u16, cooldown: u32) -> Result<()> {
+        require!(
+            max_bin_movement == 0 || window > 0,
+            LBError::InvalidCircuitBreakerParameters
+        );
+
+        self.max_bin_movement = max_bin_movement;
+        self.window = window;
+        self.cooldown = cooldown;
+        // Re-configuring resume the swap
+        self.halted_until = 0;
+
+        Ok(())
+    }
+}
+
+#[zero_copy]
+#[derive(InitSpace, Default, Debug)]
+/// Oracle sampling of the pair. The default value keep the default sample lifetime, and the oracle only grow by increase_oracle_length
+pub struct OracleParameters {
+    /// Duration, in seconds, before a new observation sample is created. 0 = default sample lifetime.
+    pub sample_lifetime: u16,
+    /// Whether swap grow the oracle until the target history duration is covered. 0 = disabled.
+    pub auto_growth: u8,
+    /// padding
+    pub _padding: u8,
+    /// Duration, in seconds, of the history the oracle should cover.
+    pub target_history_duration: u32,
+}
+
+impl OracleParameters {
+    pub fn get_sample_lifetime(&self) -> i64 {
+        if self.sample_lifetime > 0 {
+            self.sample_lifetime.into()
+        } else {
+            get_default_sample_lifetime()
+        }
+    }
+
+    pub fn is_auto_growth_enabled(&self) -> bool {
+        self.auto_growth > 0
+    }
+
+    /// Number of observations required to cover the target history duration
+    pub fn get_target_length(&self) -> Result<u64> {
+        let sample_lifetime = self.get_sample_lifetime() as u64;
+        let target_history_duration: u64 = self.target_history_duration.into();
+
+        Ok(target_history_duration
+            .safe_add(sample_lifetime)?
+            .safe_sub(1)?
+            .safe_div(sample_lifetime)?)
+    }
+
+    /// Number of observations a swap add to an oracle of `current_length`. 0 when auto-growth is disabled or the target length is reached.
+    pub fn get_auto_growth_length(&self, current_length: u64) -> Result<u64> {
+        if !self.is_auto_growth_enabled() {
+            return Ok(0);
+        }
+
+        let target_length = self.get_target_length()?;
+        Ok(std::cmp::min(
+            ORACLE_AUTO_GROWTH_LENGTH,
+            target_length.saturating_sub(current_length),
+        ))
+    }
+
+    pub fn update(
+        &mut self,
+        sample_lifetime: u16,
+        target_history_duration: u32,
+        auto_growth: bool,
+    ) -> Result<()> {
+        require!(sample_lifetime > 0, LBError::InvalidOracleParameters);
+        require!(
+            !auto_growth || target_history_duration > 0,
+            LBError::InvalidOracleParameters
+        );
+
+        self.sample_lifetime = sample_lifetime;
+        self.target_history_duration = target_history_duration;
+        self.auto_growth = auto_growth.into();
+
+        require!(
+            self.get_target_length()? <= MAX_ORACLE_TARGET_LENGTH,
+            LBError::InvalidOracleParameters
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn circuit_breaker(
+        max_bin_movement: u16,
+        window: u16,
+        cooldown: u32,
+    ) -> CircuitBreakerParameters {
+        let mut circuit_breaker = CircuitBreakerParameters::default();
+        circuit_breaker
+            .update(max_bin_movement, window, cooldown)
+            .unwrap();
+        circuit_breaker
+    }
+
+    #[test]
+    fn test_circuit_breaker_threshold() {
+        let circuit_breaker = circuit_breaker(10, 60, 300);
+
+        // Moving exactly max_bin_movement bins is allowed, in both direction
+        assert!(!circuit_breaker.is_exceeded(110, 100));
+        assert!(!circuit_breaker.is_exceeded(90, 100));
+        assert!(circuit_breaker.is_exceeded(111, 100));
+        assert!(circuit_breaker.is_exceeded(89, 100));
+
+        // Across the zero bin
+        assert!(!circuit_breaker.is_exceeded(-5, 5));
+        assert!(circuit_breaker.is_exceeded(-6, 5));
+
+        // No overflow at the edge of the bin id range
+        assert!(circuit_breaker.is_exceeded(i32::MAX, i32::MIN));
+    }
+
+    #[test]
+    fn test_circuit_breaker_disabled() {
+        let circuit_breaker = circuit_breaker(0, 0, 300);
+
+        assert!(!circuit_breaker.is_enabled());
+        assert!(!circuit_breaker.is_exceeded(i32::MAX, i32::MIN));
+    }
+
+    #[test]
+    fn test_circuit_breaker_cooldown() {
+        let mut circuit_breaker = circuit_breaker(10, 60, 300);
+        let tripped_at = 1_700_000_000;
+
+        assert!(!circuit_breaker.is_halted(tripped_at));
+        circuit_breaker.trip(tripped_at).unwrap();
+        assert_eq!(circuit_breaker.halted_until, tripped_at + 300);
+
+        // Halted until the last second of the cooldown, swap resume at halted_until
+        assert!(circuit_breaker.is_halted(tripped_at));
+        assert!(circuit_breaker.is_halted(tripped_at + 299));
+        assert!(!circuit_breaker.is_halted(tripped_at + 300));
+    }
+
+    #[test]
+    fn test_circuit_breaker_zero_cooldown() {
+        let mut circuit_breaker = circuit_breaker(10, 60, 0);
+        let tripped_at = 1_700_000_000;
+
+        circuit_breaker.trip(tripped_at).unwrap();
+        assert!(!circuit_breaker.is_halted(tripped_at));
+    }
+
+    #[test]
+    fn test_circuit_breaker_trip_overflow() {
+        let mut circuit_breaker = circuit_breaker(10, 60, 300);
+        assert!(circuit_breaker.trip(i64::MAX - 299).is_err());
+    }
+
+    #[test]
+    fn test_circuit_breaker_update_resume() {
+        let mut circuit_breaker = circuit_breaker(10, 60, 300);
+        let tripped_at = 1_700_000_000;
+        circuit_breaker.trip(tripped_at).unwrap();
+
+        circuit_breaker.update(20, 120, 600).unwrap();
+        assert!(!circuit_breaker.is_halted(tripped_at));
+        assert_eq!(circuit_breaker.halted_until, 0);
+
+        // Enabled circuit breaker require a window
+        assert!(circuit_breaker.update(20, 0, 600).is_err());
+    }
+
+    fn oracle_parameters(
+        sample_lifetime: u16,
+        target_history_duration: u32,
+        auto_growth: bool,
+    ) -> OracleParameters {
+        let mut oracle_parameters = OracleParameters::default();
+        oracle_parameters
+            .update(sample_lifetime, target_history_duration, auto_growth)
+            .unwrap();
+        oracle_parameters
+    }
+
+    #[test]
+    fn test_oracle_default_sample_lifetime() {
+        let oracle_parameters = OracleParameters::default();
+
+        assert_eq!(
+            oracle_parameters.get_sample_lifetime(),
+            get_default_sample_lifetime()
+        );
+        assert_eq!(oracle_parameters.get_target_length().unwrap(), 0);
+        assert_eq!(oracle_parameters.get_auto_growth_length(0).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_oracle_target_length() {
+        // Exact multiple of the sample lifetime
+        assert_eq!(
+            oracle_parameters(60, 3600, true)
+                .get_target_length()
+                .unwrap(),
+            60
+        );
+        // Round up, the oracle must cover the whole target history duration
+        assert_eq!(
+            oracle_parameters(60, 3601, true)
+                .get_target_length()
+                .unwrap(),
+            61
+        );
+        assert_eq!(
+            oracle_parameters(60, 1, true).get_target_length().unwrap(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_oracle_auto_growth_length() {
+        let oracle_parameters = oracle_parameters(60, 3600, true);
+
+        assert_eq!(
+            oracle_parameters.get_auto_growth_length(0).unwrap(),
+            ORACLE_AUTO_GROWTH_LENGTH
+        );
+        assert_eq!(oracle_parameters.get_auto_growth_length(59).unwrap(), 1);
+        // Stop growing once the target length is reached, or an oracle already longer
+        assert_eq!(oracle_parameters.get_auto_growth_length(60).unwrap(), 0);
+        assert_eq!(oracle_parameters.get_auto_growth_length(100).unwrap(), 0);
+
+        // Disabled auto-growth never grow the oracle
+        let oracle_parameters = self::oracle_parameters(60, 3600, false);
+        assert_eq!(oracle_parameters.get_auto_growth_length(0).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_oracle_parameters_update_validation() {
+        let mut oracle_parameters = OracleParameters::default();
+
+        assert!(oracle_parameters.update(0, 3600, false).is_err());
+        // Auto-growth requires a target history duration
+        assert!(oracle_parameters.update(60, 0, true).is_err());
+        assert!(oracle_parameters.update(60, 0, false).is_ok());
+
+        // Target length at the limit
+        let max_duration = (MAX_ORACLE_TARGET_LENGTH * 60) as u32;
+        assert!(oracle_parameters.update(60, max_duration, true).is_ok());
+        assert!(oracle_parameters
+            .update(60, max_duration + 1, true)
+            .is_err());
+    }
+}
@@ -1,11 +1,13 @@
 use super::bin::Bin;
 use crate::{
-    constants::{MAX_BIN_PER_POSITION, NUM_REWARDS},
+    constants::{BASIS_POINT_MAX, MAX_BIN_PER_POSITION, NUM_REWARDS, VESTING_RATIO_PRECISION},
     errors::LBError,
     manager::bin_array_manager::BinArrayManager,
     math::{
-        safe_math::SafeMath, u128x128_math::Rounding, u64x64_math::SCALE_OFFSET,
-        utils_math::safe_mul_shr_cast,
+        safe_math::SafeMath,
+        u128x128_math::Rounding,
+        u64x64_math::SCALE_OFFSET,
+        utils_math::{safe_mul_div_cast, safe_mul_shr_cast},
     },
 };
 use anchor_lang::prelude::*;
@@ -74,8 +76,20 @@ pub struct PositionV2 {
     pub _padding_0: u8,
     /// Address is able to claim fee in this position, only valid for bootstrap_liquidity_position
     pub fee_owner: Pubkey,
+    /// Mint of the NFT representing the ownership of this position. Default for non-tokenized position.
+    pub position_nft_mint: Pubkey,
+    /// Padding to align the vesting schedule
+    pub _padding_1: [u8; 7],
+    /// Point which the linear vesting of the liquidity starts
+    pub vesting_start_point: u64,
+    /// Point before which none of the vesting liquidity can be withdrawn
+    pub vesting_cliff_point: u64,
+    /// Point which the liquidity is fully vested. 0 for position without vesting.
+    pub vesting_end_point: u64,
+    /// Ratio of the vesting liquidity remaining in the position, in VESTING_RATIO_PRECISION
+    pub vesting_remaining_ratio: u64,
     /// Reserved space for future use
-    pub _reserved: [u8; 87],
+    pub _reserved: [u8; 16],
 }
 
 impl Default for PositionV2 {
@@ -96,7 +110,13 @@ impl Default for PositionV2 {
             lock_release_point: 0,
             fee_owner: Pubkey::default(),
             _padding_0: 0,
-            _reserved: [0u8; 87],
+            position_nft_mint: Pubkey::default(),
+            _padding_1: [0u8; 7],
+            vesting_start_point: 0,
+            vesting_cliff_point: 0,
+            vesting_end_point: 0,
+            vesting_remaining_ratio: 0,
+            _reserved: [0u8; 16],
         }
     }
 }
@@ -246,6 +266,13 @@ impl PositionV2 {
 
         let fee_infos = &mut self.fee_infos[idx];
 
+        // Without liquidity there is nothing to earn. The bin might be reset by closing and re-initializing the bin array, so only sync the checkpoint.
+        if self.liquidity_shares[idx] == 0 {
+            fee_infos.fee_x_per_token_complete = bin.fee_amount_x_per_token_stored;
+            fee_infos.fee_y_per_token_complete = bin.fee_amount_y_per_token_stored;
+            return Ok(());
+        }
+
         let fee_x_per_token_stored = bin.fee_amount_x_per_token_stored;
 
         let new_fee_x: u64 = safe_mul_shr_cast(
@@ -283,6 +310,13 @@ impl PositionV2 {
         let idx = self.get_idx(bin_id)?;
 
         let reward_info = &mut self.reward_infos[idx];
+
+        // Same as fee, only sync the checkpoint of a bin without liquidity
+        if self.liquidity_shares[idx] == 0 {
+            reward_info.reward_per_token_completes = bin.reward_per_token_stored;
+            return Ok(());
+        }
+
         for reward_idx in 0..NUM_REWARDS {
             let reward_per_token_stored = bin.reward_per_token_stored[reward_idx];
 
@@ -334,6 +368,15 @@ impl PositionV2 {
         Ok((fee_x, fee_y))
     }
 
+    /// Return the part of the claimed fee which was not used back to the pending fee of the position.
+    pub fn restore_pending_fee(&mut self, fee_x: u64, fee_y: u64) -> Result<()> {
+        let fee_info = &mut self.fee_infos[0];
+        fee_info.fee_x_pending = fee_info.fee_x_pending.safe_add(fee_x)?;
+        fee_info.fee_y_pending = fee_info.fee_y_pending.safe_add(fee_y)?;
+
+        Ok(())
+    }
+
     pub fn set_last_updated_at(&mut self, current_time: i64) {
         self.last_updated_at = current_time;
     }
@@ -363,4 +406,88 @@ impl PositionV2 {
     pub fn is_liquidity_locked(&self, current_point: u64) -> bool {
         current_point < self.lock_release_point
     }
+
+    /// Hand over the position to a new owner. The operator was delegated by the previous owner, therefore it is revoked.
+    /// The fee owner is kept, as the fee of the position is committed to it independently of the owner.
+    pub fn transfer_owner(&mut self, new_owner: Pubkey) {
+        self.owner = new_owner;
+        self.operator = Pubkey::default();
+    }
+
+    /// Tokenized position is owned by the holder of the position NFT instead of the owner field.
+    pub fn is_tokenized(&self) -> bool {
+        self.position_nft_mint != Pubkey::default()
+    }
+
+    pub fn has_vesting(&self) -> bool {
+        self.vesting_end_point != 0
+    }
+
+    /// Set a linear vesting schedule with cliff on the liquidity of the position.
+    pub fn set_vesting(
+        &mut self,
+        start_point: u64,
+        cliff_point: u64,
+        end_point: u64,
+    ) -> Result<()> {
+        require!(
+            start_point < end_point && start_point <= cliff_point && cliff_point <= end_point,
+            LBError::InvalidVestingSchedule
+        );
+
+        self.vesting_start_point = start_point;
+        self.vesting_cliff_point = cliff_point;
+        self.vesting_end_point = end_point;
+        self.vesting_remaining_ratio = VESTING_RATIO_PRECISION;
+
+        Ok(())
+    }
+
+    /// Ratio of the vesting liquidity which is still locked at the current point, in VESTING_RATIO_PRECISION.
+    pub fn get_vesting_locked_ratio(&self, current_point: u64) -> Result<u64> {
+        if !self.has_vesting() || current_point >= self.vesting_end_point {
+            return Ok(0);
+        }
+
+        if current_point < self.vesting_cliff_point || current_point <= self.vesting_start_point {
+            return Ok(VESTING_RATIO_PRECISION);
+        }
+
+        let remaining_duration = self.vesting_end_point.safe_sub(current_point)?;
+        let vesting_duration = self.vesting_end_point.safe_sub(self.vesting_start_point)?;
+
+        // Round up in favor of the lock
+        safe_mul_div_cast(
+            remaining_duration.into(),
+            VESTING_RATIO_PRECISION.into(),
+            vesting_duration.into(),
+            Rounding::Up,
+        )
+    }
+
+    /// Withdraw bps_to_remove of the liquidity from every bin of a position with vesting. The remaining ratio must stay above the locked ratio.
+    pub fn withdraw_vesting_liquidity(
+        &mut self,
+        bps_to_remove: u16,
+        current_point: u64,
+    ) -> Result<()> {
+        let locked_ratio = self.get_vesting_locked_ratio(current_point)?;
+
+        let remaining_bps = u64::try_from(BASIS_POINT_MAX)
+            .map_err(|_| LBError::TypeCastFailed)?
+            .safe_sub(bps_to_remove.into())?;
+
+        let remaining_ratio: u64 = safe_mul_div_cast(
+            self.vesting_remaining_ratio.into(),
+            remaining_bps.into(),
+            BASIS_POINT_MAX as u128,
+            Rounding::Down,
+        )?;
+
+        require!(remaining_ratio >= locked_ratio, LBError::LiquidityLocked);
+
+        self.vesting_remaining_ratio = remaining_ratio;
+
+        Ok(())
+    }
 }
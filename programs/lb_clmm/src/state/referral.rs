@@ -0,0 +1,69 @@
+use crate::constants::MAX_REFERRER_FEE_SHARE;
+use crate::errors::LBError;
+use crate::math::safe_math::SafeMath;
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace, Debug)]
+/// Referrer registered by the admin. Swaps referred by the referrer share the protocol fee with it.
+pub struct Referrer {
+    /// Owner of the referrer, allowed to claim the referral fee
+    pub owner: Pubkey,
+    /// Portion of the protocol fee shared with the referrer, in bps
+    pub fee_share: u16,
+}
+
+impl Referrer {
+    pub fn init(&mut self, owner: Pubkey, fee_share: u16) -> Result<()> {
+        self.owner = owner;
+        self.update_fee_share(fee_share)
+    }
+
+    pub fn update_fee_share(&mut self, fee_share: u16) -> Result<()> {
+        require!(
+            fee_share <= MAX_REFERRER_FEE_SHARE,
+            LBError::InvalidReferrerFeeShare
+        );
+        self.fee_share = fee_share;
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(InitSpace, Debug)]
+/// Referral fee of a referrer accumulated in a pair. The fee stay in the pair reserves until claimed.
+pub struct ReferralFee {
+    /// Referrer earning the fee
+    pub referrer: Pubkey,
+    /// Liquidity pair the fee was collected from
+    pub lb_pair: Pubkey,
+    /// Claimable fee of token X
+    pub fee_x: u64,
+    /// Claimable fee of token Y
+    pub fee_y: u64,
+}
+
+impl ReferralFee {
+    pub fn init(&mut self, referrer: Pubkey, lb_pair: Pubkey) {
+        self.referrer = referrer;
+        self.lb_pair = lb_pair;
+    }
+
+    /// Accumulate the referral fee. Fee was charged at swap-in side.
+    pub fn accumulate(&mut self, fee: u64, swap_for_y: bool) -> Result<()> {
+        if swap_for_y {
+            self.fee_x = self.fee_x.safe_add(fee)?;
+        } else {
+            self.fee_y = self.fee_y.safe_add(fee)?;
+        }
+        Ok(())
+    }
+
+    /// Reset the claimable fee, returning the claimed amount
+    pub fn claim(&mut self) -> (u64, u64) {
+        let claimed = (self.fee_x, self.fee_y);
+        self.fee_x = 0;
+        self.fee_y = 0;
+        claimed
+    }
+}
@@ -1,2 +1,3 @@
 pub mod pda;
 pub mod seeds;
+pub mod token_2022;
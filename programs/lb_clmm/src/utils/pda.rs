@@ -87,6 +87,13 @@ pub fn derive_position_pda(
     )
 }
 
+pub fn derive_tokenized_position_pda(position_nft_mint: Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::POSITION.as_ref(), position_nft_mint.as_ref()],
+        &crate::ID,
+    )
+}
+
 pub fn derive_oracle_pda(lb_pair: Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[ORACLE, lb_pair.as_ref()], &crate::ID)
 }
@@ -113,6 +120,25 @@ pub fn derive_reward_vault_pda(lb_pair: Pubkey, reward_index: u64) -> (Pubkey, u
     )
 }
 
+pub fn derive_referrer_pda(owner: Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[seeds::REFERRER, owner.as_ref()], &crate::ID)
+}
+
+pub fn derive_referral_fee_pda(referrer: Pubkey, lb_pair: Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::REFERRAL_FEE, referrer.as_ref(), lb_pair.as_ref()],
+        &crate::ID,
+    )
+}
+
+pub fn derive_bin_array_funder_pda(bin_array: Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[seeds::BIN_ARRAY_FUNDER, bin_array.as_ref()], &crate::ID)
+}
+
+pub fn derive_admin_config_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[seeds::ADMIN_CONFIG], &crate::ID)
+}
+
 pub fn derive_event_authority_pda() -> (Pubkey, u8) {
     Pubkey::find_program_address(&[b"__event_authority"], &crate::ID)
 }
@@ -4,6 +4,9 @@ use anchor_lang::solana_program::pubkey;
 #[constant]
 pub const BIN_ARRAY: &[u8] = b"bin_array";
 
+#[constant]
+pub const BIN_ARRAY_FUNDER: &[u8] = b"bin_array_funder";
+
 #[constant]
 pub const ORACLE: &[u8] = b"oracle";
 
@@ -16,4 +19,13 @@ pub const PRESET_PARAMETER: &[u8] = b"preset_parameter";
 #[constant]
 pub const POSITION: &[u8] = b"position";
 
+#[constant]
+pub const REFERRER: &[u8] = b"referrer";
+
+#[constant]
+pub const ADMIN_CONFIG: &[u8] = b"admin_config";
+
+#[constant]
+pub const REFERRAL_FEE: &[u8] = b"referral_fee";
+
 pub const ILM_BASE_KEY: Pubkey = pubkey!("MFGQxwAmB91SwuYX36okv2Qmdc9aMuHTwWGUrp4AtB1");
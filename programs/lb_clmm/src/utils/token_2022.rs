@@ -0,0 +1,171 @@
+use crate::errors::LBError;
+use crate::state::bin::BinArray;
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{
+        transfer_fee::{TransferFee, TransferFeeConfig, MAX_FEE_BASIS_POINTS},
+        BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+    },
+};
+use anchor_spl::token_interface::Mint;
+
+/// Token 2022 mint extensions which are supported by the pair
+const SUPPORTED_MINT_EXTENSIONS: [ExtensionType; 4] = [
+    ExtensionType::TransferFeeConfig,
+    ExtensionType::TransferHook,
+    ExtensionType::MetadataPointer,
+    ExtensionType::TokenMetadata,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransferFeeIncludedAmount {
+    /// Amount to be transferred, including the transfer fee
+    pub amount: u64,
+    /// Transfer fee withheld by the token program
+    pub transfer_fee: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransferFeeExcludedAmount {
+    /// Amount received by the destination, excluding the transfer fee
+    pub amount: u64,
+    /// Transfer fee withheld by the token program
+    pub transfer_fee: u64,
+}
+
+pub fn is_supported_mint(mint: &InterfaceAccount<Mint>) -> Result<bool> {
+    let mint_info = mint.to_account_info();
+    if mint_info.owner.eq(&anchor_spl::token::ID) {
+        return Ok(true);
+    }
+
+    let mint_data = mint_info.try_borrow_data()?;
+    let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+
+    Ok(mint
+        .get_extension_types()?
+        .iter()
+        .all(|extension| SUPPORTED_MINT_EXTENSIONS.contains(extension)))
+}
+
+/// Get the transfer fee of the epoch. Return None for SPL token, or token 2022 without transfer fee extension.
+pub fn get_epoch_transfer_fee(mint_data: &[u8], epoch: u64) -> Result<Option<TransferFee>> {
+    let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(mint_data)?;
+    let Ok(transfer_fee_config) = mint.get_extension::<TransferFeeConfig>() else {
+        return Ok(None);
+    };
+
+    Ok(Some(*transfer_fee_config.get_epoch_fee(epoch)))
+}
+
+/// Amount received by the destination when `transfer_fee_included_amount` is transferred.
+pub fn calculate_transfer_fee_excluded_amount(
+    mint_data: &[u8],
+    transfer_fee_included_amount: u64,
+    epoch: u64,
+) -> Result<TransferFeeExcludedAmount> {
+    if let Some(epoch_transfer_fee) = get_epoch_transfer_fee(mint_data, epoch)? {
+        let transfer_fee = epoch_transfer_fee
+            .calculate_fee(transfer_fee_included_amount)
+            .ok_or(LBError::MathOverflow)?;
+        let transfer_fee_excluded_amount = transfer_fee_included_amount
+            .checked_sub(transfer_fee)
+            .ok_or(LBError::MathOverflow)?;
+
+        return Ok(TransferFeeExcludedAmount {
+            amount: transfer_fee_excluded_amount,
+            transfer_fee,
+        });
+    }
+
+    Ok(TransferFeeExcludedAmount {
+        amount: transfer_fee_included_amount,
+        transfer_fee: 0,
+    })
+}
+
+/// Amount to be transferred for the destination to receive `transfer_fee_excluded_amount`.
+pub fn calculate_transfer_fee_included_amount(
+    mint_data: &[u8],
+    transfer_fee_excluded_amount: u64,
+    epoch: u64,
+) -> Result<TransferFeeIncludedAmount> {
+    if transfer_fee_excluded_amount == 0 {
+        return Ok(TransferFeeIncludedAmount {
+            amount: 0,
+            transfer_fee: 0,
+        });
+    }
+
+    if let Some(epoch_transfer_fee) = get_epoch_transfer_fee(mint_data, epoch)? {
+        let transfer_fee: u64 =
+            if u16::from(epoch_transfer_fee.transfer_fee_basis_points) == MAX_FEE_BASIS_POINTS {
+                // 100% fee, the destination always receive nothing
+                u64::from(epoch_transfer_fee.maximum_fee)
+            } else {
+                epoch_transfer_fee
+                    .calculate_inverse_fee(transfer_fee_excluded_amount)
+                    .ok_or(LBError::MathOverflow)?
+            };
+
+        let transfer_fee_included_amount = transfer_fee_excluded_amount
+            .checked_add(transfer_fee)
+            .ok_or(LBError::MathOverflow)?;
+
+        return Ok(TransferFeeIncludedAmount {
+            amount: transfer_fee_included_amount,
+            transfer_fee,
+        });
+    }
+
+    Ok(TransferFeeIncludedAmount {
+        amount: transfer_fee_excluded_amount,
+        transfer_fee: 0,
+    })
+}
+
+/// Remaining accounts of swap are the bin arrays, followed by the extra accounts required by the transfer hook of token X and Y.
+pub fn split_transfer_hook_accounts<'a, 'info>(
+    remaining_accounts: &'a [AccountInfo<'info>],
+) -> (&'a [AccountInfo<'info>], &'a [AccountInfo<'info>]) {
+    let bin_array_count = remaining_accounts
+        .iter()
+        .take_while(|account| {
+            account.owner.eq(&crate::ID)
+                && account
+                    .try_borrow_data()
+                    .map(|data| data.starts_with(&BinArray::DISCRIMINATOR))
+                    .unwrap_or(false)
+        })
+        .count();
+
+    remaining_accounts.split_at(bin_array_count)
+}
+
+/// Transfer with `transfer_checked`, passing the extra accounts required by the transfer hook of the mint.
+pub fn transfer_checked_with_hook<'info>(
+    token_program: AccountInfo<'info>,
+    source: AccountInfo<'info>,
+    mint: &InterfaceAccount<'info, Mint>,
+    destination: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    transfer_hook_accounts: &[AccountInfo<'info>],
+    amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    spl_token_2022::onchain::invoke_transfer_checked(
+        token_program.key,
+        source,
+        mint.to_account_info(),
+        destination,
+        authority,
+        transfer_hook_accounts,
+        amount,
+        mint.decimals,
+        signer_seeds,
+    )?;
+
+    Ok(())
+}
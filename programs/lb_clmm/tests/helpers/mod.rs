@@ -1 +1,3 @@
+pub mod native;
+pub mod pair;
 pub mod utils;
@@ -0,0 +1,138 @@
+use anchor_lang::solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, entrypoint::MAX_PERMITTED_DATA_INCREASE,
+    pubkey::Pubkey, rent::Rent,
+};
+use anchor_lang::Discriminator;
+use bytemuck::Pod;
+use lb_clmm::state::oracle::Oracle;
+use solana_program_test::{processor, BanksClient, ProgramTest, ProgramTestContext};
+use solana_sdk::account::Account;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// Load lb_clmm as a native program. Programs already added with a .so file keep running on the BPF VM.
+pub fn add_lb_clmm_native(test: &mut ProgramTest) {
+    test.prefer_bpf(false);
+    test.add_program(
+        "lb_clmm",
+        lb_clmm::id(),
+        processor!(process_instruction_aligned),
+    );
+}
+
+/// Run lb_clmm natively on an aligned copy of the account data. Zero copy accounts holding u128 require 16 bytes
+/// alignment on the host, while the serialized account data after the discriminator is only 8 bytes aligned.
+pub fn process_instruction_aligned<'a>(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'a>],
+    data: &[u8],
+) -> ProgramResult {
+    let mut buffers: HashMap<Pubkey, Rc<RefCell<&'a mut [u8]>>> = HashMap::new();
+
+    let aligned_accounts: Vec<AccountInfo<'a>> = accounts
+        .iter()
+        .map(|account| {
+            let data = buffers
+                .entry(*account.key)
+                .or_insert_with(|| Rc::new(RefCell::new(aligned_copy(&account.data.borrow()))))
+                .clone();
+
+            AccountInfo {
+                // The original key is kept as realloc read the original data length in front of it
+                key: account.key,
+                lamports: account.lamports.clone(),
+                data,
+                owner: account.owner,
+                rent_epoch: account.rent_epoch,
+                is_signer: account.is_signer,
+                is_writable: account.is_writable,
+                executable: account.executable,
+            }
+        })
+        .collect();
+
+    let aligned_accounts: &'a [AccountInfo<'a>] = Box::leak(aligned_accounts.into_boxed_slice());
+    lb_clmm::entry(program_id, aligned_accounts, data)?;
+
+    for account in accounts.iter().filter(|account| account.is_writable) {
+        let aligned_data = buffers[account.key].borrow();
+        if aligned_data.len() != account.data_len() {
+            account.realloc(aligned_data.len(), false)?;
+        }
+        account.data.borrow_mut().copy_from_slice(&aligned_data);
+    }
+
+    Ok(())
+}
+
+/// Copy the data into a leaked buffer where the data start at 8 mod 16, so the zero copy struct after the discriminator
+/// is 16 bytes aligned. Observations of the oracle follow 32 bytes of header instead, so the oracle data start at 0 mod 16.
+/// The length is stored in the 8 bytes in front of the data, the same way the runtime does for realloc.
+fn aligned_copy<'a>(data: &[u8]) -> &'a mut [u8] {
+    let offset = if data.starts_with(&Oracle::DISCRIMINATOR) {
+        16
+    } else {
+        8
+    };
+    let words = (offset + data.len() + MAX_PERMITTED_DATA_INCREASE) / 16 + 1;
+    let buffer: &'a mut [u128] = Box::leak(vec![0u128; words].into_boxed_slice());
+    let buffer: &'a mut [u8] = bytemuck::cast_slice_mut(buffer);
+
+    let (header, rest) = buffer.split_at_mut(offset);
+    header[offset - 8..].copy_from_slice(&(data.len() as u64).to_le_bytes());
+
+    let aligned_data = &mut rest[..data.len()];
+    aligned_data.copy_from_slice(data);
+    aligned_data
+}
+
+/// Add a zero copy account of lb_clmm with the given state.
+pub fn add_zero_copy_account<T: Discriminator + Pod>(
+    test: &mut ProgramTest,
+    address: Pubkey,
+    state: &T,
+) {
+    let mut data = T::DISCRIMINATOR.to_vec();
+    data.extend_from_slice(bytemuck::bytes_of(state));
+
+    test.add_account(
+        address,
+        Account {
+            lamports: Rent::default().minimum_balance(data.len()),
+            data,
+            owner: lb_clmm::id(),
+            ..Account::default()
+        },
+    );
+}
+
+/// Set a zero copy account of lb_clmm to the given state, after the test started.
+pub fn set_zero_copy_account<T: Discriminator + Pod>(
+    context: &mut ProgramTestContext,
+    address: Pubkey,
+    state: &T,
+) {
+    let mut data = T::DISCRIMINATOR.to_vec();
+    data.extend_from_slice(bytemuck::bytes_of(state));
+
+    context.set_account(
+        &address,
+        &Account {
+            lamports: Rent::default().minimum_balance(data.len()),
+            data,
+            owner: lb_clmm::id(),
+            ..Account::default()
+        }
+        .into(),
+    );
+}
+
+/// Read a zero copy account of lb_clmm without alignment requirement.
+pub async fn get_zero_copy_account<T: Discriminator + Pod>(
+    banks_client: &mut BanksClient,
+    address: Pubkey,
+) -> T {
+    let account = banks_client.get_account(address).await.unwrap().unwrap();
+    commons::decode::decode_zero_copy(&account.data).unwrap()
+}
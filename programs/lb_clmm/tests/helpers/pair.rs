@@ -0,0 +1,357 @@
+use super::native::set_zero_copy_account;
+use super::utils::process_and_assert_ok;
+use anchor_lang::solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, instruction::AccountMeta,
+    program_error::ProgramError, pubkey::Pubkey,
+};
+use anchor_lang::Discriminator;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use lb_clmm::constants::{DEFAULT_OBSERVATION_LENGTH, MAX_BIN_ID, MIN_BIN_ID};
+use lb_clmm::state::bin::BinArray;
+use lb_clmm::state::lb_pair::{LbPair, PairStatus, PairType};
+use lb_clmm::state::oracle::{Observation, Oracle};
+use lb_clmm::state::parameters::StaticParameters;
+use lb_clmm::state::position::PositionV2;
+use lb_clmm::utils::pda::{
+    derive_bin_array_pda, derive_event_authority_pda, derive_lb_pair_pda2, derive_oracle_pda,
+};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::account::Account;
+use solana_sdk::signature::Signer;
+use spl_tlv_account_resolution::{account::ExtraAccountMeta, state::ExtraAccountMetaList};
+use spl_transfer_hook_interface::{
+    get_extra_account_metas_address,
+    instruction::{ExecuteInstruction, TransferHookInstruction},
+};
+
+pub const BIN_STEP: u16 = 10;
+pub const BASE_FACTOR: u16 = 10_000;
+
+/// Transfer hook program counting the transfers of its mints in a counter account
+pub const TRANSFER_HOOK_PROGRAM_ID: Pubkey = Pubkey::new_from_array([7u8; 32]);
+
+/// Pair with an empty oracle and the two bin arrays around bin 0, created without the initialization instructions
+pub struct PairFixture {
+    pub lb_pair: Pubkey,
+    pub token_x_mint: Pubkey,
+    pub token_y_mint: Pubkey,
+    pub token_x_program: Pubkey,
+    pub token_y_program: Pubkey,
+    pub reserve_x: Pubkey,
+    pub reserve_y: Pubkey,
+    pub oracle: Pubkey,
+    /// Bin array of index -1, holding the bins -70 to -1
+    pub bin_array_lower: Pubkey,
+    /// Bin array of index 0, holding the bins 0 to 69
+    pub bin_array_upper: Pubkey,
+}
+
+pub async fn setup_pair(
+    context: &mut ProgramTestContext,
+    token_x_mint: Pubkey,
+    token_x_program: Pubkey,
+    token_y_mint: Pubkey,
+    token_y_program: Pubkey,
+) -> PairFixture {
+    let (lb_pair, bump) = derive_lb_pair_pda2(token_x_mint, token_y_mint, BIN_STEP, BASE_FACTOR);
+    let (oracle, _bump) = derive_oracle_pda(lb_pair);
+
+    let mut reserves = vec![];
+    for (mint, token_program) in [
+        (token_x_mint, token_x_program),
+        (token_y_mint, token_y_program),
+    ] {
+        let ix = spl_associated_token_account::instruction::create_associated_token_account(
+            &context.payer.pubkey(),
+            &lb_pair,
+            &mint,
+            &token_program,
+        );
+        let payer = context.payer.insecure_clone();
+        process_and_assert_ok(&[ix], &payer, &[], &mut context.banks_client).await;
+        reserves.push(get_associated_token_address_with_program_id(
+            &lb_pair,
+            &mint,
+            &token_program,
+        ));
+    }
+
+    let mut lb_pair_state = LbPair::default();
+    lb_pair_state
+        .initialize(
+            bump,
+            0,
+            BIN_STEP,
+            token_x_mint,
+            token_y_mint,
+            reserves[0],
+            reserves[1],
+            oracle,
+            StaticParameters {
+                base_factor: BASE_FACTOR,
+                filter_period: 30,
+                decay_period: 600,
+                reduction_factor: 5000,
+                variable_fee_control: 40_000,
+                max_volatility_accumulator: 350_000,
+                min_bin_id: MIN_BIN_ID,
+                max_bin_id: MAX_BIN_ID,
+                protocol_share: 1000,
+                _padding: [0u8; 6],
+            },
+            PairType::Permissionless,
+            PairStatus::Enabled.into(),
+            Pubkey::default(),
+            context.payer.pubkey(),
+            0,
+            0,
+            Pubkey::default(),
+            0,
+        )
+        .unwrap();
+    set_zero_copy_account(context, lb_pair, &lb_pair_state);
+
+    set_oracle(context, oracle, DEFAULT_OBSERVATION_LENGTH);
+
+    let mut bin_arrays = vec![];
+    for index in [-1, 0] {
+        let (bin_array, _bump) = derive_bin_array_pda(lb_pair, index);
+        let mut bin_array_state: BinArray = bytemuck::Zeroable::zeroed();
+        bin_array_state.initialize(index, lb_pair).unwrap();
+        set_zero_copy_account(context, bin_array, &bin_array_state);
+        bin_arrays.push(bin_array);
+    }
+
+    PairFixture {
+        lb_pair,
+        token_x_mint,
+        token_y_mint,
+        token_x_program,
+        token_y_program,
+        reserve_x: reserves[0],
+        reserve_y: reserves[1],
+        oracle,
+        bin_array_lower: bin_arrays[0],
+        bin_array_upper: bin_arrays[1],
+    }
+}
+
+/// Set an oracle without any observation
+pub fn set_oracle(context: &mut ProgramTestContext, address: Pubkey, length: u64) {
+    let mut data = Oracle::DISCRIMINATOR.to_vec();
+    data.extend_from_slice(bytemuck::bytes_of(&Oracle {
+        idx: 0,
+        active_size: 0,
+        length,
+    }));
+    for _ in 0..length {
+        data.extend_from_slice(bytemuck::bytes_of(&Observation::default()));
+    }
+
+    context.set_account(
+        &address,
+        &Account {
+            lamports: solana_sdk::rent::Rent::default().minimum_balance(data.len()),
+            data,
+            owner: lb_clmm::id(),
+            ..Account::default()
+        }
+        .into(),
+    );
+}
+
+/// Create an empty position of the pair, covering the bins from lower_bin_id to upper_bin_id
+pub fn add_position(
+    context: &mut ProgramTestContext,
+    fixture: &PairFixture,
+    owner: Pubkey,
+    lower_bin_id: i32,
+    upper_bin_id: i32,
+) -> Pubkey {
+    let position = Pubkey::new_unique();
+
+    let mut position_state: PositionV2 = bytemuck::Zeroable::zeroed();
+    position_state.lb_pair = fixture.lb_pair;
+    position_state.owner = owner;
+    position_state.lower_bin_id = lower_bin_id;
+    position_state.upper_bin_id = upper_bin_id;
+    set_zero_copy_account(context, position, &position_state);
+
+    position
+}
+
+impl PairFixture {
+    pub fn user_token_x(&self, user: Pubkey) -> Pubkey {
+        get_associated_token_address_with_program_id(
+            &user,
+            &self.token_x_mint,
+            &self.token_x_program,
+        )
+    }
+
+    pub fn user_token_y(&self, user: Pubkey) -> Pubkey {
+        get_associated_token_address_with_program_id(
+            &user,
+            &self.token_y_mint,
+            &self.token_y_program,
+        )
+    }
+
+    pub fn modify_liquidity_accounts(
+        &self,
+        position: Pubkey,
+        sender: Pubkey,
+    ) -> lb_clmm::accounts::ModifyLiquidity {
+        let (event_authority, _bump) = derive_event_authority_pda();
+
+        lb_clmm::accounts::ModifyLiquidity {
+            position,
+            lb_pair: self.lb_pair,
+            bin_array_bitmap_extension: None,
+            user_token_x: self.user_token_x(sender),
+            user_token_y: self.user_token_y(sender),
+            reserve_x: self.reserve_x,
+            reserve_y: self.reserve_y,
+            token_x_mint: self.token_x_mint,
+            token_y_mint: self.token_y_mint,
+            bin_array_lower: self.bin_array_lower,
+            bin_array_upper: self.bin_array_upper,
+            sender,
+            token_x_program: self.token_x_program,
+            token_y_program: self.token_y_program,
+            position_nft_account: None,
+            event_authority,
+            program: lb_clmm::id(),
+        }
+    }
+
+    pub fn swap_accounts(
+        &self,
+        user: Pubkey,
+        swap_for_y: bool,
+        host_fee_in: Option<Pubkey>,
+    ) -> lb_clmm::accounts::Swap {
+        let (event_authority, _bump) = derive_event_authority_pda();
+
+        let (user_token_in, user_token_out) = if swap_for_y {
+            (self.user_token_x(user), self.user_token_y(user))
+        } else {
+            (self.user_token_y(user), self.user_token_x(user))
+        };
+
+        lb_clmm::accounts::Swap {
+            lb_pair: self.lb_pair,
+            bin_array_bitmap_extension: None,
+            reserve_x: self.reserve_x,
+            reserve_y: self.reserve_y,
+            user_token_in,
+            user_token_out,
+            token_x_mint: self.token_x_mint,
+            token_y_mint: self.token_y_mint,
+            oracle: self.oracle,
+            host_fee_in,
+            user,
+            token_x_program: self.token_x_program,
+            token_y_program: self.token_y_program,
+            event_authority,
+            program: lb_clmm::id(),
+        }
+    }
+
+    /// Bin arrays in the swap order, as remaining accounts
+    pub fn swap_bin_arrays(&self, swap_for_y: bool) -> Vec<AccountMeta> {
+        let bin_arrays = if swap_for_y {
+            [self.bin_array_upper, self.bin_array_lower]
+        } else {
+            [self.bin_array_lower, self.bin_array_upper]
+        };
+
+        bin_arrays
+            .into_iter()
+            .map(|bin_array| AccountMeta::new(bin_array, false))
+            .collect()
+    }
+}
+
+/// Increment the counter account of the mint on every transfer
+pub fn process_transfer_hook(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let TransferHookInstruction::Execute { .. } = TransferHookInstruction::unpack(data)? else {
+        return Err(ProgramError::InvalidInstructionData);
+    };
+
+    // Source, mint, destination, authority and validation accounts are followed by the counter
+    let counter = accounts.get(5).ok_or(ProgramError::NotEnoughAccountKeys)?;
+    if counter.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let mut data = counter.try_borrow_mut_data()?;
+    let count = u64::from_le_bytes(data[..8].try_into().unwrap());
+    data[..8].copy_from_slice(&(count + 1).to_le_bytes());
+
+    Ok(())
+}
+
+/// Counter of the transfers of a mint with the transfer hook program
+pub struct TransferHookCounter {
+    pub counter: Pubkey,
+    pub validation: Pubkey,
+}
+
+impl TransferHookCounter {
+    /// Extra accounts required by the transfer hook, to be passed as remaining accounts
+    pub fn account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.counter, false),
+            AccountMeta::new_readonly(TRANSFER_HOOK_PROGRAM_ID, false),
+            AccountMeta::new_readonly(self.validation, false),
+        ]
+    }
+
+    pub async fn get_count(&self, context: &mut ProgramTestContext) -> u64 {
+        let account = context
+            .banks_client
+            .get_account(self.counter)
+            .await
+            .unwrap()
+            .unwrap();
+        u64::from_le_bytes(account.data[..8].try_into().unwrap())
+    }
+}
+
+/// Create the counter and the extra account metas of the mint for the transfer hook program
+pub fn add_transfer_hook_counter(
+    context: &mut ProgramTestContext,
+    mint: Pubkey,
+) -> TransferHookCounter {
+    let counter = Pubkey::new_unique();
+    let validation = get_extra_account_metas_address(&mint, &TRANSFER_HOOK_PROGRAM_ID);
+
+    let extra_account_metas = [ExtraAccountMeta::new_with_pubkey(&counter, false, true).unwrap()];
+    let mut validation_data =
+        vec![0u8; ExtraAccountMetaList::size_of(extra_account_metas.len()).unwrap()];
+    ExtraAccountMetaList::init::<ExecuteInstruction>(&mut validation_data, &extra_account_metas)
+        .unwrap();
+
+    for (address, data) in [(counter, vec![0u8; 8]), (validation, validation_data)] {
+        context.set_account(
+            &address,
+            &Account {
+                lamports: solana_sdk::rent::Rent::default().minimum_balance(data.len()),
+                data,
+                owner: TRANSFER_HOOK_PROGRAM_ID,
+                ..Account::default()
+            }
+            .into(),
+        );
+    }
+
+    TransferHookCounter {
+        counter,
+        validation,
+    }
+}
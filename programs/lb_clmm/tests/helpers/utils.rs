@@ -2,12 +2,19 @@ use anchor_lang::solana_program::{instruction::Instruction, pubkey::Pubkey};
 use anchor_lang::AccountDeserialize;
 use anchor_spl::associated_token::*;
 use anchor_spl::token::spl_token;
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{
+        transfer_fee::instruction::initialize_transfer_fee_config, transfer_hook, ExtensionType,
+    },
+};
 use assert_matches::assert_matches;
 use async_trait::async_trait;
 use solana_program_test::BanksClient;
 use solana_sdk::{
+    instruction::InstructionError,
     signature::{Keypair, Signer},
-    transaction::Transaction,
+    transaction::{Transaction, TransactionError},
 };
 pub async fn process_and_assert_ok(
     instructions: &[Instruction],
@@ -44,7 +51,12 @@ impl AnchorAdapter for BanksClient {
         address: Pubkey,
     ) -> Option<T> {
         let account = self.get_account(address).await.unwrap()?;
-        T::try_deserialize(&mut account.data.as_ref()).ok()
+        // Zero copy accounts require the data following the discriminator to be 16 bytes aligned
+        let mut aligned_data = vec![0u128; account.data.len() / 16 + 2];
+        let data =
+            &mut bytemuck::cast_slice_mut::<u128, u8>(&mut aligned_data)[8..8 + account.data.len()];
+        data.copy_from_slice(&account.data);
+        T::try_deserialize(&mut &data[..]).ok()
     }
 }
 
@@ -125,3 +137,138 @@ pub async fn get_clock(banks_client: &mut BanksClient) -> solana_program::clock:
 
     clock_state
 }
+
+pub async fn create_token_2022_mint_with_transfer_fee(
+    payer: &Keypair,
+    transfer_fee_bps: u16,
+    maximum_fee: u64,
+    decimals: u8,
+    banks_client: &mut BanksClient,
+) -> Pubkey {
+    let mint = Keypair::new();
+    let space = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&[
+        ExtensionType::TransferFeeConfig,
+    ])
+    .unwrap();
+    let rent = banks_client.get_rent().await.unwrap();
+
+    let ins = vec![
+        solana_program::system_instruction::create_account(
+            &payer.pubkey(),
+            &mint.pubkey(),
+            rent.minimum_balance(space),
+            space as u64,
+            &spl_token_2022::id(),
+        ),
+        initialize_transfer_fee_config(
+            &spl_token_2022::id(),
+            &mint.pubkey(),
+            Some(&payer.pubkey()),
+            Some(&payer.pubkey()),
+            transfer_fee_bps,
+            maximum_fee,
+        )
+        .unwrap(),
+        spl_token_2022::instruction::initialize_mint2(
+            &spl_token_2022::id(),
+            &mint.pubkey(),
+            &payer.pubkey(),
+            None,
+            decimals,
+        )
+        .unwrap(),
+    ];
+
+    process_and_assert_ok(&ins, payer, &[&mint], banks_client).await;
+
+    mint.pubkey()
+}
+
+pub async fn create_token_2022_mint_with_transfer_hook(
+    payer: &Keypair,
+    transfer_hook_program_id: Pubkey,
+    decimals: u8,
+    banks_client: &mut BanksClient,
+) -> Pubkey {
+    let mint = Keypair::new();
+    let space = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&[
+        ExtensionType::TransferHook,
+    ])
+    .unwrap();
+    let rent = banks_client.get_rent().await.unwrap();
+
+    let ins = vec![
+        solana_program::system_instruction::create_account(
+            &payer.pubkey(),
+            &mint.pubkey(),
+            rent.minimum_balance(space),
+            space as u64,
+            &spl_token_2022::id(),
+        ),
+        transfer_hook::instruction::initialize(
+            &spl_token_2022::id(),
+            &mint.pubkey(),
+            Some(payer.pubkey()),
+            Some(transfer_hook_program_id),
+        )
+        .unwrap(),
+        spl_token_2022::instruction::initialize_mint2(
+            &spl_token_2022::id(),
+            &mint.pubkey(),
+            &payer.pubkey(),
+            None,
+            decimals,
+        )
+        .unwrap(),
+    ];
+
+    process_and_assert_ok(&ins, payer, &[&mint], banks_client).await;
+
+    mint.pubkey()
+}
+
+pub async fn create_token_2022_ata(
+    payer: &Keypair,
+    token_mint: &Pubkey,
+    authority: &Pubkey,
+    banks_client: &mut BanksClient,
+) -> Pubkey {
+    let ins = vec![
+        spl_associated_token_account::instruction::create_associated_token_account(
+            &payer.pubkey(),
+            &authority,
+            &token_mint,
+            &spl_token_2022::id(),
+        ),
+    ];
+
+    process_and_assert_ok(&ins, payer, &[payer], banks_client).await;
+
+    get_associated_token_address_with_program_id(authority, token_mint, &spl_token_2022::id())
+}
+
+pub async fn process_and_assert_err(
+    instructions: &[Instruction],
+    payer: &Keypair,
+    signers: &[&Keypair],
+    banks_client: &mut BanksClient,
+    error: lb_clmm::errors::LBError,
+) {
+    let recent_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+
+    let mut all_signers = vec![payer];
+    all_signers.extend_from_slice(signers);
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&payer.pubkey()),
+        &all_signers,
+        recent_blockhash,
+    );
+
+    let error_code = anchor_lang::error::ERROR_CODE_OFFSET + error as u32;
+    assert_matches!(
+        banks_client.process_transaction(tx).await.map_err(|err| err.unwrap()),
+        Err(TransactionError::InstructionError(_, InstructionError::Custom(code))) if code == error_code
+    );
+}
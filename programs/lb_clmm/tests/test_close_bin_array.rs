@@ -0,0 +1,275 @@
+#![cfg(feature = "test-bpf")]
+mod helpers;
+use anchor_lang::solana_program::system_instruction;
+use anchor_spl::token_2022::spl_token_2022;
+use commons::instructions::build_instruction;
+use commons::instructions::close_bin_array::{close_bin_array, close_legacy_bin_array};
+use helpers::*;
+use lb_clmm::errors::LBError;
+use lb_clmm::state::bin::BinArray;
+use lb_clmm::state::bin_array_funder::BinArrayFunder;
+use lb_clmm::utils::pda::{derive_bin_array_funder_pda, derive_bin_array_pda};
+use native::*;
+use pair::*;
+use solana_program_test::*;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::Transaction;
+use utils::*;
+
+const DECIMALS: u8 = 6;
+
+async fn setup(context: &mut ProgramTestContext) -> PairFixture {
+    let payer = context.payer.insecure_clone();
+
+    let mut mints = vec![];
+    for _ in 0..2 {
+        let mint = create_token_2022_mint_with_transfer_fee(
+            &payer,
+            0,
+            0,
+            DECIMALS,
+            &mut context.banks_client,
+        )
+        .await;
+        mints.push(mint);
+    }
+    mints.sort();
+
+    setup_pair(
+        context,
+        mints[0],
+        spl_token_2022::id(),
+        mints[1],
+        spl_token_2022::id(),
+    )
+    .await
+}
+
+async fn get_lamports(context: &mut ProgramTestContext, address: Pubkey) -> u64 {
+    context
+        .banks_client
+        .get_account(address)
+        .await
+        .unwrap()
+        .map(|account| account.lamports)
+        .unwrap_or_default()
+}
+
+async fn create_funder(context: &mut ProgramTestContext) -> Keypair {
+    let payer = context.payer.insecure_clone();
+    let funder = Keypair::new();
+
+    let transfer_ix =
+        system_instruction::transfer(&payer.pubkey(), &funder.pubkey(), LAMPORTS_PER_SOL);
+    process_and_assert_ok(&[transfer_ix], &payer, &[], &mut context.banks_client).await;
+
+    funder
+}
+
+async fn initialize_bin_array_with_funder(
+    context: &mut ProgramTestContext,
+    lb_pair: Pubkey,
+    index: i64,
+    funder: &Keypair,
+) -> Pubkey {
+    let payer = context.payer.insecure_clone();
+    let (bin_array, _bump) = derive_bin_array_pda(lb_pair, index);
+
+    let ix = build_instruction(
+        lb_clmm::accounts::InitializeBinArrayWithFunder {
+            lb_pair,
+            bin_array,
+            bin_array_funder: derive_bin_array_funder_pda(bin_array).0,
+            funder: funder.pubkey(),
+            system_program: solana_sdk::system_program::ID,
+        },
+        lb_clmm::instruction::InitializeBinArrayWithFunder { index },
+        vec![],
+    );
+    process_and_assert_ok(&[ix], &payer, &[funder], &mut context.banks_client).await;
+
+    bin_array
+}
+
+#[tokio::test]
+async fn test_initialize_bin_array_with_funder_and_close_with_refund() {
+    let mut test = ProgramTest::default();
+    add_lb_clmm_native(&mut test);
+
+    let mut context = test.start_with_context().await;
+    let payer = context.payer.insecure_clone();
+    let fixture = setup(&mut context).await;
+    let funder = create_funder(&mut context).await;
+
+    let funder_lamports_before = get_lamports(&mut context, funder.pubkey()).await;
+    let bin_array =
+        initialize_bin_array_with_funder(&mut context, fixture.lb_pair, 1, &funder).await;
+    let (bin_array_funder, _bump) = derive_bin_array_funder_pda(bin_array);
+
+    let bin_array_state: BinArray =
+        get_zero_copy_account(&mut context.banks_client, bin_array).await;
+    assert_eq!(bin_array_state.index, 1);
+    assert_eq!(bin_array_state.lb_pair, fixture.lb_pair);
+
+    let bin_array_funder_state = context
+        .banks_client
+        .get_account_with_anchor_seder::<BinArrayFunder>(bin_array_funder)
+        .await
+        .unwrap();
+    assert_eq!(bin_array_funder_state.bin_array, bin_array);
+    assert_eq!(bin_array_funder_state.funder, funder.pubkey());
+
+    let rent = get_lamports(&mut context, bin_array).await
+        + get_lamports(&mut context, bin_array_funder).await;
+    assert_eq!(
+        funder_lamports_before - get_lamports(&mut context, funder.pubkey()).await,
+        rent
+    );
+
+    // Anyone can close the empty bin array, the rent goes back to the recorded funder
+    let close_ix = close_bin_array(fixture.lb_pair, bin_array, funder.pubkey(), None);
+    process_and_assert_ok(&[close_ix], &payer, &[], &mut context.banks_client).await;
+
+    assert_eq!(
+        get_lamports(&mut context, funder.pubkey()).await,
+        funder_lamports_before
+    );
+    assert!(context
+        .banks_client
+        .get_account(bin_array)
+        .await
+        .unwrap()
+        .is_none());
+    assert!(context
+        .banks_client
+        .get_account(bin_array_funder)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_close_bin_array_reject_other_funder() {
+    let mut test = ProgramTest::default();
+    add_lb_clmm_native(&mut test);
+
+    let mut context = test.start_with_context().await;
+    let payer = context.payer.insecure_clone();
+    let fixture = setup(&mut context).await;
+    let funder = create_funder(&mut context).await;
+
+    let bin_array =
+        initialize_bin_array_with_funder(&mut context, fixture.lb_pair, 1, &funder).await;
+
+    let close_ix = close_bin_array(fixture.lb_pair, bin_array, payer.pubkey(), None);
+    let tx = Transaction::new_signed_with_payer(
+        &[close_ix],
+        Some(&payer.pubkey()),
+        &[&payer],
+        context.banks_client.get_latest_blockhash().await.unwrap(),
+    );
+    assert!(context.banks_client.process_transaction(tx).await.is_err());
+
+    // Nor through the legacy path, even by the pair creator
+    let close_ix = close_legacy_bin_array(
+        fixture.lb_pair,
+        bin_array,
+        payer.pubkey(),
+        payer.pubkey(),
+        None,
+    );
+    process_and_assert_err(
+        &[close_ix],
+        &payer,
+        &[],
+        &mut context.banks_client,
+        LBError::BinArrayFunderRecorded,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_initialize_legacy_bin_array_and_close_with_refund() {
+    let mut test = ProgramTest::default();
+    add_lb_clmm_native(&mut test);
+
+    let mut context = test.start_with_context().await;
+    let payer = context.payer.insecure_clone();
+    let fixture = setup(&mut context).await;
+
+    // Account list of initialize_bin_array is unchanged, no funder is recorded
+    let (bin_array, _bump) = derive_bin_array_pda(fixture.lb_pair, 1);
+    let (bin_array_funder, _bump) = derive_bin_array_funder_pda(bin_array);
+    let ix = build_instruction(
+        lb_clmm::accounts::InitializeBinArray {
+            lb_pair: fixture.lb_pair,
+            bin_array,
+            funder: payer.pubkey(),
+            system_program: solana_sdk::system_program::ID,
+        },
+        lb_clmm::instruction::InitializeBinArray { index: 1 },
+        vec![],
+    );
+    assert_eq!(ix.accounts.len(), 4);
+    process_and_assert_ok(&[ix], &payer, &[], &mut context.banks_client).await;
+
+    let bin_array_state: BinArray =
+        get_zero_copy_account(&mut context.banks_client, bin_array).await;
+    assert_eq!(bin_array_state.index, 1);
+    assert_eq!(bin_array_state.lb_pair, fixture.lb_pair);
+    assert!(context
+        .banks_client
+        .get_account(bin_array_funder)
+        .await
+        .unwrap()
+        .is_none());
+
+    // Neither the permissionless close, without funder record, nor an authority other than the pair creator and the fee admin
+    let close_ix = close_bin_array(fixture.lb_pair, bin_array, payer.pubkey(), None);
+    let tx = Transaction::new_signed_with_payer(
+        &[close_ix],
+        Some(&payer.pubkey()),
+        &[&payer],
+        context.banks_client.get_latest_blockhash().await.unwrap(),
+    );
+    assert!(context.banks_client.process_transaction(tx).await.is_err());
+
+    let other = create_funder(&mut context).await;
+    let close_ix = close_legacy_bin_array(
+        fixture.lb_pair,
+        bin_array,
+        other.pubkey(),
+        other.pubkey(),
+        None,
+    );
+    process_and_assert_err(
+        &[close_ix],
+        &payer,
+        &[&other],
+        &mut context.banks_client,
+        LBError::InvalidAdmin,
+    )
+    .await;
+
+    // The pair creator close it, refunding the rent to the receiver
+    let rent_receiver = Pubkey::new_unique();
+    let rent = get_lamports(&mut context, bin_array).await;
+    let close_ix = close_legacy_bin_array(
+        fixture.lb_pair,
+        bin_array,
+        payer.pubkey(),
+        rent_receiver,
+        None,
+    );
+    process_and_assert_ok(&[close_ix], &payer, &[], &mut context.banks_client).await;
+
+    assert_eq!(get_lamports(&mut context, rent_receiver).await, rent);
+    assert!(context
+        .banks_client
+        .get_account(bin_array)
+        .await
+        .unwrap()
+        .is_none());
+}
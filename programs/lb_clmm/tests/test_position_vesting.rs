@@ -0,0 +1,162 @@
+#![cfg(feature = "test-bpf")]
+mod helpers;
+use anchor_spl::token_2022::spl_token_2022;
+use commons::instructions::build_instruction;
+use helpers::*;
+use lb_clmm::constants::VESTING_RATIO_PRECISION;
+use lb_clmm::errors::LBError;
+use lb_clmm::instructions::deposit::{BinLiquidityDistribution, LiquidityParameter};
+use lb_clmm::instructions::withdraw::BinLiquidityReduction;
+use lb_clmm::state::position::PositionV2;
+use native::*;
+use pair::*;
+use solana_program_test::*;
+use solana_sdk::signature::Signer;
+use utils::*;
+
+const DECIMALS: u8 = 6;
+const VESTING_END_SLOT: u64 = 1_000;
+
+#[tokio::test]
+async fn test_remove_liquidity_duplicated_bins_of_vesting_position_rejected() {
+    let mut test = ProgramTest::default();
+    add_lb_clmm_native(&mut test);
+
+    let mut context = test.start_with_context().await;
+    let payer = context.payer.insecure_clone();
+
+    let mut mints = vec![];
+    for _ in 0..2 {
+        let mint = create_token_2022_mint_with_transfer_fee(
+            &payer,
+            0,
+            0,
+            DECIMALS,
+            &mut context.banks_client,
+        )
+        .await;
+        mints.push(mint);
+    }
+    mints.sort();
+    let (token_x_mint, token_y_mint) = (mints[0], mints[1]);
+
+    let fixture = setup_pair(
+        &mut context,
+        token_x_mint,
+        spl_token_2022::id(),
+        token_y_mint,
+        spl_token_2022::id(),
+    )
+    .await;
+
+    for mint in [token_x_mint, token_y_mint] {
+        let user_token =
+            create_token_2022_ata(&payer, &mint, &payer.pubkey(), &mut context.banks_client).await;
+        let mint_to_ix = spl_token_2022::instruction::mint_to(
+            &spl_token_2022::id(),
+            &mint,
+            &user_token,
+            &payer.pubkey(),
+            &[],
+            1_000_000_000_000,
+        )
+        .unwrap();
+        process_and_assert_ok(&[mint_to_ix], &payer, &[], &mut context.banks_client).await;
+    }
+
+    let position = add_position(&mut context, &fixture, payer.pubkey(), -3, 3);
+    let amount = 1_000_000_000;
+    let deposit_ix = build_instruction(
+        fixture.modify_liquidity_accounts(position, payer.pubkey()),
+        lb_clmm::instruction::AddLiquidity {
+            liquidity_parameter: LiquidityParameter {
+                amount_x: amount,
+                amount_y: amount,
+                bin_liquidity_dist: (-3..=3)
+                    .map(|bin_id| BinLiquidityDistribution {
+                        bin_id,
+                        distribution_x: if bin_id >= 0 { 2500 } else { 0 },
+                        distribution_y: if bin_id <= 0 { 2500 } else { 0 },
+                    })
+                    .collect(),
+            },
+        },
+        vec![],
+    );
+    process_and_assert_ok(&[deposit_ix], &payer, &[], &mut context.banks_client).await;
+
+    // Half of the liquidity is vested at the middle of the schedule
+    let mut position_state: PositionV2 =
+        get_zero_copy_account(&mut context.banks_client, position).await;
+    position_state.set_vesting(0, 0, VESTING_END_SLOT).unwrap();
+    set_zero_copy_account(&mut context, position, &position_state);
+    context.warp_to_slot(VESTING_END_SLOT / 2).unwrap();
+
+    let remove_liquidity_ix = |bin_liquidity_removal: Vec<BinLiquidityReduction>| {
+        build_instruction(
+            fixture.modify_liquidity_accounts(position, payer.pubkey()),
+            lb_clmm::instruction::RemoveLiquidity {
+                bin_liquidity_removal,
+            },
+            vec![],
+        )
+    };
+
+    // 30% of every bin 3 times would remove 65.7% of the liquidity, while the vesting remaining ratio only drop by 30%
+    let duplicated_reductions = (0..3)
+        .flat_map(|_| {
+            (-3..=3).map(|bin_id| BinLiquidityReduction {
+                bin_id,
+                bps_to_remove: 3000,
+            })
+        })
+        .collect();
+    process_and_assert_err(
+        &[remove_liquidity_ix(duplicated_reductions)],
+        &payer,
+        &[],
+        &mut context.banks_client,
+        LBError::UnevenVestingWithdrawal,
+    )
+    .await;
+
+    let position_after: PositionV2 =
+        get_zero_copy_account(&mut context.banks_client, position).await;
+    assert_eq!(
+        position_after.liquidity_shares,
+        position_state.liquidity_shares
+    );
+    assert_eq!(
+        position_after.vesting_remaining_ratio,
+        VESTING_RATIO_PRECISION
+    );
+
+    // Removing 30% of every bin once is within the vested liquidity
+    let reductions = (-3..=3)
+        .map(|bin_id| BinLiquidityReduction {
+            bin_id,
+            bps_to_remove: 3000,
+        })
+        .collect();
+    process_and_assert_ok(
+        &[remove_liquidity_ix(reductions)],
+        &payer,
+        &[],
+        &mut context.banks_client,
+    )
+    .await;
+
+    let position_after: PositionV2 =
+        get_zero_copy_account(&mut context.banks_client, position).await;
+    for (share_after, share_before) in position_after
+        .liquidity_shares
+        .iter()
+        .zip(position_state.liquidity_shares.iter())
+    {
+        assert_eq!(*share_after, share_before - share_before * 3000 / 10_000);
+    }
+    assert_eq!(
+        position_after.vesting_remaining_ratio,
+        VESTING_RATIO_PRECISION * 7 / 10
+    );
+}
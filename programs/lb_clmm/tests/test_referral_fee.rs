@@ -0,0 +1,310 @@
+#![cfg(feature = "test-bpf")]
+mod helpers;
+use anchor_lang::AccountSerialize;
+use anchor_spl::token_2022::spl_token_2022::{self, extension::StateWithExtensions};
+use commons::instructions::build_instruction;
+use commons::instructions::claim_referral_fee::claim_referral_fee;
+use commons::instructions::initialize_referral_fee::{
+    get_referral_account_metas, initialize_referral_fee,
+};
+use helpers::*;
+use lb_clmm::instructions::deposit::{BinLiquidityDistribution, LiquidityParameter};
+use lb_clmm::state::lb_pair::LbPair;
+use lb_clmm::state::referral::{ReferralFee, Referrer};
+use lb_clmm::utils::pda::{derive_referral_fee_pda, derive_referrer_pda};
+use native::*;
+use pair::*;
+use solana_program_test::*;
+use solana_sdk::account::Account;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use utils::*;
+
+const DECIMALS: u8 = 6;
+const REFERRER_FEE_SHARE: u16 = 2000;
+
+async fn get_token_2022_balance(banks_client: &mut BanksClient, token_account: Pubkey) -> u64 {
+    let account = banks_client
+        .get_account(token_account)
+        .await
+        .unwrap()
+        .unwrap();
+
+    StateWithExtensions::<spl_token_2022::state::Account>::unpack(&account.data)
+        .unwrap()
+        .base
+        .amount
+}
+
+/// Register a referrer of `owner`, as done by the admin with `initialize_referrer`
+fn add_referrer(context: &mut ProgramTestContext, owner: Pubkey, fee_share: u16) -> Pubkey {
+    let (referrer, _bump) = derive_referrer_pda(owner);
+
+    let mut data = vec![];
+    Referrer { owner, fee_share }
+        .try_serialize(&mut data)
+        .unwrap();
+
+    context.set_account(
+        &referrer,
+        &Account {
+            lamports: solana_sdk::rent::Rent::default().minimum_balance(data.len()),
+            data,
+            owner: lb_clmm::id(),
+            ..Account::default()
+        }
+        .into(),
+    );
+
+    referrer
+}
+
+#[tokio::test]
+async fn test_referred_swap_accumulate_referral_fee() {
+    let mut test = ProgramTest::default();
+    add_lb_clmm_native(&mut test);
+
+    let mut context = test.start_with_context().await;
+    let payer = context.payer.insecure_clone();
+    let referrer_owner = Keypair::new();
+
+    let mut mints = vec![];
+    for _ in 0..2 {
+        let mint = create_token_2022_mint_with_transfer_fee(
+            &payer,
+            0,
+            0,
+            DECIMALS,
+            &mut context.banks_client,
+        )
+        .await;
+        mints.push(mint);
+    }
+    mints.sort();
+    let (token_x_mint, token_y_mint) = (mints[0], mints[1]);
+
+    let fixture = setup_pair(
+        &mut context,
+        token_x_mint,
+        spl_token_2022::id(),
+        token_y_mint,
+        spl_token_2022::id(),
+    )
+    .await;
+
+    for mint in [token_x_mint, token_y_mint] {
+        let user_token =
+            create_token_2022_ata(&payer, &mint, &payer.pubkey(), &mut context.banks_client).await;
+        let mint_to_ix = spl_token_2022::instruction::mint_to(
+            &spl_token_2022::id(),
+            &mint,
+            &user_token,
+            &payer.pubkey(),
+            &[],
+            1_000_000_000_000,
+        )
+        .unwrap();
+        process_and_assert_ok(&[mint_to_ix], &payer, &[], &mut context.banks_client).await;
+
+        create_token_2022_ata(
+            &payer,
+            &mint,
+            &referrer_owner.pubkey(),
+            &mut context.banks_client,
+        )
+        .await;
+    }
+
+    let position = add_position(&mut context, &fixture, payer.pubkey(), -3, 3);
+    let amount = 1_000_000_000;
+    let deposit_ix = build_instruction(
+        fixture.modify_liquidity_accounts(position, payer.pubkey()),
+        lb_clmm::instruction::AddLiquidity {
+            liquidity_parameter: LiquidityParameter {
+                amount_x: amount,
+                amount_y: amount,
+                bin_liquidity_dist: (-3..=3)
+                    .map(|bin_id| BinLiquidityDistribution {
+                        bin_id,
+                        distribution_x: if bin_id >= 0 { 2500 } else { 0 },
+                        distribution_y: if bin_id <= 0 { 2500 } else { 0 },
+                    })
+                    .collect(),
+            },
+        },
+        vec![],
+    );
+    process_and_assert_ok(&[deposit_ix], &payer, &[], &mut context.banks_client).await;
+
+    let referrer = add_referrer(&mut context, referrer_owner.pubkey(), REFERRER_FEE_SHARE);
+    let (referral_fee, _bump) = derive_referral_fee_pda(referrer, fixture.lb_pair);
+    process_and_assert_ok(
+        &[initialize_referral_fee(
+            referrer,
+            fixture.lb_pair,
+            payer.pubkey(),
+        )],
+        &payer,
+        &[],
+        &mut context.banks_client,
+    )
+    .await;
+
+    // The referrer take precedence over host_fee_in, which receive nothing
+    let host_fee_in = fixture.user_token_x(referrer_owner.pubkey());
+    let amount_in = 1_000_000;
+    let mut expected_fee_x = 0;
+
+    for _ in 0..2 {
+        let reserve_x_before =
+            get_token_2022_balance(&mut context.banks_client, fixture.reserve_x).await;
+        let protocol_fee_x_before =
+            get_zero_copy_account::<LbPair>(&mut context.banks_client, fixture.lb_pair)
+                .await
+                .protocol_fee
+                .amount_x;
+
+        let mut remaining_accounts = fixture.swap_bin_arrays(true);
+        remaining_accounts.extend(get_referral_account_metas(referrer, fixture.lb_pair));
+        let swap_ix = build_instruction(
+            fixture.swap_accounts(payer.pubkey(), true, Some(host_fee_in)),
+            lb_clmm::instruction::Swap {
+                amount_in,
+                min_amount_out: 0,
+            },
+            remaining_accounts,
+        );
+        process_and_assert_ok(&[swap_ix], &payer, &[], &mut context.banks_client).await;
+
+        // The referral fee stay in the reserve, split from the protocol fee by the fee share of the referrer
+        let protocol_fee =
+            get_zero_copy_account::<LbPair>(&mut context.banks_client, fixture.lb_pair)
+                .await
+                .protocol_fee
+                .amount_x
+                - protocol_fee_x_before;
+        let referral_fee_state = context
+            .banks_client
+            .get_account_with_anchor_seder::<ReferralFee>(referral_fee)
+            .await
+            .unwrap();
+
+        let host_fee = referral_fee_state.fee_x - expected_fee_x;
+        assert!(host_fee > 0);
+        assert_eq!(
+            host_fee,
+            (protocol_fee + host_fee) * u64::from(REFERRER_FEE_SHARE) / 10_000
+        );
+        expected_fee_x = referral_fee_state.fee_x;
+
+        assert_eq!(referral_fee_state.fee_y, 0);
+        assert_eq!(
+            get_token_2022_balance(&mut context.banks_client, fixture.reserve_x).await,
+            reserve_x_before + amount_in
+        );
+        assert_eq!(
+            get_token_2022_balance(&mut context.banks_client, host_fee_in).await,
+            0
+        );
+    }
+
+    // The referrer owner claim the accumulated referral fee from the reserve
+    let lb_pair_state: LbPair =
+        get_zero_copy_account(&mut context.banks_client, fixture.lb_pair).await;
+    let claim_ix = claim_referral_fee(
+        fixture.lb_pair,
+        &lb_pair_state,
+        referrer_owner.pubkey(),
+        spl_token_2022::id(),
+        spl_token_2022::id(),
+    );
+    process_and_assert_ok(
+        &[claim_ix],
+        &payer,
+        &[&referrer_owner],
+        &mut context.banks_client,
+    )
+    .await;
+
+    assert_eq!(
+        get_token_2022_balance(&mut context.banks_client, host_fee_in).await,
+        expected_fee_x
+    );
+    let referral_fee_state = context
+        .banks_client
+        .get_account_with_anchor_seder::<ReferralFee>(referral_fee)
+        .await
+        .unwrap();
+    assert_eq!(referral_fee_state.fee_x, 0);
+}
+
+#[tokio::test]
+async fn test_swap_reject_referral_fee_of_other_referrer() {
+    let mut test = ProgramTest::default();
+    add_lb_clmm_native(&mut test);
+
+    let mut context = test.start_with_context().await;
+    let payer = context.payer.insecure_clone();
+
+    let mut mints = vec![];
+    for _ in 0..2 {
+        let mint = create_token_2022_mint_with_transfer_fee(
+            &payer,
+            0,
+            0,
+            DECIMALS,
+            &mut context.banks_client,
+        )
+        .await;
+        mints.push(mint);
+    }
+    mints.sort();
+
+    let fixture = setup_pair(
+        &mut context,
+        mints[0],
+        spl_token_2022::id(),
+        mints[1],
+        spl_token_2022::id(),
+    )
+    .await;
+    for mint in [mints[0], mints[1]] {
+        create_token_2022_ata(&payer, &mint, &payer.pubkey(), &mut context.banks_client).await;
+    }
+
+    let referrer = add_referrer(&mut context, Pubkey::new_unique(), REFERRER_FEE_SHARE);
+    let other_referrer = add_referrer(&mut context, Pubkey::new_unique(), REFERRER_FEE_SHARE);
+    process_and_assert_ok(
+        &[initialize_referral_fee(
+            other_referrer,
+            fixture.lb_pair,
+            payer.pubkey(),
+        )],
+        &payer,
+        &[],
+        &mut context.banks_client,
+    )
+    .await;
+
+    let mut remaining_accounts = fixture.swap_bin_arrays(true);
+    remaining_accounts.extend(get_referral_account_metas(referrer, fixture.lb_pair));
+    // Referral fee account of the other referrer
+    remaining_accounts[3] = get_referral_account_metas(other_referrer, fixture.lb_pair)[1].clone();
+
+    let swap_ix = build_instruction(
+        fixture.swap_accounts(payer.pubkey(), true, None),
+        lb_clmm::instruction::Swap {
+            amount_in: 1_000,
+            min_amount_out: 0,
+        },
+        remaining_accounts,
+    );
+    process_and_assert_err(
+        &[swap_ix],
+        &payer,
+        &[],
+        &mut context.banks_client,
+        lb_clmm::errors::LBError::InvalidReferralFeeAccount,
+    )
+    .await;
+}
@@ -116,15 +116,37 @@ async fn test_swap() {
 
     let clock = get_clock(&mut banks_client).await;
 
+    let mint_x_account = banks_client
+        .get_account(token_x_mint)
+        .await
+        .unwrap()
+        .unwrap();
+    let mint_y_account = banks_client
+        .get_account(token_y_mint)
+        .await
+        .unwrap()
+        .unwrap();
+
+    let oracle_account = banks_client
+        .get_account(lb_pair_state.oracle)
+        .await
+        .unwrap()
+        .unwrap();
+
     let quote_result = commons::quote::quote_exact_in(
-        lb_pair,
-        &lb_pair_state,
+        commons::quote::SwapQuoteAccounts {
+            lb_pair_pubkey: lb_pair,
+            lb_pair: &lb_pair_state,
+            bin_arrays,
+            bitmap_extension: None,
+            mint_x_account: &mint_x_account,
+            mint_y_account: &mint_y_account,
+            oracle_account: &oracle_account,
+        },
         amount_in,
         false,
-        bin_arrays,
+        &clock,
         None,
-        clock.unix_timestamp as u64,
-        clock.slot,
     )
     .unwrap();
 
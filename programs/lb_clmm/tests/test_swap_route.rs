@@ -0,0 +1,237 @@
+#![cfg(feature = "test-bpf")]
+mod helpers;
+use anchor_spl::token_2022::spl_token_2022::{self, extension::StateWithExtensions};
+use commons::instructions::build_instruction;
+use commons::instructions::swap_route::swap_route;
+use commons::quote::{quote_route_exact_in, SwapQuoteAccounts};
+use helpers::*;
+use lb_clmm::errors::LBError;
+use lb_clmm::instructions::deposit::{BinLiquidityDistribution, LiquidityParameter};
+use lb_clmm::state::{bin::BinArray, lb_pair::LbPair};
+use native::*;
+use pair::*;
+use solana_program_test::*;
+use solana_sdk::instruction::AccountMeta;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use std::collections::HashMap;
+use utils::*;
+
+const DECIMALS: u8 = 6;
+
+async fn get_token_2022_balance(banks_client: &mut BanksClient, token_account: Pubkey) -> u64 {
+    let account = banks_client
+        .get_account(token_account)
+        .await
+        .unwrap()
+        .unwrap();
+    StateWithExtensions::<spl_token_2022::state::Account>::unpack(&account.data)
+        .unwrap()
+        .base
+        .amount
+}
+
+/// Deposit into the bins -3 to 3 of a new position of the pair, token X above and token Y below the active bin
+async fn deposit(
+    context: &mut ProgramTestContext,
+    payer: &Keypair,
+    fixture: &PairFixture,
+    transfer_hook_accounts: Vec<AccountMeta>,
+) {
+    let position = add_position(context, fixture, payer.pubkey(), -3, 3);
+    let amount = 1_000_000_000;
+    let deposit_ix = build_instruction(
+        fixture.modify_liquidity_accounts(position, payer.pubkey()),
+        lb_clmm::instruction::AddLiquidity {
+            liquidity_parameter: LiquidityParameter {
+                amount_x: amount,
+                amount_y: amount,
+                bin_liquidity_dist: (-3..=3)
+                    .map(|bin_id| BinLiquidityDistribution {
+                        bin_id,
+                        distribution_x: if bin_id >= 0 { 2500 } else { 0 },
+                        distribution_y: if bin_id <= 0 { 2500 } else { 0 },
+                    })
+                    .collect(),
+            },
+        },
+        transfer_hook_accounts,
+    );
+    process_and_assert_ok(&[deposit_ix], payer, &[], &mut context.banks_client).await;
+}
+
+#[tokio::test]
+async fn test_swap_route_through_two_pairs() {
+    let mut test = ProgramTest::default();
+    add_lb_clmm_native(&mut test);
+    test.add_program(
+        "transfer_hook",
+        TRANSFER_HOOK_PROGRAM_ID,
+        processor!(process_transfer_hook),
+    );
+
+    let mut context = test.start_with_context().await;
+    let payer = context.payer.insecure_clone();
+
+    // Route A -> B -> C, token A having a transfer hook
+    let mint_a = create_token_2022_mint_with_transfer_hook(
+        &payer,
+        TRANSFER_HOOK_PROGRAM_ID,
+        DECIMALS,
+        &mut context.banks_client,
+    )
+    .await;
+    let mut mints = vec![mint_a];
+    for _ in 0..2 {
+        let mint = create_token_2022_mint_with_transfer_fee(
+            &payer,
+            0,
+            0,
+            DECIMALS,
+            &mut context.banks_client,
+        )
+        .await;
+        mints.push(mint);
+    }
+    let (mint_b, mint_c) = (mints[1], mints[2]);
+    let transfer_hook_counter = add_transfer_hook_counter(&mut context, mint_a);
+
+    let mut fixtures = vec![];
+    for (mint_in, mint_out) in [(mint_a, mint_b), (mint_b, mint_c)] {
+        let (token_x_mint, token_y_mint) = if mint_in < mint_out {
+            (mint_in, mint_out)
+        } else {
+            (mint_out, mint_in)
+        };
+        let fixture = setup_pair(
+            &mut context,
+            token_x_mint,
+            spl_token_2022::id(),
+            token_y_mint,
+            spl_token_2022::id(),
+        )
+        .await;
+        fixtures.push(fixture);
+    }
+
+    let mut user_tokens = HashMap::new();
+    for mint in mints {
+        let user_token =
+            create_token_2022_ata(&payer, &mint, &payer.pubkey(), &mut context.banks_client).await;
+        user_tokens.insert(mint, user_token);
+        let mint_to_ix = spl_token_2022::instruction::mint_to(
+            &spl_token_2022::id(),
+            &mint,
+            &user_token,
+            &payer.pubkey(),
+            &[],
+            1_000_000_000_000,
+        )
+        .unwrap();
+        process_and_assert_ok(&[mint_to_ix], &payer, &[], &mut context.banks_client).await;
+    }
+
+    deposit(
+        &mut context,
+        &payer,
+        &fixtures[0],
+        transfer_hook_counter.account_metas(),
+    )
+    .await;
+    deposit(&mut context, &payer, &fixtures[1], vec![]).await;
+    assert_eq!(transfer_hook_counter.get_count(&mut context).await, 1);
+
+    let mut lb_pair_states = vec![];
+    let mut mint_accounts = HashMap::new();
+    let mut oracle_accounts = vec![];
+    for fixture in fixtures.iter() {
+        let lb_pair_state: LbPair =
+            get_zero_copy_account(&mut context.banks_client, fixture.lb_pair).await;
+        lb_pair_states.push(lb_pair_state);
+        for mint in [fixture.token_x_mint, fixture.token_y_mint] {
+            let mint_account = context
+                .banks_client
+                .get_account(mint)
+                .await
+                .unwrap()
+                .unwrap();
+            mint_accounts.insert(mint, mint_account);
+        }
+        let oracle_account = context
+            .banks_client
+            .get_account(fixture.oracle)
+            .await
+            .unwrap()
+            .unwrap();
+        oracle_accounts.push(oracle_account);
+    }
+
+    let mut hops = vec![];
+    for (index, fixture) in fixtures.iter().enumerate() {
+        let mut bin_arrays = HashMap::new();
+        for bin_array in [fixture.bin_array_lower, fixture.bin_array_upper] {
+            let bin_array_state: BinArray =
+                get_zero_copy_account(&mut context.banks_client, bin_array).await;
+            bin_arrays.insert(bin_array, bin_array_state);
+        }
+        hops.push(SwapQuoteAccounts {
+            lb_pair_pubkey: fixture.lb_pair,
+            lb_pair: &lb_pair_states[index],
+            bin_arrays,
+            bitmap_extension: None,
+            mint_x_account: &mint_accounts[&fixture.token_x_mint],
+            mint_y_account: &mint_accounts[&fixture.token_y_mint],
+            oracle_account: &oracle_accounts[index],
+        });
+    }
+
+    let amount_in = 1_000_000;
+    let clock = get_clock(&mut context.banks_client).await;
+    let quote = quote_route_exact_in(hops, mint_a, amount_in, &clock).unwrap();
+    assert!(quote.amount_out > 0);
+
+    let mut route = quote.route;
+    route[0].transfer_hook_accounts = transfer_hook_counter.account_metas();
+
+    // The final amount out is below the minimum
+    let swap_route_ix =
+        swap_route(payer.pubkey(), &route, amount_in, quote.amount_out + 1).unwrap();
+    process_and_assert_err(
+        &[swap_route_ix],
+        &payer,
+        &[],
+        &mut context.banks_client,
+        LBError::ExceededAmountSlippageTolerance,
+    )
+    .await;
+
+    // Accounts left after the last hop
+    let mut swap_route_ix =
+        swap_route(payer.pubkey(), &route, amount_in, quote.amount_out).unwrap();
+    swap_route_ix.accounts.push(AccountMeta::new_readonly(
+        fixtures[1].bin_array_lower,
+        false,
+    ));
+    process_and_assert_err(
+        &[swap_route_ix],
+        &payer,
+        &[],
+        &mut context.banks_client,
+        LBError::InvalidSwapRoute,
+    )
+    .await;
+
+    let (user_token_b, user_token_c) = (user_tokens[&mint_b], user_tokens[&mint_c]);
+    let user_token_b_before = get_token_2022_balance(&mut context.banks_client, user_token_b).await;
+    let user_token_c_before = get_token_2022_balance(&mut context.banks_client, user_token_c).await;
+
+    let swap_route_ix = swap_route(payer.pubkey(), &route, amount_in, quote.amount_out).unwrap();
+    process_and_assert_ok(&[swap_route_ix], &payer, &[], &mut context.banks_client).await;
+
+    // Token B is only passed through, the user receive the quoted amount of token C
+    let user_token_b_after = get_token_2022_balance(&mut context.banks_client, user_token_b).await;
+    let user_token_c_after = get_token_2022_balance(&mut context.banks_client, user_token_c).await;
+    assert_eq!(user_token_b_after, user_token_b_before);
+    assert_eq!(user_token_c_after - user_token_c_before, quote.amount_out);
+    assert_eq!(transfer_hook_counter.get_count(&mut context).await, 2);
+}
@@ -0,0 +1,283 @@
+#![cfg(feature = "test-bpf")]
+mod helpers;
+use anchor_spl::token_2022::spl_token_2022::{self, extension::StateWithExtensions};
+use commons::instructions::build_instruction;
+use commons::instructions::swap_v2::swap_v2;
+use helpers::*;
+use lb_clmm::errors::LBError;
+use lb_clmm::instructions::deposit::{BinLiquidityDistribution, LiquidityParameter};
+use lb_clmm::instructions::swap::SwapV2Parameters;
+use lb_clmm::state::{bin::BinArray, lb_pair::LbPair};
+use native::*;
+use pair::*;
+use solana_program_test::*;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+use std::collections::HashMap;
+use utils::*;
+
+const DECIMALS: u8 = 6;
+const SLOT: u64 = 100;
+
+async fn get_token_2022_balance(banks_client: &mut BanksClient, token_account: Pubkey) -> u64 {
+    let account = banks_client
+        .get_account(token_account)
+        .await
+        .unwrap()
+        .unwrap();
+
+    StateWithExtensions::<spl_token_2022::state::Account>::unpack(&account.data)
+        .unwrap()
+        .base
+        .amount
+}
+
+/// Pair with 250_000_000 of token X in each of the bins 0 to 3, and 250_000_000 of token Y in each of the bins -3 to 0
+async fn setup_pair_with_liquidity(context: &mut ProgramTestContext) -> PairFixture {
+    let payer = context.payer.insecure_clone();
+
+    let mut mints = vec![];
+    for _ in 0..2 {
+        let mint = create_token_2022_mint_with_transfer_fee(
+            &payer,
+            0,
+            0,
+            DECIMALS,
+            &mut context.banks_client,
+        )
+        .await;
+        mints.push(mint);
+    }
+    mints.sort();
+
+    let fixture = setup_pair(
+        context,
+        mints[0],
+        spl_token_2022::id(),
+        mints[1],
+        spl_token_2022::id(),
+    )
+    .await;
+
+    for mint in mints {
+        let user_token =
+            create_token_2022_ata(&payer, &mint, &payer.pubkey(), &mut context.banks_client).await;
+        let mint_to_ix = spl_token_2022::instruction::mint_to(
+            &spl_token_2022::id(),
+            &mint,
+            &user_token,
+            &payer.pubkey(),
+            &[],
+            1_000_000_000_000,
+        )
+        .unwrap();
+        process_and_assert_ok(&[mint_to_ix], &payer, &[], &mut context.banks_client).await;
+    }
+
+    let position = add_position(context, &fixture, payer.pubkey(), -3, 3);
+    let amount = 1_000_000_000;
+    let deposit_ix = build_instruction(
+        fixture.modify_liquidity_accounts(position, payer.pubkey()),
+        lb_clmm::instruction::AddLiquidity {
+            liquidity_parameter: LiquidityParameter {
+                amount_x: amount,
+                amount_y: amount,
+                bin_liquidity_dist: (-3..=3)
+                    .map(|bin_id| BinLiquidityDistribution {
+                        bin_id,
+                        distribution_x: if bin_id >= 0 { 2500 } else { 0 },
+                        distribution_y: if bin_id <= 0 { 2500 } else { 0 },
+                    })
+                    .collect(),
+            },
+        },
+        vec![],
+    );
+    process_and_assert_ok(&[deposit_ix], &payer, &[], &mut context.banks_client).await;
+
+    fixture
+}
+
+async fn quote_exact_in(
+    context: &mut ProgramTestContext,
+    fixture: &PairFixture,
+    amount_in: u64,
+    swap_for_y: bool,
+) -> commons::quote::SwapExactInQuote {
+    let lb_pair_state: LbPair =
+        get_zero_copy_account(&mut context.banks_client, fixture.lb_pair).await;
+    let mut bin_arrays = HashMap::new();
+    for bin_array in [fixture.bin_array_lower, fixture.bin_array_upper] {
+        let bin_array_state: BinArray =
+            get_zero_copy_account(&mut context.banks_client, bin_array).await;
+        bin_arrays.insert(bin_array, bin_array_state);
+    }
+    let clock = get_clock(&mut context.banks_client).await;
+
+    let mut accounts = vec![];
+    for address in [fixture.token_x_mint, fixture.token_y_mint, fixture.oracle] {
+        let account = context
+            .banks_client
+            .get_account(address)
+            .await
+            .unwrap()
+            .unwrap();
+        accounts.push(account);
+    }
+
+    commons::quote::quote_exact_in(
+        commons::quote::SwapQuoteAccounts {
+            lb_pair_pubkey: fixture.lb_pair,
+            lb_pair: &lb_pair_state,
+            bin_arrays,
+            bitmap_extension: None,
+            mint_x_account: &accounts[0],
+            mint_y_account: &accounts[1],
+            oracle_account: &accounts[2],
+        },
+        amount_in,
+        swap_for_y,
+        &clock,
+        None,
+    )
+    .unwrap()
+}
+
+fn swap_v2_ix(
+    fixture: &PairFixture,
+    user: Pubkey,
+    parameters: SwapV2Parameters,
+) -> solana_sdk::instruction::Instruction {
+    let lb_pair_state = LbPair {
+        reserve_x: fixture.reserve_x,
+        reserve_y: fixture.reserve_y,
+        token_x_mint: fixture.token_x_mint,
+        token_y_mint: fixture.token_y_mint,
+        oracle: fixture.oracle,
+        ..LbPair::default()
+    };
+
+    swap_v2(
+        fixture.lb_pair,
+        &lb_pair_state,
+        None,
+        user,
+        fixture.token_x_program,
+        fixture.token_y_program,
+        true,
+        &[fixture.bin_array_upper, fixture.bin_array_lower],
+        parameters,
+    )
+}
+
+#[tokio::test]
+async fn test_swap_v2_protections() {
+    let mut test = ProgramTest::default();
+    add_lb_clmm_native(&mut test);
+
+    let mut context = test.start_with_context().await;
+    context.warp_to_slot(SLOT).unwrap();
+    let payer = context.payer.insecure_clone();
+
+    let fixture = setup_pair_with_liquidity(&mut context).await;
+
+    // Swap through the active bin into the bin -1
+    let amount_in = 400_000_000;
+    let quote = quote_exact_in(&mut context, &fixture, amount_in, true).await;
+    let start_bin_id = 0;
+    let end_bin_id = -1;
+    let price_impact_bps =
+        lb_clmm::instructions::swap::get_price_impact_bps(start_bin_id, end_bin_id, BIN_STEP)
+            .unwrap() as u16;
+    assert!(price_impact_bps > 0);
+
+    let parameters = SwapV2Parameters {
+        amount_in,
+        min_amount_out: quote.amount_out,
+        deadline: Some(SLOT),
+        max_price_impact_bps: Some(price_impact_bps),
+        max_bin_movement: Some(1),
+    };
+
+    for (parameters, error) in [
+        (
+            SwapV2Parameters {
+                deadline: Some(SLOT - 1),
+                ..parameters
+            },
+            LBError::SwapDeadlineExceeded,
+        ),
+        (
+            SwapV2Parameters {
+                min_amount_out: quote.amount_out + 1,
+                ..parameters
+            },
+            LBError::ExceededAmountSlippageTolerance,
+        ),
+        (
+            SwapV2Parameters {
+                max_price_impact_bps: Some(price_impact_bps - 1),
+                ..parameters
+            },
+            LBError::ExceededPriceImpactTolerance,
+        ),
+        (
+            SwapV2Parameters {
+                max_bin_movement: Some(0),
+                ..parameters
+            },
+            LBError::ExceededBinSlippageTolerance,
+        ),
+    ] {
+        process_and_assert_err(
+            &[swap_v2_ix(&fixture, payer.pubkey(), parameters)],
+            &payer,
+            &[],
+            &mut context.banks_client,
+            error,
+        )
+        .await;
+    }
+
+    // Every protection at its limit
+    let user_token_x = fixture.user_token_x(payer.pubkey());
+    let user_token_y = fixture.user_token_y(payer.pubkey());
+    let amount_x_before = get_token_2022_balance(&mut context.banks_client, user_token_x).await;
+    let amount_y_before = get_token_2022_balance(&mut context.banks_client, user_token_y).await;
+
+    process_and_assert_ok(
+        &[swap_v2_ix(&fixture, payer.pubkey(), parameters)],
+        &payer,
+        &[],
+        &mut context.banks_client,
+    )
+    .await;
+
+    assert_eq!(
+        amount_x_before - get_token_2022_balance(&mut context.banks_client, user_token_x).await,
+        amount_in
+    );
+    assert_eq!(
+        get_token_2022_balance(&mut context.banks_client, user_token_y).await - amount_y_before,
+        quote.amount_out
+    );
+
+    let lb_pair_state: LbPair =
+        get_zero_copy_account(&mut context.banks_client, fixture.lb_pair).await;
+    assert_eq!(lb_pair_state.active_id, end_bin_id);
+
+    // The swap sampled the active bin before the swap into the oracle
+    let oracle_account = context
+        .banks_client
+        .get_account(fixture.oracle)
+        .await
+        .unwrap()
+        .unwrap();
+    let (oracle, observations) = commons::oracle::decode_oracle(&oracle_account).unwrap();
+    let clock = get_clock(&mut context.banks_client).await;
+    assert_eq!(oracle.active_size, 1);
+    assert_eq!(
+        observations[oracle.idx as usize].last_updated_at,
+        clock.unix_timestamp
+    );
+}
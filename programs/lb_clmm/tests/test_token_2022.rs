@@ -0,0 +1,359 @@
+#![cfg(feature = "test-bpf")]
+mod helpers;
+use anchor_spl::token_2022::spl_token_2022::{self, extension::StateWithExtensions};
+use commons::instructions::build_instruction;
+use helpers::*;
+use lb_clmm::instructions::deposit::{BinLiquidityDistribution, LiquidityParameter};
+use lb_clmm::state::{bin::BinArray, lb_pair::LbPair, position::PositionV2};
+use lb_clmm::utils::token_2022::{
+    calculate_transfer_fee_excluded_amount, calculate_transfer_fee_included_amount,
+};
+use native::*;
+use pair::*;
+use solana_program_test::*;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::Transaction;
+use std::collections::HashMap;
+use utils::*;
+
+const TRANSFER_FEE_BPS: u16 = 150;
+const MAXIMUM_FEE: u64 = 1_000_000;
+const DECIMALS: u8 = 6;
+
+async fn get_token_2022_balance(banks_client: &mut BanksClient, token_account: Pubkey) -> u64 {
+    let account = banks_client
+        .get_account(token_account)
+        .await
+        .unwrap()
+        .unwrap();
+
+    StateWithExtensions::<spl_token_2022::state::Account>::unpack(&account.data)
+        .unwrap()
+        .base
+        .amount
+}
+
+#[tokio::test]
+async fn test_transfer_fee_calculation_match_token_2022() {
+    let mut test = ProgramTest::default();
+    test.add_program("../../artifacts/token_2022", spl_token_2022::id(), None);
+
+    let (mut banks_client, payer, _recent_blockhash) = test.start().await;
+
+    let mint = create_token_2022_mint_with_transfer_fee(
+        &payer,
+        TRANSFER_FEE_BPS,
+        MAXIMUM_FEE,
+        DECIMALS,
+        &mut banks_client,
+    )
+    .await;
+
+    let receiver = Keypair::new();
+    let source = create_token_2022_ata(&payer, &mint, &payer.pubkey(), &mut banks_client).await;
+    let destination =
+        create_token_2022_ata(&payer, &mint, &receiver.pubkey(), &mut banks_client).await;
+
+    let mint_to_ix = spl_token_2022::instruction::mint_to(
+        &spl_token_2022::id(),
+        &mint,
+        &source,
+        &payer.pubkey(),
+        &[],
+        u64::MAX / 2,
+    )
+    .unwrap();
+    process_and_assert_ok(&[mint_to_ix], &payer, &[&payer], &mut banks_client).await;
+
+    let mint_account = banks_client.get_account(mint).await.unwrap().unwrap();
+    let epoch = get_clock(&mut banks_client).await.epoch;
+
+    // Includes dust, rounding and the amount where the maximum fee kicks in
+    for amount in [1, 67, 100_000, 123_456_789, 1_000_000_000_000] {
+        // Gross amount sent, the destination receive the excluded amount
+        let excluded_amount =
+            calculate_transfer_fee_excluded_amount(&mint_account.data, amount, epoch).unwrap();
+
+        let before = get_token_2022_balance(&mut banks_client, destination).await;
+        let transfer_ix = spl_token_2022::instruction::transfer_checked(
+            &spl_token_2022::id(),
+            &source,
+            &mint,
+            &destination,
+            &payer.pubkey(),
+            &[],
+            amount,
+            DECIMALS,
+        )
+        .unwrap();
+        process_and_assert_ok(&[transfer_ix], &payer, &[&payer], &mut banks_client).await;
+        let after = get_token_2022_balance(&mut banks_client, destination).await;
+
+        assert_eq!(after - before, excluded_amount.amount);
+        assert_eq!(
+            amount - excluded_amount.amount,
+            excluded_amount.transfer_fee
+        );
+
+        // Net amount wanted, sending the included amount deliver exactly the net amount
+        let included_amount =
+            calculate_transfer_fee_included_amount(&mint_account.data, amount, epoch).unwrap();
+
+        let before = get_token_2022_balance(&mut banks_client, destination).await;
+        let transfer_ix = spl_token_2022::instruction::transfer_checked(
+            &spl_token_2022::id(),
+            &source,
+            &mint,
+            &destination,
+            &payer.pubkey(),
+            &[],
+            included_amount.amount,
+            DECIMALS,
+        )
+        .unwrap();
+        process_and_assert_ok(&[transfer_ix], &payer, &[&payer], &mut banks_client).await;
+        let after = get_token_2022_balance(&mut banks_client, destination).await;
+
+        assert_eq!(after - before, amount);
+        assert_eq!(
+            included_amount.amount - amount,
+            included_amount.transfer_fee
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_transfer_fee_calculation_spl_token() {
+    let (mut banks_client, _payer, _recent_blockhash) = ProgramTest::default().start().await;
+
+    let native_mint_account = banks_client
+        .get_account(anchor_spl::token::spl_token::native_mint::id())
+        .await
+        .unwrap()
+        .unwrap();
+
+    let excluded_amount =
+        calculate_transfer_fee_excluded_amount(&native_mint_account.data, 1_000, 0).unwrap();
+    assert_eq!(excluded_amount.amount, 1_000);
+    assert_eq!(excluded_amount.transfer_fee, 0);
+
+    let included_amount =
+        calculate_transfer_fee_included_amount(&native_mint_account.data, 1_000, 0).unwrap();
+    assert_eq!(included_amount.amount, 1_000);
+    assert_eq!(included_amount.transfer_fee, 0);
+}
+
+#[tokio::test]
+async fn test_transfer_hook_accounts_passed_to_deposit_swap_and_withdraw() {
+    // The token 2022 artifact predates the transfer hook extension, the token 2022 bundled with program test support it
+    let mut test = ProgramTest::default();
+    add_lb_clmm_native(&mut test);
+    test.add_program(
+        "transfer_hook",
+        TRANSFER_HOOK_PROGRAM_ID,
+        processor!(process_transfer_hook),
+    );
+
+    let mut context = test.start_with_context().await;
+    let payer = context.payer.insecure_clone();
+
+    let token_x_mint = create_token_2022_mint_with_transfer_hook(
+        &payer,
+        TRANSFER_HOOK_PROGRAM_ID,
+        DECIMALS,
+        &mut context.banks_client,
+    )
+    .await;
+    let token_y_mint = create_token_2022_mint_with_transfer_fee(
+        &payer,
+        TRANSFER_FEE_BPS,
+        MAXIMUM_FEE,
+        DECIMALS,
+        &mut context.banks_client,
+    )
+    .await;
+    let transfer_hook_counter = add_transfer_hook_counter(&mut context, token_x_mint);
+
+    let fixture = setup_pair(
+        &mut context,
+        token_x_mint,
+        spl_token_2022::id(),
+        token_y_mint,
+        spl_token_2022::id(),
+    )
+    .await;
+
+    for mint in [token_x_mint, token_y_mint] {
+        let user_token =
+            create_token_2022_ata(&payer, &mint, &payer.pubkey(), &mut context.banks_client).await;
+        let mint_to_ix = spl_token_2022::instruction::mint_to(
+            &spl_token_2022::id(),
+            &mint,
+            &user_token,
+            &payer.pubkey(),
+            &[],
+            1_000_000_000_000,
+        )
+        .unwrap();
+        process_and_assert_ok(&[mint_to_ix], &payer, &[], &mut context.banks_client).await;
+    }
+
+    let position = add_position(&mut context, &fixture, payer.pubkey(), -3, 3);
+
+    // Token X deposited into the active bin and the 3 bins above, token Y into the active bin and the 3 bins below
+    let amount = 1_000_000_000;
+    let liquidity_parameter = LiquidityParameter {
+        amount_x: amount,
+        amount_y: amount,
+        bin_liquidity_dist: (-3..=3)
+            .map(|bin_id| BinLiquidityDistribution {
+                bin_id,
+                distribution_x: if bin_id >= 0 { 2500 } else { 0 },
+                distribution_y: if bin_id <= 0 { 2500 } else { 0 },
+            })
+            .collect(),
+    };
+
+    // The transfer of token X fails without the extra accounts of the transfer hook
+    let deposit_ix = build_instruction(
+        fixture.modify_liquidity_accounts(position, payer.pubkey()),
+        lb_clmm::instruction::AddLiquidity {
+            liquidity_parameter: liquidity_parameter.clone(),
+        },
+        vec![],
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[deposit_ix],
+        Some(&payer.pubkey()),
+        &[&payer],
+        context.banks_client.get_latest_blockhash().await.unwrap(),
+    );
+    assert!(context.banks_client.process_transaction(tx).await.is_err());
+
+    let deposit_ix = build_instruction(
+        fixture.modify_liquidity_accounts(position, payer.pubkey()),
+        lb_clmm::instruction::AddLiquidity {
+            liquidity_parameter,
+        },
+        transfer_hook_counter.account_metas(),
+    );
+    process_and_assert_ok(&[deposit_ix], &payer, &[], &mut context.banks_client).await;
+
+    assert_eq!(transfer_hook_counter.get_count(&mut context).await, 1);
+    // Token Y is transferred with the transfer fee on top, so the reserve receive the deposited amount
+    assert_eq!(
+        get_token_2022_balance(&mut context.banks_client, fixture.reserve_x).await,
+        amount
+    );
+    assert_eq!(
+        get_token_2022_balance(&mut context.banks_client, fixture.reserve_y).await,
+        amount
+    );
+
+    // Swap X for Y, token X transferred in with the hook, matching the quote
+    let amount_in = 1_000_000;
+    let lb_pair_state: LbPair =
+        get_zero_copy_account(&mut context.banks_client, fixture.lb_pair).await;
+    let mut bin_arrays = HashMap::new();
+    for bin_array in [fixture.bin_array_lower, fixture.bin_array_upper] {
+        let bin_array_state: BinArray =
+            get_zero_copy_account(&mut context.banks_client, bin_array).await;
+        bin_arrays.insert(bin_array, bin_array_state);
+    }
+    let clock = get_clock(&mut context.banks_client).await;
+    let mint_x_account = context
+        .banks_client
+        .get_account(token_x_mint)
+        .await
+        .unwrap()
+        .unwrap();
+    let mint_y_account = context
+        .banks_client
+        .get_account(token_y_mint)
+        .await
+        .unwrap()
+        .unwrap();
+    let oracle_account = context
+        .banks_client
+        .get_account(fixture.oracle)
+        .await
+        .unwrap()
+        .unwrap();
+
+    let quote = commons::quote::quote_exact_in(
+        commons::quote::SwapQuoteAccounts {
+            lb_pair_pubkey: fixture.lb_pair,
+            lb_pair: &lb_pair_state,
+            bin_arrays,
+            bitmap_extension: None,
+            mint_x_account: &mint_x_account,
+            mint_y_account: &mint_y_account,
+            oracle_account: &oracle_account,
+        },
+        amount_in,
+        true,
+        &clock,
+        None,
+    )
+    .unwrap();
+
+    let user_token_x = fixture.user_token_x(payer.pubkey());
+    let user_token_y = fixture.user_token_y(payer.pubkey());
+    let user_token_y_before = get_token_2022_balance(&mut context.banks_client, user_token_y).await;
+
+    let swap_ix = build_instruction(
+        fixture.swap_accounts(payer.pubkey(), true, None),
+        lb_clmm::instruction::Swap {
+            amount_in,
+            min_amount_out: quote.amount_out,
+        },
+        [
+            fixture.swap_bin_arrays(true),
+            transfer_hook_counter.account_metas(),
+        ]
+        .concat(),
+    );
+    process_and_assert_ok(&[swap_ix], &payer, &[], &mut context.banks_client).await;
+
+    let user_token_y_after = get_token_2022_balance(&mut context.banks_client, user_token_y).await;
+    assert_eq!(user_token_y_after - user_token_y_before, quote.amount_out);
+    assert_eq!(transfer_hook_counter.get_count(&mut context).await, 2);
+
+    // Swap Y for X, token X transferred out of the reserve with the hook
+    let user_token_x_before = get_token_2022_balance(&mut context.banks_client, user_token_x).await;
+
+    let swap_ix = build_instruction(
+        fixture.swap_accounts(payer.pubkey(), false, None),
+        lb_clmm::instruction::Swap {
+            amount_in,
+            min_amount_out: 1,
+        },
+        [
+            fixture.swap_bin_arrays(false),
+            transfer_hook_counter.account_metas(),
+        ]
+        .concat(),
+    );
+    process_and_assert_ok(&[swap_ix], &payer, &[], &mut context.banks_client).await;
+
+    let user_token_x_after = get_token_2022_balance(&mut context.banks_client, user_token_x).await;
+    assert!(user_token_x_after > user_token_x_before);
+    assert_eq!(transfer_hook_counter.get_count(&mut context).await, 3);
+
+    // Withdraw everything, token X transferred out of the reserve with the hook
+    let withdraw_ix = build_instruction(
+        fixture.modify_liquidity_accounts(position, payer.pubkey()),
+        lb_clmm::instruction::RemoveAllLiquidity {},
+        transfer_hook_counter.account_metas(),
+    );
+    process_and_assert_ok(&[withdraw_ix], &payer, &[], &mut context.banks_client).await;
+
+    assert_eq!(transfer_hook_counter.get_count(&mut context).await, 4);
+    let position_state: PositionV2 =
+        get_zero_copy_account(&mut context.banks_client, position).await;
+    assert!(position_state
+        .liquidity_shares
+        .iter()
+        .all(|&share| share == 0));
+}
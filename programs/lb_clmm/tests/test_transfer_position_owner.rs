@@ -0,0 +1,92 @@
+#![cfg(feature = "test-bpf")]
+mod helpers;
+use bytemuck::Zeroable;
+use commons::instructions::transfer_position_owner::transfer_position_owner;
+use helpers::*;
+use lb_clmm::errors::LBError;
+use lb_clmm::state::{lb_pair::LbPair, position::PositionV2};
+use native::*;
+use solana_program_test::*;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use utils::*;
+
+struct TransferPositionOwnerFixture {
+    position: Pubkey,
+    lb_pair: Pubkey,
+    owner: Keypair,
+    operator: Keypair,
+    fee_owner: Pubkey,
+}
+
+fn setup() -> (ProgramTest, TransferPositionOwnerFixture) {
+    let mut test = ProgramTest::default();
+    add_lb_clmm_native(&mut test);
+
+    let fixture = TransferPositionOwnerFixture {
+        position: Pubkey::new_unique(),
+        lb_pair: Pubkey::new_unique(),
+        owner: Keypair::new(),
+        operator: Keypair::new(),
+        fee_owner: Pubkey::new_unique(),
+    };
+
+    add_zero_copy_account(&mut test, fixture.lb_pair, &LbPair::zeroed());
+
+    let mut position = PositionV2::zeroed();
+    position.lb_pair = fixture.lb_pair;
+    position.owner = fixture.owner.pubkey();
+    position.operator = fixture.operator.pubkey();
+    position.fee_owner = fixture.fee_owner;
+    add_zero_copy_account(&mut test, fixture.position, &position);
+
+    (test, fixture)
+}
+
+#[tokio::test]
+async fn test_transfer_position_owner_revoke_operator_and_keep_fee_owner() {
+    let (test, fixture) = setup();
+    let (mut banks_client, payer, _recent_blockhash) = test.start().await;
+
+    let new_owner = Pubkey::new_unique();
+    let ix = transfer_position_owner(
+        fixture.position,
+        fixture.lb_pair,
+        fixture.owner.pubkey(),
+        new_owner,
+    );
+    process_and_assert_ok(&[ix], &payer, &[&fixture.owner], &mut banks_client).await;
+
+    let position: PositionV2 = get_zero_copy_account(&mut banks_client, fixture.position).await;
+    assert_eq!(position.owner, new_owner);
+    assert_eq!(position.operator, Pubkey::default());
+    // The fee of the position stays committed to the fee owner
+    assert_eq!(position.fee_owner, fixture.fee_owner);
+}
+
+#[tokio::test]
+async fn test_transfer_position_owner_by_operator_rejected() {
+    let (test, fixture) = setup();
+    let (mut banks_client, payer, _recent_blockhash) = test.start().await;
+
+    // The operator must not be able to take over the position
+    let ix = transfer_position_owner(
+        fixture.position,
+        fixture.lb_pair,
+        fixture.operator.pubkey(),
+        fixture.operator.pubkey(),
+    );
+    process_and_assert_err(
+        &[ix],
+        &payer,
+        &[&fixture.operator],
+        &mut banks_client,
+        LBError::UnauthorizedAccess,
+    )
+    .await;
+
+    let position: PositionV2 = get_zero_copy_account(&mut banks_client, fixture.position).await;
+    assert_eq!(position.owner, fixture.owner.pubkey());
+    assert_eq!(position.operator, fixture.operator.pubkey());
+    assert_eq!(position.fee_owner, fixture.fee_owner);
+}
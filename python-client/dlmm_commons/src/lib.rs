@@ -0,0 +1,3 @@
+//! Builds the `dlmm_commons` Python extension module, defined by the `python` feature of `commons`, as a cdylib.
+
+pub use commons::python::*;
@@ -121,6 +121,13 @@ export function deriveOracle(lbPair: PublicKey, programId: PublicKey) {
   );
 }
 
+export function deriveAdminConfig(programId: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("admin_config")],
+    programId
+  );
+}
+
 export function derivePosition(
   lbPair: PublicKey,
   base: PublicKey,
@@ -51,6 +51,28 @@ export type LbClmm = {
       "type": "u16",
       "value": "2_000"
     },
+    {
+      "name": "MAX_REFERRER_FEE_SHARE",
+      "type": "u16",
+      "value": "5_000"
+    },
+    {
+      "name": "VESTING_RATIO_PRECISION",
+      "type": "u64",
+      "value": "1_000_000_000_000"
+    },
+    {
+      "name": "FLASH_LOAN_FEE_BPS",
+      "type": "u16",
+      "value": "5"
+    },
+    {
+      "name": "MAX_SWAP_ROUTE_HOPS",
+      "type": {
+        "defined": "usize"
+      },
+      "value": "3"
+    },
     {
       "name": "NUM_REWARDS",
       "type": {
@@ -107,6 +129,11 @@ export type LbClmm = {
       "type": "bytes",
       "value": "[98, 105, 110, 95, 97, 114, 114, 97, 121]"
     },
+    {
+      "name": "BIN_ARRAY_FUNDER",
+      "type": "bytes",
+      "value": "[98, 105, 110, 95, 97, 114, 114, 97, 121, 95, 102, 117, 110, 100, 101, 114]"
+    },
     {
       "name": "ORACLE",
       "type": "bytes",
@@ -126,6 +153,21 @@ export type LbClmm = {
       "name": "POSITION",
       "type": "bytes",
       "value": "[112, 111, 115, 105, 116, 105, 111, 110]"
+    },
+    {
+      "name": "REFERRER",
+      "type": "bytes",
+      "value": "[114, 101, 102, 101, 114, 114, 101, 114]"
+    },
+    {
+      "name": "ADMIN_CONFIG",
+      "type": "bytes",
+      "value": "[97, 100, 109, 105, 110, 95, 99, 111, 110, 102, 105, 103]"
+    },
+    {
+      "name": "REFERRAL_FEE",
+      "type": "bytes",
+      "value": "[114, 101, 102, 101, 114, 114, 97, 108, 95, 102, 101, 101]"
     }
   ],
   "instructions": [
@@ -216,13 +258,8 @@ export type LbClmm = {
       ]
     },
     {
-      "name": "initializePermissionLbPair",
+      "name": "initializeCustomizablePermissionlessLbPair",
       "accounts": [
-        {
-          "name": "base",
-          "isMut": false,
-          "isSigner": true
-        },
         {
           "name": "lbPair",
           "isMut": true,
@@ -260,7 +297,12 @@ export type LbClmm = {
           "isSigner": false
         },
         {
-          "name": "admin",
+          "name": "userTokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "funder",
           "isMut": true,
           "isSigner": true
         },
@@ -292,16 +334,21 @@ export type LbClmm = {
       ],
       "args": [
         {
-          "name": "ixData",
+          "name": "params",
           "type": {
-            "defined": "InitPermissionPairIx"
+            "defined": "CustomizableParams"
           }
         }
       ]
     },
     {
-      "name": "initializeCustomizablePermissionlessLbPair",
+      "name": "initializePermissionLbPair",
       "accounts": [
+        {
+          "name": "base",
+          "isMut": false,
+          "isSigner": true
+        },
         {
           "name": "lbPair",
           "isMut": true,
@@ -339,12 +386,7 @@ export type LbClmm = {
           "isSigner": false
         },
         {
-          "name": "userTokenX",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "funder",
+          "name": "admin",
           "isMut": true,
           "isSigner": true
         },
@@ -363,6 +405,11 @@ export type LbClmm = {
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "adminConfig",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "eventAuthority",
           "isMut": false,
@@ -376,9 +423,9 @@ export type LbClmm = {
       ],
       "args": [
         {
-          "name": "params",
+          "name": "ixData",
           "type": {
-            "defined": "CustomizableParams"
+            "defined": "InitPermissionPairIx"
           }
         }
       ]
@@ -448,6 +495,45 @@ export type LbClmm = {
         }
       ]
     },
+    {
+      "name": "initializeBinArrayWithFunder",
+      "accounts": [
+        {
+          "name": "lbPair",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "binArray",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "binArrayFunder",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Record the funder, refunded when the bin array is closed"
+          ]
+        },
+        {
+          "name": "funder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "i64"
+        }
+      ]
+    },
     {
       "name": "addLiquidity",
       "accounts": [
@@ -522,6 +608,15 @@ export type LbClmm = {
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "positionNftAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Token account holding the position NFT. Only required for tokenized position."
+          ]
+        },
         {
           "name": "eventAuthority",
           "isMut": false,
@@ -616,6 +711,15 @@ export type LbClmm = {
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "positionNftAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Token account holding the position NFT. Only required for tokenized position."
+          ]
+        },
         {
           "name": "eventAuthority",
           "isMut": false,
@@ -710,6 +814,15 @@ export type LbClmm = {
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "positionNftAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Token account holding the position NFT. Only required for tokenized position."
+          ]
+        },
         {
           "name": "eventAuthority",
           "isMut": false,
@@ -784,6 +897,15 @@ export type LbClmm = {
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "positionNftAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Token account holding the position NFT. Only required for tokenized position."
+          ]
+        },
         {
           "name": "eventAuthority",
           "isMut": false,
@@ -858,6 +980,15 @@ export type LbClmm = {
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "positionNftAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Token account holding the position NFT. Only required for tokenized position."
+          ]
+        },
         {
           "name": "eventAuthority",
           "isMut": false,
@@ -952,6 +1083,15 @@ export type LbClmm = {
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "positionNftAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Token account holding the position NFT. Only required for tokenized position."
+          ]
+        },
         {
           "name": "eventAuthority",
           "isMut": false,
@@ -1173,6 +1313,95 @@ export type LbClmm = {
         }
       ]
     },
+    {
+      "name": "initializeTokenizedPosition",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "positionNftMint",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lbPair",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ownerPositionNftAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionNftMetadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "lowerBinId",
+          "type": "i32"
+        },
+        {
+          "name": "width",
+          "type": "i32"
+        },
+        {
+          "name": "uri",
+          "type": "string"
+        }
+      ]
+    },
     {
       "name": "updatePositionOperator",
       "accounts": [
@@ -1204,6 +1433,45 @@ export type LbClmm = {
         }
       ]
     },
+    {
+      "name": "transferPositionOwner",
+      "accounts": [
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lbPair",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sender",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Only the owner can hand over the position. The operator is not allowed to."
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "newOwner",
+          "type": "publicKey"
+        }
+      ]
+    },
     {
       "name": "swap",
       "accounts": [
@@ -1297,19 +1565,13 @@ export type LbClmm = {
       ]
     },
     {
-      "name": "swapExactOut",
+      "name": "withdrawProtocolFee",
       "accounts": [
         {
           "name": "lbPair",
           "isMut": true,
           "isSigner": false
         },
-        {
-          "name": "binArrayBitmapExtension",
-          "isMut": false,
-          "isSigner": false,
-          "isOptional": true
-        },
         {
           "name": "reserveX",
           "isMut": true,
@@ -1320,16 +1582,6 @@ export type LbClmm = {
           "isMut": true,
           "isSigner": false
         },
-        {
-          "name": "userTokenIn",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "userTokenOut",
-          "isMut": true,
-          "isSigner": false
-        },
         {
           "name": "tokenXMint",
           "isMut": false,
@@ -1341,110 +1593,17 @@ export type LbClmm = {
           "isSigner": false
         },
         {
-          "name": "oracle",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "hostFeeIn",
-          "isMut": true,
-          "isSigner": false,
-          "isOptional": true
-        },
-        {
-          "name": "user",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "tokenXProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenYProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "eventAuthority",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "program",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "maxInAmount",
-          "type": "u64"
-        },
-        {
-          "name": "outAmount",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "swapWithPriceImpact",
-      "accounts": [
-        {
-          "name": "lbPair",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "binArrayBitmapExtension",
-          "isMut": false,
-          "isSigner": false,
-          "isOptional": true
-        },
-        {
-          "name": "reserveX",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "reserveY",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "userTokenIn",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "userTokenOut",
+          "name": "receiverTokenX",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenXMint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenYMint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "oracle",
+          "name": "receiverTokenY",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "hostFeeIn",
-          "isMut": true,
-          "isSigner": false,
-          "isOptional": true
-        },
-        {
-          "name": "user",
+          "name": "feeOwner",
           "isMut": false,
           "isSigner": true
         },
@@ -1453,82 +1612,6 @@ export type LbClmm = {
           "isMut": false,
           "isSigner": false
         },
-        {
-          "name": "tokenYProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "eventAuthority",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "program",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "amountIn",
-          "type": "u64"
-        },
-        {
-          "name": "activeId",
-          "type": {
-            "option": "i32"
-          }
-        },
-        {
-          "name": "maxPriceImpactBps",
-          "type": "u16"
-        }
-      ]
-    },
-    {
-      "name": "withdrawProtocolFee",
-      "accounts": [
-        {
-          "name": "lbPair",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "reserveX",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "reserveY",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "tokenXMint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenYMint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "receiverTokenX",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "receiverTokenY",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "tokenXProgram",
-          "isMut": false,
-          "isSigner": false
-        },
         {
           "name": "tokenYProgram",
           "isMut": false,
@@ -1584,6 +1667,11 @@ export type LbClmm = {
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "adminConfig",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "eventAuthority",
           "isMut": false,
@@ -1687,6 +1775,11 @@ export type LbClmm = {
           "isMut": false,
           "isSigner": true
         },
+        {
+          "name": "adminConfig",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "eventAuthority",
           "isMut": false,
@@ -1727,6 +1820,11 @@ export type LbClmm = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "adminConfig",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "eventAuthority",
           "isMut": false,
@@ -1797,6 +1895,15 @@ export type LbClmm = {
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "positionNftAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Token account holding the position NFT. Only required for tokenized position."
+          ]
+        },
         {
           "name": "eventAuthority",
           "isMut": false,
@@ -1878,6 +1985,21 @@ export type LbClmm = {
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "positionNftAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Token account holding the position NFT. Only required for tokenized position."
+          ]
+        },
+        {
+          "name": "adminConfig",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
         {
           "name": "eventAuthority",
           "isMut": false,
@@ -1924,6 +2046,15 @@ export type LbClmm = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "positionNftAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Token account holding the position NFT. Only required for tokenized position."
+          ]
+        },
         {
           "name": "eventAuthority",
           "isMut": false,
@@ -1950,6 +2081,11 @@ export type LbClmm = {
           "isMut": false,
           "isSigner": true
         },
+        {
+          "name": "adminConfig",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "eventAuthority",
           "isMut": false,
@@ -2028,6 +2164,11 @@ export type LbClmm = {
           "name": "rent",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "adminConfig",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
@@ -2056,6 +2197,11 @@ export type LbClmm = {
           "name": "rentReceiver",
           "isMut": true,
           "isSigner": false
+        },
+        {
+          "name": "adminConfig",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": []
@@ -2134,6 +2280,15 @@ export type LbClmm = {
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "positionNftAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Token account holding the position NFT. Only required for tokenized position."
+          ]
+        },
         {
           "name": "eventAuthority",
           "isMut": false,
@@ -2159,6 +2314,11 @@ export type LbClmm = {
           "name": "admin",
           "isMut": false,
           "isSigner": true
+        },
+        {
+          "name": "adminConfig",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": []
@@ -2257,6 +2417,15 @@ export type LbClmm = {
           "name": "owner",
           "isMut": false,
           "isSigner": true
+        },
+        {
+          "name": "positionNftAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Token account holding the position NFT. Only required for tokenized position."
+          ]
         }
       ],
       "args": []
@@ -2493,7 +2662,16 @@ export type LbClmm = {
           "isSigner": false
         },
         {
-          "name": "eventAuthority",
+          "name": "positionNftAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Token account holding the position NFT. Only required for tokenized position."
+          ]
+        },
+        {
+          "name": "eventAuthority",
           "isMut": false,
           "isSigner": false
         },
@@ -2573,7 +2751,7 @@ export type LbClmm = {
       "args": [
         {
           "name": "preActivationDuration",
-          "type": "u64"
+          "type": "u16"
         }
       ]
     },
@@ -2597,169 +2775,1398 @@ export type LbClmm = {
           "type": "publicKey"
         }
       ]
-    }
-  ],
-  "accounts": [
-    {
-      "name": "binArrayBitmapExtension",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "lbPair",
-            "type": "publicKey"
-          },
-          {
-            "name": "positiveBinArrayBitmap",
-            "docs": [
-              "Packed initialized bin array state for start_bin_index is positive"
-            ],
-            "type": {
-              "array": [
-                {
-                  "array": [
-                    "u64",
-                    8
-                  ]
-                },
-                12
-              ]
-            }
-          },
-          {
-            "name": "negativeBinArrayBitmap",
-            "docs": [
-              "Packed initialized bin array state for start_bin_index is negative"
-            ],
-            "type": {
-              "array": [
-                {
-                  "array": [
-                    "u64",
-                    8
-                  ]
-                },
-                12
-              ]
-            }
-          }
-        ]
-      }
     },
     {
-      "name": "binArray",
-      "docs": [
-        "An account to contain a range of bin. For example: Bin 100 <-> 200.",
-        "For example:",
-        "BinArray index: 0 contains bin 0 <-> 599",
-        "index: 2 contains bin 600 <-> 1199, ..."
+      "name": "swapExactOut",
+      "accounts": [
+        {
+          "name": "lbPair",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "binArrayBitmapExtension",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenIn",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenOut",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "oracle",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "hostFeeIn",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "user",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
       ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "index",
-            "type": "i64"
-          },
-          {
-            "name": "version",
-            "docs": [
-              "Version of binArray"
-            ],
-            "type": "u8"
-          },
-          {
-            "name": "padding",
-            "type": {
-              "array": [
-                "u8",
-                7
-              ]
-            }
-          },
-          {
-            "name": "lbPair",
-            "type": "publicKey"
-          },
-          {
-            "name": "bins",
-            "type": {
-              "array": [
-                {
-                  "defined": "Bin"
-                },
-                70
-              ]
-            }
-          }
-        ]
-      }
+      "args": [
+        {
+          "name": "maxInAmount",
+          "type": "u64"
+        },
+        {
+          "name": "outAmount",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "name": "lbPair",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "parameters",
-            "type": {
-              "defined": "StaticParameters"
-            }
-          },
-          {
-            "name": "vParameters",
-            "type": {
-              "defined": "VariableParameters"
-            }
-          },
-          {
-            "name": "bumpSeed",
-            "type": {
-              "array": [
-                "u8",
-                1
-              ]
-            }
-          },
-          {
-            "name": "binStepSeed",
-            "docs": [
-              "Bin step signer seed"
-            ],
-            "type": {
-              "array": [
-                "u8",
-                2
-              ]
-            }
-          },
-          {
-            "name": "pairType",
-            "docs": [
-              "Type of the pair"
-            ],
-            "type": "u8"
-          },
-          {
-            "name": "activeId",
-            "docs": [
-              "Active bin id"
-            ],
-            "type": "i32"
-          },
-          {
-            "name": "binStep",
-            "docs": [
-              "Bin step. Represent the price increment / decrement."
-            ],
-            "type": "u16"
-          },
-          {
-            "name": "status",
-            "docs": [
-              "Status of the pair. Check PairStatus enum."
-            ],
-            "type": "u8"
+      "name": "swapWithPriceImpact",
+      "accounts": [
+        {
+          "name": "lbPair",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "binArrayBitmapExtension",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenIn",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenOut",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "oracle",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "hostFeeIn",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "user",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amountIn",
+          "type": "u64"
+        },
+        {
+          "name": "activeId",
+          "type": {
+            "option": "i32"
+          }
+        },
+        {
+          "name": "maxPriceImpactBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "flashBorrow",
+      "accounts": [
+        {
+          "name": "lbPair",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "user",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructionsSysvar",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amountX",
+          "type": "u64"
+        },
+        {
+          "name": "amountY",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "flashRepay",
+      "accounts": [
+        {
+          "name": "lbPair",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "binArray",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Bin array of the active bin, which receive the LP share of the fee"
+          ]
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "user",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructionsSysvar",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "borrowInstructionIndex",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "updateCircuitBreaker",
+      "accounts": [
+        {
+          "name": "lbPair",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "adminConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "parameter",
+          "type": {
+            "defined": "CircuitBreakerParameter"
+          }
+        }
+      ]
+    },
+    {
+      "name": "initializeReferrer",
+      "accounts": [
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "adminConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "feeShare",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "updateReferrerFeeShare",
+      "accounts": [
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "adminConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "feeShare",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "initializeReferralFee",
+      "accounts": [
+        {
+          "name": "referralFee",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referrer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lbPair",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimReferralFee",
+      "accounts": [
+        {
+          "name": "lbPair",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "referralFee",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referrer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "receiverTokenX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "receiverTokenY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "swapV2",
+      "accounts": [
+        {
+          "name": "lbPair",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "binArrayBitmapExtension",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenIn",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenOut",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "oracle",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "hostFeeIn",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "user",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "parameters",
+          "type": {
+            "defined": "SwapV2Parameters"
+          }
+        }
+      ]
+    },
+    {
+      "name": "claimFeeMultiple",
+      "accounts": [
+        {
+          "name": "lbPair",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sender",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "adminConfig",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "positionCount",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "claimAllRewards",
+      "accounts": [
+        {
+          "name": "lbPair",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sender",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "rewardVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "rewardIndex",
+          "type": "u64"
+        },
+        {
+          "name": "positionCount",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "compoundFees",
+      "accounts": [
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lbPair",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "binArrayBitmapExtension",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "binArrayLower",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "binArrayUpper",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sender",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "positionNftAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Token account holding the position NFT. Only required for tokenized position."
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "parameter",
+          "type": {
+            "defined": "CompoundFeeParameter"
+          }
+        }
+      ]
+    },
+    {
+      "name": "closeBinArray",
+      "accounts": [
+        {
+          "name": "lbPair",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "binArrayBitmapExtension",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "binArray",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "binArrayFunder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "funder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeLegacyBinArray",
+      "accounts": [
+        {
+          "name": "lbPair",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "binArrayBitmapExtension",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "binArray",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "binArrayFunder",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Creator of the pair, or the fee admin"
+          ]
+        },
+        {
+          "name": "rentReceiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "adminConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setPositionVesting",
+      "accounts": [
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "operator",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "parameter",
+          "type": {
+            "defined": "VestingParameter"
+          }
+        }
+      ]
+    },
+    {
+      "name": "initializeAdminConfig",
+      "accounts": [
+        {
+          "name": "adminConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "parameter",
+          "type": {
+            "defined": "InitAdminConfigParameter"
+          }
+        }
+      ]
+    },
+    {
+      "name": "proposeAdminRole",
+      "accounts": [
+        {
+          "name": "adminConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "role",
+          "type": {
+            "defined": "AdminRole"
+          }
+        },
+        {
+          "name": "newAuthority",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "acceptAdminRole",
+      "accounts": [
+        {
+          "name": "adminConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Pending authority of the role. Can be a PDA, such as a multisig vault, signing through CPI."
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "role",
+          "type": {
+            "defined": "AdminRole"
+          }
+        }
+      ]
+    },
+    {
+      "name": "swapRoute",
+      "accounts": [
+        {
+          "name": "userTokenIn",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Token account the route swap from. Must be the user_token_in of the first hop."
+          ]
+        },
+        {
+          "name": "userTokenOut",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Token account the route swap to. Must be the user_token_out of the last hop."
+          ]
+        },
+        {
+          "name": "user",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "parameters",
+          "type": {
+            "defined": "SwapRouteParameters"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateOracleParameters",
+      "accounts": [
+        {
+          "name": "lbPair",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "adminConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "parameter",
+          "type": {
+            "defined": "OracleParameter"
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "adminConfig",
+      "docs": [
+        "Authorities of the admin roles. Once initialized, it replaces the predefined admins of the program."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authorities",
+            "docs": [
+              "Authority of each role, indexed by AdminRole"
+            ],
+            "type": {
+              "array": [
+                "publicKey",
+                4
+              ]
+            }
+          },
+          {
+            "name": "pendingAuthorities",
+            "docs": [
+              "Authority proposed for each role, pending to be accepted. Default when there is no pending change."
+            ],
+            "type": {
+              "array": [
+                "publicKey",
+                4
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "binArrayBitmapExtension",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lbPair",
+            "type": "publicKey"
+          },
+          {
+            "name": "positiveBinArrayBitmap",
+            "docs": [
+              "Packed initialized bin array state for start_bin_index is positive"
+            ],
+            "type": {
+              "array": [
+                {
+                  "array": [
+                    "u64",
+                    8
+                  ]
+                },
+                12
+              ]
+            }
+          },
+          {
+            "name": "negativeBinArrayBitmap",
+            "docs": [
+              "Packed initialized bin array state for start_bin_index is negative"
+            ],
+            "type": {
+              "array": [
+                {
+                  "array": [
+                    "u64",
+                    8
+                  ]
+                },
+                12
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "binArrayFunder",
+      "docs": [
+        "Record the funder of a bin array, which receive the rent back when the bin array is closed."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "binArray",
+            "docs": [
+              "Bin array funded"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "funder",
+            "docs": [
+              "Address paid the rent of the bin array"
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "binArray",
+      "docs": [
+        "An account to contain a range of bin. For example: Bin 100 <-> 200.",
+        "For example:",
+        "BinArray index: 0 contains bin 0 <-> 599",
+        "index: 2 contains bin 600 <-> 1199, ..."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "index",
+            "type": "i64"
+          },
+          {
+            "name": "version",
+            "docs": [
+              "Version of binArray"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "padding",
+            "type": {
+              "array": [
+                "u8",
+                7
+              ]
+            }
+          },
+          {
+            "name": "lbPair",
+            "type": "publicKey"
+          },
+          {
+            "name": "bins",
+            "type": {
+              "array": [
+                {
+                  "defined": "Bin"
+                },
+                70
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "lbPair",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "parameters",
+            "type": {
+              "defined": "StaticParameters"
+            }
+          },
+          {
+            "name": "vParameters",
+            "type": {
+              "defined": "VariableParameters"
+            }
+          },
+          {
+            "name": "bumpSeed",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "binStepSeed",
+            "docs": [
+              "Bin step signer seed"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                2
+              ]
+            }
+          },
+          {
+            "name": "pairType",
+            "docs": [
+              "Type of the pair"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "activeId",
+            "docs": [
+              "Active bin id"
+            ],
+            "type": "i32"
+          },
+          {
+            "name": "binStep",
+            "docs": [
+              "Bin step. Represent the price increment / decrement."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "status",
+            "docs": [
+              "Status of the pair. Check PairStatus enum."
+            ],
+            "type": "u8"
           },
           {
             "name": "requireBaseFactorSeed",
@@ -2919,7 +4326,7 @@ export type LbClmm = {
           {
             "name": "preActivationDuration",
             "docs": [
-              "Duration before activation activation_point. Used to calculate pre-activation time point for pre_activation_swap_address"
+              "Duration before activation point. Used to calculate pre-activation point for pre_activation_swap_address"
             ],
             "type": "u64"
           },
@@ -2950,15 +4357,21 @@ export type LbClmm = {
             "type": "publicKey"
           },
           {
-            "name": "reserved",
+            "name": "circuitBreaker",
             "docs": [
-              "Reserved space for future use"
+              "Circuit breaker parameters"
             ],
             "type": {
-              "array": [
-                "u8",
-                24
-              ]
+              "defined": "CircuitBreakerParameters"
+            }
+          },
+          {
+            "name": "oracleParameters",
+            "docs": [
+              "Oracle parameters"
+            ],
+            "type": {
+              "defined": "OracleParameters"
             }
           }
         ]
@@ -3248,23 +4661,250 @@ export type LbClmm = {
             ],
             "type": "publicKey"
           },
+          {
+            "name": "positionNftMint",
+            "docs": [
+              "Mint of the NFT representing the ownership of this position. Default for non-tokenized position."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "padding1",
+            "docs": [
+              "Padding to align the vesting schedule"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                7
+              ]
+            }
+          },
+          {
+            "name": "vestingStartPoint",
+            "docs": [
+              "Point which the linear vesting of the liquidity starts"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "vestingCliffPoint",
+            "docs": [
+              "Point before which none of the vesting liquidity can be withdrawn"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "vestingEndPoint",
+            "docs": [
+              "Point which the liquidity is fully vested. 0 for position without vesting."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "vestingRemainingRatio",
+            "docs": [
+              "Ratio of the vesting liquidity remaining in the position, in VESTING_RATIO_PRECISION"
+            ],
+            "type": "u64"
+          },
           {
             "name": "reserved",
             "docs": [
               "Reserved space for future use"
             ],
-            "type": {
-              "array": [
-                "u8",
-                87
-              ]
-            }
+            "type": {
+              "array": [
+                "u8",
+                16
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "presetParameter",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "binStep",
+            "docs": [
+              "Bin step. Represent the price increment / decrement."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "baseFactor",
+            "docs": [
+              "Used for base fee calculation. base_fee_rate = base_factor * bin_step"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "filterPeriod",
+            "docs": [
+              "Filter period determine high frequency trading time window."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "decayPeriod",
+            "docs": [
+              "Decay period determine when the volatile fee start decay / decrease."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "reductionFactor",
+            "docs": [
+              "Reduction factor controls the volatile fee rate decrement rate."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "variableFeeControl",
+            "docs": [
+              "Used to scale the variable fee component depending on the dynamic of the market"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "maxVolatilityAccumulator",
+            "docs": [
+              "Maximum number of bin crossed can be accumulated. Used to cap volatile fee rate."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "minBinId",
+            "docs": [
+              "Min bin id supported by the pool based on the configured bin step."
+            ],
+            "type": "i32"
+          },
+          {
+            "name": "maxBinId",
+            "docs": [
+              "Max bin id supported by the pool based on the configured bin step."
+            ],
+            "type": "i32"
+          },
+          {
+            "name": "protocolShare",
+            "docs": [
+              "Portion of swap fees retained by the protocol by controlling protocol_share parameter. protocol_swap_fee = protocol_share * total_swap_fee"
+            ],
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "referrer",
+      "docs": [
+        "Referrer registered by the admin. Swaps referred by the referrer share the protocol fee with it."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "docs": [
+              "Owner of the referrer, allowed to claim the referral fee"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "feeShare",
+            "docs": [
+              "Portion of the protocol fee shared with the referrer, in bps"
+            ],
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "referralFee",
+      "docs": [
+        "Referral fee of a referrer accumulated in a pair. The fee stay in the pair reserves until claimed."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "referrer",
+            "docs": [
+              "Referrer earning the fee"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "lbPair",
+            "docs": [
+              "Liquidity pair the fee was collected from"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "feeX",
+            "docs": [
+              "Claimable fee of token X"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "feeY",
+            "docs": [
+              "Claimable fee of token Y"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "InitAdminConfigParameter",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feeAdmin",
+            "docs": [
+              "Authority of the fee admin role"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "pauseGuardian",
+            "docs": [
+              "Authority of the pause guardian role"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "rewardAdmin",
+            "docs": [
+              "Authority of the reward admin role"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "launchPoolAdmin",
+            "docs": [
+              "Authority of the launch pool admin role"
+            ],
+            "type": "publicKey"
           }
         ]
       }
     },
     {
-      "name": "presetParameter",
+      "name": "InitPresetParametersIx",
       "type": {
         "kind": "struct",
         "fields": [
@@ -3340,105 +4980,114 @@ export type LbClmm = {
           }
         ]
       }
-    }
-  ],
-  "types": [
+    },
     {
-      "name": "InitPresetParametersIx",
+      "name": "CircuitBreakerParameter",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "binStep",
+            "name": "maxBinMovement",
             "docs": [
-              "Bin step. Represent the price increment / decrement."
+              "Maximum number of bins the active bin can move away from the time weighted active bin of the window. 0 = disabled."
             ],
             "type": "u16"
           },
           {
-            "name": "baseFactor",
+            "name": "window",
             "docs": [
-              "Used for base fee calculation. base_fee_rate = base_factor * bin_step"
+              "Time window, in seconds, of the time weighted active bin measured from the oracle"
             ],
             "type": "u16"
           },
           {
-            "name": "filterPeriod",
+            "name": "cooldown",
             "docs": [
-              "Filter period determine high frequency trading time window."
+              "Duration, in seconds, swap stay halted after the circuit breaker tripped"
             ],
-            "type": "u16"
-          },
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeParameter",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "decayPeriod",
+            "name": "protocolShare",
             "docs": [
-              "Decay period determine when the volatile fee start decay / decrease."
+              "Portion of swap fees retained by the protocol by controlling protocol_share parameter. protocol_swap_fee = protocol_share * total_swap_fee"
             ],
             "type": "u16"
           },
           {
-            "name": "reductionFactor",
+            "name": "baseFactor",
             "docs": [
-              "Reduction factor controls the volatile fee rate decrement rate."
+              "Base factor for base fee rate"
             ],
             "type": "u16"
-          },
+          }
+        ]
+      }
+    },
+    {
+      "name": "OracleParameter",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "variableFeeControl",
+            "name": "sampleLifetime",
             "docs": [
-              "Used to scale the variable fee component depending on the dynamic of the market"
+              "Duration, in seconds, before a new observation sample is created"
             ],
-            "type": "u32"
+            "type": "u16"
           },
           {
-            "name": "maxVolatilityAccumulator",
+            "name": "targetHistoryDuration",
             "docs": [
-              "Maximum number of bin crossed can be accumulated. Used to cap volatile fee rate."
+              "Duration, in seconds, of the history the oracle should cover"
             ],
             "type": "u32"
           },
           {
-            "name": "minBinId",
-            "docs": [
-              "Min bin id supported by the pool based on the configured bin step."
-            ],
-            "type": "i32"
-          },
-          {
-            "name": "maxBinId",
-            "docs": [
-              "Max bin id supported by the pool based on the configured bin step."
-            ],
-            "type": "i32"
-          },
-          {
-            "name": "protocolShare",
+            "name": "autoGrowth",
             "docs": [
-              "Portion of swap fees retained by the protocol by controlling protocol_share parameter. protocol_swap_fee = protocol_share * total_swap_fee"
+              "Whether swap grow the oracle until the target history duration is covered"
             ],
-            "type": "u16"
+            "type": "bool"
           }
         ]
       }
     },
     {
-      "name": "FeeParameter",
+      "name": "CompoundFeeParameter",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "protocolShare",
+            "name": "activeId",
             "docs": [
-              "Portion of swap fees retained by the protocol by controlling protocol_share parameter. protocol_swap_fee = protocol_share * total_swap_fee"
+              "Active bin that integrator observe off-chain"
             ],
-            "type": "u16"
+            "type": "i32"
           },
           {
-            "name": "baseFactor",
+            "name": "maxActiveBinSlippage",
             "docs": [
-              "Base factor for base fee rate"
+              "max active bin slippage allowed"
             ],
-            "type": "u16"
+            "type": "i32"
+          },
+          {
+            "name": "strategyType",
+            "docs": [
+              "Strategy to distribute the fee into the bins of the position. Only balanced strategies are supported."
+            ],
+            "type": {
+              "defined": "StrategyType"
+            }
           }
         ]
       }
@@ -3865,6 +5514,10 @@ export type LbClmm = {
             "name": "maxBinId",
             "type": "i32"
           },
+          {
+            "name": "lockDuration",
+            "type": "u64"
+          },
           {
             "name": "activationType",
             "type": "u8"
@@ -3872,6 +5525,146 @@ export type LbClmm = {
         ]
       }
     },
+    {
+      "name": "VestingParameter",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "startPoint",
+            "docs": [
+              "Point which the linear vesting starts"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "cliffPoint",
+            "docs": [
+              "Point before which none of the liquidity can be withdrawn"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "endPoint",
+            "docs": [
+              "Point which the liquidity is fully vested"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapRouteHopParameter",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "binArrayCount",
+            "docs": [
+              "Number of bin arrays of the hop, following the Swap accounts of the hop"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "transferHookAccountCount",
+            "docs": [
+              "Number of extra accounts required by the transfer hooks of token X and Y of the hop, following the bin arrays"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "referred",
+            "docs": [
+              "Whether the referrer and its referral fee account of the pair follow the transfer hook accounts"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapRouteParameters",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amountIn",
+            "docs": [
+              "Amount of token to be swapped in at the first hop"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "minAmountOut",
+            "docs": [
+              "Minimum amount of token to be swapped out at the last hop"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "hops",
+            "docs": [
+              "Accounts of each hop, in the order of the route"
+            ],
+            "type": {
+              "vec": {
+                "defined": "SwapRouteHopParameter"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapV2Parameters",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amountIn",
+            "docs": [
+              "Amount of token to be swapped in"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "minAmountOut",
+            "docs": [
+              "Minimum amount of token to be swapped out"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "deadline",
+            "docs": [
+              "Last point the swap can be executed. Slot or timestamp based on the activation type of the pair"
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "maxPriceImpactBps",
+            "docs": [
+              "Maximum price movement of the active bin, in basis point"
+            ],
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "maxBinMovement",
+            "docs": [
+              "Maximum number of bins the active bin can move"
+            ],
+            "type": {
+              "option": "u16"
+            }
+          }
+        ]
+      }
+    },
     {
       "name": "BinLiquidityReduction",
       "type": {
@@ -4226,6 +6019,84 @@ export type LbClmm = {
         ]
       }
     },
+    {
+      "name": "CircuitBreakerParameters",
+      "docs": [
+        "Circuit breaker halting swap when the active bin move too far away from the time weighted active bin of the oracle"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxBinMovement",
+            "docs": [
+              "Maximum number of bins the active bin can move away from the time weighted active bin of the window. 0 = disabled."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "window",
+            "docs": [
+              "Time window, in seconds, of the time weighted active bin."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "cooldown",
+            "docs": [
+              "Duration, in seconds, swap stay halted after the circuit breaker tripped."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "haltedUntil",
+            "docs": [
+              "Timestamp until which swap is halted."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OracleParameters",
+      "docs": [
+        "Oracle sampling of the pair. The default value keep the default sample lifetime, and the oracle only grow by increase_oracle_length"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sampleLifetime",
+            "docs": [
+              "Duration, in seconds, before a new observation sample is created. 0 = default sample lifetime."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "autoGrowth",
+            "docs": [
+              "Whether swap grow the oracle until the target history duration is covered. 0 = disabled."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "padding",
+            "docs": [
+              "padding"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "targetHistoryDuration",
+            "docs": [
+              "Duration, in seconds, of the history the oracle should cover."
+            ],
+            "type": "u32"
+          }
+        ]
+      }
+    },
     {
       "name": "FeeInfo",
       "type": {
@@ -4342,6 +6213,29 @@ export type LbClmm = {
         ]
       }
     },
+    {
+      "name": "AdminRole",
+      "docs": [
+        "Admin role. 0 = FeeAdmin, 1 = PauseGuardian, 2 = RewardAdmin, 3 = LaunchPoolAdmin"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "FeeAdmin"
+          },
+          {
+            "name": "PauseGuardian"
+          },
+          {
+            "name": "RewardAdmin"
+          },
+          {
+            "name": "LaunchPoolAdmin"
+          }
+        ]
+      }
+    },
     {
       "name": "LayoutVersion",
       "docs": [
@@ -4362,7 +6256,7 @@ export type LbClmm = {
     {
       "name": "PairType",
       "docs": [
-        "Type of the Pair. 0 = Permissionless, 1 = Permission, 2 = CustomizablePermissionless. Putting 0 as permissionless for backward compatibility."
+        "Type of the Pair. 0 = Permissionless, 1 = Permission, 2 = CustomizablePermissionless"
       ],
       "type": {
         "kind": "enum",
@@ -4407,34 +6301,274 @@ export type LbClmm = {
           "index": false
         },
         {
-          "name": "binId",
-          "type": "i16",
+          "name": "binId",
+          "type": "i16",
+          "index": false
+        },
+        {
+          "name": "tokenXFeeAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "tokenYFeeAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "protocolTokenXFeeAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "protocolTokenYFeeAmount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AddLiquidity",
+      "fields": [
+        {
+          "name": "lbPair",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "from",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "position",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amounts",
+          "type": {
+            "array": [
+              "u64",
+              2
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "activeBinId",
+          "type": "i32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RemoveLiquidity",
+      "fields": [
+        {
+          "name": "lbPair",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "from",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "position",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amounts",
+          "type": {
+            "array": [
+              "u64",
+              2
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "activeBinId",
+          "type": "i32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "Swap",
+      "fields": [
+        {
+          "name": "lbPair",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "from",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "startBinId",
+          "type": "i32",
+          "index": false
+        },
+        {
+          "name": "endBinId",
+          "type": "i32",
+          "index": false
+        },
+        {
+          "name": "amountIn",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountOut",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "swapForY",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "fee",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "protocolFee",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "feeBps",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "hostFee",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ClaimReward",
+      "fields": [
+        {
+          "name": "lbPair",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "position",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "rewardIndex",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "totalReward",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "FundReward",
+      "fields": [
+        {
+          "name": "lbPair",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "funder",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "rewardIndex",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InitializeReward",
+      "fields": [
+        {
+          "name": "lbPair",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "rewardMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "funder",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "rewardIndex",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "rewardDuration",
+          "type": "u64",
           "index": false
-        },
+        }
+      ]
+    },
+    {
+      "name": "UpdateRewardDuration",
+      "fields": [
         {
-          "name": "tokenXFeeAmount",
-          "type": "u64",
+          "name": "lbPair",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "tokenYFeeAmount",
+          "name": "rewardIndex",
           "type": "u64",
           "index": false
         },
         {
-          "name": "protocolTokenXFeeAmount",
+          "name": "oldRewardDuration",
           "type": "u64",
           "index": false
         },
         {
-          "name": "protocolTokenYFeeAmount",
+          "name": "newRewardDuration",
           "type": "u64",
           "index": false
         }
       ]
     },
     {
-      "name": "AddLiquidity",
+      "name": "UpdateRewardFunder",
       "fields": [
         {
           "name": "lbPair",
@@ -4442,34 +6576,39 @@ export type LbClmm = {
           "index": false
         },
         {
-          "name": "from",
-          "type": "publicKey",
+          "name": "rewardIndex",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "position",
+          "name": "oldFunder",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "amounts",
-          "type": {
-            "array": [
-              "u64",
-              2
-            ]
-          },
+          "name": "newFunder",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PositionClose",
+      "fields": [
+        {
+          "name": "position",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "activeBinId",
-          "type": "i32",
+          "name": "owner",
+          "type": "publicKey",
           "index": false
         }
       ]
     },
     {
-      "name": "RemoveLiquidity",
+      "name": "ClaimFee",
       "fields": [
         {
           "name": "lbPair",
@@ -4477,34 +6616,29 @@ export type LbClmm = {
           "index": false
         },
         {
-          "name": "from",
+          "name": "position",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "position",
+          "name": "owner",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "amounts",
-          "type": {
-            "array": [
-              "u64",
-              2
-            ]
-          },
+          "name": "feeX",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "activeBinId",
-          "type": "i32",
+          "name": "feeY",
+          "type": "u64",
           "index": false
         }
       ]
     },
     {
-      "name": "Swap",
+      "name": "LbPairCreate",
       "fields": [
         {
           "name": "lbPair",
@@ -4512,114 +6646,169 @@ export type LbClmm = {
           "index": false
         },
         {
-          "name": "from",
-          "type": "publicKey",
+          "name": "binStep",
+          "type": "u16",
           "index": false
         },
         {
-          "name": "startBinId",
-          "type": "i32",
+          "name": "tokenX",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "endBinId",
-          "type": "i32",
+          "name": "tokenY",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PositionCreate",
+      "fields": [
+        {
+          "name": "lbPair",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "amountIn",
-          "type": "u64",
+          "name": "position",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "amountOut",
-          "type": "u64",
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "FeeParameterUpdate",
+      "fields": [
+        {
+          "name": "lbPair",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "swapForY",
-          "type": "bool",
+          "name": "protocolShare",
+          "type": "u16",
           "index": false
         },
         {
-          "name": "fee",
-          "type": "u64",
+          "name": "baseFactor",
+          "type": "u16",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "IncreaseObservation",
+      "fields": [
+        {
+          "name": "oracle",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "protocolFee",
+          "name": "newObservationLength",
           "type": "u64",
           "index": false
+        }
+      ]
+    },
+    {
+      "name": "WithdrawIneligibleReward",
+      "fields": [
+        {
+          "name": "lbPair",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "feeBps",
-          "type": "u128",
+          "name": "rewardMint",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "hostFee",
+          "name": "amount",
           "type": "u64",
           "index": false
         }
       ]
     },
     {
-      "name": "ClaimReward",
+      "name": "UpdatePositionOperator",
       "fields": [
         {
-          "name": "lbPair",
+          "name": "position",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "position",
+          "name": "oldOperator",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "owner",
+          "name": "newOperator",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransferPositionOwner",
+      "fields": [
+        {
+          "name": "position",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "rewardIndex",
-          "type": "u64",
+          "name": "oldOwner",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "totalReward",
-          "type": "u64",
+          "name": "newOwner",
+          "type": "publicKey",
           "index": false
         }
       ]
     },
     {
-      "name": "FundReward",
+      "name": "UpdatePositionLockReleasePoint",
       "fields": [
         {
-          "name": "lbPair",
+          "name": "position",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "funder",
-          "type": "publicKey",
+          "name": "currentPoint",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "rewardIndex",
+          "name": "newLockReleasePoint",
           "type": "u64",
           "index": false
         },
         {
-          "name": "amount",
+          "name": "oldLockReleasePoint",
           "type": "u64",
           "index": false
+        },
+        {
+          "name": "sender",
+          "type": "publicKey",
+          "index": false
         }
       ]
     },
     {
-      "name": "InitializeReward",
+      "name": "GoToABin",
       "fields": [
         {
           "name": "lbPair",
@@ -4627,29 +6816,64 @@ export type LbClmm = {
           "index": false
         },
         {
-          "name": "rewardMint",
+          "name": "fromBinId",
+          "type": "i32",
+          "index": false
+        },
+        {
+          "name": "toBinId",
+          "type": "i32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "FlashLoan",
+      "fields": [
+        {
+          "name": "lbPair",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "funder",
+          "name": "user",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "rewardIndex",
+          "name": "amountX",
           "type": "u64",
           "index": false
         },
         {
-          "name": "rewardDuration",
+          "name": "amountY",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "feeX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "feeY",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "protocolFeeX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "protocolFeeY",
           "type": "u64",
           "index": false
         }
       ]
     },
     {
-      "name": "UpdateRewardDuration",
+      "name": "CircuitBreakerParametersUpdate",
       "fields": [
         {
           "name": "lbPair",
@@ -4657,24 +6881,24 @@ export type LbClmm = {
           "index": false
         },
         {
-          "name": "rewardIndex",
-          "type": "u64",
+          "name": "maxBinMovement",
+          "type": "u16",
           "index": false
         },
         {
-          "name": "oldRewardDuration",
-          "type": "u64",
+          "name": "window",
+          "type": "u16",
           "index": false
         },
         {
-          "name": "newRewardDuration",
-          "type": "u64",
+          "name": "cooldown",
+          "type": "u32",
           "index": false
         }
       ]
     },
     {
-      "name": "UpdateRewardFunder",
+      "name": "CircuitBreakerTripped",
       "fields": [
         {
           "name": "lbPair",
@@ -4682,27 +6906,27 @@ export type LbClmm = {
           "index": false
         },
         {
-          "name": "rewardIndex",
-          "type": "u64",
+          "name": "activeId",
+          "type": "i32",
           "index": false
         },
         {
-          "name": "oldFunder",
-          "type": "publicKey",
+          "name": "timeWeightedActiveId",
+          "type": "i32",
           "index": false
         },
         {
-          "name": "newFunder",
-          "type": "publicKey",
+          "name": "haltedUntil",
+          "type": "i64",
           "index": false
         }
       ]
     },
     {
-      "name": "PositionClose",
+      "name": "ReferrerUpdate",
       "fields": [
         {
-          "name": "position",
+          "name": "referrer",
           "type": "publicKey",
           "index": false
         },
@@ -4710,11 +6934,16 @@ export type LbClmm = {
           "name": "owner",
           "type": "publicKey",
           "index": false
+        },
+        {
+          "name": "feeShare",
+          "type": "u16",
+          "index": false
         }
       ]
     },
     {
-      "name": "ClaimFee",
+      "name": "ClaimReferralFee",
       "fields": [
         {
           "name": "lbPair",
@@ -4722,7 +6951,7 @@ export type LbClmm = {
           "index": false
         },
         {
-          "name": "position",
+          "name": "referrer",
           "type": "publicKey",
           "index": false
         },
@@ -4744,7 +6973,7 @@ export type LbClmm = {
       ]
     },
     {
-      "name": "LbPairCreate",
+      "name": "CompoundFee",
       "fields": [
         {
           "name": "lbPair",
@@ -4752,24 +6981,34 @@ export type LbClmm = {
           "index": false
         },
         {
-          "name": "binStep",
-          "type": "u16",
+          "name": "position",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "tokenX",
+          "name": "owner",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "tokenY",
-          "type": "publicKey",
+          "name": "amounts",
+          "type": {
+            "array": [
+              "u64",
+              2
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "activeBinId",
+          "type": "i32",
           "index": false
         }
       ]
     },
     {
-      "name": "PositionCreate",
+      "name": "BinArrayClose",
       "fields": [
         {
           "name": "lbPair",
@@ -4777,124 +7016,119 @@ export type LbClmm = {
           "index": false
         },
         {
-          "name": "position",
+          "name": "binArray",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "owner",
+          "name": "index",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "funder",
           "type": "publicKey",
           "index": false
         }
       ]
     },
     {
-      "name": "FeeParameterUpdate",
+      "name": "UpdatePositionVesting",
       "fields": [
         {
-          "name": "lbPair",
+          "name": "position",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "protocolShare",
-          "type": "u16",
+          "name": "startPoint",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "baseFactor",
-          "type": "u16",
+          "name": "cliffPoint",
+          "type": "u64",
           "index": false
-        }
-      ]
-    },
-    {
-      "name": "IncreaseObservation",
-      "fields": [
+        },
         {
-          "name": "oracle",
-          "type": "publicKey",
+          "name": "endPoint",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "newObservationLength",
-          "type": "u64",
+          "name": "sender",
+          "type": "publicKey",
           "index": false
         }
       ]
     },
     {
-      "name": "WithdrawIneligibleReward",
+      "name": "InitializeAdminConfig",
       "fields": [
         {
-          "name": "lbPair",
+          "name": "feeAdmin",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "rewardMint",
+          "name": "pauseGuardian",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "amount",
-          "type": "u64",
+          "name": "rewardAdmin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "launchPoolAdmin",
+          "type": "publicKey",
           "index": false
         }
       ]
     },
     {
-      "name": "UpdatePositionOperator",
+      "name": "ProposeAdminRole",
       "fields": [
         {
-          "name": "position",
-          "type": "publicKey",
+          "name": "role",
+          "type": "u8",
           "index": false
         },
         {
-          "name": "oldOperator",
+          "name": "authority",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "newOperator",
+          "name": "pendingAuthority",
           "type": "publicKey",
           "index": false
         }
       ]
     },
     {
-      "name": "UpdatePositionLockReleasePoint",
+      "name": "AcceptAdminRole",
       "fields": [
         {
-          "name": "position",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "currentPoint",
-          "type": "u64",
-          "index": false
-        },
-        {
-          "name": "newLockReleasePoint",
-          "type": "u64",
+          "name": "role",
+          "type": "u8",
           "index": false
         },
         {
-          "name": "oldLockReleasePoint",
-          "type": "u64",
+          "name": "oldAuthority",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "sender",
+          "name": "newAuthority",
           "type": "publicKey",
           "index": false
         }
       ]
     },
     {
-      "name": "GoToABin",
+      "name": "OracleParametersUpdate",
       "fields": [
         {
           "name": "lbPair",
@@ -4902,13 +7136,18 @@ export type LbClmm = {
           "index": false
         },
         {
-          "name": "fromBinId",
-          "type": "i32",
+          "name": "sampleLifetime",
+          "type": "u16",
           "index": false
         },
         {
-          "name": "toBinId",
-          "type": "i32",
+          "name": "targetHistoryDuration",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "autoGrowth",
+          "type": "bool",
           "index": false
         }
       ]
@@ -5244,6 +7483,111 @@ export type LbClmm = {
       "code": 6065,
       "name": "AlreadyPassPreActivationSwapPoint",
       "msg": "Already pass pre-activation swap point"
+    },
+    {
+      "code": 6066,
+      "name": "OwnersAreTheSame",
+      "msg": "Cannot transfer because owners are the same"
+    },
+    {
+      "code": 6067,
+      "name": "InvalidPositionNftAccount",
+      "msg": "Position NFT account is missing or not holding the position NFT"
+    },
+    {
+      "code": 6068,
+      "name": "PositionIsTokenized",
+      "msg": "Tokenized position can only be transferred through the position NFT"
+    },
+    {
+      "code": 6069,
+      "name": "InvalidPositionNftUri",
+      "msg": "Invalid position NFT metadata uri"
+    },
+    {
+      "code": 6070,
+      "name": "UnsupportedMintExtension",
+      "msg": "Unsupported mint extension"
+    },
+    {
+      "code": 6071,
+      "name": "FlashLoanNotRepaid",
+      "msg": "Flash loan is not repaid in the same transaction"
+    },
+    {
+      "code": 6072,
+      "name": "InvalidFlashLoanInstruction",
+      "msg": "Invalid flash borrow or flash repay instruction"
+    },
+    {
+      "code": 6073,
+      "name": "FlashLoanCpiNotAllowed",
+      "msg": "Flash loan is not allowed through CPI"
+    },
+    {
+      "code": 6074,
+      "name": "CircuitBreakerHalted",
+      "msg": "Swap is halted by circuit breaker"
+    },
+    {
+      "code": 6075,
+      "name": "InvalidCircuitBreakerParameters",
+      "msg": "Invalid circuit breaker parameters"
+    },
+    {
+      "code": 6076,
+      "name": "InvalidReferrerFeeShare",
+      "msg": "Invalid referrer fee share"
+    },
+    {
+      "code": 6077,
+      "name": "InvalidReferralFeeAccount",
+      "msg": "Referral fee account does not belong to the referrer"
+    },
+    {
+      "code": 6078,
+      "name": "SwapDeadlineExceeded",
+      "msg": "Swap deadline exceeded"
+    },
+    {
+      "code": 6079,
+      "name": "ExceededPriceImpactTolerance",
+      "msg": "Exceeded price impact tolerance"
+    },
+    {
+      "code": 6080,
+      "name": "NonEmptyBinArray",
+      "msg": "Bin array is not empty"
+    },
+    {
+      "code": 6081,
+      "name": "InconsistentBinArrayBitmap",
+      "msg": "Bin array bitmap is inconsistent with the bin array liquidity"
+    },
+    {
+      "code": 6082,
+      "name": "InvalidVestingSchedule",
+      "msg": "Invalid vesting schedule"
+    },
+    {
+      "code": 6083,
+      "name": "UnevenVestingWithdrawal",
+      "msg": "Vesting liquidity must be withdrawn evenly from every bin of the position"
+    },
+    {
+      "code": 6084,
+      "name": "InvalidSwapRoute",
+      "msg": "Invalid swap route"
+    },
+    {
+      "code": 6085,
+      "name": "InvalidOracleParameters",
+      "msg": "Invalid oracle parameters"
+    },
+    {
+      "code": 6086,
+      "name": "BinArrayFunderRecorded",
+      "msg": "Bin array has a recorded funder"
     }
   ]
 };
@@ -5302,137 +7646,1320 @@ export const IDL: LbClmm = {
       "value": "2_000"
     },
     {
-      "name": "NUM_REWARDS",
-      "type": {
-        "defined": "usize"
-      },
-      "value": "2"
+      "name": "MAX_REFERRER_FEE_SHARE",
+      "type": "u16",
+      "value": "5_000"
+    },
+    {
+      "name": "VESTING_RATIO_PRECISION",
+      "type": "u64",
+      "value": "1_000_000_000_000"
+    },
+    {
+      "name": "FLASH_LOAN_FEE_BPS",
+      "type": "u16",
+      "value": "5"
+    },
+    {
+      "name": "MAX_SWAP_ROUTE_HOPS",
+      "type": {
+        "defined": "usize"
+      },
+      "value": "3"
+    },
+    {
+      "name": "NUM_REWARDS",
+      "type": {
+        "defined": "usize"
+      },
+      "value": "2"
+    },
+    {
+      "name": "MIN_REWARD_DURATION",
+      "type": "u64",
+      "value": "1"
+    },
+    {
+      "name": "MAX_REWARD_DURATION",
+      "type": "u64",
+      "value": "31536000"
+    },
+    {
+      "name": "EXTENSION_BINARRAY_BITMAP_SIZE",
+      "type": {
+        "defined": "usize"
+      },
+      "value": "12"
+    },
+    {
+      "name": "BIN_ARRAY_BITMAP_SIZE",
+      "type": "i32",
+      "value": "512"
+    },
+    {
+      "name": "MAX_REWARD_BIN_SPLIT",
+      "type": {
+        "defined": "usize"
+      },
+      "value": "15"
+    },
+    {
+      "name": "MAX_BIN_STEP",
+      "type": "u16",
+      "value": "400"
+    },
+    {
+      "name": "MAX_BASE_FEE",
+      "type": "u128",
+      "value": "100_000_000"
+    },
+    {
+      "name": "MIN_BASE_FEE",
+      "type": "u128",
+      "value": "100_000"
+    },
+    {
+      "name": "BIN_ARRAY",
+      "type": "bytes",
+      "value": "[98, 105, 110, 95, 97, 114, 114, 97, 121]"
+    },
+    {
+      "name": "BIN_ARRAY_FUNDER",
+      "type": "bytes",
+      "value": "[98, 105, 110, 95, 97, 114, 114, 97, 121, 95, 102, 117, 110, 100, 101, 114]"
+    },
+    {
+      "name": "ORACLE",
+      "type": "bytes",
+      "value": "[111, 114, 97, 99, 108, 101]"
+    },
+    {
+      "name": "BIN_ARRAY_BITMAP_SEED",
+      "type": "bytes",
+      "value": "[98, 105, 116, 109, 97, 112]"
+    },
+    {
+      "name": "PRESET_PARAMETER",
+      "type": "bytes",
+      "value": "[112, 114, 101, 115, 101, 116, 95, 112, 97, 114, 97, 109, 101, 116, 101, 114]"
+    },
+    {
+      "name": "POSITION",
+      "type": "bytes",
+      "value": "[112, 111, 115, 105, 116, 105, 111, 110]"
+    },
+    {
+      "name": "REFERRER",
+      "type": "bytes",
+      "value": "[114, 101, 102, 101, 114, 114, 101, 114]"
+    },
+    {
+      "name": "ADMIN_CONFIG",
+      "type": "bytes",
+      "value": "[97, 100, 109, 105, 110, 95, 99, 111, 110, 102, 105, 103]"
+    },
+    {
+      "name": "REFERRAL_FEE",
+      "type": "bytes",
+      "value": "[114, 101, 102, 101, 114, 114, 97, 108, 95, 102, 101, 101]"
+    }
+  ],
+  "instructions": [
+    {
+      "name": "initializeLbPair",
+      "accounts": [
+        {
+          "name": "lbPair",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "binArrayBitmapExtension",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "tokenMintX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMintY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "oracle",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "presetParameter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "funder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "activeId",
+          "type": "i32"
+        },
+        {
+          "name": "binStep",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "initializeCustomizablePermissionlessLbPair",
+      "accounts": [
+        {
+          "name": "lbPair",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "binArrayBitmapExtension",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "tokenMintX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMintY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "oracle",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "funder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": "CustomizableParams"
+          }
+        }
+      ]
     },
     {
-      "name": "MIN_REWARD_DURATION",
-      "type": "u64",
-      "value": "1"
+      "name": "initializePermissionLbPair",
+      "accounts": [
+        {
+          "name": "base",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "lbPair",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "binArrayBitmapExtension",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "tokenMintX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMintY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "oracle",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "adminConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "ixData",
+          "type": {
+            "defined": "InitPermissionPairIx"
+          }
+        }
+      ]
     },
     {
-      "name": "MAX_REWARD_DURATION",
-      "type": "u64",
-      "value": "31536000"
+      "name": "initializeBinArrayBitmapExtension",
+      "accounts": [
+        {
+          "name": "lbPair",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "binArrayBitmapExtension",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Initialize an account to store if a bin array is initialized."
+          ]
+        },
+        {
+          "name": "funder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
-      "name": "EXTENSION_BINARRAY_BITMAP_SIZE",
-      "type": {
-        "defined": "usize"
-      },
-      "value": "12"
+      "name": "initializeBinArray",
+      "accounts": [
+        {
+          "name": "lbPair",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "binArray",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "funder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "i64"
+        }
+      ]
     },
     {
-      "name": "BIN_ARRAY_BITMAP_SIZE",
-      "type": "i32",
-      "value": "512"
+      "name": "initializeBinArrayWithFunder",
+      "accounts": [
+        {
+          "name": "lbPair",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "binArray",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "binArrayFunder",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Record the funder, refunded when the bin array is closed"
+          ]
+        },
+        {
+          "name": "funder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "i64"
+        }
+      ]
     },
     {
-      "name": "MAX_REWARD_BIN_SPLIT",
-      "type": {
-        "defined": "usize"
-      },
-      "value": "15"
+      "name": "addLiquidity",
+      "accounts": [
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lbPair",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "binArrayBitmapExtension",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "userTokenX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "binArrayLower",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "binArrayUpper",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sender",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "positionNftAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Token account holding the position NFT. Only required for tokenized position."
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "liquidityParameter",
+          "type": {
+            "defined": "LiquidityParameter"
+          }
+        }
+      ]
     },
     {
-      "name": "MAX_BIN_STEP",
-      "type": "u16",
-      "value": "400"
+      "name": "addLiquidityByWeight",
+      "accounts": [
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lbPair",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "binArrayBitmapExtension",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "userTokenX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "binArrayLower",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "binArrayUpper",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sender",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "positionNftAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Token account holding the position NFT. Only required for tokenized position."
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "liquidityParameter",
+          "type": {
+            "defined": "LiquidityParameterByWeight"
+          }
+        }
+      ]
     },
     {
-      "name": "MAX_BASE_FEE",
-      "type": "u128",
-      "value": "100_000_000"
+      "name": "addLiquidityByStrategy",
+      "accounts": [
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lbPair",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "binArrayBitmapExtension",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "userTokenX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "binArrayLower",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "binArrayUpper",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sender",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "positionNftAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Token account holding the position NFT. Only required for tokenized position."
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "liquidityParameter",
+          "type": {
+            "defined": "LiquidityParameterByStrategy"
+          }
+        }
+      ]
     },
     {
-      "name": "MIN_BASE_FEE",
-      "type": "u128",
-      "value": "100_000"
+      "name": "addLiquidityByStrategyOneSide",
+      "accounts": [
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lbPair",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "binArrayBitmapExtension",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "userToken",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "binArrayLower",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "binArrayUpper",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sender",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "positionNftAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Token account holding the position NFT. Only required for tokenized position."
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "liquidityParameter",
+          "type": {
+            "defined": "LiquidityParameterByStrategyOneSide"
+          }
+        }
+      ]
     },
     {
-      "name": "BIN_ARRAY",
-      "type": "bytes",
-      "value": "[98, 105, 110, 95, 97, 114, 114, 97, 121]"
+      "name": "addLiquidityOneSide",
+      "accounts": [
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lbPair",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "binArrayBitmapExtension",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "userToken",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "binArrayLower",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "binArrayUpper",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sender",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "positionNftAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Token account holding the position NFT. Only required for tokenized position."
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "liquidityParameter",
+          "type": {
+            "defined": "LiquidityOneSideParameter"
+          }
+        }
+      ]
     },
     {
-      "name": "ORACLE",
-      "type": "bytes",
-      "value": "[111, 114, 97, 99, 108, 101]"
+      "name": "removeLiquidity",
+      "accounts": [
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lbPair",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "binArrayBitmapExtension",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "userTokenX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "binArrayLower",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "binArrayUpper",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sender",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "positionNftAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Token account holding the position NFT. Only required for tokenized position."
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "binLiquidityRemoval",
+          "type": {
+            "vec": {
+              "defined": "BinLiquidityReduction"
+            }
+          }
+        }
+      ]
     },
     {
-      "name": "BIN_ARRAY_BITMAP_SEED",
-      "type": "bytes",
-      "value": "[98, 105, 116, 109, 97, 112]"
+      "name": "initializePosition",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "lbPair",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "lowerBinId",
+          "type": "i32"
+        },
+        {
+          "name": "width",
+          "type": "i32"
+        }
+      ]
     },
     {
-      "name": "PRESET_PARAMETER",
-      "type": "bytes",
-      "value": "[112, 114, 101, 115, 101, 116, 95, 112, 97, 114, 97, 109, 101, 116, 101, 114]"
+      "name": "initializePositionPda",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "base",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lbPair",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "owner"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "lowerBinId",
+          "type": "i32"
+        },
+        {
+          "name": "width",
+          "type": "i32"
+        }
+      ]
     },
     {
-      "name": "POSITION",
-      "type": "bytes",
-      "value": "[112, 111, 115, 105, 116, 105, 111, 110]"
-    }
-  ],
-  "instructions": [
-    {
-      "name": "initializeLbPair",
+      "name": "initializePositionByOperator",
       "accounts": [
         {
-          "name": "lbPair",
+          "name": "payer",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "binArrayBitmapExtension",
+          "name": "base",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "position",
           "isMut": true,
-          "isSigner": false,
-          "isOptional": true
+          "isSigner": false
         },
         {
-          "name": "tokenMintX",
+          "name": "lbPair",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenMintY",
+          "name": "owner",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "reserveX",
-          "isMut": true,
+          "name": "operator",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "operator"
+          ]
+        },
+        {
+          "name": "operatorTokenX",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "reserveY",
-          "isMut": true,
+          "name": "ownerTokenX",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "oracle",
-          "isMut": true,
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "presetParameter",
+          "name": "eventAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "funder",
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "lowerBinId",
+          "type": "i32"
+        },
+        {
+          "name": "width",
+          "type": "i32"
+        },
+        {
+          "name": "feeOwner",
+          "type": "publicKey"
+        },
+        {
+          "name": "lockReleasePoint",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "initializeTokenizedPosition",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "positionNftMint",
           "isMut": true,
           "isSigner": true
         },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lbPair",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ownerPositionNftAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionNftMetadata",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -5456,43 +8983,102 @@ export const IDL: LbClmm = {
       ],
       "args": [
         {
-          "name": "activeId",
+          "name": "lowerBinId",
           "type": "i32"
         },
         {
-          "name": "binStep",
-          "type": "u16"
+          "name": "width",
+          "type": "i32"
+        },
+        {
+          "name": "uri",
+          "type": "string"
         }
       ]
     },
     {
-      "name": "initializePermissionLbPair",
+      "name": "updatePositionOperator",
       "accounts": [
         {
-          "name": "base",
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
           "isMut": false,
           "isSigner": true
         },
         {
-          "name": "lbPair",
-          "isMut": true,
+          "name": "eventAuthority",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "binArrayBitmapExtension",
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "operator",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "transferPositionOwner",
+      "accounts": [
+        {
+          "name": "position",
           "isMut": true,
-          "isSigner": false,
-          "isOptional": true
+          "isSigner": false
         },
         {
-          "name": "tokenMintX",
+          "name": "lbPair",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenMintY",
+          "name": "sender",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Only the owner can hand over the position. The operator is not allowed to."
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "newOwner",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "swap",
+      "accounts": [
+        {
+          "name": "lbPair",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "binArrayBitmapExtension",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
         },
         {
           "name": "reserveX",
@@ -5505,27 +9091,48 @@ export const IDL: LbClmm = {
           "isSigner": false
         },
         {
-          "name": "oracle",
+          "name": "userTokenIn",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "admin",
+          "name": "userTokenOut",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "tokenXMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "tokenYMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "oracle",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "hostFeeIn",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "user",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -5542,37 +9149,23 @@ export const IDL: LbClmm = {
       ],
       "args": [
         {
-          "name": "ixData",
-          "type": {
-            "defined": "InitPermissionPairIx"
-          }
+          "name": "amountIn",
+          "type": "u64"
+        },
+        {
+          "name": "minAmountOut",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "initializeCustomizablePermissionlessLbPair",
+      "name": "withdrawProtocolFee",
       "accounts": [
         {
           "name": "lbPair",
           "isMut": true,
           "isSigner": false
         },
-        {
-          "name": "binArrayBitmapExtension",
-          "isMut": true,
-          "isSigner": false,
-          "isOptional": true
-        },
-        {
-          "name": "tokenMintX",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenMintY",
-          "isMut": false,
-          "isSigner": false
-        },
         {
           "name": "reserveX",
           "isMut": true,
@@ -5584,191 +9177,200 @@ export const IDL: LbClmm = {
           "isSigner": false
         },
         {
-          "name": "oracle",
-          "isMut": true,
+          "name": "tokenXMint",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "userTokenX",
+          "name": "tokenYMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "funder",
+          "name": "receiverTokenX",
           "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
+          "name": "receiverTokenY",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "feeOwner",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "eventAuthority",
+          "name": "tokenXProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "program",
+          "name": "tokenYProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "params",
-          "type": {
-            "defined": "CustomizableParams"
-          }
+          "name": "amountX",
+          "type": "u64"
+        },
+        {
+          "name": "amountY",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "initializeBinArrayBitmapExtension",
+      "name": "initializeReward",
       "accounts": [
         {
           "name": "lbPair",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "binArrayBitmapExtension",
+          "name": "rewardVault",
           "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Initialize an account to store if a bin array is initialized."
-          ]
+          "isSigner": false
         },
         {
-          "name": "funder",
+          "name": "rewardMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "systemProgram",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "initializeBinArray",
-      "accounts": [
+        },
         {
-          "name": "lbPair",
+          "name": "rent",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "binArray",
-          "isMut": true,
+          "name": "adminConfig",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "funder",
-          "isMut": true,
-          "isSigner": true
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "program",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "index",
-          "type": "i64"
+          "name": "rewardIndex",
+          "type": "u64"
+        },
+        {
+          "name": "rewardDuration",
+          "type": "u64"
+        },
+        {
+          "name": "funder",
+          "type": "publicKey"
         }
       ]
     },
     {
-      "name": "addLiquidity",
+      "name": "fundReward",
       "accounts": [
         {
-          "name": "position",
+          "name": "lbPair",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "lbPair",
+          "name": "rewardVault",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "binArrayBitmapExtension",
-          "isMut": true,
-          "isSigner": false,
-          "isOptional": true
+          "name": "rewardMint",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "userTokenX",
+          "name": "funderTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userTokenY",
-          "isMut": true,
-          "isSigner": false
+          "name": "funder",
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "reserveX",
+          "name": "binArray",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "reserveY",
-          "isMut": true,
+          "name": "tokenProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenXMint",
+          "name": "eventAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenYMint",
+          "name": "program",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "rewardIndex",
+          "type": "u64"
         },
         {
-          "name": "binArrayLower",
-          "isMut": true,
-          "isSigner": false
+          "name": "amount",
+          "type": "u64"
         },
         {
-          "name": "binArrayUpper",
+          "name": "carryForward",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "updateRewardFunder",
+      "accounts": [
+        {
+          "name": "lbPair",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "sender",
+          "name": "admin",
           "isMut": false,
           "isSigner": true
         },
         {
-          "name": "tokenXProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenYProgram",
+          "name": "adminConfig",
           "isMut": false,
           "isSigner": false
         },
@@ -5785,60 +9387,71 @@ export const IDL: LbClmm = {
       ],
       "args": [
         {
-          "name": "liquidityParameter",
-          "type": {
-            "defined": "LiquidityParameter"
-          }
+          "name": "rewardIndex",
+          "type": "u64"
+        },
+        {
+          "name": "newFunder",
+          "type": "publicKey"
         }
       ]
     },
     {
-      "name": "addLiquidityByWeight",
+      "name": "updateRewardDuration",
       "accounts": [
-        {
-          "name": "position",
-          "isMut": true,
-          "isSigner": false
-        },
         {
           "name": "lbPair",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "binArrayBitmapExtension",
-          "isMut": true,
-          "isSigner": false,
-          "isOptional": true
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "userTokenX",
+          "name": "binArray",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userTokenY",
-          "isMut": true,
+          "name": "adminConfig",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "reserveX",
-          "isMut": true,
+          "name": "eventAuthority",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "reserveY",
-          "isMut": true,
+          "name": "program",
+          "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "rewardIndex",
+          "type": "u64"
         },
         {
-          "name": "tokenXMint",
-          "isMut": false,
+          "name": "newDuration",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "claimReward",
+      "accounts": [
+        {
+          "name": "lbPair",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenYMint",
-          "isMut": false,
+          "name": "position",
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -5857,15 +9470,34 @@ export const IDL: LbClmm = {
           "isSigner": true
         },
         {
-          "name": "tokenXProgram",
+          "name": "rewardVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenYProgram",
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "positionNftAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Token account holding the position NFT. Only required for tokenized position."
+          ]
+        },
         {
           "name": "eventAuthority",
           "isMut": false,
@@ -5879,41 +9511,38 @@ export const IDL: LbClmm = {
       ],
       "args": [
         {
-          "name": "liquidityParameter",
-          "type": {
-            "defined": "LiquidityParameterByWeight"
-          }
+          "name": "rewardIndex",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "addLiquidityByStrategy",
+      "name": "claimFee",
       "accounts": [
         {
-          "name": "position",
+          "name": "lbPair",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "lbPair",
+          "name": "position",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "binArrayBitmapExtension",
+          "name": "binArrayLower",
           "isMut": true,
-          "isSigner": false,
-          "isOptional": true
+          "isSigner": false
         },
         {
-          "name": "userTokenX",
+          "name": "binArrayUpper",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userTokenY",
-          "isMut": true,
-          "isSigner": false
+          "name": "sender",
+          "isMut": false,
+          "isSigner": true
         },
         {
           "name": "reserveX",
@@ -5926,39 +9555,44 @@ export const IDL: LbClmm = {
           "isSigner": false
         },
         {
-          "name": "tokenXMint",
-          "isMut": false,
+          "name": "userTokenX",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenYMint",
-          "isMut": false,
+          "name": "userTokenY",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "binArrayLower",
-          "isMut": true,
+          "name": "tokenXMint",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "binArrayUpper",
-          "isMut": true,
+          "name": "tokenYMint",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "sender",
+          "name": "tokenProgram",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "tokenXProgram",
+          "name": "positionNftAccount",
           "isMut": false,
-          "isSigner": false
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Token account holding the position NFT. Only required for tokenized position."
+          ]
         },
         {
-          "name": "tokenYProgram",
+          "name": "adminConfig",
           "isMut": false,
-          "isSigner": false
+          "isSigner": false,
+          "isOptional": true
         },
         {
           "name": "eventAuthority",
@@ -5971,17 +9605,10 @@ export const IDL: LbClmm = {
           "isSigner": false
         }
       ],
-      "args": [
-        {
-          "name": "liquidityParameter",
-          "type": {
-            "defined": "LiquidityParameterByStrategy"
-          }
-        }
-      ]
+      "args": []
     },
     {
-      "name": "addLiquidityByStrategyOneSide",
+      "name": "closePosition",
       "accounts": [
         {
           "name": "position",
@@ -5994,43 +9621,62 @@ export const IDL: LbClmm = {
           "isSigner": false
         },
         {
-          "name": "binArrayBitmapExtension",
+          "name": "binArrayLower",
           "isMut": true,
-          "isSigner": false,
-          "isOptional": true
+          "isSigner": false
         },
         {
-          "name": "userToken",
+          "name": "binArrayUpper",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "reserve",
+          "name": "sender",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "rentReceiver",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenMint",
+          "name": "positionNftAccount",
           "isMut": false,
-          "isSigner": false
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Token account holding the position NFT. Only required for tokenized position."
+          ]
         },
         {
-          "name": "binArrayLower",
-          "isMut": true,
+          "name": "eventAuthority",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "binArrayUpper",
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateFeeParameters",
+      "accounts": [
+        {
+          "name": "lbPair",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "sender",
+          "name": "admin",
           "isMut": false,
           "isSigner": true
         },
         {
-          "name": "tokenProgram",
+          "name": "adminConfig",
           "isMut": false,
           "isSigner": false
         },
@@ -6047,89 +9693,115 @@ export const IDL: LbClmm = {
       ],
       "args": [
         {
-          "name": "liquidityParameter",
+          "name": "feeParameter",
           "type": {
-            "defined": "LiquidityParameterByStrategyOneSide"
+            "defined": "FeeParameter"
           }
         }
       ]
     },
     {
-      "name": "addLiquidityOneSide",
+      "name": "increaseOracleLength",
       "accounts": [
         {
-          "name": "position",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "lbPair",
+          "name": "oracle",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "binArrayBitmapExtension",
+          "name": "funder",
           "isMut": true,
-          "isSigner": false,
-          "isOptional": true
+          "isSigner": true
         },
         {
-          "name": "userToken",
-          "isMut": true,
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "reserve",
-          "isMut": true,
+          "name": "eventAuthority",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenMint",
+          "name": "program",
           "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "binArrayLower",
-          "isMut": true,
-          "isSigner": false
-        },
+          "name": "lengthToAdd",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "initializePresetParameter",
+      "accounts": [
         {
-          "name": "binArrayUpper",
+          "name": "presetParameter",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "sender",
-          "isMut": false,
+          "name": "admin",
+          "isMut": true,
           "isSigner": true
         },
         {
-          "name": "tokenProgram",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "eventAuthority",
+          "name": "rent",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "program",
+          "name": "adminConfig",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "liquidityParameter",
+          "name": "ix",
           "type": {
-            "defined": "LiquidityOneSideParameter"
+            "defined": "InitPresetParametersIx"
           }
         }
       ]
     },
     {
-      "name": "removeLiquidity",
+      "name": "closePresetParameter",
+      "accounts": [
+        {
+          "name": "presetParameter",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rentReceiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "adminConfig",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "removeAllLiquidity",
       "accounts": [
         {
           "name": "position",
@@ -6202,6 +9874,15 @@ export const IDL: LbClmm = {
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "positionNftAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Token account holding the position NFT. Only required for tokenized position."
+          ]
+        },
         {
           "name": "eventAuthority",
           "isMut": false,
@@ -6213,102 +9894,61 @@ export const IDL: LbClmm = {
           "isSigner": false
         }
       ],
-      "args": [
-        {
-          "name": "binLiquidityRemoval",
-          "type": {
-            "vec": {
-              "defined": "BinLiquidityReduction"
-            }
-          }
-        }
-      ]
+      "args": []
     },
     {
-      "name": "initializePosition",
+      "name": "togglePairStatus",
       "accounts": [
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "position",
-          "isMut": true,
-          "isSigner": true
-        },
         {
           "name": "lbPair",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "owner",
+          "name": "admin",
           "isMut": false,
           "isSigner": true
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "eventAuthority",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "program",
+          "name": "adminConfig",
           "isMut": false,
           "isSigner": false
         }
       ],
-      "args": [
-        {
-          "name": "lowerBinId",
-          "type": "i32"
-        },
-        {
-          "name": "width",
-          "type": "i32"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "initializePositionPda",
+      "name": "migratePosition",
       "accounts": [
         {
-          "name": "payer",
+          "name": "positionV2",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "base",
+          "name": "positionV1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lbPair",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "position",
+          "name": "binArrayLower",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "lbPair",
-          "isMut": false,
+          "name": "binArrayUpper",
+          "isMut": true,
           "isSigner": false
         },
         {
           "name": "owner",
-          "isMut": false,
-          "isSigner": true,
-          "docs": [
-            "owner"
-          ]
+          "isMut": true,
+          "isSigner": true
         },
         {
           "name": "systemProgram",
@@ -6316,8 +9956,8 @@ export const IDL: LbClmm = {
           "isSigner": false
         },
         {
-          "name": "rent",
-          "isMut": false,
+          "name": "rentReceiver",
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -6331,65 +9971,94 @@ export const IDL: LbClmm = {
           "isSigner": false
         }
       ],
-      "args": [
-        {
-          "name": "lowerBinId",
-          "type": "i32"
-        },
+      "args": []
+    },
+    {
+      "name": "migrateBinArray",
+      "accounts": [
         {
-          "name": "width",
-          "type": "i32"
+          "name": "lbPair",
+          "isMut": false,
+          "isSigner": false
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "initializePositionByOperator",
+      "name": "updateFeesAndRewards",
       "accounts": [
         {
-          "name": "payer",
+          "name": "position",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "base",
-          "isMut": false,
-          "isSigner": true
+          "name": "lbPair",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "position",
+          "name": "binArrayLower",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "lbPair",
-          "isMut": false,
+          "name": "binArrayUpper",
+          "isMut": true,
           "isSigner": false
         },
         {
           "name": "owner",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "operator",
+          "name": "positionNftAccount",
           "isMut": false,
-          "isSigner": true,
+          "isSigner": false,
+          "isOptional": true,
           "docs": [
-            "operator"
+            "Token account holding the position NFT. Only required for tokenized position."
           ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "withdrawIneligibleReward",
+      "accounts": [
+        {
+          "name": "lbPair",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "operatorTokenX",
+          "name": "rewardVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "ownerTokenX",
+          "name": "funderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "funder",
           "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "binArray",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -6406,57 +10075,40 @@ export const IDL: LbClmm = {
       ],
       "args": [
         {
-          "name": "lowerBinId",
-          "type": "i32"
-        },
-        {
-          "name": "width",
-          "type": "i32"
-        },
-        {
-          "name": "feeOwner",
-          "type": "publicKey"
-        },
-        {
-          "name": "lockReleasePoint",
+          "name": "rewardIndex",
           "type": "u64"
         }
       ]
     },
     {
-      "name": "updatePositionOperator",
+      "name": "setActivationPoint",
       "accounts": [
         {
-          "name": "position",
+          "name": "lbPair",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "owner",
-          "isMut": false,
+          "name": "admin",
+          "isMut": true,
           "isSigner": true
-        },
-        {
-          "name": "eventAuthority",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "program",
-          "isMut": false,
-          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "operator",
-          "type": "publicKey"
+          "name": "activationPoint",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "swap",
+      "name": "removeLiquidityByRange",
       "accounts": [
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "lbPair",
           "isMut": true,
@@ -6464,27 +10116,27 @@ export const IDL: LbClmm = {
         },
         {
           "name": "binArrayBitmapExtension",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false,
           "isOptional": true
         },
         {
-          "name": "reserveX",
+          "name": "userTokenX",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "reserveY",
+          "name": "userTokenY",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userTokenIn",
+          "name": "reserveX",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userTokenOut",
+          "name": "reserveY",
           "isMut": true,
           "isSigner": false
         },
@@ -6499,18 +10151,17 @@ export const IDL: LbClmm = {
           "isSigner": false
         },
         {
-          "name": "oracle",
+          "name": "binArrayLower",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "hostFeeIn",
+          "name": "binArrayUpper",
           "isMut": true,
-          "isSigner": false,
-          "isOptional": true
+          "isSigner": false
         },
         {
-          "name": "user",
+          "name": "sender",
           "isMut": false,
           "isSigner": true
         },
@@ -6537,18 +10188,27 @@ export const IDL: LbClmm = {
       ],
       "args": [
         {
-          "name": "amountIn",
-          "type": "u64"
+          "name": "fromBinId",
+          "type": "i32"
         },
         {
-          "name": "minAmountOut",
-          "type": "u64"
+          "name": "toBinId",
+          "type": "i32"
+        },
+        {
+          "name": "bpsToRemove",
+          "type": "u16"
         }
       ]
     },
     {
-      "name": "swapExactOut",
+      "name": "addLiquidityOneSidePrecise",
       "accounts": [
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "lbPair",
           "isMut": true,
@@ -6556,90 +10216,162 @@ export const IDL: LbClmm = {
         },
         {
           "name": "binArrayBitmapExtension",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false,
           "isOptional": true
         },
         {
-          "name": "reserveX",
+          "name": "userToken",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "reserveY",
+          "name": "reserve",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userTokenIn",
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "binArrayLower",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userTokenOut",
+          "name": "binArrayUpper",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenXMint",
+          "name": "sender",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenYMint",
+          "name": "positionNftAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Token account holding the position NFT. Only required for tokenized position."
+          ]
+        },
+        {
+          "name": "eventAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "oracle",
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "parameter",
+          "type": {
+            "defined": "AddLiquiditySingleSidePreciseParameter"
+          }
+        }
+      ]
+    },
+    {
+      "name": "goToABin",
+      "accounts": [
+        {
+          "name": "lbPair",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "hostFeeIn",
-          "isMut": true,
+          "name": "binArrayBitmapExtension",
+          "isMut": false,
           "isSigner": false,
           "isOptional": true
         },
         {
-          "name": "user",
+          "name": "fromBinArray",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false,
+          "isOptional": true
         },
         {
-          "name": "tokenXProgram",
+          "name": "toBinArray",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "eventAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenYProgram",
+          "name": "program",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "binId",
+          "type": "i32"
+        }
+      ]
+    },
+    {
+      "name": "setPreActivationDuration",
+      "accounts": [
+        {
+          "name": "lbPair",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "eventAuthority",
+          "name": "creator",
           "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "preActivationDuration",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setPreActivationSwapAddress",
+      "accounts": [
+        {
+          "name": "lbPair",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "program",
+          "name": "creator",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         }
       ],
       "args": [
         {
-          "name": "maxInAmount",
-          "type": "u64"
-        },
-        {
-          "name": "outAmount",
-          "type": "u64"
+          "name": "preActivationSwapAddress",
+          "type": "publicKey"
         }
       ]
     },
     {
-      "name": "swapWithPriceImpact",
+      "name": "swapExactOut",
       "accounts": [
         {
           "name": "lbPair",
@@ -6721,23 +10453,17 @@ export const IDL: LbClmm = {
       ],
       "args": [
         {
-          "name": "amountIn",
+          "name": "maxInAmount",
           "type": "u64"
         },
         {
-          "name": "activeId",
-          "type": {
-            "option": "i32"
-          }
-        },
-        {
-          "name": "maxPriceImpactBps",
-          "type": "u16"
+          "name": "outAmount",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "withdrawProtocolFee",
+      "name": "swapWithPriceImpact",
       "accounts": [
         {
           "name": "lbPair",
@@ -6745,156 +10471,64 @@ export const IDL: LbClmm = {
           "isSigner": false
         },
         {
-          "name": "reserveX",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "reserveY",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "tokenXMint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenYMint",
+          "name": "binArrayBitmapExtension",
           "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "receiverTokenX",
-          "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "isOptional": true
         },
         {
-          "name": "receiverTokenY",
+          "name": "reserveX",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenXProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenYProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "amountX",
-          "type": "u64"
-        },
-        {
-          "name": "amountY",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "initializeReward",
-      "accounts": [
-        {
-          "name": "lbPair",
+          "name": "reserveY",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "rewardVault",
+          "name": "userTokenIn",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "rewardMint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "admin",
+          "name": "userTokenOut",
           "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "rent",
-          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "eventAuthority",
+          "name": "tokenXMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "program",
+          "name": "tokenYMint",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "rewardIndex",
-          "type": "u64"
-        },
-        {
-          "name": "rewardDuration",
-          "type": "u64"
-        },
-        {
-          "name": "funder",
-          "type": "publicKey"
-        }
-      ]
-    },
-    {
-      "name": "fundReward",
-      "accounts": [
-        {
-          "name": "lbPair",
-          "isMut": true,
-          "isSigner": false
         },
         {
-          "name": "rewardVault",
+          "name": "oracle",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "rewardMint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "funderTokenAccount",
+          "name": "hostFeeIn",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "isOptional": true
         },
         {
-          "name": "funder",
+          "name": "user",
           "isMut": false,
           "isSigner": true
         },
         {
-          "name": "binArray",
-          "isMut": true,
+          "name": "tokenXProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "tokenYProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -6911,162 +10545,93 @@ export const IDL: LbClmm = {
       ],
       "args": [
         {
-          "name": "rewardIndex",
-          "type": "u64"
-        },
-        {
-          "name": "amount",
+          "name": "amountIn",
           "type": "u64"
         },
         {
-          "name": "carryForward",
-          "type": "bool"
-        }
-      ]
-    },
-    {
-      "name": "updateRewardFunder",
-      "accounts": [
-        {
-          "name": "lbPair",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "admin",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "eventAuthority",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "program",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "rewardIndex",
-          "type": "u64"
+          "name": "activeId",
+          "type": {
+            "option": "i32"
+          }
         },
         {
-          "name": "newFunder",
-          "type": "publicKey"
+          "name": "maxPriceImpactBps",
+          "type": "u16"
         }
       ]
     },
     {
-      "name": "updateRewardDuration",
+      "name": "flashBorrow",
       "accounts": [
         {
           "name": "lbPair",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "admin",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "binArray",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "eventAuthority",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "program",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "rewardIndex",
-          "type": "u64"
         },
         {
-          "name": "newDuration",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "claimReward",
-      "accounts": [
-        {
-          "name": "lbPair",
+          "name": "reserveX",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "position",
+          "name": "reserveY",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "binArrayLower",
+          "name": "userTokenX",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "binArrayUpper",
+          "name": "userTokenY",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "sender",
+          "name": "tokenXMint",
           "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "rewardVault",
-          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "rewardMint",
+          "name": "tokenYMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "userTokenAccount",
-          "isMut": true,
-          "isSigner": false
+          "name": "user",
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "tokenProgram",
+          "name": "tokenXProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "eventAuthority",
+          "name": "tokenYProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "program",
+          "name": "instructionsSysvar",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "rewardIndex",
+          "name": "amountX",
+          "type": "u64"
+        },
+        {
+          "name": "amountY",
           "type": "u64"
         }
       ]
     },
     {
-      "name": "claimFee",
+      "name": "flashRepay",
       "accounts": [
         {
           "name": "lbPair",
@@ -7074,24 +10639,12 @@ export const IDL: LbClmm = {
           "isSigner": false
         },
         {
-          "name": "position",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "binArrayLower",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "binArrayUpper",
+          "name": "binArray",
           "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "sender",
-          "isMut": false,
-          "isSigner": true
+          "isSigner": false,
+          "docs": [
+            "Bin array of the active bin, which receive the LP share of the fee"
+          ]
         },
         {
           "name": "reserveX",
@@ -7124,54 +10677,23 @@ export const IDL: LbClmm = {
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "user",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "eventAuthority",
+          "name": "tokenXProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "program",
+          "name": "tokenYProgram",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "closePosition",
-      "accounts": [
-        {
-          "name": "position",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "lbPair",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "binArrayLower",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "binArrayUpper",
-          "isMut": true,
-          "isSigner": false
         },
         {
-          "name": "sender",
+          "name": "instructionsSysvar",
           "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "rentReceiver",
-          "isMut": true,
           "isSigner": false
         },
         {
@@ -7185,10 +10707,15 @@ export const IDL: LbClmm = {
           "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "borrowInstructionIndex",
+          "type": "u16"
+        }
+      ]
     },
     {
-      "name": "updateFeeParameters",
+      "name": "updateCircuitBreaker",
       "accounts": [
         {
           "name": "lbPair",
@@ -7200,6 +10727,11 @@ export const IDL: LbClmm = {
           "isMut": false,
           "isSigner": true
         },
+        {
+          "name": "adminConfig",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "eventAuthority",
           "isMut": false,
@@ -7213,23 +10745,28 @@ export const IDL: LbClmm = {
       ],
       "args": [
         {
-          "name": "feeParameter",
+          "name": "parameter",
           "type": {
-            "defined": "FeeParameter"
+            "defined": "CircuitBreakerParameter"
           }
         }
       ]
     },
     {
-      "name": "increaseOracleLength",
+      "name": "initializeReferrer",
       "accounts": [
         {
-          "name": "oracle",
+          "name": "referrer",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "funder",
+          "name": "owner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
           "isMut": true,
           "isSigner": true
         },
@@ -7238,6 +10775,11 @@ export const IDL: LbClmm = {
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "adminConfig",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "eventAuthority",
           "isMut": false,
@@ -7251,73 +10793,157 @@ export const IDL: LbClmm = {
       ],
       "args": [
         {
-          "name": "lengthToAdd",
-          "type": "u64"
+          "name": "feeShare",
+          "type": "u16"
         }
       ]
     },
     {
-      "name": "initializePresetParameter",
+      "name": "updateReferrerFeeShare",
       "accounts": [
         {
-          "name": "presetParameter",
+          "name": "referrer",
           "isMut": true,
           "isSigner": false
         },
         {
           "name": "admin",
-          "isMut": true,
+          "isMut": false,
           "isSigner": true
         },
         {
-          "name": "systemProgram",
+          "name": "adminConfig",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "ix",
-          "type": {
-            "defined": "InitPresetParametersIx"
-          }
+          "name": "feeShare",
+          "type": "u16"
         }
       ]
     },
     {
-      "name": "closePresetParameter",
+      "name": "initializeReferralFee",
       "accounts": [
         {
-          "name": "presetParameter",
+          "name": "referralFee",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "admin",
+          "name": "referrer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lbPair",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "rentReceiver",
-          "isMut": true,
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
         }
       ],
       "args": []
     },
     {
-      "name": "removeAllLiquidity",
+      "name": "claimReferralFee",
       "accounts": [
         {
-          "name": "position",
+          "name": "lbPair",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "referralFee",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referrer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "receiverTokenX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "receiverTokenY",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "tokenXMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "swapV2",
+      "accounts": [
         {
           "name": "lbPair",
           "isMut": true,
@@ -7325,27 +10951,27 @@ export const IDL: LbClmm = {
         },
         {
           "name": "binArrayBitmapExtension",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false,
           "isOptional": true
         },
         {
-          "name": "userTokenX",
+          "name": "reserveX",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userTokenY",
+          "name": "reserveY",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "reserveX",
+          "name": "userTokenIn",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "reserveY",
+          "name": "userTokenOut",
           "isMut": true,
           "isSigner": false
         },
@@ -7360,17 +10986,18 @@ export const IDL: LbClmm = {
           "isSigner": false
         },
         {
-          "name": "binArrayLower",
+          "name": "oracle",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "binArrayUpper",
+          "name": "hostFeeIn",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "isOptional": true
         },
         {
-          "name": "sender",
+          "name": "user",
           "isMut": false,
           "isSigner": true
         },
@@ -7395,10 +11022,17 @@ export const IDL: LbClmm = {
           "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "parameters",
+          "type": {
+            "defined": "SwapV2Parameters"
+          }
+        }
+      ]
     },
     {
-      "name": "togglePairStatus",
+      "name": "claimFeeMultiple",
       "accounts": [
         {
           "name": "lbPair",
@@ -7406,56 +11040,56 @@ export const IDL: LbClmm = {
           "isSigner": false
         },
         {
-          "name": "admin",
+          "name": "sender",
           "isMut": false,
           "isSigner": true
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "migratePosition",
-      "accounts": [
+        },
         {
-          "name": "positionV2",
+          "name": "reserveX",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "positionV1",
+          "name": "reserveY",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "lbPair",
-          "isMut": false,
+          "name": "userTokenX",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "binArrayLower",
+          "name": "userTokenY",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "binArrayUpper",
-          "isMut": true,
+          "name": "tokenXMint",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "owner",
-          "isMut": true,
-          "isSigner": true
+          "name": "tokenYMint",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "tokenXProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rentReceiver",
-          "isMut": true,
+          "name": "tokenYProgram",
+          "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "adminConfig",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
         {
           "name": "eventAuthority",
           "isMut": false,
@@ -7467,57 +11101,25 @@ export const IDL: LbClmm = {
           "isSigner": false
         }
       ],
-      "args": []
-    },
-    {
-      "name": "migrateBinArray",
-      "accounts": [
+      "args": [
         {
-          "name": "lbPair",
-          "isMut": false,
-          "isSigner": false
+          "name": "positionCount",
+          "type": "u8"
         }
-      ],
-      "args": []
+      ]
     },
     {
-      "name": "updateFeesAndRewards",
+      "name": "claimAllRewards",
       "accounts": [
-        {
-          "name": "position",
-          "isMut": true,
-          "isSigner": false
-        },
         {
           "name": "lbPair",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "binArrayLower",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "binArrayUpper",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "owner",
+          "name": "sender",
           "isMut": false,
           "isSigner": true
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "withdrawIneligibleReward",
-      "accounts": [
-        {
-          "name": "lbPair",
-          "isMut": true,
-          "isSigner": false
         },
         {
           "name": "rewardVault",
@@ -7530,17 +11132,7 @@ export const IDL: LbClmm = {
           "isSigner": false
         },
         {
-          "name": "funderTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "funder",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "binArray",
+          "name": "userTokenAccount",
           "isMut": true,
           "isSigner": false
         },
@@ -7564,101 +11156,157 @@ export const IDL: LbClmm = {
         {
           "name": "rewardIndex",
           "type": "u64"
+        },
+        {
+          "name": "positionCount",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "setActivationPoint",
+      "name": "compoundFees",
       "accounts": [
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "lbPair",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "admin",
+          "name": "binArrayBitmapExtension",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "binArrayLower",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "binArrayUpper",
           "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sender",
+          "isMut": false,
           "isSigner": true
+        },
+        {
+          "name": "positionNftAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Token account holding the position NFT. Only required for tokenized position."
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "activationPoint",
-          "type": "u64"
+          "name": "parameter",
+          "type": {
+            "defined": "CompoundFeeParameter"
+          }
         }
       ]
     },
     {
-      "name": "removeLiquidityByRange",
+      "name": "closeBinArray",
       "accounts": [
-        {
-          "name": "position",
-          "isMut": true,
-          "isSigner": false
-        },
         {
           "name": "lbPair",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
           "name": "binArrayBitmapExtension",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false,
           "isOptional": true
         },
         {
-          "name": "userTokenX",
+          "name": "binArray",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userTokenY",
+          "name": "binArrayFunder",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "reserveX",
+          "name": "funder",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "reserveY",
-          "isMut": true,
+          "name": "eventAuthority",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenXMint",
+          "name": "program",
           "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeLegacyBinArray",
+      "accounts": [
         {
-          "name": "tokenYMint",
+          "name": "lbPair",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "binArrayLower",
-          "isMut": true,
-          "isSigner": false
+          "name": "binArrayBitmapExtension",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
         },
         {
-          "name": "binArrayUpper",
+          "name": "binArray",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "sender",
+          "name": "binArrayFunder",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "tokenXProgram",
+          "name": "authority",
           "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Creator of the pair, or the fee admin"
+          ]
+        },
+        {
+          "name": "rentReceiver",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenYProgram",
+          "name": "adminConfig",
           "isMut": false,
           "isSigner": false
         },
@@ -7673,23 +11321,10 @@ export const IDL: LbClmm = {
           "isSigner": false
         }
       ],
-      "args": [
-        {
-          "name": "fromBinId",
-          "type": "i32"
-        },
-        {
-          "name": "toBinId",
-          "type": "i32"
-        },
-        {
-          "name": "bpsToRemove",
-          "type": "u16"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "addLiquidityOneSidePrecise",
+      "name": "setPositionVesting",
       "accounts": [
         {
           "name": "position",
@@ -7697,48 +11332,45 @@ export const IDL: LbClmm = {
           "isSigner": false
         },
         {
-          "name": "lbPair",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "binArrayBitmapExtension",
-          "isMut": true,
-          "isSigner": false,
-          "isOptional": true
-        },
-        {
-          "name": "userToken",
-          "isMut": true,
-          "isSigner": false
+          "name": "operator",
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "reserve",
-          "isMut": true,
+          "name": "eventAuthority",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenMint",
+          "name": "program",
           "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "binArrayLower",
-          "isMut": true,
-          "isSigner": false
-        },
+          "name": "parameter",
+          "type": {
+            "defined": "VestingParameter"
+          }
+        }
+      ]
+    },
+    {
+      "name": "initializeAdminConfig",
+      "accounts": [
         {
-          "name": "binArrayUpper",
+          "name": "adminConfig",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "sender",
-          "isMut": false,
+          "name": "admin",
+          "isMut": true,
           "isSigner": true
         },
         {
-          "name": "tokenProgram",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -7757,36 +11389,63 @@ export const IDL: LbClmm = {
         {
           "name": "parameter",
           "type": {
-            "defined": "AddLiquiditySingleSidePreciseParameter"
+            "defined": "InitAdminConfigParameter"
           }
         }
       ]
     },
     {
-      "name": "goToABin",
+      "name": "proposeAdminRole",
       "accounts": [
         {
-          "name": "lbPair",
+          "name": "adminConfig",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "binArrayBitmapExtension",
+          "name": "authority",
           "isMut": false,
-          "isSigner": false,
-          "isOptional": true
+          "isSigner": true
         },
         {
-          "name": "fromBinArray",
+          "name": "eventAuthority",
           "isMut": false,
-          "isSigner": false,
-          "isOptional": true
+          "isSigner": false
         },
         {
-          "name": "toBinArray",
+          "name": "program",
           "isMut": false,
-          "isSigner": false,
-          "isOptional": true
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "role",
+          "type": {
+            "defined": "AdminRole"
+          }
+        },
+        {
+          "name": "newAuthority",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "acceptAdminRole",
+      "accounts": [
+        {
+          "name": "adminConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Pending authority of the role. Can be a PDA, such as a multisig vault, signing through CPI."
+          ]
         },
         {
           "name": "eventAuthority",
@@ -7801,34 +11460,49 @@ export const IDL: LbClmm = {
       ],
       "args": [
         {
-          "name": "binId",
-          "type": "i32"
+          "name": "role",
+          "type": {
+            "defined": "AdminRole"
+          }
         }
       ]
     },
     {
-      "name": "setPreActivationDuration",
+      "name": "swapRoute",
       "accounts": [
         {
-          "name": "lbPair",
+          "name": "userTokenIn",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "docs": [
+            "Token account the route swap from. Must be the user_token_in of the first hop."
+          ]
         },
         {
-          "name": "creator",
+          "name": "userTokenOut",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Token account the route swap to. Must be the user_token_out of the last hop."
+          ]
+        },
+        {
+          "name": "user",
           "isMut": false,
           "isSigner": true
         }
       ],
       "args": [
         {
-          "name": "preActivationDuration",
-          "type": "u64"
+          "name": "parameters",
+          "type": {
+            "defined": "SwapRouteParameters"
+          }
         }
       ]
     },
     {
-      "name": "setPreActivationSwapAddress",
+      "name": "updateOracleParameters",
       "accounts": [
         {
           "name": "lbPair",
@@ -7836,20 +11510,72 @@ export const IDL: LbClmm = {
           "isSigner": false
         },
         {
-          "name": "creator",
+          "name": "admin",
           "isMut": false,
           "isSigner": true
+        },
+        {
+          "name": "adminConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "preActivationSwapAddress",
-          "type": "publicKey"
+          "name": "parameter",
+          "type": {
+            "defined": "OracleParameter"
+          }
         }
       ]
     }
   ],
   "accounts": [
+    {
+      "name": "adminConfig",
+      "docs": [
+        "Authorities of the admin roles. Once initialized, it replaces the predefined admins of the program."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authorities",
+            "docs": [
+              "Authority of each role, indexed by AdminRole"
+            ],
+            "type": {
+              "array": [
+                "publicKey",
+                4
+              ]
+            }
+          },
+          {
+            "name": "pendingAuthorities",
+            "docs": [
+              "Authority proposed for each role, pending to be accepted. Default when there is no pending change."
+            ],
+            "type": {
+              "array": [
+                "publicKey",
+                4
+              ]
+            }
+          }
+        ]
+      }
+    },
     {
       "name": "binArrayBitmapExtension",
       "type": {
@@ -7896,6 +11622,31 @@ export const IDL: LbClmm = {
         ]
       }
     },
+    {
+      "name": "binArrayFunder",
+      "docs": [
+        "Record the funder of a bin array, which receive the rent back when the bin array is closed."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "binArray",
+            "docs": [
+              "Bin array funded"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "funder",
+            "docs": [
+              "Address paid the rent of the bin array"
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
     {
       "name": "binArray",
       "docs": [
@@ -8169,7 +11920,7 @@ export const IDL: LbClmm = {
           {
             "name": "preActivationDuration",
             "docs": [
-              "Duration before activation activation_point. Used to calculate pre-activation time point for pre_activation_swap_address"
+              "Duration before activation point. Used to calculate pre-activation point for pre_activation_swap_address"
             ],
             "type": "u64"
           },
@@ -8200,15 +11951,21 @@ export const IDL: LbClmm = {
             "type": "publicKey"
           },
           {
-            "name": "reserved",
+            "name": "circuitBreaker",
             "docs": [
-              "Reserved space for future use"
+              "Circuit breaker parameters"
             ],
             "type": {
-              "array": [
-                "u8",
-                24
-              ]
+              "defined": "CircuitBreakerParameters"
+            }
+          },
+          {
+            "name": "oracleParameters",
+            "docs": [
+              "Oracle parameters"
+            ],
+            "type": {
+              "defined": "OracleParameters"
             }
           }
         ]
@@ -8498,6 +12255,53 @@ export const IDL: LbClmm = {
             ],
             "type": "publicKey"
           },
+          {
+            "name": "positionNftMint",
+            "docs": [
+              "Mint of the NFT representing the ownership of this position. Default for non-tokenized position."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "padding1",
+            "docs": [
+              "Padding to align the vesting schedule"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                7
+              ]
+            }
+          },
+          {
+            "name": "vestingStartPoint",
+            "docs": [
+              "Point which the linear vesting of the liquidity starts"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "vestingCliffPoint",
+            "docs": [
+              "Point before which none of the vesting liquidity can be withdrawn"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "vestingEndPoint",
+            "docs": [
+              "Point which the liquidity is fully vested. 0 for position without vesting."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "vestingRemainingRatio",
+            "docs": [
+              "Ratio of the vesting liquidity remaining in the position, in VESTING_RATIO_PRECISION"
+            ],
+            "type": "u64"
+          },
           {
             "name": "reserved",
             "docs": [
@@ -8506,7 +12310,7 @@ export const IDL: LbClmm = {
             "type": {
               "array": [
                 "u8",
-                87
+                16
               ]
             }
           }
@@ -8590,9 +12394,109 @@ export const IDL: LbClmm = {
           }
         ]
       }
+    },
+    {
+      "name": "referrer",
+      "docs": [
+        "Referrer registered by the admin. Swaps referred by the referrer share the protocol fee with it."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "docs": [
+              "Owner of the referrer, allowed to claim the referral fee"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "feeShare",
+            "docs": [
+              "Portion of the protocol fee shared with the referrer, in bps"
+            ],
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "referralFee",
+      "docs": [
+        "Referral fee of a referrer accumulated in a pair. The fee stay in the pair reserves until claimed."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "referrer",
+            "docs": [
+              "Referrer earning the fee"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "lbPair",
+            "docs": [
+              "Liquidity pair the fee was collected from"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "feeX",
+            "docs": [
+              "Claimable fee of token X"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "feeY",
+            "docs": [
+              "Claimable fee of token Y"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     }
   ],
   "types": [
+    {
+      "name": "InitAdminConfigParameter",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feeAdmin",
+            "docs": [
+              "Authority of the fee admin role"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "pauseGuardian",
+            "docs": [
+              "Authority of the pause guardian role"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "rewardAdmin",
+            "docs": [
+              "Authority of the reward admin role"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "launchPoolAdmin",
+            "docs": [
+              "Authority of the launch pool admin role"
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
     {
       "name": "InitPresetParametersIx",
       "type": {
@@ -8671,6 +12575,35 @@ export const IDL: LbClmm = {
         ]
       }
     },
+    {
+      "name": "CircuitBreakerParameter",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxBinMovement",
+            "docs": [
+              "Maximum number of bins the active bin can move away from the time weighted active bin of the window. 0 = disabled."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "window",
+            "docs": [
+              "Time window, in seconds, of the time weighted active bin measured from the oracle"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "cooldown",
+            "docs": [
+              "Duration, in seconds, swap stay halted after the circuit breaker tripped"
+            ],
+            "type": "u32"
+          }
+        ]
+      }
+    },
     {
       "name": "FeeParameter",
       "type": {
@@ -8693,6 +12626,66 @@ export const IDL: LbClmm = {
         ]
       }
     },
+    {
+      "name": "OracleParameter",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sampleLifetime",
+            "docs": [
+              "Duration, in seconds, before a new observation sample is created"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "targetHistoryDuration",
+            "docs": [
+              "Duration, in seconds, of the history the oracle should cover"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "autoGrowth",
+            "docs": [
+              "Whether swap grow the oracle until the target history duration is covered"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CompoundFeeParameter",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "activeId",
+            "docs": [
+              "Active bin that integrator observe off-chain"
+            ],
+            "type": "i32"
+          },
+          {
+            "name": "maxActiveBinSlippage",
+            "docs": [
+              "max active bin slippage allowed"
+            ],
+            "type": "i32"
+          },
+          {
+            "name": "strategyType",
+            "docs": [
+              "Strategy to distribute the fee into the bins of the position. Only balanced strategies are supported."
+            ],
+            "type": {
+              "defined": "StrategyType"
+            }
+          }
+        ]
+      }
+    },
     {
       "name": "LiquidityParameterByStrategyOneSide",
       "type": {
@@ -9115,6 +13108,10 @@ export const IDL: LbClmm = {
             "name": "maxBinId",
             "type": "i32"
           },
+          {
+            "name": "lockDuration",
+            "type": "u64"
+          },
           {
             "name": "activationType",
             "type": "u8"
@@ -9122,6 +13119,146 @@ export const IDL: LbClmm = {
         ]
       }
     },
+    {
+      "name": "VestingParameter",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "startPoint",
+            "docs": [
+              "Point which the linear vesting starts"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "cliffPoint",
+            "docs": [
+              "Point before which none of the liquidity can be withdrawn"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "endPoint",
+            "docs": [
+              "Point which the liquidity is fully vested"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapRouteHopParameter",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "binArrayCount",
+            "docs": [
+              "Number of bin arrays of the hop, following the Swap accounts of the hop"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "transferHookAccountCount",
+            "docs": [
+              "Number of extra accounts required by the transfer hooks of token X and Y of the hop, following the bin arrays"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "referred",
+            "docs": [
+              "Whether the referrer and its referral fee account of the pair follow the transfer hook accounts"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapRouteParameters",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amountIn",
+            "docs": [
+              "Amount of token to be swapped in at the first hop"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "minAmountOut",
+            "docs": [
+              "Minimum amount of token to be swapped out at the last hop"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "hops",
+            "docs": [
+              "Accounts of each hop, in the order of the route"
+            ],
+            "type": {
+              "vec": {
+                "defined": "SwapRouteHopParameter"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapV2Parameters",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amountIn",
+            "docs": [
+              "Amount of token to be swapped in"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "minAmountOut",
+            "docs": [
+              "Minimum amount of token to be swapped out"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "deadline",
+            "docs": [
+              "Last point the swap can be executed. Slot or timestamp based on the activation type of the pair"
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "maxPriceImpactBps",
+            "docs": [
+              "Maximum price movement of the active bin, in basis point"
+            ],
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "maxBinMovement",
+            "docs": [
+              "Maximum number of bins the active bin can move"
+            ],
+            "type": {
+              "option": "u16"
+            }
+          }
+        ]
+      }
+    },
     {
       "name": "BinLiquidityReduction",
       "type": {
@@ -9447,31 +13584,109 @@ export const IDL: LbClmm = {
             "docs": [
               "Padding for bytemuck safe alignment"
             ],
-            "type": {
-              "array": [
-                "u8",
-                4
-              ]
-            }
+            "type": {
+              "array": [
+                "u8",
+                4
+              ]
+            }
+          },
+          {
+            "name": "lastUpdateTimestamp",
+            "docs": [
+              "Last timestamp the variable parameters was updated"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "padding1",
+            "docs": [
+              "Padding for bytemuck safe alignment"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "CircuitBreakerParameters",
+      "docs": [
+        "Circuit breaker halting swap when the active bin move too far away from the time weighted active bin of the oracle"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxBinMovement",
+            "docs": [
+              "Maximum number of bins the active bin can move away from the time weighted active bin of the window. 0 = disabled."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "window",
+            "docs": [
+              "Time window, in seconds, of the time weighted active bin."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "cooldown",
+            "docs": [
+              "Duration, in seconds, swap stay halted after the circuit breaker tripped."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "haltedUntil",
+            "docs": [
+              "Timestamp until which swap is halted."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OracleParameters",
+      "docs": [
+        "Oracle sampling of the pair. The default value keep the default sample lifetime, and the oracle only grow by increase_oracle_length"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sampleLifetime",
+            "docs": [
+              "Duration, in seconds, before a new observation sample is created. 0 = default sample lifetime."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "autoGrowth",
+            "docs": [
+              "Whether swap grow the oracle until the target history duration is covered. 0 = disabled."
+            ],
+            "type": "u8"
           },
           {
-            "name": "lastUpdateTimestamp",
+            "name": "padding",
             "docs": [
-              "Last timestamp the variable parameters was updated"
+              "padding"
             ],
-            "type": "i64"
+            "type": "u8"
           },
           {
-            "name": "padding1",
+            "name": "targetHistoryDuration",
             "docs": [
-              "Padding for bytemuck safe alignment"
+              "Duration, in seconds, of the history the oracle should cover."
             ],
-            "type": {
-              "array": [
-                "u8",
-                8
-              ]
-            }
+            "type": "u32"
           }
         ]
       }
@@ -9592,6 +13807,29 @@ export const IDL: LbClmm = {
         ]
       }
     },
+    {
+      "name": "AdminRole",
+      "docs": [
+        "Admin role. 0 = FeeAdmin, 1 = PauseGuardian, 2 = RewardAdmin, 3 = LaunchPoolAdmin"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "FeeAdmin"
+          },
+          {
+            "name": "PauseGuardian"
+          },
+          {
+            "name": "RewardAdmin"
+          },
+          {
+            "name": "LaunchPoolAdmin"
+          }
+        ]
+      }
+    },
     {
       "name": "LayoutVersion",
       "docs": [
@@ -9610,81 +13848,321 @@ export const IDL: LbClmm = {
       }
     },
     {
-      "name": "PairType",
-      "docs": [
-        "Type of the Pair. 0 = Permissionless, 1 = Permission, 2 = CustomizablePermissionless. Putting 0 as permissionless for backward compatibility."
-      ],
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Permissionless"
-          },
-          {
-            "name": "Permission"
-          },
-          {
-            "name": "CustomizablePermissionless"
-          }
-        ]
-      }
+      "name": "PairType",
+      "docs": [
+        "Type of the Pair. 0 = Permissionless, 1 = Permission, 2 = CustomizablePermissionless"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Permissionless"
+          },
+          {
+            "name": "Permission"
+          },
+          {
+            "name": "CustomizablePermissionless"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PairStatus",
+      "docs": [
+        "Pair status. 0 = Enabled, 1 = Disabled. Putting 0 as enabled for backward compatibility."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Enabled"
+          },
+          {
+            "name": "Disabled"
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "CompositionFee",
+      "fields": [
+        {
+          "name": "from",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "binId",
+          "type": "i16",
+          "index": false
+        },
+        {
+          "name": "tokenXFeeAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "tokenYFeeAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "protocolTokenXFeeAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "protocolTokenYFeeAmount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AddLiquidity",
+      "fields": [
+        {
+          "name": "lbPair",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "from",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "position",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amounts",
+          "type": {
+            "array": [
+              "u64",
+              2
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "activeBinId",
+          "type": "i32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RemoveLiquidity",
+      "fields": [
+        {
+          "name": "lbPair",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "from",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "position",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amounts",
+          "type": {
+            "array": [
+              "u64",
+              2
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "activeBinId",
+          "type": "i32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "Swap",
+      "fields": [
+        {
+          "name": "lbPair",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "from",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "startBinId",
+          "type": "i32",
+          "index": false
+        },
+        {
+          "name": "endBinId",
+          "type": "i32",
+          "index": false
+        },
+        {
+          "name": "amountIn",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountOut",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "swapForY",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "fee",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "protocolFee",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "feeBps",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "hostFee",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ClaimReward",
+      "fields": [
+        {
+          "name": "lbPair",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "position",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "rewardIndex",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "totalReward",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "FundReward",
+      "fields": [
+        {
+          "name": "lbPair",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "funder",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "rewardIndex",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "PairStatus",
-      "docs": [
-        "Pair status. 0 = Enabled, 1 = Disabled. Putting 0 as enabled for backward compatibility."
-      ],
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Enabled"
-          },
-          {
-            "name": "Disabled"
-          }
-        ]
-      }
-    }
-  ],
-  "events": [
-    {
-      "name": "CompositionFee",
+      "name": "InitializeReward",
       "fields": [
         {
-          "name": "from",
+          "name": "lbPair",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "binId",
-          "type": "i16",
+          "name": "rewardMint",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "tokenXFeeAmount",
+          "name": "funder",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "rewardIndex",
           "type": "u64",
           "index": false
         },
         {
-          "name": "tokenYFeeAmount",
+          "name": "rewardDuration",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "UpdateRewardDuration",
+      "fields": [
+        {
+          "name": "lbPair",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "rewardIndex",
           "type": "u64",
           "index": false
         },
         {
-          "name": "protocolTokenXFeeAmount",
+          "name": "oldRewardDuration",
           "type": "u64",
           "index": false
         },
         {
-          "name": "protocolTokenYFeeAmount",
+          "name": "newRewardDuration",
           "type": "u64",
           "index": false
         }
       ]
     },
     {
-      "name": "AddLiquidity",
+      "name": "UpdateRewardFunder",
       "fields": [
         {
           "name": "lbPair",
@@ -9692,7 +14170,42 @@ export const IDL: LbClmm = {
           "index": false
         },
         {
-          "name": "from",
+          "name": "rewardIndex",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "oldFunder",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "newFunder",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PositionClose",
+      "fields": [
+        {
+          "name": "position",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ClaimFee",
+      "fields": [
+        {
+          "name": "lbPair",
           "type": "publicKey",
           "index": false
         },
@@ -9702,24 +14215,24 @@ export const IDL: LbClmm = {
           "index": false
         },
         {
-          "name": "amounts",
-          "type": {
-            "array": [
-              "u64",
-              2
-            ]
-          },
+          "name": "owner",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "activeBinId",
-          "type": "i32",
+          "name": "feeX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "feeY",
+          "type": "u64",
           "index": false
         }
       ]
     },
     {
-      "name": "RemoveLiquidity",
+      "name": "LbPairCreate",
       "fields": [
         {
           "name": "lbPair",
@@ -9727,7 +14240,27 @@ export const IDL: LbClmm = {
           "index": false
         },
         {
-          "name": "from",
+          "name": "binStep",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "tokenX",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "tokenY",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PositionCreate",
+      "fields": [
+        {
+          "name": "lbPair",
           "type": "publicKey",
           "index": false
         },
@@ -9737,24 +14270,49 @@ export const IDL: LbClmm = {
           "index": false
         },
         {
-          "name": "amounts",
-          "type": {
-            "array": [
-              "u64",
-              2
-            ]
-          },
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "FeeParameterUpdate",
+      "fields": [
+        {
+          "name": "lbPair",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "activeBinId",
-          "type": "i32",
+          "name": "protocolShare",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "baseFactor",
+          "type": "u16",
           "index": false
         }
       ]
     },
     {
-      "name": "Swap",
+      "name": "IncreaseObservation",
+      "fields": [
+        {
+          "name": "oracle",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "newObservationLength",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "WithdrawIneligibleReward",
       "fields": [
         {
           "name": "lbPair",
@@ -9762,59 +14320,89 @@ export const IDL: LbClmm = {
           "index": false
         },
         {
-          "name": "from",
+          "name": "rewardMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "UpdatePositionOperator",
+      "fields": [
+        {
+          "name": "position",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "oldOperator",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "startBinId",
-          "type": "i32",
+          "name": "newOperator",
+          "type": "publicKey",
           "index": false
-        },
+        }
+      ]
+    },
+    {
+      "name": "TransferPositionOwner",
+      "fields": [
         {
-          "name": "endBinId",
-          "type": "i32",
+          "name": "position",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "amountIn",
-          "type": "u64",
+          "name": "oldOwner",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "amountOut",
-          "type": "u64",
+          "name": "newOwner",
+          "type": "publicKey",
           "index": false
-        },
+        }
+      ]
+    },
+    {
+      "name": "UpdatePositionLockReleasePoint",
+      "fields": [
         {
-          "name": "swapForY",
-          "type": "bool",
+          "name": "position",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "fee",
+          "name": "currentPoint",
           "type": "u64",
           "index": false
         },
         {
-          "name": "protocolFee",
+          "name": "newLockReleasePoint",
           "type": "u64",
           "index": false
         },
         {
-          "name": "feeBps",
-          "type": "u128",
+          "name": "oldLockReleasePoint",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "hostFee",
-          "type": "u64",
+          "name": "sender",
+          "type": "publicKey",
           "index": false
         }
       ]
     },
     {
-      "name": "ClaimReward",
+      "name": "GoToABin",
       "fields": [
         {
           "name": "lbPair",
@@ -9822,29 +14410,19 @@ export const IDL: LbClmm = {
           "index": false
         },
         {
-          "name": "position",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "owner",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "rewardIndex",
-          "type": "u64",
+          "name": "fromBinId",
+          "type": "i32",
           "index": false
         },
         {
-          "name": "totalReward",
-          "type": "u64",
+          "name": "toBinId",
+          "type": "i32",
           "index": false
         }
       ]
     },
     {
-      "name": "FundReward",
+      "name": "FlashLoan",
       "fields": [
         {
           "name": "lbPair",
@@ -9852,54 +14430,44 @@ export const IDL: LbClmm = {
           "index": false
         },
         {
-          "name": "funder",
+          "name": "user",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "rewardIndex",
+          "name": "amountX",
           "type": "u64",
           "index": false
         },
         {
-          "name": "amount",
+          "name": "amountY",
           "type": "u64",
           "index": false
-        }
-      ]
-    },
-    {
-      "name": "InitializeReward",
-      "fields": [
-        {
-          "name": "lbPair",
-          "type": "publicKey",
-          "index": false
         },
         {
-          "name": "rewardMint",
-          "type": "publicKey",
+          "name": "feeX",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "funder",
-          "type": "publicKey",
+          "name": "feeY",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "rewardIndex",
+          "name": "protocolFeeX",
           "type": "u64",
           "index": false
         },
         {
-          "name": "rewardDuration",
+          "name": "protocolFeeY",
           "type": "u64",
           "index": false
         }
       ]
     },
     {
-      "name": "UpdateRewardDuration",
+      "name": "CircuitBreakerParametersUpdate",
       "fields": [
         {
           "name": "lbPair",
@@ -9907,24 +14475,24 @@ export const IDL: LbClmm = {
           "index": false
         },
         {
-          "name": "rewardIndex",
-          "type": "u64",
+          "name": "maxBinMovement",
+          "type": "u16",
           "index": false
         },
         {
-          "name": "oldRewardDuration",
-          "type": "u64",
+          "name": "window",
+          "type": "u16",
           "index": false
         },
         {
-          "name": "newRewardDuration",
-          "type": "u64",
+          "name": "cooldown",
+          "type": "u32",
           "index": false
         }
       ]
     },
     {
-      "name": "UpdateRewardFunder",
+      "name": "CircuitBreakerTripped",
       "fields": [
         {
           "name": "lbPair",
@@ -9932,27 +14500,27 @@ export const IDL: LbClmm = {
           "index": false
         },
         {
-          "name": "rewardIndex",
-          "type": "u64",
+          "name": "activeId",
+          "type": "i32",
           "index": false
         },
         {
-          "name": "oldFunder",
-          "type": "publicKey",
+          "name": "timeWeightedActiveId",
+          "type": "i32",
           "index": false
         },
         {
-          "name": "newFunder",
-          "type": "publicKey",
+          "name": "haltedUntil",
+          "type": "i64",
           "index": false
         }
       ]
     },
     {
-      "name": "PositionClose",
+      "name": "ReferrerUpdate",
       "fields": [
         {
-          "name": "position",
+          "name": "referrer",
           "type": "publicKey",
           "index": false
         },
@@ -9960,11 +14528,16 @@ export const IDL: LbClmm = {
           "name": "owner",
           "type": "publicKey",
           "index": false
+        },
+        {
+          "name": "feeShare",
+          "type": "u16",
+          "index": false
         }
       ]
     },
     {
-      "name": "ClaimFee",
+      "name": "ClaimReferralFee",
       "fields": [
         {
           "name": "lbPair",
@@ -9972,7 +14545,7 @@ export const IDL: LbClmm = {
           "index": false
         },
         {
-          "name": "position",
+          "name": "referrer",
           "type": "publicKey",
           "index": false
         },
@@ -9994,7 +14567,7 @@ export const IDL: LbClmm = {
       ]
     },
     {
-      "name": "LbPairCreate",
+      "name": "CompoundFee",
       "fields": [
         {
           "name": "lbPair",
@@ -10002,24 +14575,34 @@ export const IDL: LbClmm = {
           "index": false
         },
         {
-          "name": "binStep",
-          "type": "u16",
+          "name": "position",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "tokenX",
+          "name": "owner",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "tokenY",
-          "type": "publicKey",
+          "name": "amounts",
+          "type": {
+            "array": [
+              "u64",
+              2
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "activeBinId",
+          "type": "i32",
           "index": false
         }
       ]
     },
     {
-      "name": "PositionCreate",
+      "name": "BinArrayClose",
       "fields": [
         {
           "name": "lbPair",
@@ -10027,124 +14610,119 @@ export const IDL: LbClmm = {
           "index": false
         },
         {
-          "name": "position",
+          "name": "binArray",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "owner",
+          "name": "index",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "funder",
           "type": "publicKey",
           "index": false
         }
       ]
     },
     {
-      "name": "FeeParameterUpdate",
+      "name": "UpdatePositionVesting",
       "fields": [
         {
-          "name": "lbPair",
+          "name": "position",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "protocolShare",
-          "type": "u16",
+          "name": "startPoint",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "baseFactor",
-          "type": "u16",
+          "name": "cliffPoint",
+          "type": "u64",
           "index": false
-        }
-      ]
-    },
-    {
-      "name": "IncreaseObservation",
-      "fields": [
+        },
         {
-          "name": "oracle",
-          "type": "publicKey",
+          "name": "endPoint",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "newObservationLength",
-          "type": "u64",
+          "name": "sender",
+          "type": "publicKey",
           "index": false
         }
       ]
     },
     {
-      "name": "WithdrawIneligibleReward",
+      "name": "InitializeAdminConfig",
       "fields": [
         {
-          "name": "lbPair",
+          "name": "feeAdmin",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "rewardMint",
+          "name": "pauseGuardian",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "amount",
-          "type": "u64",
+          "name": "rewardAdmin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "launchPoolAdmin",
+          "type": "publicKey",
           "index": false
         }
       ]
     },
     {
-      "name": "UpdatePositionOperator",
+      "name": "ProposeAdminRole",
       "fields": [
         {
-          "name": "position",
-          "type": "publicKey",
+          "name": "role",
+          "type": "u8",
           "index": false
         },
         {
-          "name": "oldOperator",
+          "name": "authority",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "newOperator",
+          "name": "pendingAuthority",
           "type": "publicKey",
           "index": false
         }
       ]
     },
     {
-      "name": "UpdatePositionLockReleasePoint",
+      "name": "AcceptAdminRole",
       "fields": [
         {
-          "name": "position",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "currentPoint",
-          "type": "u64",
-          "index": false
-        },
-        {
-          "name": "newLockReleasePoint",
-          "type": "u64",
+          "name": "role",
+          "type": "u8",
           "index": false
         },
         {
-          "name": "oldLockReleasePoint",
-          "type": "u64",
+          "name": "oldAuthority",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "sender",
+          "name": "newAuthority",
           "type": "publicKey",
           "index": false
         }
       ]
     },
     {
-      "name": "GoToABin",
+      "name": "OracleParametersUpdate",
       "fields": [
         {
           "name": "lbPair",
@@ -10152,13 +14730,18 @@ export const IDL: LbClmm = {
           "index": false
         },
         {
-          "name": "fromBinId",
-          "type": "i32",
+          "name": "sampleLifetime",
+          "type": "u16",
           "index": false
         },
         {
-          "name": "toBinId",
-          "type": "i32",
+          "name": "targetHistoryDuration",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "autoGrowth",
+          "type": "bool",
           "index": false
         }
       ]
@@ -10494,6 +15077,111 @@ export const IDL: LbClmm = {
       "code": 6065,
       "name": "AlreadyPassPreActivationSwapPoint",
       "msg": "Already pass pre-activation swap point"
+    },
+    {
+      "code": 6066,
+      "name": "OwnersAreTheSame",
+      "msg": "Cannot transfer because owners are the same"
+    },
+    {
+      "code": 6067,
+      "name": "InvalidPositionNftAccount",
+      "msg": "Position NFT account is missing or not holding the position NFT"
+    },
+    {
+      "code": 6068,
+      "name": "PositionIsTokenized",
+      "msg": "Tokenized position can only be transferred through the position NFT"
+    },
+    {
+      "code": 6069,
+      "name": "InvalidPositionNftUri",
+      "msg": "Invalid position NFT metadata uri"
+    },
+    {
+      "code": 6070,
+      "name": "UnsupportedMintExtension",
+      "msg": "Unsupported mint extension"
+    },
+    {
+      "code": 6071,
+      "name": "FlashLoanNotRepaid",
+      "msg": "Flash loan is not repaid in the same transaction"
+    },
+    {
+      "code": 6072,
+      "name": "InvalidFlashLoanInstruction",
+      "msg": "Invalid flash borrow or flash repay instruction"
+    },
+    {
+      "code": 6073,
+      "name": "FlashLoanCpiNotAllowed",
+      "msg": "Flash loan is not allowed through CPI"
+    },
+    {
+      "code": 6074,
+      "name": "CircuitBreakerHalted",
+      "msg": "Swap is halted by circuit breaker"
+    },
+    {
+      "code": 6075,
+      "name": "InvalidCircuitBreakerParameters",
+      "msg": "Invalid circuit breaker parameters"
+    },
+    {
+      "code": 6076,
+      "name": "InvalidReferrerFeeShare",
+      "msg": "Invalid referrer fee share"
+    },
+    {
+      "code": 6077,
+      "name": "InvalidReferralFeeAccount",
+      "msg": "Referral fee account does not belong to the referrer"
+    },
+    {
+      "code": 6078,
+      "name": "SwapDeadlineExceeded",
+      "msg": "Swap deadline exceeded"
+    },
+    {
+      "code": 6079,
+      "name": "ExceededPriceImpactTolerance",
+      "msg": "Exceeded price impact tolerance"
+    },
+    {
+      "code": 6080,
+      "name": "NonEmptyBinArray",
+      "msg": "Bin array is not empty"
+    },
+    {
+      "code": 6081,
+      "name": "InconsistentBinArrayBitmap",
+      "msg": "Bin array bitmap is inconsistent with the bin array liquidity"
+    },
+    {
+      "code": 6082,
+      "name": "InvalidVestingSchedule",
+      "msg": "Invalid vesting schedule"
+    },
+    {
+      "code": 6083,
+      "name": "UnevenVestingWithdrawal",
+      "msg": "Vesting liquidity must be withdrawn evenly from every bin of the position"
+    },
+    {
+      "code": 6084,
+      "name": "InvalidSwapRoute",
+      "msg": "Invalid swap route"
+    },
+    {
+      "code": 6085,
+      "name": "InvalidOracleParameters",
+      "msg": "Invalid oracle parameters"
+    },
+    {
+      "code": 6086,
+      "name": "BinArrayFunderRecorded",
+      "msg": "Bin array has a recorded funder"
     }
   ]
 };
@@ -44,6 +44,7 @@ import {
   chunks,
   computeFee,
   computeFeeFromAmount,
+  deriveAdminConfig,
   deriveBinArray,
   deriveBinArrayBitmapExtension,
   deriveCustomizablePermissionlessLbPair,
@@ -864,6 +865,7 @@ export class DLMM {
     const [reserveX] = deriveReserve(tokenX, lbPair, program.programId);
     const [reserveY] = deriveReserve(tokenY, lbPair, program.programId);
     const [oracle] = deriveOracle(lbPair, program.programId);
+    const [adminConfig] = deriveAdminConfig(program.programId);
 
     const activeBinArrayIndex = binIdToBinArrayIndex(activeId);
     const binArrayBitmapExtension = isOverflowDefaultBinArrayBitmap(
@@ -898,6 +900,7 @@ export class DLMM {
         systemProgram: SystemProgram.programId,
         admin: creatorKey,
         base: baseKey,
+        adminConfig,
       })
       .transaction();
   }
@@ -0,0 +1,3 @@
+//! Builds the WebAssembly exports of the `wasm` feature of `commons` as a cdylib.
+
+pub use commons::wasm::*;